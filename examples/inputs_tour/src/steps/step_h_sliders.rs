@@ -103,38 +103,38 @@ impl Default for HSliderStep {
                 1,
                 1,
                 1,
-                Some(tick_marks::Tier::Two),
+                Some(tick_marks::Tier::TWO),
             ),
 
             int_tick_marks: tick_marks::Group::evenly_spaced(
                 6,
-                tick_marks::Tier::Two,
+                tick_marks::Tier::TWO,
             ),
 
             db_tick_marks: vec![
-                (db_range.map_to_normal(0.0), tick_marks::Tier::One),
-                (db_range.map_to_normal(1.0), tick_marks::Tier::Two),
-                (db_range.map_to_normal(3.0), tick_marks::Tier::Two),
-                (db_range.map_to_normal(6.0), tick_marks::Tier::Two),
-                (db_range.map_to_normal(12.0), tick_marks::Tier::Two),
-                (db_range.map_to_normal(-1.0), tick_marks::Tier::Two),
-                (db_range.map_to_normal(-3.0), tick_marks::Tier::Two),
-                (db_range.map_to_normal(-6.0), tick_marks::Tier::Two),
-                (db_range.map_to_normal(-12.0), tick_marks::Tier::Two),
+                (db_range.map_to_normal(0.0), tick_marks::Tier::ONE),
+                (db_range.map_to_normal(1.0), tick_marks::Tier::TWO),
+                (db_range.map_to_normal(3.0), tick_marks::Tier::TWO),
+                (db_range.map_to_normal(6.0), tick_marks::Tier::TWO),
+                (db_range.map_to_normal(12.0), tick_marks::Tier::TWO),
+                (db_range.map_to_normal(-1.0), tick_marks::Tier::TWO),
+                (db_range.map_to_normal(-3.0), tick_marks::Tier::TWO),
+                (db_range.map_to_normal(-6.0), tick_marks::Tier::TWO),
+                (db_range.map_to_normal(-12.0), tick_marks::Tier::TWO),
             ]
             .into(),
 
             freq_tick_marks: vec![
-                (freq_range.map_to_normal(20.0), tick_marks::Tier::Two),
-                (freq_range.map_to_normal(50.0), tick_marks::Tier::Two),
-                (freq_range.map_to_normal(100.0), tick_marks::Tier::One),
-                (freq_range.map_to_normal(200.0), tick_marks::Tier::Two),
-                (freq_range.map_to_normal(400.0), tick_marks::Tier::Two),
-                (freq_range.map_to_normal(1000.0), tick_marks::Tier::One),
-                (freq_range.map_to_normal(2000.0), tick_marks::Tier::Two),
-                (freq_range.map_to_normal(5000.0), tick_marks::Tier::Two),
-                (freq_range.map_to_normal(10000.0), tick_marks::Tier::One),
-                (freq_range.map_to_normal(20000.0), tick_marks::Tier::Two),
+                (freq_range.map_to_normal(20.0), tick_marks::Tier::TWO),
+                (freq_range.map_to_normal(50.0), tick_marks::Tier::TWO),
+                (freq_range.map_to_normal(100.0), tick_marks::Tier::ONE),
+                (freq_range.map_to_normal(200.0), tick_marks::Tier::TWO),
+                (freq_range.map_to_normal(400.0), tick_marks::Tier::TWO),
+                (freq_range.map_to_normal(1000.0), tick_marks::Tier::ONE),
+                (freq_range.map_to_normal(2000.0), tick_marks::Tier::TWO),
+                (freq_range.map_to_normal(5000.0), tick_marks::Tier::TWO),
+                (freq_range.map_to_normal(10000.0), tick_marks::Tier::ONE),
+                (freq_range.map_to_normal(20000.0), tick_marks::Tier::TWO),
             ]
             .into(),
 
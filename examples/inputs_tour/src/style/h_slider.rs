@@ -8,13 +8,13 @@ use super::colors;
 pub struct RectStyle;
 impl RectStyle {
     const ACTIVE_RECT_STYLE: h_slider::RectStyle = h_slider::RectStyle {
-        back_color: colors::EMPTY,
+        back_color: h_slider::Background::Color(colors::EMPTY),
         back_border_width: 1.0,
         back_border_radius: 2.0,
         back_border_color: colors::BORDER,
-        filled_color: colors::FILLED,
-        handle_width: 4,
-        handle_color: colors::HANDLE,
+        filled_color: h_slider::Background::Color(colors::FILLED),
+        handle_width: h_slider::StyleLength::Units(4.0),
+        handle_color: h_slider::Background::Color(colors::HANDLE),
         handle_filled_gap: 1.0,
     };
 }
@@ -25,8 +25,8 @@ impl h_slider::StyleSheet for RectStyle {
 
     fn hovered(&self) -> h_slider::Style {
         h_slider::Style::Rect(h_slider::RectStyle {
-            filled_color: colors::FILLED_HOVER,
-            handle_width: 5,
+            filled_color: h_slider::Background::Color(colors::FILLED_HOVER),
+            handle_width: h_slider::StyleLength::Units(5.0),
             ..Self::ACTIVE_RECT_STYLE
         })
     }
@@ -47,6 +47,7 @@ impl h_slider::StyleSheet for RectStyle {
             back_color: Some(colors::KNOB_ARC_EMPTY),
             filled_color: colors::KNOB_ARC,
             filled_inverse_color: colors::KNOB_ARC_RIGHT,
+            insets: Default::default(),
         })
     }
 }
@@ -57,13 +58,15 @@ pub struct RectBipolarStyle;
 impl RectBipolarStyle {
     const ACTIVE_RECT_STYLE: h_slider::RectBipolarStyle =
         h_slider::RectBipolarStyle {
-            back_color: colors::EMPTY,
+            back_color: h_slider::Background::Color(colors::EMPTY),
             back_border_width: 1.0,
             back_border_radius: 2.0,
             back_border_color: colors::BORDER,
-            left_filled_color: colors::FILLED,
-            right_filled_color: Color::from_rgb(0.0, 0.605, 0.0),
-            handle_width: 4,
+            left_filled_color: h_slider::Background::Color(colors::FILLED),
+            right_filled_color: h_slider::Background::Color(Color::from_rgb(
+                0.0, 0.605, 0.0,
+            )),
+            handle_width: h_slider::StyleLength::Units(4.0),
             handle_left_color: colors::HANDLE,
             handle_right_color: Color::from_rgb(0.0, 0.9, 0.0),
             handle_center_color: Color::from_rgb(0.7, 0.7, 0.7),
@@ -77,9 +80,13 @@ impl h_slider::StyleSheet for RectBipolarStyle {
 
     fn hovered(&self) -> h_slider::Style {
         h_slider::Style::RectBipolar(h_slider::RectBipolarStyle {
-            left_filled_color: colors::FILLED_HOVER,
-            right_filled_color: Color::from_rgb(0.0, 0.64, 0.0),
-            handle_width: 5,
+            left_filled_color: h_slider::Background::Color(
+                colors::FILLED_HOVER,
+            ),
+            right_filled_color: h_slider::Background::Color(Color::from_rgb(
+                0.0, 0.64, 0.0,
+            )),
+            handle_width: h_slider::StyleLength::Units(5.0),
             ..Self::ACTIVE_RECT_STYLE
         })
     }
@@ -97,15 +104,19 @@ impl h_slider::StyleSheet for TextureStyle {
         h_slider::Style::Texture(h_slider::TextureStyle {
             rail: h_slider::ClassicRail {
                 rail_colors: (
-                    [0.0, 0.0, 0.0, 0.9].into(),
-                    [0.36, 0.36, 0.36, 0.75].into(),
+                    h_slider::Background::Color([0.0, 0.0, 0.0, 0.9].into()),
+                    h_slider::Background::Color(
+                        [0.36, 0.36, 0.36, 0.75].into(),
+                    ),
                 ),
                 rail_widths: (1.0, 2.0),
                 rail_padding: 14.0,
+                notches: None,
             },
             handle_width: 38,
             image_handle: self.0.clone(),
             image_bounds: self.1,
+            sprite_frame: None,
         })
     }
 
@@ -120,21 +131,31 @@ impl h_slider::StyleSheet for TextureStyle {
     fn tick_marks_style(&self) -> Option<h_slider::TickMarksStyle> {
         Some(h_slider::TickMarksStyle {
             style: tick_marks::Style {
-                tier_1: tick_marks::Shape::Line {
-                    length: 12.0,
-                    width: 2.0,
-                    color: [0.56, 0.56, 0.56, 0.75].into(),
-                },
-                tier_2: tick_marks::Shape::Line {
-                    length: 10.0,
-                    width: 1.0,
-                    color: [0.56, 0.56, 0.56, 0.75].into(),
-                },
-                tier_3: tick_marks::Shape::Line {
-                    length: 8.0,
-                    width: 1.0,
-                    color: [0.56, 0.56, 0.56, 0.75].into(),
-                },
+                tiers: vec![
+                    tick_marks::Shape::Line {
+                        length: 12.0,
+                        width: 2.0,
+                        color: [0.56, 0.56, 0.56, 0.75].into(),
+                        line_cap: tick_marks::LineCap::Butt,
+                        dash: None,
+                    },
+                    tick_marks::Shape::Line {
+                        length: 10.0,
+                        width: 1.0,
+                        color: [0.56, 0.56, 0.56, 0.75].into(),
+                        line_cap: tick_marks::LineCap::Butt,
+                        dash: None,
+                    },
+                    tick_marks::Shape::Line {
+                        length: 8.0,
+                        width: 1.0,
+                        color: [0.56, 0.56, 0.56, 0.75].into(),
+                        line_cap: tick_marks::LineCap::Butt,
+                        dash: None,
+                    },
+                ],
+                gradient: None,
+                insets: Default::default(),
             },
             placement: tick_marks::Placement::CenterSplit {
                 offset: Offset::ZERO,
@@ -152,6 +173,9 @@ impl h_slider::StyleSheet for TextureStyle {
                 font: Default::default(),
                 bounds_width: 30,
                 bounds_height: 14,
+                orientation: text_marks::Orientation::Horizontal,
+                background: None,
+                insets: Default::default(),
             },
             placement: text_marks::Placement::Center {
                 align: text_marks::Align::Start,
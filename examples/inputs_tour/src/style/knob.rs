@@ -15,10 +15,12 @@ impl CustomStyleCircle {
         offset: knob::StyleLength::Scaled(0.21),
     };
     const ACTIVE_CIRCLE_STYLE: knob::CircleStyle = knob::CircleStyle {
-        color: colors::KNOB,
+        color: knob::Background::Color(colors::KNOB),
         border_width: 3.0,
         border_color: colors::KNOB_BORDER,
         notch: knob::NotchShape::Circle(Self::ACTIVE_CIRCLE_NOTCH),
+        shadow: None,
+        highlight: None,
     };
 }
 impl knob::StyleSheet for CustomStyleCircle {
@@ -71,6 +73,9 @@ impl knob::StyleSheet for CustomStyleCircle {
                 font: Default::default(),
                 bounds_width: 20,
                 bounds_height: 20,
+                orientation: text_marks::Orientation::Horizontal,
+                background: None,
+                insets: Default::default(),
             },
             offset: 15.0,
             h_char_offset: 3.0,
@@ -89,12 +94,16 @@ impl CustomStyleLine {
         length: knob::StyleLength::Scaled(0.12),
         offset: knob::StyleLength::Units(5.0),
         cap: knob::LineCap::Round,
+        length_scale_with_value: None,
+        max_value_color: None,
     };
     const ACTIVE_CIRCLE_STYLE: knob::CircleStyle = knob::CircleStyle {
-        color: colors::KNOB,
+        color: knob::Background::Color(colors::KNOB),
         border_width: 0.0,
         border_color: Color::TRANSPARENT,
         notch: knob::NotchShape::Line(Self::ACTIVE_CIRCLE_NOTCH),
+        shadow: None,
+        highlight: None,
     };
 }
 impl knob::StyleSheet for CustomStyleLine {
@@ -138,6 +147,8 @@ impl knob::StyleSheet for CustomArc {
                 length: knob::StyleLength::Scaled(0.25),
                 cap: knob::LineCap::Round,
                 offset: knob::StyleLength::Units(2.5),
+                length_scale_with_value: None,
+                max_value_color: None,
             }),
             cap: knob::LineCap::Round,
         })
@@ -177,6 +188,8 @@ impl CustomArcBipolar {
         length: knob::StyleLength::Scaled(0.39),
         cap: knob::LineCap::Butt,
         offset: knob::StyleLength::Units(0.0),
+        length_scale_with_value: None,
+        max_value_color: None,
     };
 }
 impl knob::StyleSheet for CustomArcBipolar {
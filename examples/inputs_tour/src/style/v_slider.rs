@@ -8,13 +8,13 @@ use super::colors;
 pub struct RectStyle;
 impl RectStyle {
     const ACTIVE_RECT_STYLE: v_slider::RectStyle = v_slider::RectStyle {
-        back_color: colors::EMPTY,
+        back_color: v_slider::Background::Color(colors::EMPTY),
         back_border_width: 1.0,
         back_border_radius: 2.0,
         back_border_color: colors::BORDER,
-        filled_color: colors::FILLED,
-        handle_height: 4,
-        handle_color: colors::HANDLE,
+        filled_color: v_slider::Background::Color(colors::FILLED),
+        handle_height: v_slider::StyleLength::Units(4.0),
+        handle_color: v_slider::Background::Color(colors::HANDLE),
         handle_filled_gap: 1.0,
     };
 }
@@ -25,8 +25,8 @@ impl v_slider::StyleSheet for RectStyle {
 
     fn hovered(&self) -> v_slider::Style {
         v_slider::Style::Rect(v_slider::RectStyle {
-            filled_color: colors::FILLED_HOVER,
-            handle_height: 5,
+            filled_color: v_slider::Background::Color(colors::FILLED_HOVER),
+            handle_height: v_slider::StyleLength::Units(5.0),
             ..Self::ACTIVE_RECT_STYLE
         })
     }
@@ -56,6 +56,7 @@ impl v_slider::StyleSheet for RectStyle {
                 b: 0.0,
                 a: 0.2,
             },
+            insets: Default::default(),
         })
     }
 }
@@ -66,13 +67,15 @@ pub struct RectBipolarStyle;
 impl RectBipolarStyle {
     const ACTIVE_RECT_STYLE: v_slider::RectBipolarStyle =
         v_slider::RectBipolarStyle {
-            back_color: colors::EMPTY,
+            back_color: v_slider::Background::Color(colors::EMPTY),
             back_border_width: 1.0,
             back_border_radius: 2.0,
             back_border_color: colors::BORDER,
-            top_filled_color: colors::FILLED,
-            bottom_filled_color: Color::from_rgb(0.0, 0.605, 0.0),
-            handle_height: 4,
+            top_filled_color: v_slider::Background::Color(colors::FILLED),
+            bottom_filled_color: v_slider::Background::Color(Color::from_rgb(
+                0.0, 0.605, 0.0,
+            )),
+            handle_height: v_slider::StyleLength::Units(4.0),
             handle_top_color: colors::HANDLE,
             handle_bottom_color: Color::from_rgb(0.0, 0.9, 0.0),
             handle_center_color: Color::from_rgb(0.7, 0.7, 0.7),
@@ -86,9 +89,11 @@ impl v_slider::StyleSheet for RectBipolarStyle {
 
     fn hovered(&self) -> v_slider::Style {
         v_slider::Style::RectBipolar(v_slider::RectBipolarStyle {
-            top_filled_color: colors::FILLED_HOVER,
-            bottom_filled_color: Color::from_rgb(0.0, 0.64, 0.0),
-            handle_height: 5,
+            top_filled_color: v_slider::Background::Color(colors::FILLED_HOVER),
+            bottom_filled_color: v_slider::Background::Color(Color::from_rgb(
+                0.0, 0.64, 0.0,
+            )),
+            handle_height: v_slider::StyleLength::Units(5.0),
             ..Self::ACTIVE_RECT_STYLE
         })
     }
@@ -106,15 +111,19 @@ impl v_slider::StyleSheet for TextureStyle {
         v_slider::Style::Texture(v_slider::TextureStyle {
             rail: v_slider::ClassicRail {
                 rail_colors: (
-                    [0.0, 0.0, 0.0, 0.9].into(),
-                    [0.36, 0.36, 0.36, 0.75].into(),
+                    v_slider::Background::Color([0.0, 0.0, 0.0, 0.9].into()),
+                    v_slider::Background::Color(
+                        [0.36, 0.36, 0.36, 0.75].into(),
+                    ),
                 ),
                 rail_widths: (1.0, 2.0),
                 rail_padding: 14.0,
+                notches: None,
             },
             handle_height: 38,
             image_handle: self.0.clone(),
             image_bounds: self.1,
+            sprite_frame: None,
         })
     }
 
@@ -129,21 +138,31 @@ impl v_slider::StyleSheet for TextureStyle {
     fn tick_marks_style(&self) -> Option<v_slider::TickMarksStyle> {
         Some(v_slider::TickMarksStyle {
             style: tick_marks::Style {
-                tier_1: tick_marks::Shape::Line {
-                    length: 12.0,
-                    width: 2.0,
-                    color: [0.56, 0.56, 0.56, 0.75].into(),
-                },
-                tier_2: tick_marks::Shape::Line {
-                    length: 10.0,
-                    width: 1.0,
-                    color: [0.56, 0.56, 0.56, 0.75].into(),
-                },
-                tier_3: tick_marks::Shape::Line {
-                    length: 8.0,
-                    width: 1.0,
-                    color: [0.56, 0.56, 0.56, 0.75].into(),
-                },
+                tiers: vec![
+                    tick_marks::Shape::Line {
+                        length: 12.0,
+                        width: 2.0,
+                        color: [0.56, 0.56, 0.56, 0.75].into(),
+                        line_cap: tick_marks::LineCap::Butt,
+                        dash: None,
+                    },
+                    tick_marks::Shape::Line {
+                        length: 10.0,
+                        width: 1.0,
+                        color: [0.56, 0.56, 0.56, 0.75].into(),
+                        line_cap: tick_marks::LineCap::Butt,
+                        dash: None,
+                    },
+                    tick_marks::Shape::Line {
+                        length: 8.0,
+                        width: 1.0,
+                        color: [0.56, 0.56, 0.56, 0.75].into(),
+                        line_cap: tick_marks::LineCap::Butt,
+                        dash: None,
+                    },
+                ],
+                gradient: None,
+                insets: Default::default(),
             },
             placement: tick_marks::Placement::CenterSplit {
                 offset: Offset::ZERO,
@@ -161,6 +180,9 @@ impl v_slider::StyleSheet for TextureStyle {
                 font: Default::default(),
                 bounds_width: 30,
                 bounds_height: 14,
+                orientation: text_marks::Orientation::Horizontal,
+                background: None,
+                insets: Default::default(),
             },
             placement: text_marks::Placement::Center {
                 align: text_marks::Align::End,
@@ -13,15 +13,17 @@ impl CustomStyle {
         border_width: 1.0,
         border_radius: 2.0,
         border_color: colors::HANDLE,
+        shadow: None,
     };
     const ACTIVE_STYLE: xy_pad::Style = xy_pad::Style {
         rail_width: 1.0,
         h_rail_color: colors::HANDLE,
         v_rail_color: colors::HANDLE,
         handle: xy_pad::HandleShape::Square(Self::ACTIVE_HANDLE),
-        back_color: colors::EMPTY,
+        back_color: xy_pad::Background::Color(colors::EMPTY),
         border_width: 2.0,
         border_color: Color::BLACK,
+        back_image: None,
         center_line_width: 1.0,
         center_line_color: Color {
             r: 0.2,
@@ -29,6 +31,7 @@ impl CustomStyle {
             b: 0.2,
             a: 0.7,
         },
+        grid: None,
     };
 }
 impl xy_pad::StyleSheet for CustomStyle {
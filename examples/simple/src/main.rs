@@ -84,7 +84,7 @@ impl Sandbox for App {
             ),
 
             // Add a tick mark at the center position with the tier 2 size
-            center_tick_mark: tick_marks::Group::center(tick_marks::Tier::Two),
+            center_tick_mark: tick_marks::Group::center(tick_marks::Tier::TWO),
 
             output_text: "Move a widget!".into(),
         }
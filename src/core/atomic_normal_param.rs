@@ -0,0 +1,111 @@
+//! Module for the [`AtomicNormalParam`] struct
+//!
+//! [`AtomicNormalParam`]: struct.AtomicNormalParam.html
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use crate::core::{Normal, NormalParam};
+
+/// A [`NormalParam`] that can be shared between threads without locking,
+/// such as between the GUI and an audio thread.
+///
+/// The values are stored as the bits of an `f32` in an `AtomicU32`, so
+/// loads and stores are lock-free. Wrap it in an `Arc` to share it.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Arc;
+/// use iced_audio::{AtomicNormalParam, Normal, NormalParam};
+///
+/// let param = Arc::new(AtomicNormalParam::new(NormalParam::default()));
+///
+/// // In the GUI thread, when the widget reports a change
+/// param.set_value(Normal::new(0.75));
+///
+/// // In the audio thread
+/// assert_eq!(param.value().as_f32(), 0.75);
+/// ```
+///
+/// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+#[derive(Debug)]
+pub struct AtomicNormalParam {
+    value: AtomicU32,
+    default: AtomicU32,
+}
+
+impl AtomicNormalParam {
+    /// Creates a new `AtomicNormalParam` from a [`NormalParam`].
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn new(normal_param: NormalParam) -> Self {
+        Self {
+            value: AtomicU32::new(normal_param.value.as_f32().to_bits()),
+            default: AtomicU32::new(normal_param.default.as_f32().to_bits()),
+        }
+    }
+
+    /// Returns the value of the parameter.
+    #[inline]
+    pub fn value(&self) -> Normal {
+        load(&self.value)
+    }
+
+    /// Sets the value of the parameter.
+    #[inline]
+    pub fn set_value(&self, value: Normal) {
+        store(&self.value, value)
+    }
+
+    /// Returns the default value of the parameter.
+    #[inline]
+    pub fn default_value(&self) -> Normal {
+        load(&self.default)
+    }
+
+    /// Sets the default value of the parameter.
+    #[inline]
+    pub fn set_default_value(&self, default: Normal) {
+        store(&self.default, default)
+    }
+
+    /// Returns a [`NormalParam`] with the value and the default value of
+    /// the parameter, such as for the state of a widget.
+    ///
+    /// The value and the default value are loaded separately, so a
+    /// concurrent [`store`](#method.store) may be seen partially.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn load(&self) -> NormalParam {
+        NormalParam::new(self.value(), self.default_value())
+    }
+
+    /// Sets the value and the default value of the parameter from a
+    /// [`NormalParam`].
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn store(&self, normal_param: NormalParam) {
+        self.set_value(normal_param.value);
+        self.set_default_value(normal_param.default);
+    }
+}
+
+impl Default for AtomicNormalParam {
+    fn default() -> Self {
+        Self::new(NormalParam::default())
+    }
+}
+
+impl From<NormalParam> for AtomicNormalParam {
+    fn from(normal_param: NormalParam) -> Self {
+        Self::new(normal_param)
+    }
+}
+
+fn load(atomic: &AtomicU32) -> Normal {
+    f32::from_bits(atomic.load(Ordering::Relaxed)).into()
+}
+
+fn store(atomic: &AtomicU32, normal: Normal) {
+    atomic.store(normal.as_f32().to_bits(), Ordering::Relaxed)
+}
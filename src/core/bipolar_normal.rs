@@ -0,0 +1,134 @@
+//! An `f32` value that is gauranteed to be constrained to the range of
+//!
+//! `-1.0 >= value <= 1.0`
+
+use crate::core::Normal;
+
+/// An `f32` value that is gauranteed to be constrained to the range of
+///
+/// `-1.0 >= value <= 1.0`
+///
+/// This is useful for bipolar parameters, such as a pan or a pitch bend,
+/// where `0.0` is the center. A `BipolarNormal` converts to and from a
+/// [`Normal`], where the center is `0.5`.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{BipolarNormal, Normal};
+///
+/// let mut bipolar = BipolarNormal::new(-3.0);
+/// assert_eq!(bipolar.as_f32(), -1.0);
+///
+/// bipolar.set(0.5);
+/// assert_eq!(Normal::from(bipolar).as_f32(), 0.75);
+///
+/// let bipolar = BipolarNormal::from(Normal::center());
+/// assert_eq!(bipolar.as_f32(), 0.0);
+/// ```
+///
+/// [`Normal`]: ../normal/struct.Normal.html
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "f32", into = "f32")
+)]
+pub struct BipolarNormal {
+    value: f32,
+}
+
+impl Default for BipolarNormal {
+    fn default() -> Self {
+        Self { value: 0.0 }
+    }
+}
+
+impl BipolarNormal {
+    /// Creates a new `BipolarNormal`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - the value to initialize the `BipolarNormal` with
+    ///
+    /// if `value < -1.0`, then `bipolar.value` is set to `-1.0`
+    ///
+    /// else if `value > 1.0`, then `bipolar.value` is set to `1.0`
+    ///
+    /// else `bipolar.value` is set to `value`
+    pub fn new(value: f32) -> Self {
+        let mut bipolar = Self::default();
+        bipolar.set(value);
+        bipolar
+    }
+
+    /// Returns a `BipolarNormal` with the value `-1.0`.
+    pub fn min() -> Self {
+        Self { value: -1.0 }
+    }
+
+    /// Returns a `BipolarNormal` with the value `1.0`.
+    pub fn max() -> Self {
+        Self { value: 1.0 }
+    }
+
+    /// Returns a `BipolarNormal` with the value `0.0`.
+    pub fn center() -> Self {
+        Self { value: 0.0 }
+    }
+
+    /// Set a value for the `BipolarNormal`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - the value to set the `BipolarNormal` with
+    ///
+    /// if `value < -1.0`, then `bipolar.value` is set to `-1.0`
+    ///
+    /// else if `value > 1.0`, then `bipolar.value` is set to `1.0`
+    ///
+    /// else `bipolar.value` is set to `value`
+    #[inline]
+    pub fn set(&mut self, value: f32) {
+        self.value = value.clamp(-1.0, 1.0);
+    }
+
+    /// Returns the value of the `BipolarNormal` as an `f32`
+    #[inline]
+    pub fn as_f32(&self) -> f32 {
+        self.value
+    }
+
+    /// Returns the value of the `BipolarNormal` as a [`Normal`], where
+    /// `-1.0` is `0.0` and `1.0` is `1.0`.
+    ///
+    /// [`Normal`]: ../normal/struct.Normal.html
+    #[inline]
+    pub fn as_normal(&self) -> Normal {
+        Normal::new((self.value + 1.0) / 2.0)
+    }
+}
+
+impl From<f32> for BipolarNormal {
+    fn from(value: f32) -> Self {
+        BipolarNormal::new(value)
+    }
+}
+
+impl From<BipolarNormal> for f32 {
+    fn from(bipolar: BipolarNormal) -> f32 {
+        bipolar.value
+    }
+}
+
+impl From<Normal> for BipolarNormal {
+    fn from(normal: Normal) -> Self {
+        BipolarNormal::new(normal.as_f32() * 2.0 - 1.0)
+    }
+}
+
+impl From<BipolarNormal> for Normal {
+    fn from(bipolar: BipolarNormal) -> Normal {
+        bipolar.as_normal()
+    }
+}
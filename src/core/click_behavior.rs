@@ -0,0 +1,17 @@
+//! How a slider responds to a click on its rail
+
+/// How a slider responds when it is clicked
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum ClickBehavior {
+    /// The handle jumps to the cursor when clicked and follows the cursor
+    /// while dragging. The drag becomes relative while the modifier key is
+    /// held down or while the cursor is outside of the window.
+    JumpToCursor,
+    /// The handle stays where it is when clicked and moves relative to the
+    /// cursor while dragging.
+    #[default]
+    RelativeDrag,
+    /// The handle jumps to the cursor when clicked and then moves relative
+    /// to the cursor while dragging.
+    JumpThenDrag,
+}
@@ -0,0 +1,89 @@
+//! Easing curves for animations
+//!
+//! These are used by the animations of the widgets, such as the smoothing
+//! and the spring return, and can be reused by applications.
+
+use std::f32::consts::PI;
+
+/// An easing curve that maps the linear progress of an animation to the
+/// progress of the animated value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Easing {
+    /// Constant speed
+    Linear,
+    /// Starts slow and speeds up, with a quadratic curve
+    EaseInQuad,
+    /// Starts fast and slows down towards the end, with a quadratic curve
+    EaseOutQuad,
+    /// Starts slow, speeds up, and slows down towards the end, with a
+    /// quadratic curve
+    EaseInOutQuad,
+    /// Starts slow and speeds up, with a cubic curve
+    EaseInCubic,
+    /// Starts fast and slows down towards the end
+    #[default]
+    EaseOutCubic,
+    /// Starts slow, speeds up, and slows down towards the end, with a cubic
+    /// curve
+    EaseInOutCubic,
+    /// Starts very slow and speeds up, with an exponential curve
+    EaseInExpo,
+    /// Starts very fast and slows down towards the end, with an exponential
+    /// curve
+    EaseOutExpo,
+    /// Overshoots the end and settles back to it, like a damped spring
+    ///
+    /// The eased progress goes above `1.0` during the animation.
+    Spring,
+}
+
+impl Easing {
+    /// Returns the eased progress for the linear progress `t` in the range
+    /// `[0.0, 1.0]`.
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseInQuad => t * t,
+            Easing::EaseOutQuad => 1.0 - (1.0 - t).powi(2),
+            Easing::EaseInOutQuad => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+                }
+            }
+            Easing::EaseInCubic => t.powi(3),
+            Easing::EaseOutCubic => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOutCubic => {
+                if t < 0.5 {
+                    4.0 * t.powi(3)
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Easing::EaseInExpo => {
+                if t == 0.0 {
+                    0.0
+                } else {
+                    2.0f32.powf(10.0 * t - 10.0)
+                }
+            }
+            Easing::EaseOutExpo => {
+                if t >= 1.0 {
+                    1.0
+                } else {
+                    1.0 - 2.0f32.powf(-10.0 * t)
+                }
+            }
+            Easing::Spring => {
+                if t >= 1.0 {
+                    1.0
+                } else {
+                    1.0 - (-6.0 * t).exp() * (3.0 * PI * t).cos()
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//! Uniform messages for the gestures of the widgets
+//!
+//! Hosts expect automation changes to be bracketed by the start and the end
+//! of the gesture which made them. [`Gesture`] wraps the `on_change`,
+//! `on_drag_start` and `on_drag_end` functions of a widget into a single
+//! message, with the ID of the parameter attached.
+//!
+//! [`Gesture`]: enum.Gesture.html
+
+use crate::core::Normal;
+
+/// A step of a user gesture on the widget of a parameter
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Gesture<Id> {
+    /// The user grabbed the widget of the parameter
+    Begin(Id),
+    /// The user changed the value of the parameter
+    Change(Id, Normal),
+    /// The user released the widget of the parameter
+    End(Id),
+}
+
+impl<Id> Gesture<Id> {
+    /// Returns the ID of the parameter of the gesture.
+    pub fn id(&self) -> &Id {
+        match self {
+            Gesture::Begin(id) | Gesture::Change(id, _) | Gesture::End(id) => {
+                id
+            }
+        }
+    }
+}
+
+impl<Id: Clone + 'static> Gesture<Id> {
+    /// Returns the `on_change`, `on_drag_start` and `on_drag_end` functions
+    /// expected by the widgets, which produce [`Gesture`]s for the parameter
+    /// `id` wrapped by `to_message`.
+    ///
+    /// A [`Knob`] takes all three in its constructor. The sliders take
+    /// `on_change` in their constructor and the other two through their
+    /// `on_drag_start` and `on_drag_end` builders, as does the [`XYPad`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::{h_slider, knob, native, Gesture};
+    ///
+    /// #[derive(Debug, Copy, Clone, PartialEq)]
+    /// enum ParamId {
+    ///     Cutoff,
+    ///     Resonance,
+    /// }
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     Param(Gesture<ParamId>),
+    /// }
+    ///
+    /// let mut knob_state = knob::State::new(Default::default());
+    /// let mut slider_state = h_slider::State::new(Default::default());
+    ///
+    /// let (on_change, on_drag_start, on_drag_end) =
+    ///     Gesture::handlers(ParamId::Cutoff, Message::Param);
+    ///
+    /// let knob: native::knob::Knob<'_, Message, iced::Renderer> =
+    ///     native::knob::Knob::new(
+    ///         &mut knob_state,
+    ///         on_change,
+    ///         on_drag_start,
+    ///         on_drag_end,
+    ///     );
+    ///
+    /// let (on_change, on_drag_start, on_drag_end) =
+    ///     Gesture::handlers(ParamId::Resonance, Message::Param);
+    ///
+    /// let h_slider: native::h_slider::HSlider<'_, Message, iced::Renderer> =
+    ///     native::h_slider::HSlider::new(&mut slider_state, on_change)
+    ///         .on_drag_start(on_drag_start)
+    ///         .on_drag_end(on_drag_end);
+    /// ```
+    ///
+    /// [`Gesture`]: enum.Gesture.html
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    pub fn handlers<Message, F>(
+        id: Id,
+        to_message: F,
+    ) -> (
+        impl Fn(Normal) -> Message,
+        impl Fn() -> Option<Message>,
+        impl Fn() -> Option<Message>,
+    )
+    where
+        F: 'static + Fn(Gesture<Id>) -> Message + Clone,
+    {
+        let on_change = {
+            let id = id.clone();
+            let to_message = to_message.clone();
+            move |normal| to_message(Gesture::Change(id.clone(), normal))
+        };
+
+        let on_drag_start = {
+            let id = id.clone();
+            let to_message = to_message.clone();
+            move || Some(to_message(Gesture::Begin(id.clone())))
+        };
+
+        let on_drag_end = move || Some(to_message(Gesture::End(id.clone())));
+
+        (on_change, on_drag_start, on_drag_end)
+    }
+}
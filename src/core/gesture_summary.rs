@@ -0,0 +1,23 @@
+//! A summary of a completed user gesture on a widget
+
+use std::time::Duration;
+
+use crate::core::Normal;
+
+/// A summary of a gesture on a widget, from the moment the user grabbed it
+/// to the moment they released it.
+///
+/// This gives hosts the full context of a gesture, for example to record a
+/// single undo step or an automation event.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GestureSummary {
+    /// The value when the gesture started
+    pub start: Normal,
+    /// The value when the gesture ended
+    pub end: Normal,
+    /// Whether the value changed at any point during the gesture, even if
+    /// it ended at its starting value
+    pub moved: bool,
+    /// How long the gesture lasted
+    pub duration: Duration,
+}
@@ -0,0 +1,339 @@
+//! Undo and redo of the edits of parameters
+//!
+//! [`EditHistory`] records the edits made with the widgets so they can be
+//! undone and redone. Fed with [`Gesture`]s, one drag of a widget becomes
+//! exactly one undo step.
+//!
+//! [`EditHistory`]: struct.EditHistory.html
+//! [`Gesture`]: ../gesture/enum.Gesture.html
+
+use crate::core::{Gesture, GestureSummary, Normal};
+
+/// An edit of a parameter
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Edit<Id> {
+    /// The ID of the edited parameter
+    pub param_id: Id,
+    /// The value before the edit
+    pub old: Normal,
+    /// The value after the edit
+    pub new: Normal,
+}
+
+/// A history of the edits of parameters, with undo and redo.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{EditHistory, Gesture, Normal};
+///
+/// let mut history = EditHistory::new();
+/// let mut value = Normal::min();
+///
+/// for gesture in vec![
+///     Gesture::Begin(0),
+///     Gesture::Change(0, Normal::new(0.2)),
+///     Gesture::Change(0, Normal::new(0.4)),
+///     Gesture::End(0),
+/// ] {
+///     // Record the gesture before applying it
+///     history.record_gesture(&gesture, value);
+///
+///     if let Gesture::Change(_, normal) = gesture {
+///         value = normal;
+///     }
+/// }
+///
+/// let edit = history.undo().unwrap();
+/// assert_eq!(edit.old, Normal::min());
+/// assert!(!history.can_undo());
+/// ```
+#[derive(Debug, Clone)]
+pub struct EditHistory<Id> {
+    undo_stack: Vec<Edit<Id>>,
+    redo_stack: Vec<Edit<Id>>,
+    pending: Vec<(Id, Normal)>,
+    limit: Option<usize>,
+}
+
+impl<Id: Clone + PartialEq> EditHistory<Id> {
+    /// Creates a new empty `EditHistory` with an unlimited number of steps.
+    pub fn new() -> Self {
+        Self {
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            pending: Vec::new(),
+            limit: None,
+        }
+    }
+
+    /// Creates a new empty `EditHistory` which keeps at most `limit` undo
+    /// steps, dropping the oldest ones.
+    pub fn with_limit(limit: usize) -> Self {
+        Self {
+            limit: Some(limit),
+            ..Self::new()
+        }
+    }
+
+    /// Records an edit as a new undo step, unless its value didn't change.
+    ///
+    /// This clears the redo steps.
+    pub fn push(&mut self, edit: Edit<Id>) {
+        if edit.old == edit.new {
+            return;
+        }
+
+        self.redo_stack.clear();
+        self.undo_stack.push(edit);
+
+        if let Some(limit) = self.limit {
+            if self.undo_stack.len() > limit {
+                let excess = self.undo_stack.len() - limit;
+                let _ = self.undo_stack.drain(..excess);
+            }
+        }
+    }
+
+    /// Records a [`Gesture`], where `current` is the value of its parameter
+    /// before the gesture is applied.
+    ///
+    /// The edits made between [`Gesture::Begin`] and [`Gesture::End`] are
+    /// recorded as a single undo step. A [`Gesture::Change`] outside of a
+    /// gesture, such as from the mouse wheel or the keyboard, is recorded as
+    /// its own undo step.
+    ///
+    /// [`Gesture`]: ../gesture/enum.Gesture.html
+    /// [`Gesture::Begin`]: ../gesture/enum.Gesture.html#variant.Begin
+    /// [`Gesture::End`]: ../gesture/enum.Gesture.html#variant.End
+    /// [`Gesture::Change`]: ../gesture/enum.Gesture.html#variant.Change
+    pub fn record_gesture(&mut self, gesture: &Gesture<Id>, current: Normal) {
+        let pending =
+            self.pending.iter().position(|(id, _)| id == gesture.id());
+
+        match (gesture, pending) {
+            (Gesture::Begin(id), None) => {
+                self.pending.push((id.clone(), current));
+            }
+            (Gesture::Change(id, new), None) => self.push(Edit {
+                param_id: id.clone(),
+                old: current,
+                new: *new,
+            }),
+            (Gesture::End(id), Some(index)) => {
+                let (_, old) = self.pending.remove(index);
+
+                self.push(Edit {
+                    param_id: id.clone(),
+                    old,
+                    new: current,
+                });
+            }
+            _ => (),
+        }
+    }
+
+    /// Records the [`GestureSummary`] of a gesture on the widget of the
+    /// parameter `param_id` as a single undo step.
+    ///
+    /// [`GestureSummary`]: ../gesture_summary/struct.GestureSummary.html
+    pub fn record_summary(&mut self, param_id: Id, summary: &GestureSummary) {
+        self.push(Edit {
+            param_id,
+            old: summary.start,
+            new: summary.end,
+        });
+    }
+
+    /// Returns whether there is an edit to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    /// Returns whether there is an edit to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// Undoes the last edit and returns it. The caller is expected to set
+    /// its parameter back to its `old` value.
+    pub fn undo(&mut self) -> Option<Edit<Id>> {
+        let edit = self.undo_stack.pop()?;
+        self.redo_stack.push(edit.clone());
+        Some(edit)
+    }
+
+    /// Redoes the last undone edit and returns it. The caller is expected to
+    /// set its parameter to its `new` value.
+    pub fn redo(&mut self) -> Option<Edit<Id>> {
+        let edit = self.redo_stack.pop()?;
+        self.undo_stack.push(edit.clone());
+        Some(edit)
+    }
+
+    /// Removes all the undo and redo steps.
+    pub fn clear(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.pending.clear();
+    }
+}
+
+impl<Id: Clone + PartialEq> Default for EditHistory<Id> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Records the `gestures` in the `history` and applies them to the
+    /// `values` of the parameters.
+    fn replay(
+        history: &mut EditHistory<usize>,
+        values: &mut [Normal],
+        gestures: &[Gesture<usize>],
+    ) {
+        for gesture in gestures {
+            let id = *gesture.id();
+            history.record_gesture(gesture, values[id]);
+
+            if let Gesture::Change(_, normal) = gesture {
+                values[id] = *normal;
+            }
+        }
+    }
+
+    #[test]
+    fn gesture_is_one_undo_step() {
+        let mut history = EditHistory::new();
+        let mut values = [Normal::min()];
+
+        replay(
+            &mut history,
+            &mut values,
+            &[
+                Gesture::Begin(0),
+                Gesture::Change(0, 0.2.into()),
+                Gesture::Change(0, 0.4.into()),
+                Gesture::Change(0, 0.6.into()),
+                Gesture::End(0),
+            ],
+        );
+
+        let edit = history.undo().unwrap();
+        assert_eq!(edit.old, Normal::min());
+        assert_eq!(edit.new, 0.6.into());
+        assert!(!history.can_undo());
+
+        assert_eq!(history.redo(), Some(edit));
+        assert!(!history.can_redo());
+        assert!(history.can_undo());
+    }
+
+    #[test]
+    fn interleaved_gestures_are_coalesced_per_param() {
+        let mut history = EditHistory::new();
+        let mut values = [Normal::min(), Normal::max()];
+
+        replay(
+            &mut history,
+            &mut values,
+            &[
+                Gesture::Begin(0),
+                Gesture::Begin(1),
+                Gesture::Change(0, 0.3.into()),
+                Gesture::Change(1, 0.7.into()),
+                Gesture::End(0),
+                Gesture::Change(1, 0.5.into()),
+                Gesture::End(1),
+            ],
+        );
+
+        let edit = history.undo().unwrap();
+        assert_eq!(
+            (edit.param_id, edit.old, edit.new),
+            (1, 1.0.into(), 0.5.into())
+        );
+
+        let edit = history.undo().unwrap();
+        assert_eq!(
+            (edit.param_id, edit.old, edit.new),
+            (0, 0.0.into(), 0.3.into())
+        );
+
+        assert!(history.undo().is_none());
+    }
+
+    #[test]
+    fn change_outside_of_a_gesture_is_its_own_step() {
+        let mut history = EditHistory::new();
+        let mut values = [Normal::min()];
+
+        replay(
+            &mut history,
+            &mut values,
+            &[
+                Gesture::Change(0, 0.1.into()),
+                Gesture::Change(0, 0.2.into()),
+            ],
+        );
+
+        assert_eq!(history.undo().unwrap().old, 0.1.into());
+        assert_eq!(history.undo().unwrap().old, Normal::min());
+    }
+
+    #[test]
+    fn unchanged_gesture_is_not_recorded() {
+        let mut history = EditHistory::new();
+        let mut values = [Normal::center()];
+
+        replay(
+            &mut history,
+            &mut values,
+            &[
+                Gesture::Begin(0),
+                Gesture::Change(0, 0.8.into()),
+                Gesture::Change(0, Normal::center()),
+                Gesture::End(0),
+            ],
+        );
+
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn new_edit_clears_the_redo_steps() {
+        let mut history = EditHistory::new();
+        let mut values = [Normal::min()];
+
+        replay(&mut history, &mut values, &[Gesture::Change(0, 0.1.into())]);
+        let _ = history.undo();
+        assert!(history.can_redo());
+
+        replay(&mut history, &mut values, &[Gesture::Change(0, 0.2.into())]);
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn limit_drops_the_oldest_steps() {
+        let mut history = EditHistory::with_limit(2);
+        let mut values = [Normal::min()];
+
+        replay(
+            &mut history,
+            &mut values,
+            &[
+                Gesture::Change(0, 0.1.into()),
+                Gesture::Change(0, 0.2.into()),
+                Gesture::Change(0, 0.3.into()),
+            ],
+        );
+
+        assert_eq!(history.undo().unwrap().old, 0.2.into());
+        assert_eq!(history.undo().unwrap().old, 0.1.into());
+        assert!(history.undo().is_none());
+    }
+}
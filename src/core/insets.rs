@@ -0,0 +1,89 @@
+//! Insets type
+
+use iced_native::Rectangle;
+
+use crate::core::Offset;
+
+/// Insets in pixels from each edge of a rectangle.
+///
+/// Positive insets shrink the rectangle, and negative insets grow it.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Insets {
+    /// The inset from the top edge in pixels.
+    pub top: f32,
+    /// The inset from the bottom edge in pixels.
+    pub bottom: f32,
+    /// The inset from the left edge in pixels.
+    pub left: f32,
+    /// The inset from the right edge in pixels.
+    pub right: f32,
+}
+
+impl Insets {
+    /// [`Insets`] of zero on every edge.
+    ///
+    /// [`Insets`]: struct.Insets.html
+    pub const ZERO: Insets = Insets {
+        top: 0.0,
+        bottom: 0.0,
+        left: 0.0,
+        right: 0.0,
+    };
+
+    /// Creates new [`Insets`].
+    ///
+    /// * `top` - The inset from the top edge in pixels.
+    /// * `bottom` - The inset from the bottom edge in pixels.
+    /// * `left` - The inset from the left edge in pixels.
+    /// * `right` - The inset from the right edge in pixels.
+    ///
+    /// [`Insets`]: struct.Insets.html
+    pub fn new(top: f32, bottom: f32, left: f32, right: f32) -> Self {
+        Self {
+            top,
+            bottom,
+            left,
+            right,
+        }
+    }
+
+    /// Creates new [`Insets`] with the same inset on every edge.
+    ///
+    /// [`Insets`]: struct.Insets.html
+    pub fn uniform(inset: f32) -> Self {
+        Self::new(inset, inset, inset, inset)
+    }
+
+    /// Return an inset rectangle.
+    #[inline]
+    pub fn inset_rect(&self, rect: &Rectangle) -> Rectangle {
+        Rectangle {
+            x: rect.x + self.left,
+            y: rect.y + self.top,
+            width: rect.width - self.left - self.right,
+            height: rect.height - self.top - self.bottom,
+        }
+    }
+}
+
+impl Default for Insets {
+    fn default() -> Self {
+        Insets::ZERO
+    }
+}
+
+/// [`Insets`] moving a rectangle by an [`Offset`] without resizing it.
+///
+/// [`Insets`]: struct.Insets.html
+/// [`Offset`]: ../offset/struct.Offset.html
+impl From<Offset> for Insets {
+    fn from(offset: Offset) -> Self {
+        Self {
+            top: offset.y,
+            bottom: -offset.y,
+            left: offset.x,
+            right: -offset.x,
+        }
+    }
+}
@@ -20,6 +20,7 @@ pub static DEFAULT_ANGLE_MAX: f32 = (360.0 - 30.0) * PI_OVER_180;
 /// maximum is `330` degrees, giving a span of `300` degrees, and a halfway
 /// point pointing strait up.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KnobAngleRange {
     min: f32,
     max: f32,
@@ -0,0 +1,12 @@
+//! The axis a 2D drag is constrained to
+
+/// The axis a drag on a 2D widget, such as an [`XYPad`], is constrained to
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LockedAxis {
+    /// Only the x axis moves
+    X,
+    /// Only the y axis moves
+    Y,
+}
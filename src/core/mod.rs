@@ -3,17 +3,51 @@
 //! This module holds basic types that can be reused and re-exported in
 //! different runtime implementations.
 
+pub mod atomic_normal_param;
+pub mod bipolar_normal;
+pub mod click_behavior;
+pub mod easing;
+pub mod gesture;
+pub mod gesture_summary;
+pub mod history;
+pub mod insets;
 pub mod knob_angle_range;
+pub mod locked_axis;
 pub mod math;
 pub mod modulation_range;
 pub mod normal;
 pub mod normal_param;
 pub mod offset;
+pub mod param;
+pub mod param_bank;
+pub mod polar;
+pub mod precise_normal;
 pub mod range;
+pub mod slider_group;
+pub mod smoothed_normal;
+pub mod value_formatter;
+pub mod wheel_axes;
 
+pub use atomic_normal_param::AtomicNormalParam;
+pub use bipolar_normal::BipolarNormal;
+pub use click_behavior::ClickBehavior;
+pub use easing::Easing;
+pub use gesture::Gesture;
+pub use gesture_summary::GestureSummary;
+pub use history::{Edit, EditHistory};
+pub use insets::Insets;
 pub use knob_angle_range::*;
-pub use modulation_range::ModulationRange;
+pub use locked_axis::LockedAxis;
+pub use modulation_range::{ModulationRange, Polarity};
 pub use normal::Normal;
 pub use normal_param::NormalParam;
 pub use offset::Offset;
+pub use param::Param;
+pub use param_bank::{AnyParam, ParamBank};
+pub use polar::Polar;
+pub use precise_normal::PreciseNormal;
 pub use range::*;
+pub use slider_group::SliderGroup;
+pub use smoothed_normal::SmoothedNormal;
+pub use value_formatter::ValueFormatter;
+pub use wheel_axes::WheelAxes;
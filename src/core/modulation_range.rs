@@ -4,6 +4,7 @@ use super::normal::Normal;
 
 /// The state of a modulation range
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ModulationRange {
     /// Where the modulation range starts.
     /// `0.0.into()` is all the way minimum, and `1.0.into()` is all the way maximum.
@@ -30,6 +31,53 @@ impl ModulationRange {
             filled_visible: true,
         }
     }
+
+    /// Creates a new `ModulationRange` from a base value and a signed
+    /// modulation amount, the way synth engines express modulation.
+    ///
+    /// The range is clipped to the `0.0` to `1.0` range of a [`Normal`].
+    ///
+    /// * base - The unmodulated value of the parameter.
+    /// * depth - The signed amount of modulation, in the normalized units of
+    ///   the parameter. `1.0` spans the whole range of the parameter.
+    /// * polarity - Whether the modulation goes from the base value in the
+    ///   direction of the depth, or both ways around it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::{ModulationRange, Normal, Polarity};
+    ///
+    /// let mod_range =
+    ///     ModulationRange::from_depth(Normal::new(0.75), 0.5, Polarity::Unipolar);
+    ///
+    /// assert_eq!(mod_range.start.as_f32(), 0.75);
+    /// assert_eq!(mod_range.end.as_f32(), 1.0);
+    /// ```
+    ///
+    /// [`Normal`]: ../normal/struct.Normal.html
+    pub fn from_depth(base: Normal, depth: f32, polarity: Polarity) -> Self {
+        let base = base.as_f32();
+
+        let (start, end) = match polarity {
+            Polarity::Unipolar => (base, base + depth),
+            Polarity::Bipolar => (base - depth, base + depth),
+        };
+
+        Self::new(start.into(), end.into())
+    }
+}
+
+/// The polarity of a modulation
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Polarity {
+    /// The modulation goes from the base value to the base value plus the
+    /// depth, like an envelope.
+    Unipolar,
+    /// The modulation goes both ways around the base value, by the depth,
+    /// like an LFO.
+    Bipolar,
 }
 
 impl Default for ModulationRange {
@@ -21,6 +21,11 @@
 /// assert_eq!(normal.as_f32(), 0.5);
 /// ```
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "f32", into = "f32")
+)]
 pub struct Normal {
     value: f32,
 }
@@ -96,6 +101,49 @@ impl Normal {
         }
     }
 
+    /// Returns the `Normal` snapped to the nearest of `steps + 1` evenly
+    /// spaced positions, from `0.0` to `1.0`.
+    ///
+    /// If `steps` is `0`, the `Normal` is returned unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::Normal;
+    ///
+    /// assert_eq!(Normal::new(0.3).quantize(4).as_f32(), 0.25);
+    /// ```
+    pub fn quantize(&self, steps: u32) -> Normal {
+        if steps == 0 {
+            return *self;
+        }
+
+        let steps = steps as f32;
+        Normal::new((self.value * steps).round() / steps)
+    }
+
+    /// Returns the nearest of the `targets` if it is within `threshold` of
+    /// the `Normal`, or the `Normal` unchanged otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::Normal;
+    ///
+    /// let detents = [Normal::center()];
+    ///
+    /// assert_eq!(Normal::new(0.52).snap_to(&detents, 0.05).as_f32(), 0.5);
+    /// assert_eq!(Normal::new(0.6).snap_to(&detents, 0.05).as_f32(), 0.6);
+    /// ```
+    pub fn snap_to(&self, targets: &[Normal], threshold: f32) -> Normal {
+        targets
+            .iter()
+            .map(|target| (*target, (target.value - self.value).abs()))
+            .filter(|(_, distance)| *distance <= threshold)
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map_or(*self, |(target, _)| target)
+    }
+
     /// Returns the value of the `Normal` as an `f32`
     #[inline]
     pub fn as_f32(&self) -> f32 {
@@ -10,8 +10,15 @@ use std::fmt::Debug;
 ///
 /// The values are stored as the [`Normal`] type.
 ///
+/// A `NormalParam` also counts the changes of its value made with
+/// [`set_value`](#method.set_value) or
+/// [`update_from_host`](#method.update_from_host) in a
+/// [`generation`](#method.generation), so caches can tell whether it changed
+/// since they were last updated without comparing values.
+///
 /// [`Normal`]: ../struct.Normal.html
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NormalParam {
     /// The value of the parameter represented as a [`Normal`]
     ///
@@ -22,13 +29,95 @@ pub struct NormalParam {
     ///
     /// [`Normal`]: ../struct.Normal.html
     pub default: Normal,
+
+    #[cfg_attr(feature = "serde", serde(skip))]
+    generation: u64,
 }
 
-impl Default for NormalParam {
-    fn default() -> Self {
+impl NormalParam {
+    /// Creates a new `NormalParam`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - the value of the parameter
+    /// * `default` - the default value of the parameter
+    pub fn new(value: Normal, default: Normal) -> Self {
         Self {
-            value: Normal::min(),
-            default: Normal::min(),
+            value,
+            default,
+            generation: 0,
         }
     }
+
+    /// Sets the value of the parameter and increments the
+    /// [`generation`](#method.generation) if it changed.
+    ///
+    /// This is how the widgets change the value of their parameter.
+    pub fn set_value(&mut self, value: Normal) {
+        if value != self.value {
+            self.value = value;
+            self.generation = self.generation.wrapping_add(1);
+        }
+    }
+
+    /// Sets the value of the parameter, such as from an update of the host,
+    /// and increments the [`generation`](#method.generation) if it changed.
+    ///
+    /// Returns whether the value changed.
+    pub fn update_from_host(&mut self, value: Normal) -> bool {
+        let changed = value != self.value;
+        self.set_value(value);
+
+        changed
+    }
+
+    /// Returns the number of changes of the value made with
+    /// [`set_value`](#method.set_value) or
+    /// [`update_from_host`](#method.update_from_host).
+    ///
+    /// Setting the `value` field directly doesn't change the generation.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Returns whether the value changed since the supplied `generation`,
+    /// as previously returned by [`generation`](#method.generation).
+    pub fn has_changed_since(&self, generation: u64) -> bool {
+        self.generation != generation
+    }
+}
+
+/// Compares the values and the default values, but not the generations.
+impl PartialEq for NormalParam {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.default == other.default
+    }
+}
+
+impl Default for NormalParam {
+    fn default() -> Self {
+        Self::new(Normal::min(), Normal::min())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_value_increments_the_generation() {
+        let mut param = NormalParam::default();
+        let generation = param.generation();
+
+        param.set_value(Normal::min());
+        assert!(!param.has_changed_since(generation));
+
+        param.set_value(Normal::center());
+        assert!(param.has_changed_since(generation));
+
+        let generation = param.generation();
+        assert!(param.update_from_host(Normal::max()));
+        assert!(!param.update_from_host(Normal::max()));
+        assert_eq!(param.generation(), generation.wrapping_add(1));
+    }
 }
@@ -0,0 +1,190 @@
+//! Module for the [`Param`] struct
+//!
+//! [`Param`]: struct.Param.html
+
+use crate::core::{Normal, NormalParam, Range, ValueFormatter};
+
+use std::fmt;
+use std::sync::Arc;
+
+/// A parameter that bundles a [`Range`] with its current and default
+/// values, a display name, a unit and a formatter.
+///
+/// Widgets work with [`NormalParam`]s, which can be obtained with
+/// [`normal_param`](#method.normal_param). Changes reported by the widgets
+/// are mapped back with [`set_from_normal`](#method.set_from_normal).
+///
+/// [`Range`]: ../range/trait.Range.html
+/// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+#[derive(Clone)]
+pub struct Param<R: Range> {
+    range: R,
+    value: R::Value,
+    default: R::Value,
+    name: String,
+    unit: String,
+    formatter: Arc<dyn Fn(R::Value) -> String + Send + Sync>,
+}
+
+impl<R> Param<R>
+where
+    R: ValueFormatter + Clone + Send + Sync + 'static,
+{
+    /// Creates a new `Param`
+    ///
+    /// Values are formatted by the [`ValueFormatter`] of the `range`, such
+    /// as `"-6 dB"`. Use [`with_formatter`](#method.with_formatter) to
+    /// customize this.
+    ///
+    /// [`ValueFormatter`]: ../value_formatter/trait.ValueFormatter.html
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - the display name of the parameter
+    /// * `range` - the range of the values of the parameter
+    /// * `value` - the initial value of the parameter
+    /// * `default` - the default value of the parameter
+    pub fn new(
+        name: impl Into<String>,
+        range: R,
+        value: R::Value,
+        default: R::Value,
+    ) -> Self {
+        let formatter = range.clone();
+
+        Self::with_formatter(name, range, value, default, move |value| {
+            formatter.format_value(value)
+        })
+    }
+}
+
+impl<R: Range> Param<R> {
+    /// Creates a new `Param` with a custom formatter
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - the display name of the parameter
+    /// * `range` - the range of the values of the parameter
+    /// * `value` - the initial value of the parameter
+    /// * `default` - the default value of the parameter
+    /// * `formatter` - formats a value of the parameter, without the unit
+    pub fn with_formatter(
+        name: impl Into<String>,
+        range: R,
+        value: R::Value,
+        default: R::Value,
+        formatter: impl Fn(R::Value) -> String + Send + Sync + 'static,
+    ) -> Self {
+        let value = range.unmap_to_value(range.map_to_normal(value));
+        let default = range.unmap_to_value(range.map_to_normal(default));
+
+        Self {
+            range,
+            value,
+            default,
+            name: name.into(),
+            unit: String::new(),
+            formatter: Arc::new(formatter),
+        }
+    }
+
+    /// Sets the unit appended to the formatted values, such as `"dB"`.
+    pub fn with_unit(mut self, unit: impl Into<String>) -> Self {
+        self.unit = unit.into();
+        self
+    }
+
+    /// Returns the range of the parameter.
+    pub fn range(&self) -> &R {
+        &self.range
+    }
+
+    /// Returns the display name of the parameter.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the unit of the parameter, which may be empty.
+    pub fn unit(&self) -> &str {
+        &self.unit
+    }
+
+    /// Returns the current value of the parameter.
+    pub fn value(&self) -> R::Value {
+        self.value
+    }
+
+    /// Returns the default value of the parameter.
+    pub fn default_value(&self) -> R::Value {
+        self.default
+    }
+
+    /// Sets the current value of the parameter, constrained to its range.
+    pub fn set(&mut self, value: R::Value) {
+        self.value = self.range.unmap_to_value(self.range.map_to_normal(value));
+    }
+
+    /// Sets the current value of the parameter from a [`Normal`], such as
+    /// the one reported by a widget.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn set_from_normal(&mut self, normal: Normal) {
+        self.value = self.range.unmap_to_value(normal);
+    }
+
+    /// Resets the current value of the parameter to its default value.
+    pub fn reset(&mut self) {
+        self.value = self.default;
+    }
+
+    /// Returns the current value of the parameter as a [`Normal`].
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn normal(&self) -> Normal {
+        self.range.map_to_normal(self.value)
+    }
+
+    /// Returns a [`NormalParam`] with the current and default values of the
+    /// parameter, to be used by the widgets.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn normal_param(&self) -> NormalParam {
+        NormalParam::new(
+            self.range.map_to_normal(self.value),
+            self.range.map_to_normal(self.default),
+        )
+    }
+
+    /// Formats the supplied `value` with the formatter and the unit of the
+    /// parameter, such as `"-6 dB"`.
+    pub fn format_value(&self, value: R::Value) -> String {
+        let text = (self.formatter)(value);
+
+        if self.unit.is_empty() {
+            text
+        } else {
+            format!("{} {}", text, self.unit)
+        }
+    }
+
+    /// Formats the current value of the parameter with its unit.
+    pub fn format(&self) -> String {
+        self.format_value(self.value)
+    }
+}
+
+impl<R> fmt::Debug for Param<R>
+where
+    R: Range + fmt::Debug,
+    R::Value: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Param")
+            .field("range", &self.range)
+            .field("value", &self.value)
+            .field("default", &self.default)
+            .field("name", &self.name)
+            .field("unit", &self.unit)
+            .finish()
+    }
+}
@@ -0,0 +1,320 @@
+//! Module for the [`ParamBank`] struct
+//!
+//! [`ParamBank`]: struct.ParamBank.html
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
+use std::hash::Hash;
+
+use crate::core::{Normal, NormalParam, Param, Range};
+
+/// A [`Param`] whose [`Range`] is erased, so parameters of different ranges
+/// can be stored in the same [`ParamBank`].
+///
+/// [`Param`]: ../param/struct.Param.html
+/// [`Range`]: ../range/trait.Range.html
+/// [`ParamBank`]: struct.ParamBank.html
+pub trait AnyParam: Send + Sync {
+    /// Returns the display name of the parameter.
+    fn name(&self) -> &str;
+
+    /// Returns a [`NormalParam`] with the current and default values of the
+    /// parameter.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    fn normal_param(&self) -> NormalParam;
+
+    /// Sets the current value of the parameter from a [`Normal`].
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn set_from_normal(&mut self, normal: Normal);
+
+    /// Resets the current value of the parameter to its default value.
+    fn reset(&mut self);
+
+    /// Formats the current value of the parameter with its unit.
+    fn format(&self) -> String;
+
+    /// Returns the parameter as `Any`, to get back its concrete type.
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<R> AnyParam for Param<R>
+where
+    R: Range + Send + Sync + 'static,
+    R::Value: Send + Sync,
+{
+    fn name(&self) -> &str {
+        Param::name(self)
+    }
+
+    fn normal_param(&self) -> NormalParam {
+        Param::normal_param(self)
+    }
+
+    fn set_from_normal(&mut self, normal: Normal) {
+        Param::set_from_normal(self, normal)
+    }
+
+    fn reset(&mut self) {
+        Param::reset(self)
+    }
+
+    fn format(&self) -> String {
+        Param::format(self)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// A collection of [`Param`]s keyed by stable IDs, such as the parameters
+/// of a plugin.
+///
+/// The bank hands out [`NormalParam`]s to the widgets, applies the changes
+/// of the widgets and of the host, and keeps track of the parameters that
+/// changed since the last call to [`take_dirty`](#method.take_dirty), so
+/// the states of the widgets can be updated.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{FloatRange, LogDBRange, Normal, Param, ParamBank};
+///
+/// #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+/// enum ParamId {
+///     Gain,
+///     Mix,
+/// }
+///
+/// let mut bank = ParamBank::new();
+/// bank.insert(
+///     ParamId::Gain,
+///     Param::new("Gain", LogDBRange::new(-12.0, 12.0, 0.5.into()), 0.0, 0.0),
+/// );
+/// bank.insert(ParamId::Mix, Param::new("Mix", FloatRange::default(), 1.0, 1.0));
+///
+/// // Automation from the host
+/// bank.set_normal(&ParamId::Mix, Normal::center());
+///
+/// assert_eq!(bank.take_dirty(), vec![ParamId::Mix]);
+/// assert_eq!(bank.normal_param(&ParamId::Mix).unwrap().value.as_f32(), 0.5);
+/// ```
+///
+/// [`Param`]: ../param/struct.Param.html
+/// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+pub struct ParamBank<Id> {
+    params: Vec<(Id, Box<dyn AnyParam>)>,
+    indices: HashMap<Id, usize>,
+    dirty: Vec<bool>,
+}
+
+impl<Id: Eq + Hash + Clone> ParamBank<Id> {
+    /// Creates a new empty `ParamBank`.
+    pub fn new() -> Self {
+        Self {
+            params: Vec::new(),
+            indices: HashMap::new(),
+            dirty: Vec::new(),
+        }
+    }
+
+    /// Inserts a parameter with the given `id`, replacing the parameter
+    /// which had the same `id` if any.
+    pub fn insert(&mut self, id: Id, param: impl AnyParam + 'static) {
+        let param: Box<dyn AnyParam> = Box::new(param);
+
+        if let Some(index) = self.indices.get(&id) {
+            self.params[*index].1 = param;
+            self.dirty[*index] = true;
+        } else {
+            let _ = self.indices.insert(id.clone(), self.params.len());
+            self.params.push((id, param));
+            self.dirty.push(false);
+        }
+    }
+
+    /// Returns the number of parameters in the bank.
+    pub fn len(&self) -> usize {
+        self.params.len()
+    }
+
+    /// Returns whether the bank has no parameters.
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    /// Returns the IDs of the parameters, in the order they were inserted.
+    pub fn ids(&self) -> impl Iterator<Item = &Id> {
+        self.params.iter().map(|(id, _)| id)
+    }
+
+    /// Returns the parameter with the given `id`.
+    pub fn get(&self, id: &Id) -> Option<&dyn AnyParam> {
+        let index = *self.indices.get(id)?;
+        Some(self.params[index].1.as_ref())
+    }
+
+    /// Returns the parameter with the given `id`, if its range is `R`.
+    pub fn param<R>(&self, id: &Id) -> Option<&Param<R>>
+    where
+        R: Range + 'static,
+    {
+        self.get(id)?.as_any().downcast_ref()
+    }
+
+    /// Returns a [`NormalParam`] with the current and default values of the
+    /// parameter with the given `id`, to be used by its widget.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn normal_param(&self, id: &Id) -> Option<NormalParam> {
+        Some(self.get(id)?.normal_param())
+    }
+
+    /// Sets the value of the parameter with the given `id` from a
+    /// [`Normal`], such as an update from a widget, from the host, or from
+    /// a MIDI controller, and marks it as dirty.
+    ///
+    /// Returns `false` if there is no parameter with this `id`.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn set_normal(&mut self, id: &Id, normal: Normal) -> bool {
+        if let Some(index) = self.indices.get(id) {
+            self.params[*index].1.set_from_normal(normal);
+            self.dirty[*index] = true;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Resets all the parameters to their default values and marks them as
+    /// dirty.
+    pub fn reset_all(&mut self) {
+        for (_, param) in self.params.iter_mut() {
+            param.reset();
+        }
+
+        self.dirty.iter_mut().for_each(|dirty| *dirty = true);
+    }
+
+    /// Returns whether the parameter with the given `id` changed since the
+    /// last call to [`take_dirty`](#method.take_dirty).
+    pub fn is_dirty(&self, id: &Id) -> bool {
+        self.indices.get(id).is_some_and(|index| self.dirty[*index])
+    }
+
+    /// Returns whether any parameter changed since the last call to
+    /// [`take_dirty`](#method.take_dirty).
+    pub fn has_dirty(&self) -> bool {
+        self.dirty.iter().any(|dirty| *dirty)
+    }
+
+    /// Returns the IDs of the parameters which changed since the last call
+    /// to this method, and clears their dirty flag.
+    pub fn take_dirty(&mut self) -> Vec<Id> {
+        self.params
+            .iter()
+            .zip(self.dirty.iter_mut())
+            .filter_map(|((id, _), dirty)| {
+                if std::mem::replace(dirty, false) {
+                    Some(id.clone())
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+impl<Id: Eq + Hash + Clone> Default for ParamBank<Id> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Id: Debug> Debug for ParamBank<Id> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.params
+                    .iter()
+                    .map(|(id, param)| (id, (param.name(), param.format()))),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{FloatRange, IntRange};
+
+    fn bank() -> ParamBank<&'static str> {
+        let mut bank = ParamBank::new();
+        bank.insert("mix", Param::new("Mix", FloatRange::default(), 1.0, 1.0));
+        bank.insert("voices", Param::new("Voices", IntRange::new(1, 8), 4, 1));
+        bank
+    }
+
+    #[test]
+    fn insert_keeps_the_order_of_the_ids() {
+        let bank = bank();
+
+        assert_eq!(bank.len(), 2);
+        assert_eq!(bank.ids().copied().collect::<Vec<_>>(), ["mix", "voices"]);
+        assert!(!bank.has_dirty());
+    }
+
+    #[test]
+    fn replacing_a_param_marks_it_dirty() {
+        let mut bank = bank();
+
+        bank.insert("mix", Param::new("Mix", FloatRange::default(), 0.0, 1.0));
+
+        assert_eq!(bank.len(), 2);
+        assert_eq!(bank.take_dirty(), ["mix"]);
+        assert_eq!(bank.param::<FloatRange>(&"mix").unwrap().value(), 0.0);
+    }
+
+    #[test]
+    fn set_normal_marks_the_param_dirty() {
+        let mut bank = bank();
+
+        assert!(bank.set_normal(&"voices", Normal::max()));
+        assert!(!bank.set_normal(&"unknown", Normal::max()));
+
+        assert!(bank.is_dirty(&"voices"));
+        assert!(!bank.is_dirty(&"mix"));
+        assert_eq!(bank.param::<IntRange>(&"voices").unwrap().value(), 8);
+
+        assert_eq!(bank.take_dirty(), ["voices"]);
+        assert!(!bank.has_dirty());
+        assert!(bank.take_dirty().is_empty());
+    }
+
+    #[test]
+    fn reset_all_restores_the_defaults() {
+        let mut bank = bank();
+
+        bank.reset_all();
+
+        assert_eq!(bank.take_dirty(), ["mix", "voices"]);
+        assert_eq!(bank.param::<IntRange>(&"voices").unwrap().value(), 1);
+        assert_eq!(
+            bank.normal_param(&"voices").unwrap().value,
+            bank.normal_param(&"voices").unwrap().default,
+        );
+    }
+
+    #[test]
+    fn param_checks_the_range() {
+        let bank = bank();
+
+        assert!(bank.param::<FloatRange>(&"mix").is_some());
+        assert!(bank.param::<IntRange>(&"mix").is_none());
+        assert!(bank.get(&"unknown").is_none());
+    }
+}
@@ -0,0 +1,64 @@
+//! Polar coordinates of a handle inside a circle inscribed in a 2D widget
+
+use crate::core::math::TWO_PI;
+use crate::core::Normal;
+
+/// The polar coordinates of a handle constrained to the circle inscribed in
+/// a 2D widget, such as an [`XYPad`] in polar mode.
+///
+/// The `angle` is a full turn counter-clockwise, starting at `0.0` on the
+/// right of the center. The `radius` goes from `0.0` at the center to `1.0`
+/// on the edge of the circle.
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Polar {
+    /// The angle of the handle, a full turn counter-clockwise from the right
+    pub angle: Normal,
+    /// The distance of the handle from the center
+    pub radius: Normal,
+}
+
+impl Polar {
+    /// Creates new polar coordinates.
+    pub fn new(angle: Normal, radius: Normal) -> Self {
+        Self { angle, radius }
+    }
+
+    /// Converts normalized `x` and `y` coordinates, where `(0.5, 0.5)` is
+    /// the center of the circle, to polar coordinates.
+    ///
+    /// Coordinates outside the circle have a `radius` of `1.0`.
+    pub fn from_xy(x: f32, y: f32) -> Self {
+        let dx = (x - 0.5) * 2.0;
+        let dy = (y - 0.5) * 2.0;
+
+        let radius = (dx * dx + dy * dy).sqrt();
+
+        let angle = if radius == 0.0 {
+            0.0
+        } else {
+            dy.atan2(dx).rem_euclid(TWO_PI) / TWO_PI
+        };
+
+        Self {
+            angle: angle.into(),
+            radius: radius.into(),
+        }
+    }
+
+    /// Converts the polar coordinates to normalized `x` and `y`
+    /// coordinates, where `(0.5, 0.5)` is the center of the circle.
+    pub fn to_xy(&self) -> (f32, f32) {
+        let angle = self.angle.as_f32() * TWO_PI;
+        let radius = self.radius.as_f32() / 2.0;
+
+        (0.5 + (radius * angle.cos()), 0.5 + (radius * angle.sin()))
+    }
+
+    /// Moves normalized `x` and `y` coordinates inside the circle, keeping
+    /// their angle.
+    pub fn constrain_xy(x: f32, y: f32) -> (f32, f32) {
+        Self::from_xy(x, y).to_xy()
+    }
+}
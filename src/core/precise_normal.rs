@@ -0,0 +1,145 @@
+//! An `f64` value that is gauranteed to be constrained to the range of
+//!
+//! `0.0 >= value <= 1.0`
+
+use crate::core::Normal;
+
+/// An `f64` value that is gauranteed to be constrained to the range of
+///
+/// `0.0 >= value <= 1.0`
+///
+/// This is the high resolution counterpart of [`Normal`], for parameters
+/// where the quantization of an `f32` is visible, such as a sample accurate
+/// position in a long waveform. It converts to and from a [`Normal`] for
+/// the widgets.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{Normal, PreciseNormal};
+///
+/// let samples: u64 = 48_000 * 60 * 60;
+///
+/// let position = PreciseNormal::new(0.5);
+/// assert_eq!(position.scale(samples as f64) as u64, samples / 2);
+///
+/// let normal = Normal::from(position);
+/// assert_eq!(normal.as_f32(), 0.5);
+/// ```
+///
+/// [`Normal`]: ../normal/struct.Normal.html
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "f64", into = "f64")
+)]
+pub struct PreciseNormal {
+    value: f64,
+}
+
+impl Default for PreciseNormal {
+    fn default() -> Self {
+        Self { value: 0.0 }
+    }
+}
+
+impl PreciseNormal {
+    /// Creates a new `PreciseNormal`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - the value to initialize the `PreciseNormal` with
+    ///
+    /// if `value < 0.0`, then `normal.value` is set to `0.0`
+    ///
+    /// else if `value > 1.0`, then `normal.value` is set to `1.0`
+    ///
+    /// else `normal.value` is set to `value`
+    pub fn new(value: f64) -> Self {
+        Self {
+            value: value.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Returns a `PreciseNormal` with the value `0.0`.
+    pub fn min() -> Self {
+        Self { value: 0.0 }
+    }
+
+    /// Returns a `PreciseNormal` with the value `1.0`.
+    pub fn max() -> Self {
+        Self { value: 1.0 }
+    }
+
+    /// Returns a `PreciseNormal` with the value `0.5`.
+    pub fn center() -> Self {
+        Self { value: 0.5 }
+    }
+
+    /// Set a value for the `PreciseNormal`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - the value to set the `PreciseNormal` with
+    ///
+    /// if `value < 0.0`, then `normal.value` is set to `0.0`
+    ///
+    /// else if `value > 1.0`, then `normal.value` is set to `1.0`
+    ///
+    /// else `normal.value` is set to `value`
+    #[inline]
+    pub fn set(&mut self, value: f64) {
+        self.value = value.clamp(0.0, 1.0);
+    }
+
+    /// Returns the value of the `PreciseNormal` as an `f64`
+    #[inline]
+    pub fn as_f64(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the inverse value (`1.0 - value`) of the `PreciseNormal` as
+    /// an `f64`
+    #[inline]
+    pub fn as_f64_inv(&self) -> f64 {
+        1.0 - self.value
+    }
+
+    /// Returns the value of the `PreciseNormal` times the `scalar`
+    #[inline]
+    pub fn scale(&self, scalar: f64) -> f64 {
+        self.value * scalar
+    }
+
+    /// Returns the inverse value (`1.0 - value`) of the `PreciseNormal`
+    /// times the `scalar`
+    #[inline]
+    pub fn scale_inv(&self, scalar: f64) -> f64 {
+        (1.0 - self.value) * scalar
+    }
+}
+
+impl From<f64> for PreciseNormal {
+    fn from(value: f64) -> Self {
+        PreciseNormal::new(value)
+    }
+}
+
+impl From<PreciseNormal> for f64 {
+    fn from(normal: PreciseNormal) -> f64 {
+        normal.value
+    }
+}
+
+impl From<Normal> for PreciseNormal {
+    fn from(normal: Normal) -> Self {
+        PreciseNormal::new(f64::from(normal.as_f32()))
+    }
+}
+
+impl From<PreciseNormal> for Normal {
+    fn from(normal: PreciseNormal) -> Normal {
+        Normal::new(normal.value as f32)
+    }
+}
@@ -5,10 +5,133 @@ use crate::core::normal_param::NormalParam;
 use crate::core::Normal;
 
 use std::fmt::Debug;
+use std::sync::Arc;
+
+/// A range of parameter values that maps to a [`Normal`]
+///
+/// This is implemented by all the ranges of this module, so code that only
+/// needs to map values can be generic over the range.
+///
+/// [`Normal`]: ../struct.Normal.html
+pub trait Range {
+    /// The type of the values of the range
+    type Value: Copy;
+
+    /// Returns the corresponding [`Normal`] from the supplied `value`
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn map_to_normal(&self, value: Self::Value) -> Normal;
+
+    /// Returns the corresponding value from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn unmap_to_value(&self, normal: Normal) -> Self::Value;
+
+    /// Returns this range with an inverted mapping, where the minimum value
+    /// maps to `1.0` and the maximum value maps to `0.0`.
+    ///
+    /// This is useful to reuse a range, for example a dB range for a gain
+    /// reduction meter which fills from the top.
+    fn inverted(self) -> Inverted<Self>
+    where
+        Self: Sized,
+    {
+        Inverted { range: self }
+    }
+
+    /// Returns this range with its values clamped between `min` and `max`.
+    ///
+    /// The mapping itself is unchanged, but the normals are constrained to
+    /// the ones of `min` and `max`.
+    fn clamped(self, min: Self::Value, max: Self::Value) -> Clamped<Self>
+    where
+        Self: Sized,
+    {
+        let min = self.map_to_normal(min);
+        let max = self.map_to_normal(max);
+
+        let (min, max) = if min.as_f32() <= max.as_f32() {
+            (min, max)
+        } else {
+            (max, min)
+        };
+
+        Clamped {
+            range: self,
+            min,
+            max,
+        }
+    }
+}
+
+/// A [`Range`] with an inverted mapping, see [`Range::inverted`].
+///
+/// [`Range`]: trait.Range.html
+/// [`Range::inverted`]: trait.Range.html#method.inverted
+#[derive(Debug, Copy, Clone)]
+pub struct Inverted<R> {
+    range: R,
+}
+
+impl<R> Inverted<R> {
+    /// Returns the range which is inverted.
+    pub fn inner(&self) -> &R {
+        &self.range
+    }
+}
+
+impl<R: Range> Range for Inverted<R> {
+    type Value = R::Value;
+
+    fn map_to_normal(&self, value: R::Value) -> Normal {
+        Normal::new(self.range.map_to_normal(value).as_f32_inv())
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> R::Value {
+        self.range.unmap_to_value(Normal::new(normal.as_f32_inv()))
+    }
+}
+
+/// A [`Range`] with clamped values, see [`Range::clamped`].
+///
+/// [`Range`]: trait.Range.html
+/// [`Range::clamped`]: trait.Range.html#method.clamped
+#[derive(Debug, Copy, Clone)]
+pub struct Clamped<R> {
+    range: R,
+    min: Normal,
+    max: Normal,
+}
+
+impl<R> Clamped<R> {
+    /// Returns the range which is clamped.
+    pub fn inner(&self) -> &R {
+        &self.range
+    }
+
+    fn clamp(&self, normal: Normal) -> Normal {
+        Normal::new(normal.as_f32().clamp(self.min.as_f32(), self.max.as_f32()))
+    }
+}
+
+impl<R: Range> Range for Clamped<R> {
+    type Value = R::Value;
+
+    fn map_to_normal(&self, value: R::Value) -> Normal {
+        self.clamp(self.range.map_to_normal(value))
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> R::Value {
+        self.range.unmap_to_value(self.clamp(normal))
+    }
+}
 
 /// A range that maps a continuous linear range of `f32` values
 /// to a [`Normal`]
 ///
+/// The mapping can be made non-linear with a skew factor, see
+/// [`with_skew`](#method.with_skew).
+///
 /// [`Normal`]: ../struct.Normal.html
 #[derive(Debug, Copy, Clone)]
 pub struct FloatRange {
@@ -16,6 +139,9 @@ pub struct FloatRange {
     max: f32,
     span: f32,
     span_recip: f32,
+    skew: f32,
+    skew_recip: f32,
+    symmetric_skew: bool,
 }
 
 impl FloatRange {
@@ -40,6 +166,114 @@ impl FloatRange {
             max,
             span,
             span_recip,
+            skew: 1.0,
+            skew_recip: 1.0,
+            symmetric_skew: false,
+        }
+    }
+
+    /// Creates a new skewed `FloatRange`, where the [`Normal`] is the
+    /// linear proportion of the range raised to the power of `skew_factor`.
+    ///
+    /// A `skew_factor` of `1.0` is linear. Below `1.0`, more of the
+    /// [`Normal`] is given to the lower end of the range, like for a
+    /// frequency or a time. Above `1.0`, more of it is given to the upper
+    /// end of the range.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum of the range (inclusive)
+    /// * `max` - the maximum of the range (inclusive)
+    /// * `skew_factor` - the skew factor of the range
+    ///
+    /// # Panics
+    ///
+    /// This will panic if
+    /// * `max` <= `min`
+    /// * `skew_factor` <= `0.0`
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn with_skew(min: f32, max: f32, skew_factor: f32) -> Self {
+        assert!(skew_factor > 0.0, "skew_factor must be positive");
+
+        Self {
+            skew: skew_factor,
+            skew_recip: skew_factor.recip(),
+            ..Self::new(min, max)
+        }
+    }
+
+    /// Creates a new `FloatRange` skewed symmetrically around the center of
+    /// the range.
+    ///
+    /// Each half of the range is skewed by `skew_factor` from the center
+    /// outwards. Below `1.0`, more of the [`Normal`] is given to the values
+    /// around the center of the range, like for a fine tune parameter.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum of the range (inclusive)
+    /// * `max` - the maximum of the range (inclusive)
+    /// * `skew_factor` - the skew factor of each half of the range
+    ///
+    /// # Panics
+    ///
+    /// This will panic if
+    /// * `max` <= `min`
+    /// * `skew_factor` <= `0.0`
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn with_symmetric_skew(min: f32, max: f32, skew_factor: f32) -> Self {
+        Self {
+            symmetric_skew: true,
+            ..Self::with_skew(min, max, skew_factor)
+        }
+    }
+
+    /// Creates a new `FloatRange` from `0.0` to `1.0` following the law of
+    /// an audio (logarithmic) taper potentiometer, where the middle of the
+    /// [`Normal`] is `10 %` of the range.
+    ///
+    /// This suits a volume control.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn audio_taper() -> Self {
+        // 0.1 ^ log10(2) = 0.5
+        Self::with_skew(0.0, 1.0, std::f32::consts::LOG10_2)
+    }
+
+    /// Creates a new `FloatRange` from `0.0` to `1.0` following an S-curve
+    /// law, where the value changes slowly at both ends of the [`Normal`]
+    /// and quickly around its center, like a crossfader.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - the strength of the curve, from `0.0` (linear) to `1.0`.
+    ///   It is clamped to this range.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn s_curve(amount: f32) -> Self {
+        let amount = amount.clamp(0.0, 1.0);
+
+        Self::with_symmetric_skew(0.0, 1.0, 1.0 + (3.0 * amount))
+    }
+
+    /// Returns the skew factor of the range. A skew factor of `1.0` is
+    /// linear.
+    pub fn skew_factor(&self) -> f32 {
+        self.skew
+    }
+
+    /// Applies `exponent` to the linear `proportion` of the range.
+    fn skewed(&self, proportion: f32, exponent: f32) -> f32 {
+        if exponent == 1.0 {
+            proportion
+        } else if self.symmetric_skew {
+            let distance = (proportion * 2.0) - 1.0;
+
+            0.5 + (distance.signum() * distance.abs().powf(exponent) / 2.0)
+        } else {
+            proportion.powf(exponent)
         }
     }
 
@@ -69,10 +303,7 @@ impl FloatRange {
     /// * `value` - The inital value of the parameter.
     /// * `default_value` - The default value of the parameter.
     pub fn normal_param(&self, value: f32, default: f32) -> NormalParam {
-        NormalParam {
-            value: self.map_to_normal(value),
-            default: self.map_to_normal(default),
-        }
+        NormalParam::new(self.map_to_normal(value), self.map_to_normal(default))
     }
 
     /// Creates a new [`NormalParam`] with values mapped
@@ -80,10 +311,7 @@ impl FloatRange {
     ///
     /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
     pub fn default_normal_param(&self) -> NormalParam {
-        NormalParam {
-            value: self.map_to_normal(0.0),
-            default: self.map_to_normal(0.0),
-        }
+        NormalParam::new(self.map_to_normal(0.0), self.map_to_normal(0.0))
     }
 
     /// Returns the corresponding [`Normal`] from the supplied value
@@ -91,14 +319,27 @@ impl FloatRange {
     /// [`Normal`]: ../struct.Normal.html
     pub fn map_to_normal(&self, value: f32) -> Normal {
         let value = self.constrain(value);
-        ((value - self.min) * self.span_recip).into()
+        self.skewed((value - self.min) * self.span_recip, self.skew)
+            .into()
     }
 
     /// Returns the corresponding value from the supplied [`Normal`]
     ///
     /// [`Normal`]: ../struct.Normal.html
     pub fn unmap_to_value(&self, normal: Normal) -> f32 {
-        (normal.as_f32() * self.span) + self.min
+        (self.skewed(normal.as_f32(), self.skew_recip) * self.span) + self.min
+    }
+}
+
+impl Range for FloatRange {
+    type Value = f32;
+
+    fn map_to_normal(&self, value: f32) -> Normal {
+        FloatRange::map_to_normal(self, value)
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> f32 {
+        FloatRange::unmap_to_value(self, normal)
     }
 }
 
@@ -160,10 +401,7 @@ impl IntRange {
     /// * `value` - The inital value of the parameter.
     /// * `default_value` - The default value of the parameter.
     pub fn normal_param(&self, value: i32, default: i32) -> NormalParam {
-        NormalParam {
-            value: self.map_to_normal(value),
-            default: self.map_to_normal(default),
-        }
+        NormalParam::new(self.map_to_normal(value), self.map_to_normal(default))
     }
 
     /// Creates a new [`NormalParam`] with values mapped
@@ -171,10 +409,7 @@ impl IntRange {
     ///
     /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
     pub fn default_normal_param(&self) -> NormalParam {
-        NormalParam {
-            value: self.map_to_normal(0),
-            default: self.map_to_normal(0),
-        }
+        NormalParam::new(self.map_to_normal(0), self.map_to_normal(0))
     }
 
     /// Returns a [`Normal`] that is snapped to the closest integer
@@ -202,6 +437,18 @@ impl IntRange {
     }
 }
 
+impl Range for IntRange {
+    type Value = i32;
+
+    fn map_to_normal(&self, value: i32) -> Normal {
+        IntRange::map_to_normal(self, value)
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> i32 {
+        IntRange::unmap_to_value(self, normal)
+    }
+}
+
 impl Default for IntRange {
     fn default() -> Self {
         IntRange::new(0, 100)
@@ -293,10 +540,7 @@ impl LogDBRange {
     /// * `value` - The inital value of the parameter.
     /// * `default_value` - The default value of the parameter.
     pub fn normal_param(&self, value: f32, default: f32) -> NormalParam {
-        NormalParam {
-            value: self.map_to_normal(value),
-            default: self.map_to_normal(default),
-        }
+        NormalParam::new(self.map_to_normal(value), self.map_to_normal(default))
     }
 
     /// Creates a new [`NormalParam`] with values mapped
@@ -304,10 +548,7 @@ impl LogDBRange {
     ///
     /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
     pub fn default_normal_param(&self) -> NormalParam {
-        NormalParam {
-            value: self.map_to_normal(0.0),
-            default: self.map_to_normal(0.0),
-        }
+        NormalParam::new(self.map_to_normal(0.0), self.map_to_normal(0.0))
     }
 
     /// Returns the corresponding [`Normal`] from the supplied `value`
@@ -369,6 +610,18 @@ impl LogDBRange {
     }
 }
 
+impl Range for LogDBRange {
+    type Value = f32;
+
+    fn map_to_normal(&self, value: f32) -> Normal {
+        LogDBRange::map_to_normal(self, value)
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> f32 {
+        LogDBRange::unmap_to_value(self, normal)
+    }
+}
+
 impl Default for LogDBRange {
     fn default() -> Self {
         LogDBRange::new(-12.0, 12.0, 0.5.into())
@@ -452,10 +705,7 @@ impl FreqRange {
     /// * `value` - The inital value of the parameter.
     /// * `default_value` - The default value of the parameter.
     pub fn normal_param(&self, value: f32, default: f32) -> NormalParam {
-        NormalParam {
-            value: self.map_to_normal(value),
-            default: self.map_to_normal(default),
-        }
+        NormalParam::new(self.map_to_normal(value), self.map_to_normal(default))
     }
 
     /// Creates a new [`NormalParam`] with values mapped
@@ -463,10 +713,10 @@ impl FreqRange {
     ///
     /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
     pub fn default_normal_param(&self) -> NormalParam {
-        NormalParam {
-            value: self.map_to_normal(20_480.0),
-            default: self.map_to_normal(20_480.0),
-        }
+        NormalParam::new(
+            self.map_to_normal(20_480.0),
+            self.map_to_normal(20_480.0),
+        )
     }
 
     /// Returns the corresponding [`Normal`] from the supplied frequency value
@@ -493,12 +743,484 @@ impl FreqRange {
     }
 }
 
+impl Range for FreqRange {
+    type Value = f32;
+
+    fn map_to_normal(&self, value: f32) -> Normal {
+        FreqRange::map_to_normal(self, value)
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> f32 {
+        FreqRange::unmap_to_value(self, normal)
+    }
+}
+
 impl Default for FreqRange {
     fn default() -> Self {
         FreqRange::new(20.0, 20_000.0)
     }
 }
 
+/// A range that defines a linear range of semitones, such as a transpose
+/// offset or MIDI note numbers
+///
+/// Values are snapped to whole semitones, or to whole cents with
+/// [`with_cents`](#method.with_cents).
+#[derive(Debug, Copy, Clone)]
+pub struct SemitoneRange {
+    min: i32,
+    max: i32,
+    span: f32,
+    span_recip: f32,
+    cents: bool,
+}
+
+impl SemitoneRange {
+    /// Creates a new `SemitoneRange` snapped to whole semitones
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum of the range in semitones (inclusive)
+    /// * `max` - the maximum of the range in semitones (inclusive)
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `max` <= `min`
+    pub fn new(min: i32, max: i32) -> Self {
+        assert!(max > min);
+
+        let span = (max - min) as f32;
+
+        Self {
+            min,
+            max,
+            span,
+            span_recip: span.recip(),
+            cents: false,
+        }
+    }
+
+    /// Creates a new `SemitoneRange` snapped to whole cents (hundredths of
+    /// a semitone), for fine tuning
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum of the range in semitones (inclusive)
+    /// * `max` - the maximum of the range in semitones (inclusive)
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `max` <= `min`
+    pub fn with_cents(min: i32, max: i32) -> Self {
+        Self {
+            cents: true,
+            ..Self::new(min, max)
+        }
+    }
+
+    /// Returns whether the range is snapped to whole cents (true) or to
+    /// whole semitones (false).
+    pub fn has_cents(&self) -> bool {
+        self.cents
+    }
+
+    fn snap(&self, value: f32) -> f32 {
+        let value = value.max(self.min as f32).min(self.max as f32);
+
+        if self.cents {
+            (value * 100.0).round() / 100.0
+        } else {
+            value.round()
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital value of the parameter.
+    /// * `default_value` - The default value of the parameter.
+    pub fn normal_param(&self, value: f32, default: f32) -> NormalParam {
+        NormalParam::new(self.map_to_normal(value), self.map_to_normal(default))
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range where `value` and `default_value` is `0.0`.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn default_normal_param(&self) -> NormalParam {
+        NormalParam::new(self.map_to_normal(0.0), self.map_to_normal(0.0))
+    }
+
+    /// Returns a [`Normal`] that is snapped to the closest semitone, or
+    /// cent, in this range.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn snapped(&self, normal: Normal) -> Normal {
+        self.map_to_normal(self.unmap_to_value(normal))
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied value in
+    /// semitones
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_to_normal(&self, value: f32) -> Normal {
+        ((self.snap(value) - self.min as f32) * self.span_recip).into()
+    }
+
+    /// Returns the corresponding value in semitones from the supplied
+    /// [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_value(&self, normal: Normal) -> f32 {
+        self.snap((normal.as_f32() * self.span) + self.min as f32)
+    }
+
+    /// Returns the name of a MIDI note, such as `"C#3"`, following the
+    /// convention where MIDI note `60` is `"C4"`.
+    pub fn note_name(midi_note: i32) -> String {
+        const NAMES: [&str; 12] = [
+            "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+        ];
+
+        format!(
+            "{}{}",
+            NAMES[midi_note.rem_euclid(12) as usize],
+            midi_note.div_euclid(12) - 1
+        )
+    }
+}
+
+impl Range for SemitoneRange {
+    type Value = f32;
+
+    fn map_to_normal(&self, value: f32) -> Normal {
+        SemitoneRange::map_to_normal(self, value)
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> f32 {
+        SemitoneRange::unmap_to_value(self, normal)
+    }
+}
+
+impl Default for SemitoneRange {
+    fn default() -> Self {
+        SemitoneRange::new(-24, 24)
+    }
+}
+
+/// A range that defines a continuous linear range of percentages, starting
+/// at `0 %`
+///
+/// The maximum is usually `100 %`, but can be set higher for over-unity
+/// parameters, such as a `200 %` gain or stereo width.
+#[derive(Debug, Copy, Clone)]
+pub struct PercentRange {
+    max: f32,
+    max_recip: f32,
+}
+
+impl PercentRange {
+    /// Creates a new `PercentRange`
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - the maximum of the range in percent (inclusive)
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `max` <= `0.0`
+    pub fn new(max: f32) -> Self {
+        assert!(max > 0.0, "max must be positive");
+
+        Self {
+            max,
+            max_recip: max.recip(),
+        }
+    }
+
+    /// Returns the maximum of the range in percent.
+    pub fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital value of the parameter.
+    /// * `default_value` - The default value of the parameter.
+    pub fn normal_param(&self, value: f32, default: f32) -> NormalParam {
+        NormalParam::new(self.map_to_normal(value), self.map_to_normal(default))
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range where `value` and `default_value` is `100.0`.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn default_normal_param(&self) -> NormalParam {
+        NormalParam::new(self.map_to_normal(100.0), self.map_to_normal(100.0))
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied value in
+    /// percent
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_to_normal(&self, value: f32) -> Normal {
+        (value * self.max_recip).into()
+    }
+
+    /// Returns the corresponding value in percent from the supplied
+    /// [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_value(&self, normal: Normal) -> f32 {
+        normal.as_f32() * self.max
+    }
+
+    /// Returns the corresponding unit interval value, where `100 %` is
+    /// `1.0`, from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_unit(&self, normal: Normal) -> f32 {
+        self.unmap_to_value(normal) / 100.0
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied unit interval
+    /// value, where `1.0` is `100 %`
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_unit_to_normal(&self, unit: f32) -> Normal {
+        self.map_to_normal(unit * 100.0)
+    }
+}
+
+impl Range for PercentRange {
+    type Value = f32;
+
+    fn map_to_normal(&self, value: f32) -> Normal {
+        PercentRange::map_to_normal(self, value)
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> f32 {
+        PercentRange::unmap_to_value(self, normal)
+    }
+}
+
+impl Default for PercentRange {
+    fn default() -> Self {
+        PercentRange::new(100.0)
+    }
+}
+
+/// A range that defines a continuous logarithmic range of `f32` tempo
+/// values in beats per minute (BPM)
+///
+/// Equal ratios of tempo, such as doubling the tempo, take the same
+/// span of the range, so slower tempos will increment slower per slider
+/// movement than faster ones.
+#[derive(Debug, Copy, Clone)]
+pub struct TempoRange {
+    mapping: LogMapping,
+}
+
+impl TempoRange {
+    /// Creates a new `TempoRange`
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum of the range in BPM (inclusive)
+    /// * `max` - the maximum of the range in BPM (inclusive)
+    ///
+    /// # Panics
+    ///
+    /// This will panic if
+    /// * `max` <= `min`
+    /// * `min` <= `0.0`
+    ///
+    pub fn new(min: f32, max: f32) -> Self {
+        Self {
+            mapping: LogMapping::new(min, max),
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital value of the parameter.
+    /// * `default_value` - The default value of the parameter.
+    pub fn normal_param(&self, value: f32, default: f32) -> NormalParam {
+        NormalParam::new(self.map_to_normal(value), self.map_to_normal(default))
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range where `value` and `default_value` is `120.0`.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn default_normal_param(&self) -> NormalParam {
+        NormalParam::new(self.map_to_normal(120.0), self.map_to_normal(120.0))
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied tempo value
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_to_normal(&self, value: f32) -> Normal {
+        self.mapping.map_to_normal(value)
+    }
+
+    /// Returns the corresponding tempo value from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_value(&self, normal: Normal) -> f32 {
+        self.mapping.unmap_to_value(normal)
+    }
+}
+
+impl Range for TempoRange {
+    type Value = f32;
+
+    fn map_to_normal(&self, value: f32) -> Normal {
+        TempoRange::map_to_normal(self, value)
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> f32 {
+        TempoRange::unmap_to_value(self, normal)
+    }
+}
+
+impl Default for TempoRange {
+    fn default() -> Self {
+        TempoRange::new(20.0, 300.0)
+    }
+}
+
+/// A range that defines a continuous logarithmic range of `f32` time
+/// values in seconds, for envelope and delay times
+///
+/// Each decade (such as `1 ms` to `10 ms`) takes the same span of the range,
+/// so shorter times will increment slower per slider movement than longer
+/// ones.
+#[derive(Debug, Copy, Clone)]
+pub struct TimeRange {
+    mapping: LogMapping,
+}
+
+impl TimeRange {
+    /// Creates a new `TimeRange`
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum of the range in seconds (inclusive)
+    /// * `max` - the maximum of the range in seconds (inclusive)
+    ///
+    /// # Panics
+    ///
+    /// This will panic if
+    /// * `max` <= `min`
+    /// * `min` <= `0.0`
+    ///
+    pub fn new(min: f32, max: f32) -> Self {
+        Self {
+            mapping: LogMapping::new(min, max),
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital value of the parameter in seconds.
+    /// * `default_value` - The default value of the parameter in seconds.
+    pub fn normal_param(&self, value: f32, default: f32) -> NormalParam {
+        NormalParam::new(self.map_to_normal(value), self.map_to_normal(default))
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range where `value` and `default_value` is `0.1` seconds.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn default_normal_param(&self) -> NormalParam {
+        NormalParam::new(self.map_to_normal(0.1), self.map_to_normal(0.1))
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied time value in
+    /// seconds
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_to_normal(&self, value: f32) -> Normal {
+        self.mapping.map_to_normal(value)
+    }
+
+    /// Returns the corresponding time value in seconds from the supplied
+    /// [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_value(&self, normal: Normal) -> f32 {
+        self.mapping.unmap_to_value(normal)
+    }
+}
+
+impl Range for TimeRange {
+    type Value = f32;
+
+    fn map_to_normal(&self, value: f32) -> Normal {
+        TimeRange::map_to_normal(self, value)
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> f32 {
+        TimeRange::unmap_to_value(self, normal)
+    }
+}
+
+impl Default for TimeRange {
+    fn default() -> Self {
+        TimeRange::new(0.0001, 10.0)
+    }
+}
+
+/// A logarithmic mapping of the positive values between `min` and `max`,
+/// where equal ratios of values take the same span of the range
+#[derive(Debug, Copy, Clone)]
+struct LogMapping {
+    min: f32,
+    max: f32,
+    ln_min: f32,
+    ln_span: f32,
+    ln_span_recip: f32,
+}
+
+impl LogMapping {
+    fn new(min: f32, max: f32) -> Self {
+        assert!(max > min, "max must be greater than min");
+        assert!(min > 0.0, "min must be positive");
+
+        let ln_min = min.ln();
+        let ln_span = max.ln() - ln_min;
+
+        Self {
+            min,
+            max,
+            ln_min,
+            ln_span,
+            ln_span_recip: ln_span.recip(),
+        }
+    }
+
+    fn map_to_normal(&self, value: f32) -> Normal {
+        let value = value.clamp(self.min, self.max);
+        ((value.ln() - self.ln_min) * self.ln_span_recip).into()
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> f32 {
+        ((normal.as_f32() * self.ln_span) + self.ln_min).exp()
+    }
+}
+
 /// Returns the corresponding frequency for the whole 10 octave spectrum
 /// (between 20 Hz and 20480 Hz)
 #[inline]
@@ -514,3 +1236,162 @@ fn octave_normal_to_spectrum(value: Normal) -> f32 {
 fn octave_spectrum_map_to_normal(freq: f32) -> Normal {
     (((freq / 40.0).log2() + 1.0) * 0.1).into()
 }
+
+/// A range of `f32` values that maps to a [`Normal`] with user closures,
+/// for curves that no other range of this module provides, such as tempo
+/// synced divisions or lookup tables.
+///
+/// [`Normal`]: ../struct.Normal.html
+#[derive(Clone)]
+pub struct ClosureRange {
+    map: Arc<dyn Fn(f32) -> Normal + Send + Sync>,
+    unmap: Arc<dyn Fn(Normal) -> f32 + Send + Sync>,
+}
+
+impl ClosureRange {
+    /// Creates a new `ClosureRange`
+    ///
+    /// # Arguments
+    ///
+    /// * `map` - returns the corresponding [`Normal`] from a value
+    /// * `unmap` - returns the corresponding value from a [`Normal`], the
+    ///   inverse of `map`
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn new<M, U>(map: M, unmap: U) -> Self
+    where
+        M: Fn(f32) -> Normal + Send + Sync + 'static,
+        U: Fn(Normal) -> f32 + Send + Sync + 'static,
+    {
+        Self {
+            map: Arc::new(map),
+            unmap: Arc::new(unmap),
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital value of the parameter.
+    /// * `default_value` - The default value of the parameter.
+    pub fn normal_param(&self, value: f32, default: f32) -> NormalParam {
+        NormalParam::new(self.map_to_normal(value), self.map_to_normal(default))
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied value
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_to_normal(&self, value: f32) -> Normal {
+        (self.map)(value)
+    }
+
+    /// Returns the corresponding value from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_value(&self, normal: Normal) -> f32 {
+        (self.unmap)(normal)
+    }
+}
+
+impl Range for ClosureRange {
+    type Value = f32;
+
+    fn map_to_normal(&self, value: f32) -> Normal {
+        ClosureRange::map_to_normal(self, value)
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> f32 {
+        ClosureRange::unmap_to_value(self, normal)
+    }
+}
+
+impl Debug for ClosureRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClosureRange").finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_round_trip<R: Range<Value = f32>>(range: &R, values: &[f32]) {
+        for value in values {
+            let normal = range.map_to_normal(*value);
+            let unmapped = range.unmap_to_value(normal);
+
+            assert!(
+                (unmapped - value).abs() <= value.abs().max(1.0) * 1.0e-4,
+                "{} unmapped to {}",
+                value,
+                unmapped
+            );
+        }
+
+        for i in 0..=10 {
+            let normal = Normal::from(i as f32 / 10.0);
+            let mapped = range.map_to_normal(range.unmap_to_value(normal));
+
+            assert!(
+                (mapped.as_f32() - normal.as_f32()).abs() <= 1.0e-4,
+                "{} mapped to {}",
+                normal.as_f32(),
+                mapped.as_f32()
+            );
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        assert_round_trip(&FloatRange::new(-1.0, 1.0), &[-1.0, 0.25, 1.0]);
+        assert_round_trip(
+            &LogDBRange::new(-12.0, 12.0, Normal::center()),
+            &[-12.0, -3.0, 0.0, 6.0, 12.0],
+        );
+        assert_round_trip(
+            &FreqRange::default(),
+            &[20.0, 440.0, 1_000.0, 20_000.0],
+        );
+        assert_round_trip(&PercentRange::new(200.0), &[0.0, 50.0, 200.0]);
+        assert_round_trip(&TempoRange::default(), &[20.0, 60.0, 120.0, 300.0]);
+        assert_round_trip(&TimeRange::default(), &[0.0001, 0.0025, 0.25, 10.0]);
+    }
+
+    #[test]
+    fn stepped_round_trips() {
+        let range = IntRange::new(-5, 5);
+        for value in -5..=5 {
+            assert_eq!(range.unmap_to_value(range.map_to_normal(value)), value);
+        }
+
+        let range = SemitoneRange::new(-24, 24);
+        for value in [-24.0, -7.0, 0.0, 12.0, 24.0] {
+            assert_eq!(range.unmap_to_value(range.map_to_normal(value)), value);
+        }
+
+        let range = SemitoneRange::with_cents(-12, 12);
+        let normal = range.map_to_normal(7.25);
+        assert!((range.unmap_to_value(normal) - 7.25).abs() < 1.0e-4);
+    }
+
+    #[test]
+    fn log_ranges_are_constrained() {
+        let range = TimeRange::new(0.001, 1.0);
+
+        assert_eq!(range.map_to_normal(0.000_1), Normal::min());
+        assert_eq!(range.map_to_normal(10.0), Normal::max());
+        assert!(
+            (range.unmap_to_value(range.default_normal_param().value) - 0.1)
+                .abs()
+                < 1.0e-4
+        );
+
+        let range = TempoRange::new(60.0, 240.0);
+
+        assert_eq!(range.map_to_normal(30.0), Normal::min());
+        assert_eq!(range.map_to_normal(480.0), Normal::max());
+        assert!((range.map_to_normal(120.0).as_f32() - 0.5).abs() < 1.0e-4);
+    }
+}
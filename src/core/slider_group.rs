@@ -0,0 +1,253 @@
+//! Link several sliders together so that moving one moves the others
+
+use crate::core::Normal;
+
+/// How the other members of a [`SliderGroup`] follow the member that is
+/// being moved.
+///
+/// [`SliderGroup`]: struct.SliderGroup.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum LinkMode {
+    /// The other members move by the same offset.
+    #[default]
+    Offset,
+    /// The other members are scaled by the same ratio, keeping their
+    /// proportions to each other.
+    Proportional,
+}
+
+/// A group of linked sliders, such as the channels of a multi-channel gain.
+///
+/// The group holds the current [`Normal`] of each member. Keep it in sync
+/// with [`set`] whenever the value of one of the linked parameters changes.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{slider_group::LinkMode, Normal, SliderGroup};
+///
+/// let mut group = SliderGroup::new(
+///     vec![Normal::from(0.2), Normal::from(0.5)],
+///     LinkMode::Offset,
+/// );
+///
+/// let changes = group.move_linked(0, Normal::from(0.3));
+///
+/// assert_eq!(changes.len(), 1);
+/// assert_eq!(changes[0].0, 1);
+/// assert!((changes[0].1.as_f32() - 0.6).abs() < 0.0001);
+/// ```
+///
+/// [`Normal`]: ../normal/struct.Normal.html
+/// [`set`]: #method.set
+#[derive(Debug, Clone, Default)]
+pub struct SliderGroup {
+    normals: Vec<Normal>,
+    mode: LinkMode,
+}
+
+impl SliderGroup {
+    /// Creates a new [`SliderGroup`] with the current [`Normal`] of each
+    /// member.
+    ///
+    /// [`SliderGroup`]: struct.SliderGroup.html
+    /// [`Normal`]: ../normal/struct.Normal.html
+    pub fn new(normals: Vec<Normal>, mode: LinkMode) -> Self {
+        Self { normals, mode }
+    }
+
+    /// Returns the [`LinkMode`] of the [`SliderGroup`].
+    ///
+    /// [`LinkMode`]: enum.LinkMode.html
+    /// [`SliderGroup`]: struct.SliderGroup.html
+    pub fn mode(&self) -> LinkMode {
+        self.mode
+    }
+
+    /// Sets the [`LinkMode`] of the [`SliderGroup`].
+    ///
+    /// [`LinkMode`]: enum.LinkMode.html
+    /// [`SliderGroup`]: struct.SliderGroup.html
+    pub fn set_mode(&mut self, mode: LinkMode) {
+        self.mode = mode;
+    }
+
+    /// Returns the number of members in the [`SliderGroup`].
+    ///
+    /// [`SliderGroup`]: struct.SliderGroup.html
+    pub fn len(&self) -> usize {
+        self.normals.len()
+    }
+
+    /// Returns `true` if the [`SliderGroup`] has no members.
+    ///
+    /// [`SliderGroup`]: struct.SliderGroup.html
+    pub fn is_empty(&self) -> bool {
+        self.normals.is_empty()
+    }
+
+    /// Returns the current [`Normal`] of each member.
+    ///
+    /// [`Normal`]: ../normal/struct.Normal.html
+    pub fn normals(&self) -> &[Normal] {
+        &self.normals
+    }
+
+    /// Returns the current [`Normal`] of the member at `index`.
+    ///
+    /// [`Normal`]: ../normal/struct.Normal.html
+    pub fn get(&self, index: usize) -> Option<Normal> {
+        self.normals.get(index).copied()
+    }
+
+    /// Sets the current [`Normal`] of the member at `index` without moving
+    /// the other members.
+    ///
+    /// [`Normal`]: ../normal/struct.Normal.html
+    pub fn set(&mut self, index: usize, normal: Normal) {
+        if let Some(member) = self.normals.get_mut(index) {
+            *member = normal;
+        }
+    }
+
+    /// Returns the new [`Normal`] of each of the other members when the
+    /// member at `index` moves from `from` to `to`, without changing the
+    /// [`SliderGroup`].
+    ///
+    /// Members that would not change are left out.
+    ///
+    /// [`Normal`]: ../normal/struct.Normal.html
+    /// [`SliderGroup`]: struct.SliderGroup.html
+    pub fn linked_changes(
+        &self,
+        index: usize,
+        from: Normal,
+        to: Normal,
+    ) -> Vec<(usize, Normal)> {
+        let from = from.as_f32();
+        let to = to.as_f32();
+
+        let ratio = if self.mode == LinkMode::Proportional && from > 0.0 {
+            Some(to / from)
+        } else {
+            None
+        };
+
+        self.normals
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != index)
+            .filter_map(|(i, normal)| {
+                let current = normal.as_f32();
+
+                let new = match ratio {
+                    Some(ratio) => current * ratio,
+                    None => current + (to - from),
+                }
+                .clamp(0.0, 1.0);
+
+                if (new - current).abs() > f32::EPSILON {
+                    Some((i, Normal::from(new)))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Moves the member at `index` to `normal` and the other members along
+    /// with it.
+    ///
+    /// Returns the new [`Normal`] of each of the other members that changed.
+    ///
+    /// [`Normal`]: ../normal/struct.Normal.html
+    pub fn move_linked(
+        &mut self,
+        index: usize,
+        normal: Normal,
+    ) -> Vec<(usize, Normal)> {
+        let from = match self.get(index) {
+            Some(from) => from,
+            None => return Vec::new(),
+        };
+
+        let changes = self.linked_changes(index, from, normal);
+
+        self.set(index, normal);
+        for (i, normal) in changes.iter() {
+            self.set(*i, *normal);
+        }
+
+        changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_normals(group: &SliderGroup, expected: &[f32]) {
+        assert_eq!(group.len(), expected.len());
+
+        for (normal, expected) in group.normals().iter().zip(expected) {
+            assert!(
+                (normal.as_f32() - expected).abs() < 0.0001,
+                "{:?} != {:?}",
+                group.normals(),
+                expected,
+            );
+        }
+    }
+
+    #[test]
+    fn offset_moves_the_others_by_the_same_offset() {
+        let mut group = SliderGroup::new(
+            vec![0.2.into(), 0.5.into(), 0.9.into()],
+            LinkMode::Offset,
+        );
+
+        let changes = group.move_linked(1, 0.6.into());
+        assert_eq!(changes.iter().map(|c| c.0).collect::<Vec<_>>(), [0, 2]);
+        assert_normals(&group, &[0.3, 0.6, 1.0]);
+
+        // The members clamped at the maximum don't change anymore
+        let changes = group.move_linked(0, 0.4.into());
+        assert_eq!(changes.iter().map(|c| c.0).collect::<Vec<_>>(), [1]);
+        assert_normals(&group, &[0.4, 0.7, 1.0]);
+    }
+
+    #[test]
+    fn proportional_scales_the_others() {
+        let mut group = SliderGroup::new(
+            vec![0.2.into(), 0.4.into()],
+            LinkMode::Proportional,
+        );
+
+        let _ = group.move_linked(0, 0.1.into());
+        assert_normals(&group, &[0.1, 0.2]);
+
+        // Moving from zero can't be scaled, so it falls back to an offset
+        group.set(0, Normal::min());
+        let _ = group.move_linked(0, 0.1.into());
+        assert_normals(&group, &[0.1, 0.3]);
+    }
+
+    #[test]
+    fn linked_changes_leave_the_group_unchanged() {
+        let group =
+            SliderGroup::new(vec![0.2.into(), 0.5.into()], LinkMode::Offset);
+
+        let changes = group.linked_changes(0, 0.2.into(), 0.3.into());
+        assert_eq!(changes.len(), 1);
+        assert_normals(&group, &[0.2, 0.5]);
+    }
+
+    #[test]
+    fn moving_an_unknown_member_does_nothing() {
+        let mut group =
+            SliderGroup::new(vec![0.2.into(), 0.5.into()], LinkMode::Offset);
+
+        assert!(group.move_linked(2, 0.3.into()).is_empty());
+        assert_normals(&group, &[0.2, 0.5]);
+    }
+}
@@ -0,0 +1,137 @@
+//! A [`Normal`] that eases toward a target value over time
+//!
+//! [`Normal`]: ../normal/struct.Normal.html
+
+use std::time::Duration;
+
+use crate::core::{Easing, Normal};
+
+/// A [`Normal`] that eases toward a target value over a configurable
+/// duration.
+///
+/// The time is advanced explicitly with [`tick`](#method.tick), so the same
+/// smoothing can be driven by a UI frame clock or by an audio block size.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use iced_audio::{Normal, SmoothedNormal};
+///
+/// let mut smoothed =
+///     SmoothedNormal::new(Normal::min(), Duration::from_millis(100));
+///
+/// smoothed.set_target(Normal::max());
+/// assert_eq!(smoothed.current().as_f32(), 0.0);
+///
+/// smoothed.tick(Duration::from_millis(50));
+/// assert!(smoothed.current().as_f32() > 0.5);
+///
+/// smoothed.tick(Duration::from_millis(50));
+/// assert_eq!(smoothed.current().as_f32(), 1.0);
+/// assert!(!smoothed.is_smoothing());
+/// ```
+///
+/// [`Normal`]: ../normal/struct.Normal.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SmoothedNormal {
+    from: Normal,
+    target: Normal,
+    current: Normal,
+    duration: Duration,
+    elapsed: Duration,
+    easing: Easing,
+}
+
+impl SmoothedNormal {
+    /// Creates a new `SmoothedNormal` at `value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - the initial value, which is also the initial target
+    /// * `duration` - the time it takes to reach a new target
+    pub fn new(value: Normal, duration: Duration) -> Self {
+        Self {
+            from: value,
+            target: value,
+            current: value,
+            duration,
+            elapsed: duration,
+            easing: Easing::default(),
+        }
+    }
+
+    /// Sets the [`Easing`] curve of the smoothing. The default is
+    /// [`Easing::EaseOutCubic`].
+    ///
+    /// [`Easing`]: ../easing/enum.Easing.html
+    /// [`Easing::EaseOutCubic`]: ../easing/enum.Easing.html#variant.EaseOutCubic
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Sets the time it takes to reach a new target. This applies to the
+    /// next call to [`set_target`](#method.set_target).
+    pub fn set_duration(&mut self, duration: Duration) {
+        self.duration = duration;
+    }
+
+    /// Sets the value to ease toward, starting from the current value.
+    pub fn set_target(&mut self, target: Normal) {
+        self.from = self.current;
+        self.target = target;
+        self.elapsed = Duration::from_secs(0);
+
+        if self.duration == Duration::from_secs(0) {
+            self.current = target;
+        }
+    }
+
+    /// Jumps to `value` without smoothing.
+    pub fn set_immediate(&mut self, value: Normal) {
+        self.from = value;
+        self.target = value;
+        self.current = value;
+        self.elapsed = self.duration;
+    }
+
+    /// Advances the smoothing by `dt` and returns the new current value.
+    pub fn tick(&mut self, dt: Duration) -> Normal {
+        if !self.is_smoothing() {
+            return self.current;
+        }
+
+        self.elapsed += dt;
+
+        if self.elapsed >= self.duration {
+            self.current = self.target;
+        } else {
+            let t = self.easing.apply(
+                self.elapsed.as_secs_f32() / self.duration.as_secs_f32(),
+            );
+
+            let from = self.from.as_f32();
+            let to = self.target.as_f32();
+
+            self.current = (from + ((to - from) * t)).into();
+        }
+
+        self.current
+    }
+
+    /// Returns the current, smoothed, value.
+    pub fn current(&self) -> Normal {
+        self.current
+    }
+
+    /// Returns the value the smoothing eases toward.
+    pub fn target(&self) -> Normal {
+        self.target
+    }
+
+    /// Returns whether the current value is still easing toward the target.
+    pub fn is_smoothing(&self) -> bool {
+        self.current != self.target
+    }
+}
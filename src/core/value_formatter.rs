@@ -0,0 +1,375 @@
+//! Module for the [`ValueFormatter`] trait, which formats and parses the
+//! values of the ranges
+//!
+//! [`ValueFormatter`]: trait.ValueFormatter.html
+
+use crate::core::range::*;
+use crate::core::Normal;
+
+/// Formats the values of a [`Range`] into text, including their unit, and
+/// parses them back from user text.
+///
+/// This is implemented by all the ranges of this crate, so the text marks
+/// and the [`Param`]s of a range show the same labels.
+///
+/// Floating point values are rounded to a maximum number of decimals, the
+/// `precision`, and trailing zeros are omitted, such as `"1.5 kHz"` or
+/// `"440 Hz"`.
+///
+/// Parsing accepts an optional unit, with or without a space, and the
+/// `k`, `m` and `µ` (or `u`) multiplier prefixes, such as `"-6dB"`,
+/// `"1.5k"` or `"250ms"`. The case of the unit is ignored, but the
+/// multiplier prefixes are case sensitive.
+///
+/// [`Range`]: ../range/trait.Range.html
+/// [`Param`]: ../param/struct.Param.html
+pub trait ValueFormatter: Range {
+    /// Returns the default maximum number of decimals of the formatted
+    /// values.
+    fn precision(&self) -> usize;
+
+    /// Formats the supplied `value` with at most `precision` decimals.
+    fn format_with_precision(
+        &self,
+        value: Self::Value,
+        precision: usize,
+    ) -> String;
+
+    /// Formats the supplied `value` with the default
+    /// [`precision`](#tymethod.precision).
+    fn format_value(&self, value: Self::Value) -> String {
+        self.format_with_precision(value, self.precision())
+    }
+
+    /// Parses a value from user text, such as a value typed in a text
+    /// input, or returns `None` if the text is not a valid value.
+    ///
+    /// The parsed value is not constrained to the range.
+    fn parse(&self, text: &str) -> Option<Self::Value>;
+}
+
+/// Parses a number followed by an optional multiplier prefix and an
+/// optional `unit`.
+///
+/// The case of the `unit` is ignored, but not the case of the multiplier
+/// prefixes, so `"1M"` is not read as `"1m"`.
+fn parse_with_unit(text: &str, unit: &str) -> Option<f32> {
+    let text = normalize(text);
+    let text = strip_unit(&text, unit);
+
+    let (number, multiplier) = if let Some(number) = text.strip_suffix('k') {
+        (number, 1_000.0)
+    } else if let Some(number) = text.strip_suffix('m') {
+        (number, 0.001)
+    } else if let Some(number) =
+        text.strip_suffix('µ').or_else(|| text.strip_suffix('u'))
+    {
+        (number, 0.000_001)
+    } else {
+        (text, 1.0)
+    };
+
+    let value = number.trim_end().parse::<f32>().ok()?;
+
+    if value.is_finite() {
+        Some(value * multiplier)
+    } else {
+        None
+    }
+}
+
+/// Trims `text` and replaces the typographic minus signs with `'-'`.
+fn normalize(text: &str) -> String {
+    text.trim().replace(&['\u{2212}', '\u{2011}'][..], "-")
+}
+
+/// Removes the `unit` at the end of `text`, ignoring its case, and the
+/// whitespace before it.
+fn strip_unit<'a>(text: &'a str, unit: &str) -> &'a str {
+    let split = text.len().saturating_sub(unit.len());
+
+    match text.get(split..) {
+        Some(suffix) if suffix.eq_ignore_ascii_case(unit) => {
+            text[..split].trim_end()
+        }
+        _ => text,
+    }
+}
+
+/// Rounds `value` to `precision` decimals, avoiding a negative zero.
+fn round(value: f32, precision: usize) -> f32 {
+    let factor = 10.0f32.powi(precision as i32);
+
+    (value * factor).round() / factor + 0.0
+}
+
+impl ValueFormatter for FloatRange {
+    fn precision(&self) -> usize {
+        2
+    }
+
+    fn format_with_precision(&self, value: f32, precision: usize) -> String {
+        format!("{}", round(value, precision))
+    }
+
+    fn parse(&self, text: &str) -> Option<f32> {
+        parse_with_unit(text, "")
+    }
+}
+
+impl ValueFormatter for IntRange {
+    fn precision(&self) -> usize {
+        0
+    }
+
+    /// Formats the supplied `value`, the `precision` is ignored.
+    fn format_with_precision(&self, value: i32, _precision: usize) -> String {
+        format!("{}", value)
+    }
+
+    fn parse(&self, text: &str) -> Option<i32> {
+        parse_with_unit(text, "").map(|value| value.round() as i32)
+    }
+}
+
+impl ValueFormatter for LogDBRange {
+    fn precision(&self) -> usize {
+        1
+    }
+
+    fn format_with_precision(&self, value: f32, precision: usize) -> String {
+        let value = round(value, precision);
+
+        if value > 0.0 {
+            format!("+{} dB", value)
+        } else {
+            format!("{} dB", value)
+        }
+    }
+
+    fn parse(&self, text: &str) -> Option<f32> {
+        let text = normalize(text);
+        let number = strip_unit(&text, "db");
+
+        if number == "-∞" || number.eq_ignore_ascii_case("-inf") {
+            return Some(self.unmap_to_value(Normal::min()));
+        }
+
+        parse_with_unit(&text, "db")
+    }
+}
+
+impl ValueFormatter for FreqRange {
+    fn precision(&self) -> usize {
+        1
+    }
+
+    fn format_with_precision(&self, value: f32, precision: usize) -> String {
+        let hz = round(value, precision);
+
+        if hz.abs() < 1_000.0 {
+            format!("{} Hz", hz)
+        } else {
+            format!("{} kHz", round(value / 1_000.0, precision))
+        }
+    }
+
+    fn parse(&self, text: &str) -> Option<f32> {
+        parse_with_unit(text, "hz")
+    }
+}
+
+impl ValueFormatter for SemitoneRange {
+    fn precision(&self) -> usize {
+        if self.has_cents() {
+            2
+        } else {
+            0
+        }
+    }
+
+    fn format_with_precision(&self, value: f32, precision: usize) -> String {
+        let value = round(value, precision);
+
+        if value > 0.0 {
+            format!("+{} st", value)
+        } else {
+            format!("{} st", value)
+        }
+    }
+
+    fn parse(&self, text: &str) -> Option<f32> {
+        parse_with_unit(text, "st")
+    }
+}
+
+impl ValueFormatter for PercentRange {
+    fn precision(&self) -> usize {
+        0
+    }
+
+    fn format_with_precision(&self, value: f32, precision: usize) -> String {
+        format!("{}%", round(value, precision))
+    }
+
+    fn parse(&self, text: &str) -> Option<f32> {
+        parse_with_unit(text, "%")
+    }
+}
+
+impl ValueFormatter for TempoRange {
+    fn precision(&self) -> usize {
+        1
+    }
+
+    fn format_with_precision(&self, value: f32, precision: usize) -> String {
+        format!("{} BPM", round(value, precision))
+    }
+
+    fn parse(&self, text: &str) -> Option<f32> {
+        parse_with_unit(text, "bpm")
+    }
+}
+
+impl ValueFormatter for TimeRange {
+    fn precision(&self) -> usize {
+        1
+    }
+
+    fn format_with_precision(&self, value: f32, precision: usize) -> String {
+        let (value, unit) = if value.abs() < 0.001 {
+            (value * 1_000_000.0, "µs")
+        } else if value.abs() < 1.0 {
+            (value * 1_000.0, "ms")
+        } else {
+            (value, "s")
+        };
+
+        format!("{} {}", round(value, precision), unit)
+    }
+
+    fn parse(&self, text: &str) -> Option<f32> {
+        parse_with_unit(text, "s")
+    }
+}
+
+impl ValueFormatter for ClosureRange {
+    fn precision(&self) -> usize {
+        2
+    }
+
+    fn format_with_precision(&self, value: f32, precision: usize) -> String {
+        format!("{}", round(value, precision))
+    }
+
+    fn parse(&self, text: &str) -> Option<f32> {
+        parse_with_unit(text, "")
+    }
+}
+
+impl<R: ValueFormatter> ValueFormatter for Inverted<R> {
+    fn precision(&self) -> usize {
+        self.inner().precision()
+    }
+
+    fn format_with_precision(
+        &self,
+        value: R::Value,
+        precision: usize,
+    ) -> String {
+        self.inner().format_with_precision(value, precision)
+    }
+
+    fn parse(&self, text: &str) -> Option<R::Value> {
+        self.inner().parse(text)
+    }
+}
+
+impl<R: ValueFormatter> ValueFormatter for Clamped<R> {
+    fn precision(&self) -> usize {
+        self.inner().precision()
+    }
+
+    fn format_with_precision(
+        &self,
+        value: R::Value,
+        precision: usize,
+    ) -> String {
+        self.inner().format_with_precision(value, precision)
+    }
+
+    fn parse(&self, text: &str) -> Option<R::Value> {
+        self.inner().parse(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_multiplier_prefixes() {
+        let range = FreqRange::default();
+
+        assert_eq!(range.parse("1.5k"), Some(1_500.0));
+        assert_eq!(range.parse("1.5 kHz"), Some(1_500.0));
+        assert_eq!(range.parse("440hz"), Some(440.0));
+        assert_eq!(range.parse("440 HZ"), Some(440.0));
+        assert_eq!(range.parse("1M"), None);
+        assert_eq!(range.parse("1K"), None);
+
+        let range = TimeRange::default();
+
+        assert_eq!(range.parse("250ms"), Some(0.25));
+        assert_eq!(range.parse("250 mS"), Some(0.25));
+        assert_eq!(range.parse("1.5 s"), Some(1.5));
+        assert_eq!(range.parse("100 µs"), Some(0.000_1));
+        assert_eq!(range.parse("100us"), Some(0.000_1));
+        assert_eq!(range.parse("250 Ms"), None);
+    }
+
+    #[test]
+    fn parse_units_and_signs() {
+        assert_eq!(FloatRange::default().parse(" 0.25 "), Some(0.25));
+        assert_eq!(IntRange::new(0, 10).parse("7.4"), Some(7));
+        assert_eq!(PercentRange::default().parse("50%"), Some(50.0));
+        assert_eq!(TempoRange::default().parse("120 bpm"), Some(120.0));
+        assert_eq!(TempoRange::default().parse("120BPM"), Some(120.0));
+        assert_eq!(SemitoneRange::default().parse("+7 st"), Some(7.0));
+        assert_eq!(SemitoneRange::default().parse("\u{2212}7st"), Some(-7.0));
+        assert_eq!(FloatRange::default().parse("abc"), None);
+        assert_eq!(FloatRange::default().parse("inf"), None);
+    }
+
+    #[test]
+    fn parse_db() {
+        let range = LogDBRange::new(-60.0, 12.0, Normal::from(0.5));
+        let min = range.unmap_to_value(Normal::min());
+
+        assert_eq!(range.parse("-6dB"), Some(-6.0));
+        assert_eq!(range.parse("+6 DB"), Some(6.0));
+        assert_eq!(range.parse("-inf"), Some(min));
+        assert_eq!(range.parse("-INF dB"), Some(min));
+        assert_eq!(range.parse("-∞"), Some(min));
+        assert_eq!(range.parse("\u{2212}∞ dB"), Some(min));
+        assert_eq!(range.parse("+inf"), None);
+        assert_eq!(range.parse("inf"), None);
+        assert_eq!(range.parse("∞"), None);
+        assert_eq!(range.parse("-infinite"), None);
+    }
+
+    #[test]
+    fn format_and_parse_round_trip() {
+        let range = TimeRange::default();
+
+        for value in [0.000_25, 0.002_5, 0.25, 2.5] {
+            let text = range.format_value(value);
+            let parsed = range.parse(&text).unwrap();
+            assert!((parsed - value).abs() <= value * 1.0e-5, "{}", text);
+        }
+
+        let range = SemitoneRange::default();
+        assert_eq!(range.format_value(7.0), "+7 st");
+        assert_eq!(range.parse(&range.format_value(-7.0)), Some(-7.0));
+    }
+}
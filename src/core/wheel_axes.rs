@@ -0,0 +1,35 @@
+//! Which scroll wheel axes a widget responds to
+
+/// The scroll wheel axes a widget responds to
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WheelAxes {
+    /// Only the vertical scroll wheel axis
+    Vertical,
+    /// Only the horizontal scroll wheel axis, such as horizontal trackpad
+    /// swipes
+    Horizontal,
+    /// Both scroll wheel axes. The axis that moved the most is used.
+    Both,
+}
+
+impl WheelAxes {
+    /// Returns the scroll amount to use for the given horizontal and
+    /// vertical scroll amounts.
+    pub fn select(&self, x: f32, y: f32) -> f32 {
+        if self.is_horizontal(x, y) {
+            x
+        } else {
+            y
+        }
+    }
+
+    /// Returns whether the horizontal scroll amount is the one to use for
+    /// the given horizontal and vertical scroll amounts.
+    pub fn is_horizontal(&self, x: f32, y: f32) -> bool {
+        match self {
+            WheelAxes::Vertical => false,
+            WheelAxes::Horizontal => true,
+            WheelAxes::Both => x.abs() > y.abs(),
+        }
+    }
+}
@@ -0,0 +1,286 @@
+//! Draw a solid color or gradient background
+
+use iced_graphics::triangle::{Mesh2D, Vertex2D};
+use iced_graphics::Primitive;
+use iced_native::{Color, Rectangle, Vector};
+
+pub use crate::style::background::{Background, Gradient};
+
+/// The axis a [`Gradient`] follows.
+///
+/// [`Gradient`]: struct.Gradient.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum Axis {
+    /// From left to right
+    Horizontal,
+    /// From right to left
+    HorizontalInverse,
+    /// From bottom to top
+    Vertical,
+    /// From top to bottom
+    VerticalInverse,
+}
+
+/// Draws a [`Background`] inside `bounds`.
+///
+/// A [`Gradient`] spans `gradient_bounds` so that a partial fill shows the
+/// colors of the part of the range it covers.
+///
+/// Meshes cannot have rounded corners, so the `border_radius` is only
+/// applied to solid colors and to the border of a [`Gradient`].
+///
+/// [`Background`]: enum.Background.html
+/// [`Gradient`]: struct.Gradient.html
+pub(crate) fn draw_background(
+    bounds: Rectangle,
+    gradient_bounds: &Rectangle,
+    axis: Axis,
+    background: &Background,
+    border_radius: f32,
+    border_width: f32,
+    border_color: Color,
+) -> Primitive {
+    match background {
+        Background::Color(color) => Primitive::Quad {
+            bounds,
+            background: iced_native::Background::Color(*color),
+            border_radius,
+            border_width,
+            border_color,
+        },
+        Background::Gradient(gradient) => with_border(
+            draw_gradient(bounds, gradient_bounds, axis, gradient),
+            bounds,
+            border_radius,
+            border_width,
+            border_color,
+        ),
+    }
+}
+
+/// Draws a [`Background`] inside the circle which fits in `bounds`.
+///
+/// A [`Gradient`] goes from the bottom to the top of the circle.
+///
+/// [`Background`]: enum.Background.html
+/// [`Gradient`]: struct.Gradient.html
+pub(crate) fn draw_circle_background(
+    bounds: Rectangle,
+    background: &Background,
+    border_width: f32,
+    border_color: Color,
+) -> Primitive {
+    let radius = bounds.width.min(bounds.height) / 2.0;
+
+    match background {
+        Background::Color(_) => draw_background(
+            bounds,
+            &bounds,
+            Axis::Vertical,
+            background,
+            radius,
+            border_width,
+            border_color,
+        ),
+        Background::Gradient(gradient) => with_border(
+            draw_circle_gradient(bounds, radius, gradient),
+            bounds,
+            radius,
+            border_width,
+            border_color,
+        ),
+    }
+}
+
+fn with_border(
+    mesh: Primitive,
+    bounds: Rectangle,
+    border_radius: f32,
+    border_width: f32,
+    border_color: Color,
+) -> Primitive {
+    if border_width > 0.0 && border_color.a > 0.0 {
+        Primitive::Group {
+            primitives: vec![
+                mesh,
+                Primitive::Quad {
+                    bounds,
+                    background: iced_native::Background::Color(
+                        Color::TRANSPARENT,
+                    ),
+                    border_radius,
+                    border_width,
+                    border_color,
+                },
+            ],
+        }
+    } else {
+        mesh
+    }
+}
+
+fn draw_gradient(
+    bounds: Rectangle,
+    gradient_bounds: &Rectangle,
+    axis: Axis,
+    gradient: &Gradient,
+) -> Primitive {
+    if bounds.width <= 0.0 || bounds.height <= 0.0 {
+        return Primitive::None;
+    }
+
+    // The positions along the gradient of the edges of `bounds` where the
+    // gradient starts and ends.
+    let (start, end) = match axis {
+        Axis::Horizontal | Axis::HorizontalInverse => {
+            if gradient_bounds.width <= 0.0 {
+                (0.0, 0.0)
+            } else {
+                let left =
+                    (bounds.x - gradient_bounds.x) / gradient_bounds.width;
+                let right = (bounds.x + bounds.width - gradient_bounds.x)
+                    / gradient_bounds.width;
+
+                if axis == Axis::Horizontal {
+                    (left, right)
+                } else {
+                    (1.0 - right, 1.0 - left)
+                }
+            }
+        }
+        Axis::Vertical | Axis::VerticalInverse => {
+            if gradient_bounds.height <= 0.0 {
+                (0.0, 0.0)
+            } else {
+                let top =
+                    (bounds.y - gradient_bounds.y) / gradient_bounds.height;
+                let bottom = (bounds.y + bounds.height - gradient_bounds.y)
+                    / gradient_bounds.height;
+
+                if axis == Axis::VerticalInverse {
+                    (top, bottom)
+                } else {
+                    (1.0 - bottom, 1.0 - top)
+                }
+            }
+        }
+    };
+
+    let mut positions = vec![start];
+    positions.extend(
+        gradient
+            .stops()
+            .into_iter()
+            .filter(|stop| *stop > start && *stop < end),
+    );
+    positions.push(end);
+
+    let mut vertices: Vec<Vertex2D> = Vec::with_capacity(positions.len() * 2);
+    let mut indices: Vec<u32> = Vec::with_capacity(positions.len() * 6);
+
+    for (i, position) in positions.iter().enumerate() {
+        let color = gradient.color_at(*position).into_linear();
+
+        let amount = if end > start {
+            (position - start) / (end - start)
+        } else {
+            0.0
+        };
+
+        let (a, b) = match axis {
+            Axis::Horizontal => {
+                let x = amount * bounds.width;
+                ([x, 0.0], [x, bounds.height])
+            }
+            Axis::HorizontalInverse => {
+                let x = bounds.width - (amount * bounds.width);
+                ([x, 0.0], [x, bounds.height])
+            }
+            Axis::Vertical => {
+                let y = bounds.height - (amount * bounds.height);
+                ([0.0, y], [bounds.width, y])
+            }
+            Axis::VerticalInverse => {
+                let y = amount * bounds.height;
+                ([0.0, y], [bounds.width, y])
+            }
+        };
+
+        vertices.push(Vertex2D { position: a, color });
+        vertices.push(Vertex2D { position: b, color });
+
+        if i > 0 {
+            let i = (i as u32) * 2;
+            indices.extend_from_slice(&[i - 2, i - 1, i, i - 1, i + 1, i]);
+        }
+    }
+
+    Primitive::Translate {
+        translation: Vector::new(bounds.x, bounds.y),
+        content: Box::new(Primitive::Mesh2D {
+            buffers: Mesh2D { vertices, indices },
+            size: bounds.size(),
+        }),
+    }
+}
+
+/// The number of segments of the outline of a circle filled with a
+/// [`Gradient`].
+///
+/// [`Gradient`]: struct.Gradient.html
+const CIRCLE_SEGMENTS: u32 = 64;
+
+/// Fills the circle of the given `radius` centered in `bounds` with a
+/// triangle fan. The colors of the gradient are interpolated between the
+/// vertices, so the middle color of a [`Gradient`] is approximated.
+///
+/// [`Gradient`]: struct.Gradient.html
+fn draw_circle_gradient(
+    bounds: Rectangle,
+    radius: f32,
+    gradient: &Gradient,
+) -> Primitive {
+    if radius <= 0.0 {
+        return Primitive::None;
+    }
+
+    let center = [bounds.width / 2.0, bounds.height / 2.0];
+    let color_at = |y: f32| {
+        gradient
+            .color_at(0.5 + ((center[1] - y) / (2.0 * radius)))
+            .into_linear()
+    };
+
+    let mut vertices: Vec<Vertex2D> =
+        Vec::with_capacity(CIRCLE_SEGMENTS as usize + 1);
+    let mut indices: Vec<u32> =
+        Vec::with_capacity(CIRCLE_SEGMENTS as usize * 3);
+
+    vertices.push(Vertex2D {
+        position: center,
+        color: color_at(center[1]),
+    });
+
+    for i in 0..CIRCLE_SEGMENTS {
+        let angle =
+            (i as f32) * std::f32::consts::TAU / (CIRCLE_SEGMENTS as f32);
+        let position = [
+            center[0] + (radius * angle.cos()),
+            center[1] + (radius * angle.sin()),
+        ];
+
+        vertices.push(Vertex2D {
+            position,
+            color: color_at(position[1]),
+        });
+        indices.extend_from_slice(&[0, i + 1, ((i + 1) % CIRCLE_SEGMENTS) + 1]);
+    }
+
+    Primitive::Translate {
+        translation: Vector::new(bounds.x, bounds.y),
+        content: Box::new(Primitive::Mesh2D {
+            buffers: Mesh2D { vertices, indices },
+            size: bounds.size(),
+        }),
+    }
+}
@@ -0,0 +1,33 @@
+//! Draw the outline of a widget which has the keyboard focus
+
+use iced_graphics::Primitive;
+use iced_native::{Background, Color, Rectangle};
+
+pub use crate::style::focus_ring::FocusRing;
+
+/// Draws a [`FocusRing`] around the given `bounds`.
+///
+/// [`FocusRing`]: struct.FocusRing.html
+pub(crate) fn draw_focus_ring(
+    bounds: Rectangle,
+    focus_ring: &Option<FocusRing>,
+) -> Primitive {
+    if let Some(focus_ring) = focus_ring {
+        let grow = focus_ring.offset + focus_ring.width;
+
+        Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds.x - grow,
+                y: bounds.y - grow,
+                width: bounds.width + (grow * 2.0),
+                height: bounds.height + (grow * 2.0),
+            },
+            background: Background::Color(Color::TRANSPARENT),
+            border_radius: focus_ring.border_radius,
+            border_width: focus_ring.width,
+            border_color: focus_ring.color,
+        }
+    } else {
+        Primitive::None
+    }
+}
@@ -4,18 +4,23 @@
 
 use std::cmp::Ordering;
 
-use crate::core::{ModulationRange, Normal};
+use crate::core::{KnobAngleRange, ModulationRange, Normal};
+use crate::graphics::background::draw_circle_background;
 use crate::graphics::{text_marks, tick_marks};
 use crate::native::knob;
+use crate::style::transition::Interpolate;
+use crate::style::Status;
 use iced_graphics::widget::canvas::{path::Arc, Frame, Path, Stroke};
-use iced_graphics::{Backend, Primitive, Renderer};
-use iced_native::{Background, Point, Rectangle, Size, Vector};
+use iced_graphics::{backend, Backend, Primitive, Renderer};
+use iced_native::{Color, Point, Rectangle, Size, Vector};
 
-pub use crate::native::knob::State;
+pub use crate::native::knob::{KnobInfo, State};
 pub use crate::style::knob::{
-    ArcBipolarStyle, ArcStyle, CircleNotch, CircleStyle, LineCap, LineNotch,
-    ModRangeArcStyle, NotchShape, Style, StyleLength, StyleSheet,
-    TextMarksStyle, TickMarksStyle, ValueArcStyle,
+    ArcBipolarStyle, ArcStyle, Background, CircleHighlight, CircleNotch,
+    CircleShadow, CircleStyle, Gradient, LineCap, LineNotch, ModRangeArcStyle,
+    ModulatedValueArcStyle, ModulatedValueStyle, NotchShape, Style,
+    StyleLength, StyleSheet, Styles, TextMarksStyle, TickMarksStyle,
+    ValueArcStyle,
 };
 
 struct ValueMarkers<'a> {
@@ -23,21 +28,13 @@ struct ValueMarkers<'a> {
     text_marks: Option<&'a text_marks::Group>,
     mod_range_1: Option<&'a ModulationRange>,
     mod_range_2: Option<&'a ModulationRange>,
+    modulated_value: Option<Normal>,
     tick_marks_style: Option<TickMarksStyle>,
     text_marks_style: Option<TextMarksStyle>,
     value_arc_style: Option<ValueArcStyle>,
     mod_range_style_1: Option<ModRangeArcStyle>,
     mod_range_style_2: Option<ModRangeArcStyle>,
-}
-
-struct KnobInfo {
-    bounds: Rectangle,
-    start_angle: f32,
-    angle_span: f32,
-    radius: f32,
-    value: Normal,
-    bipolar_center: Option<Normal>,
-    value_angle: f32,
+    modulated_value_style: Option<ModulatedValueStyle>,
 }
 
 /// A rotating knob GUI widget that controls a [`Param`]
@@ -46,46 +43,67 @@ struct KnobInfo {
 pub type Knob<'a, Message, Backend> =
     knob::Knob<'a, Message, Renderer<Backend>>;
 
-impl<B: Backend> knob::Renderer for Renderer<B> {
+impl<B: Backend + backend::Text> knob::Renderer for Renderer<B> {
     type Style = Box<dyn StyleSheet>;
 
-    fn draw(
-        &mut self,
-        bounds: Rectangle,
-        cursor_position: Point,
-        normal: Normal,
-        bipolar_center: Option<Normal>,
-        is_dragging: bool,
-        mod_range_1: Option<&ModulationRange>,
-        mod_range_2: Option<&ModulationRange>,
-        tick_marks: Option<&tick_marks::Group>,
-        text_marks: Option<&text_marks::Group>,
-        style_sheet: &Self::Style,
-        tick_marks_cache: &tick_marks::PrimitiveCache,
-        text_marks_cache: &text_marks::PrimitiveCache,
-    ) {
-        let is_mouse_over = bounds.contains(cursor_position);
-
-        let angle_range = style_sheet.angle_range();
-
-        let style = if is_dragging {
+    fn angle_range(&self, style_sheet: &Self::Style) -> KnobAngleRange {
+        style_sheet.angle_range()
+    }
+
+    fn draw(&mut self, info: knob::DrawInfo<'_>, style_sheet: &Self::Style) {
+        let knob::DrawInfo {
+            bounds,
+            is_hovered,
+            normal,
+            bipolar_center,
+            angle_range,
+            modulated_value,
+            overlay_draw,
+            is_disabled,
+            is_dragging,
+            mod_range_1,
+            mod_range_2,
+            tick_marks,
+            text_marks,
+            style_transition,
+            tick_marks_cache,
+            text_marks_cache,
+        } = info;
+
+        let angle_range = match angle_range {
+            Some(angle_range) => angle_range.clone(),
+            None => style_sheet.angle_range(),
+        };
+
+        let style = if is_disabled {
+            style_sheet.disabled()
+        } else if is_dragging {
             style_sheet.dragging()
-        } else if is_mouse_over {
+        } else if is_hovered {
             style_sheet.hovered()
         } else {
             style_sheet.active()
         };
 
+        let style = match style_transition {
+            Some((from, t)) => {
+                status_style(style_sheet.as_ref(), from).interpolate(&style, t)
+            }
+            None => style,
+        };
+
         let value_markers = ValueMarkers {
             tick_marks,
             text_marks,
             mod_range_1,
             mod_range_2,
+            modulated_value,
             tick_marks_style: style_sheet.tick_marks_style(),
             text_marks_style: style_sheet.text_marks_style(),
             value_arc_style: style_sheet.value_arc_style(),
             mod_range_style_1: style_sheet.mod_range_arc_style(),
             mod_range_style_2: style_sheet.mod_range_arc_style_2(),
+            modulated_value_style: style_sheet.modulated_value_style(),
         };
 
         let bounds = {
@@ -138,7 +156,7 @@ impl<B: Backend> knob::Renderer for Renderer<B> {
             value_angle,
         };
 
-        self.draw_primitive(match style {
+        let primitive = match style {
             Style::Circle(style) => draw_circle_style(
                 &knob_info,
                 style,
@@ -160,7 +178,37 @@ impl<B: Backend> knob::Renderer for Renderer<B> {
                 tick_marks_cache,
                 text_marks_cache,
             ),
-        })
+        };
+
+        if let Some(overlay_draw) = overlay_draw {
+            let mut frame = Frame::new(bounds.size());
+
+            overlay_draw(
+                &mut frame,
+                &KnobInfo {
+                    bounds: Rectangle {
+                        x: 0.0,
+                        y: 0.0,
+                        ..bounds
+                    },
+                    ..knob_info
+                },
+            );
+
+            self.draw_primitive(Primitive::Group {
+                primitives: vec![
+                    primitive,
+                    Primitive::Translate {
+                        translation: Vector::new(bounds.x, bounds.y),
+                        content: Box::new(
+                            frame.into_geometry().into_primitive(),
+                        ),
+                    },
+                ],
+            })
+        } else {
+            self.draw_primitive(primitive)
+        }
     }
 }
 
@@ -232,11 +280,13 @@ fn draw_text_marks(
 ) -> Primitive {
     if let Some(text_marks) = text_marks {
         if let Some(style) = style {
+            let center = Point::new(
+                knob_info.bounds.center_x(),
+                knob_info.bounds.center_y() + style.v_offset,
+            );
+
             text_marks::draw_radial_text_marks(
-                Point::new(
-                    knob_info.bounds.center_x(),
-                    knob_info.bounds.center_y() + style.v_offset,
-                ),
+                center,
                 knob_info.radius + style.offset,
                 knob_info.start_angle,
                 knob_info.angle_span,
@@ -292,13 +342,14 @@ fn draw_value_arc(
         }
 
         if let Some(right_filled_color) = style.right_filled_color {
-            if knob_info.value.as_f32() < 0.499
-                || knob_info.value.as_f32() > 0.501
-            {
+            let center =
+                knob_info.bipolar_center.unwrap_or_else(Normal::center);
+
+            if (knob_info.value.as_f32() - center.as_f32()).abs() > 0.001 {
                 let half_angle =
-                    knob_info.start_angle + (knob_info.angle_span / 2.0);
+                    knob_info.start_angle + center.scale(knob_info.angle_span);
 
-                if knob_info.value < Normal::center() {
+                if knob_info.value < center {
                     let filled_stroke = Stroke {
                         width: style.width,
                         color: style.left_filled_color,
@@ -316,7 +367,7 @@ fn draw_value_arc(
                     let filled_path = Path::new(|path| path.arc(filled_arc));
 
                     frame.stroke(&filled_path, filled_stroke);
-                } else if knob_info.value > Normal::center() {
+                } else if knob_info.value > center {
                     let filled_stroke = Stroke {
                         width: style.width,
                         color: right_filled_color,
@@ -457,6 +508,94 @@ fn draw_mod_range_arc(
     }
 }
 
+fn draw_modulated_value_arc(
+    knob_info: &KnobInfo,
+    modulated_angle: f32,
+    style: &ModulatedValueArcStyle,
+) -> Primitive {
+    let half_width = style.width / 2.0;
+    let arc_radius = knob_info.radius + style.offset + half_width;
+
+    let half_frame_size = (arc_radius + half_width).ceil();
+    let frame_size = half_frame_size * 2.0;
+    let frame_offset = half_frame_size - knob_info.radius;
+    let center_point = Point::new(half_frame_size, half_frame_size);
+
+    let mut frame = Frame::new(Size::new(frame_size, frame_size));
+
+    let (start_angle, end_angle) = if modulated_angle < knob_info.value_angle {
+        (modulated_angle, knob_info.value_angle)
+    } else {
+        (knob_info.value_angle, modulated_angle)
+    };
+
+    let stroke = Stroke {
+        width: style.width,
+        color: style.color,
+        line_cap: style.cap,
+        ..Stroke::default()
+    };
+
+    let arc = Arc {
+        center: center_point,
+        radius: arc_radius,
+        start_angle,
+        end_angle,
+    };
+
+    let path = Path::new(|path| path.arc(arc));
+
+    frame.stroke(&path, stroke);
+
+    Primitive::Translate {
+        translation: Vector::new(
+            knob_info.bounds.x - frame_offset,
+            knob_info.bounds.y - frame_offset,
+        ),
+        content: Box::new(frame.into_geometry().into_primitive()),
+    }
+}
+
+fn draw_modulated_value(
+    knob_info: &KnobInfo,
+    value_markers: &ValueMarkers<'_>,
+) -> Primitive {
+    if let Some(modulated_value) = value_markers.modulated_value {
+        if let Some(style) = &value_markers.modulated_value_style {
+            let modulated_angle = knob_info.start_angle
+                + modulated_value.scale(knob_info.angle_span);
+
+            let ghost_info = KnobInfo {
+                value: modulated_value,
+                value_angle: modulated_angle,
+                ..*knob_info
+            };
+
+            let arc = if let Some(arc_style) = &style.arc {
+                if modulated_value != knob_info.value {
+                    draw_modulated_value_arc(
+                        knob_info,
+                        modulated_angle,
+                        arc_style,
+                    )
+                } else {
+                    Primitive::None
+                }
+            } else {
+                Primitive::None
+            };
+
+            Primitive::Group {
+                primitives: vec![arc, draw_notch(&ghost_info, &style.notch)],
+            }
+        } else {
+            Primitive::None
+        }
+    } else {
+        Primitive::None
+    }
+}
+
 fn draw_circle_notch(knob_info: &KnobInfo, style: &CircleNotch) -> Primitive {
     let value_angle = knob_info.value_angle + std::f32::consts::FRAC_PI_2;
 
@@ -483,7 +622,7 @@ fn draw_circle_notch(knob_info: &KnobInfo, style: &CircleNotch) -> Primitive {
             width: notch_diameter,
             height: notch_diameter,
         },
-        background: Background::Color(style.color),
+        background: iced_native::Background::Color(style.color),
         border_radius: notch_radius,
         border_width: style.border_width,
         border_color: style.border_color,
@@ -493,16 +632,34 @@ fn draw_circle_notch(knob_info: &KnobInfo, style: &CircleNotch) -> Primitive {
 fn draw_line_notch(knob_info: &KnobInfo, style: &LineNotch) -> Primitive {
     let value_angle = knob_info.value_angle + std::f32::consts::FRAC_PI_2;
 
+    let value = knob_info.value.as_f32();
+
+    let color = if let Some(max_value_color) = style.max_value_color {
+        Gradient {
+            start: style.color,
+            middle: None,
+            end: max_value_color,
+        }
+        .color_at(value)
+    } else {
+        style.color
+    };
+
     let stroke = Stroke {
         width: style.width.from_knob_diameter(knob_info.bounds.width),
-        color: style.color,
+        color,
         line_cap: style.cap,
         ..Stroke::default()
     };
 
     let stroke_begin_y = -(knob_info.radius
         - style.offset.from_knob_diameter(knob_info.bounds.width));
-    let notch_height = style.length.from_knob_diameter(knob_info.bounds.width);
+
+    let mut notch_height =
+        style.length.from_knob_diameter(knob_info.bounds.width);
+    if let Some(min_scale) = style.length_scale_with_value {
+        notch_height *= min_scale + ((1.0 - min_scale) * value);
+    }
 
     let path = Path::line(
         Point::new(0.0, stroke_begin_y),
@@ -533,6 +690,99 @@ fn draw_notch(knob_info: &KnobInfo, notch: &NotchShape) -> Primitive {
     }
 }
 
+fn draw_circle_layer(center: Point, radius: f32, color: Color) -> Primitive {
+    Primitive::Quad {
+        bounds: Rectangle {
+            x: center.x - radius,
+            y: center.y - radius,
+            width: radius * 2.0,
+            height: radius * 2.0,
+        },
+        background: iced_native::Background::Color(color),
+        border_radius: radius,
+        border_width: 0.0,
+        border_color: Color::TRANSPARENT,
+    }
+}
+
+fn draw_circle_shadow(
+    knob_info: &KnobInfo,
+    style: &Option<CircleShadow>,
+) -> Primitive {
+    if let Some(style) = style {
+        let layers = style.layers.max(1);
+        let layers_recip = 1.0 / f32::from(layers);
+
+        let spread = style.spread.from_knob_diameter(knob_info.bounds.width);
+
+        let color = Color {
+            a: style.color.a * layers_recip,
+            ..style.color
+        };
+
+        let center = Point::new(
+            knob_info.bounds.center_x() + style.offset.x,
+            knob_info.bounds.center_y() + style.offset.y,
+        );
+
+        // Draw the widest (faintest) layer first so the overlapping layers
+        // build up towards the edge of the knob.
+        let primitives = (0..layers)
+            .map(|i| {
+                let radius = knob_info.radius
+                    + (spread * f32::from(layers - i) * layers_recip);
+
+                draw_circle_layer(center, radius, color)
+            })
+            .collect();
+
+        Primitive::Group { primitives }
+    } else {
+        Primitive::None
+    }
+}
+
+fn draw_circle_highlight(
+    knob_info: &KnobInfo,
+    style: &Option<CircleHighlight>,
+) -> Primitive {
+    if let Some(style) = style {
+        let layers = style.layers.max(1);
+        let layers_recip = 1.0 / f32::from(layers);
+
+        let highlight_radius =
+            style.diameter.from_knob_diameter(knob_info.bounds.width) / 2.0;
+        let offset = style.offset.from_knob_diameter(knob_info.bounds.width);
+
+        let color = Color {
+            a: style.color.a * layers_recip,
+            ..style.color
+        };
+
+        // Each layer moves from the edge of the knob towards the brightest
+        // point of the highlight, so the overlapping layers form a gradient.
+        let primitives = (0..layers)
+            .map(|i| {
+                let t = f32::from(i + 1) * layers_recip;
+
+                let radius = knob_info.radius
+                    + ((highlight_radius - knob_info.radius) * t);
+
+                let center = Point::new(
+                    knob_info.bounds.center_x(),
+                    knob_info.bounds.center_y() - (offset * t),
+                );
+
+                draw_circle_layer(center, radius, color)
+            })
+            .collect();
+
+        Primitive::Group { primitives }
+    } else {
+        Primitive::None
+    }
+}
+
 fn draw_circle_style<'a>(
     knob_info: &KnobInfo,
     style: CircleStyle,
@@ -548,13 +798,18 @@ fn draw_circle_style<'a>(
             text_marks_cache,
         );
 
-    let knob_back = Primitive::Quad {
-        bounds: knob_info.bounds,
-        background: Background::Color(style.color),
-        border_radius: knob_info.radius,
-        border_width: style.border_width,
-        border_color: style.border_color,
-    };
+    let shadow = draw_circle_shadow(knob_info, &style.shadow);
+
+    let knob_back = draw_circle_background(
+        knob_info.bounds,
+        &style.color,
+        style.border_width,
+        style.border_color,
+    );
+
+    let highlight = draw_circle_highlight(knob_info, &style.highlight);
+
+    let modulated_value = draw_modulated_value(knob_info, value_markers);
 
     let notch = draw_notch(knob_info, &style.notch);
 
@@ -565,7 +820,10 @@ fn draw_circle_style<'a>(
             value_arc,
             mod_range_arc_1,
             mod_range_arc_2,
+            shadow,
             knob_back,
+            highlight,
+            modulated_value,
             notch,
         ],
     }
@@ -639,6 +897,8 @@ fn draw_arc_style<'a>(
         }
     };
 
+    let modulated_value = draw_modulated_value(knob_info, value_markers);
+
     let notch = draw_notch(knob_info, &style.notch);
 
     Primitive::Group {
@@ -646,6 +906,7 @@ fn draw_arc_style<'a>(
             tick_marks,
             text_marks,
             arc,
+            modulated_value,
             notch,
             value_arc,
             mod_range_arc_1,
@@ -781,6 +1042,8 @@ fn draw_arc_bipolar_style<'a>(
         }
     };
 
+    let modulated_value = draw_modulated_value(knob_info, value_markers);
+
     let notch = if let Some((notch_left, notch_right)) = style.notch_left_right
     {
         match bipolar_state {
@@ -797,6 +1060,7 @@ fn draw_arc_bipolar_style<'a>(
             tick_marks,
             text_marks,
             arc,
+            modulated_value,
             notch,
             value_arc,
             mod_range_arc_1,
@@ -804,3 +1068,13 @@ fn draw_arc_bipolar_style<'a>(
         ],
     }
 }
+
+fn status_style(style_sheet: &dyn StyleSheet, status: Status) -> Style {
+    match status {
+        Status::Active => style_sheet.active(),
+        Status::Hovered => style_sheet.hovered(),
+        Status::Focused => style_sheet.focused(),
+        Status::Dragging => style_sheet.dragging(),
+        Status::Disabled => style_sheet.disabled(),
+    }
+}
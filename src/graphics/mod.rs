@@ -3,12 +3,18 @@
 pub mod h_slider;
 pub mod knob;
 pub mod mod_range_input;
+pub mod multi_xy_pad;
 pub mod ramp;
 pub mod v_slider;
 pub mod xy_pad;
 
+pub mod background;
+pub mod focus_ring;
+pub mod shadow;
+pub mod sprite;
 pub mod text_marks;
 pub mod tick_marks;
+pub mod tooltip;
 
 //pub mod db_meter;
 //pub mod phase_meter;
@@ -9,7 +9,7 @@ use iced_native::{Background, Point, Rectangle};
 
 pub use crate::native::mod_range_input::State;
 pub use crate::style::mod_range_input::{
-    CircleStyle, DefaultInvisible, SquareStyle, Style, StyleSheet,
+    CircleStyle, DefaultInvisible, SquareStyle, Style, StyleSheet, Styles,
 };
 
 /// An interactive dot that controls an [`Param`]
@@ -0,0 +1,195 @@
+//! Display an interactive 2D pad with several draggable nodes, each
+//! controlling its own pair of [`NormalParam`] parameters. One in the `x`
+//! coordinate and one in the `y` coordinate.
+//!
+//! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
+
+use crate::core::Normal;
+use crate::graphics::shadow::draw_shadow;
+use crate::native::multi_xy_pad;
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{Background, Color, Point, Rectangle};
+
+pub use crate::native::multi_xy_pad::State;
+pub use crate::style::multi_xy_pad::{
+    HandleCircle, HandleShape, HandleSquare, Shadow, Style, StyleSheet, Styles,
+};
+
+/// A 2D pad GUI widget with several draggable nodes, each controlling its
+/// own pair of [`NormalParam`] parameters.
+///
+/// a [`MultiXYPad`] will try to fill the space of its container while
+/// keeping a square aspect ratio.
+///
+/// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+/// [`MultiXYPad`]: struct.MultiXYPad.html
+pub type MultiXYPad<'a, Message, Backend> =
+    multi_xy_pad::MultiXYPad<'a, Message, Renderer<Backend>>;
+
+impl<B: Backend> multi_xy_pad::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        nodes: &[(Normal, Normal)],
+        dragging_node: Option<usize>,
+        style_sheet: &Self::Style,
+    ) {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if dragging_node.is_some() {
+            style_sheet.dragging()
+        } else if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let bounds_x = bounds.x.floor();
+        let bounds_y = bounds.y.floor();
+
+        let bounds_size = {
+            if bounds.width <= bounds.height {
+                bounds.width.floor()
+            } else {
+                bounds.height.floor()
+            }
+        };
+
+        let back = Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds_x,
+                y: bounds_y,
+                width: bounds_size,
+                height: bounds_size,
+            },
+            background: Background::Color(style.back_color),
+            border_radius: 0.0,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        };
+
+        let bounds_center = (bounds_size / 2.0).floor();
+
+        let (h_center_line, v_center_line) = if style.center_line_color
+            != Color::TRANSPARENT
+        {
+            let center_line_width = style.center_line_width;
+            let half_center_line_width = (center_line_width / 2.0).floor();
+
+            (
+                Primitive::Quad {
+                    bounds: Rectangle {
+                        x: bounds_x,
+                        y: bounds_y + bounds_center - half_center_line_width,
+                        width: bounds_size,
+                        height: center_line_width,
+                    },
+                    background: Background::Color(style.center_line_color),
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                Primitive::Quad {
+                    bounds: Rectangle {
+                        x: bounds_x + bounds_center - half_center_line_width,
+                        y: bounds_y,
+                        width: center_line_width,
+                        height: bounds_size,
+                    },
+                    background: Background::Color(style.center_line_color),
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+            )
+        } else {
+            (Primitive::None, Primitive::None)
+        };
+
+        let mut primitives = vec![back, h_center_line, v_center_line];
+
+        let handle_position = |(normal_x, normal_y): (Normal, Normal)| {
+            (
+                (bounds_x + (bounds_size * normal_x.as_f32())).floor(),
+                (bounds_y + (bounds_size * (1.0 - normal_y.as_f32()))).floor(),
+            )
+        };
+
+        for (index, normals) in nodes.iter().enumerate() {
+            if Some(index) != dragging_node {
+                let (x, y) = handle_position(*normals);
+                primitives.push(draw_handle(x, y, style_sheet.node(index)));
+            }
+        }
+
+        // The dragged node is drawn last so it stays above the others.
+        if let Some((index, normals)) =
+            dragging_node.and_then(|index| Some((index, nodes.get(index)?)))
+        {
+            let (x, y) = handle_position(*normals);
+            primitives.push(draw_handle(
+                x,
+                y,
+                style_sheet.node_dragging(index),
+            ));
+        }
+
+        self.draw_primitive(Primitive::Group { primitives })
+    }
+}
+
+fn draw_handle(x: f32, y: f32, shape: HandleShape) -> Primitive {
+    match shape {
+        HandleShape::Circle(circle) => {
+            let diameter = circle.diameter;
+            let radius = diameter / 2.0;
+
+            let bounds = Rectangle {
+                x: x - radius,
+                y: y - radius,
+                width: diameter,
+                height: diameter,
+            };
+
+            Primitive::Group {
+                primitives: vec![
+                    draw_shadow(bounds, radius, &circle.shadow),
+                    Primitive::Quad {
+                        bounds,
+                        background: Background::Color(circle.color),
+                        border_radius: radius,
+                        border_width: circle.border_width,
+                        border_color: circle.border_color,
+                    },
+                ],
+            }
+        }
+        HandleShape::Square(square) => {
+            let size = f32::from(square.size);
+            let half_size = (size / 2.0).floor();
+
+            let bounds = Rectangle {
+                x: x - half_size,
+                y: y - half_size,
+                width: size,
+                height: size,
+            };
+
+            Primitive::Group {
+                primitives: vec![
+                    draw_shadow(bounds, square.border_radius, &square.shadow),
+                    Primitive::Quad {
+                        bounds,
+                        background: Background::Color(square.color),
+                        border_radius: square.border_radius,
+                        border_width: square.border_width,
+                        border_color: square.border_color,
+                    },
+                ],
+            }
+        }
+    }
+}
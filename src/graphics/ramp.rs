@@ -10,7 +10,7 @@ use iced_graphics::{Backend, Primitive, Renderer};
 use iced_native::{Background, Point, Rectangle, Size, Vector};
 
 pub use crate::native::ramp::{RampDirection, State};
-pub use crate::style::ramp::{Style, StyleSheet};
+pub use crate::style::ramp::{Style, StyleSheet, Styles};
 
 /// A ramp GUI widget that controls a [`Param`]. It is usually used to
 /// represent the easing of a parameter between two points in time.
@@ -0,0 +1,51 @@
+//! Draw a drop shadow
+
+use iced_graphics::Primitive;
+use iced_native::{Background, Color, Rectangle};
+
+pub use crate::style::shadow::Shadow;
+
+/// Draws a [`Shadow`] beneath the shape with the given `bounds` and
+/// `border_radius`.
+///
+/// [`Shadow`]: struct.Shadow.html
+pub(crate) fn draw_shadow(
+    bounds: Rectangle,
+    border_radius: f32,
+    shadow: &Option<Shadow>,
+) -> Primitive {
+    if let Some(shadow) = shadow {
+        let layers = shadow.layers.max(1);
+        let layers_recip = 1.0 / f32::from(layers);
+
+        let color = Color {
+            a: shadow.color.a * layers_recip,
+            ..shadow.color
+        };
+
+        // Draw the widest (faintest) layer first so the overlapping layers
+        // build up towards the edge of the shape.
+        let primitives = (0..layers)
+            .map(|i| {
+                let grow = shadow.spread * f32::from(layers - i) * layers_recip;
+
+                Primitive::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + shadow.offset.x - grow,
+                        y: bounds.y + shadow.offset.y - grow,
+                        width: bounds.width + (grow * 2.0),
+                        height: bounds.height + (grow * 2.0),
+                    },
+                    background: Background::Color(color),
+                    border_radius: border_radius + grow,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                }
+            })
+            .collect();
+
+        Primitive::Group { primitives }
+    } else {
+        Primitive::None
+    }
+}
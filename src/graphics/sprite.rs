@@ -0,0 +1,34 @@
+//! Draw an image texture or a single frame of a sprite sheet
+
+use iced_graphics::Primitive;
+use iced_native::{image, Rectangle};
+
+pub use crate::style::sprite::SpriteFrame;
+
+/// Draws the image `handle` inside `bounds`.
+///
+/// If a [`SpriteFrame`] is given, the whole sprite sheet is drawn so that
+/// the frame lines up with `bounds`, and the rest of the sheet is clipped.
+///
+/// [`SpriteFrame`]: struct.SpriteFrame.html
+pub(crate) fn draw_sprite(
+    handle: image::Handle,
+    bounds: Rectangle,
+    frame: Option<&SpriteFrame>,
+) -> Primitive {
+    match frame {
+        Some(frame) => Primitive::Clip {
+            bounds,
+            content: Box::new(Primitive::Image {
+                handle,
+                bounds: Rectangle {
+                    x: bounds.x - frame.offset.x,
+                    y: bounds.y - frame.offset.y,
+                    width: frame.sheet_size.width,
+                    height: frame.sheet_size.height,
+                },
+            }),
+        },
+        None => Primitive::Image { handle, bounds },
+    }
+}
@@ -1,8 +1,10 @@
 //! Structs for constructing a group of text marks.
 
-use iced_native::{Point, Rectangle};
-use std::cell::RefCell;
-use std::sync::Arc;
+use crate::core::Normal;
+use iced_graphics::alignment::{Horizontal, Vertical};
+use iced_graphics::{Background, Color, Primitive};
+use iced_native::{Point, Rectangle, Vector};
+use std::sync::{Arc, Mutex, MutexGuard};
 
 pub use crate::native::text_marks::*;
 pub use crate::style::text_marks::*;
@@ -15,17 +17,138 @@ pub use horizontal::*;
 pub use radial::*;
 pub use vertical::*;
 
+/// The approximate advance of a character relative to the text size. There
+/// is no way to measure glyphs here.
+const CHAR_ADVANCE_RATIO: f32 = 0.6;
+
+/// The minimum gap in pixels between two labels before they are considered
+/// overlapping.
+const MIN_LABEL_GAP: f32 = 2.0;
+
+/// Returns the approximate width of the label `text` drawn with `style`.
+fn label_width(text: &str, style: &Style) -> f32 {
+    text.chars().count() as f32
+        * f32::from(style.text_size)
+        * CHAR_ADVANCE_RATIO
+}
+
+/// Returns the `text` primitive, with the label background of the `style`
+/// drawn behind it if there is one.
+fn with_background(text: Primitive, style: &Style) -> Primitive {
+    let background = match style.background {
+        Some(background) => background,
+        None => return text,
+    };
+
+    let bounds = match &text {
+        Primitive::Text {
+            content,
+            size,
+            bounds,
+            horizontal_alignment,
+            vertical_alignment,
+            ..
+        } => {
+            let width =
+                content.chars().count() as f32 * size * CHAR_ADVANCE_RATIO;
+            let height = *size;
+
+            let x = match horizontal_alignment {
+                Horizontal::Left => bounds.x,
+                Horizontal::Center => bounds.x - (width / 2.0),
+                Horizontal::Right => bounds.x - width,
+            };
+            let y = match vertical_alignment {
+                Vertical::Top => bounds.y,
+                Vertical::Center => bounds.y - (height / 2.0),
+                Vertical::Bottom => bounds.y - height,
+            };
+
+            Rectangle {
+                x: (x - background.padding).round(),
+                y: (y - background.padding).round(),
+                width: (width + (background.padding * 2.0)).round(),
+                height: (height + (background.padding * 2.0)).round(),
+            }
+        }
+        _ => return text,
+    };
+
+    Primitive::Group {
+        primitives: vec![
+            Primitive::Quad {
+                bounds,
+                background: Background::Color(background.color),
+                border_radius: background.border_radius,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            text,
+        ],
+    }
+}
+
+/// Returns the text marks to draw along an axis of `length` pixels, sorted
+/// by position.
+///
+/// When labels would overlap, only every Nth label is kept, using the
+/// smallest N that leaves enough room between them. The labels at both
+/// ends of the axis are always kept.
+///
+/// * `extent` - the approximate extent of a label along the axis
+fn decimated(
+    text_marks: &Group,
+    length: f32,
+    extent: impl Fn(&str) -> f32,
+) -> Vec<&(Normal, String)> {
+    let mut sorted: Vec<&(Normal, String)> = text_marks.group.iter().collect();
+    sorted.sort_by(|a, b| a.0.as_f32().partial_cmp(&b.0.as_f32()).unwrap());
+
+    let fits = |a: &(Normal, String), b: &(Normal, String)| {
+        let distance = (b.0.as_f32() - a.0.as_f32()).abs() * length;
+
+        distance >= ((extent(&a.1) + extent(&b.1)) / 2.0) + MIN_LABEL_GAP
+    };
+
+    let last = match sorted.len() {
+        0..=2 => return sorted,
+        len => len - 1,
+    };
+
+    for step in 1..=last {
+        let mut kept: Vec<&(Normal, String)> =
+            sorted.iter().step_by(step).copied().collect();
+
+        if kept.last() != sorted.last() {
+            // Keep the last label, replacing the previous one if it is too
+            // close.
+            if kept.len() > 1 && !fits(kept[kept.len() - 1], sorted[last]) {
+                let _ = kept.pop();
+            }
+            kept.push(sorted[last]);
+        }
+
+        if kept.windows(2).all(|pair| fits(pair[0], pair[1])) {
+            return kept;
+        }
+    }
+
+    vec![sorted[0], sorted[last]]
+}
+
 #[derive(Clone)]
 struct PrimitiveCacheData {
     pub cache: Arc<iced_graphics::Primitive>,
+    pub generation: u64,
+    pub cache_generation: Option<u64>,
 
     pub bounds: Rectangle,
     pub text_marks_hash: u64,
     pub style: Style,
     pub placement: Placement,
     pub inverse: bool,
+    pub follow_angle: bool,
 
-    pub center: Point,
     pub radius: f32,
     pub start_angle: f32,
     pub angle_span: f32,
@@ -35,14 +158,16 @@ impl Default for PrimitiveCacheData {
     fn default() -> Self {
         Self {
             cache: Arc::new(iced_graphics::Primitive::None),
+            generation: 0,
+            cache_generation: None,
 
             bounds: Rectangle::default(),
             text_marks_hash: 0,
             style: Style::default(),
             placement: Placement::default(),
             inverse: false,
+            follow_angle: false,
 
-            center: Point::default(),
             radius: 0.0,
             start_angle: 0.0,
             angle_span: 0.0,
@@ -57,14 +182,49 @@ impl std::fmt::Debug for PrimitiveCacheData {
 }
 
 /// A cache for text mark primitives.
+///
+/// The primitives are cached relative to the position of the widget, so
+/// clones of a [`PrimitiveCache`] share the same cache and can be handed to
+/// several widgets drawing the same scale, such as a bank of knobs. The
+/// scale is then only built once for all of them.
+///
+/// [`PrimitiveCache`]: struct.PrimitiveCache.html
 #[derive(Debug, Clone)]
 pub struct PrimitiveCache {
-    data: RefCell<PrimitiveCacheData>,
+    data: Arc<Mutex<PrimitiveCacheData>>,
 }
 
 impl PrimitiveCache {
+    fn data(&self) -> MutexGuard<'_, PrimitiveCacheData> {
+        self.data.lock().unwrap_or_else(|error| error.into_inner())
+    }
+
+    /// Clears the cache, so the text marks are built again the next time they
+    /// are drawn, even if none of the compared parameters changed.
+    ///
+    /// This is needed after changing something the cache can't see, such
+    /// as the text marks of a [`Group`] changed in place, or the glyphs of
+    /// a font loaded at runtime.
+    ///
+    /// This also clears the cache of all the clones of this cache, so
+    /// keeping a clone of the cache given to a widget allows clearing it.
+    ///
+    /// [`Group`]: struct.Group.html
+    pub fn clear(&self) {
+        let mut data = self.data();
+
+        data.generation = data.generation.wrapping_add(1);
+        data.cache = Arc::new(iced_graphics::Primitive::None);
+    }
+
+    /// Returns the number of times this cache was cleared with
+    /// [`clear`](#method.clear).
+    pub fn generation(&self) -> u64 {
+        self.data().generation
+    }
+
     /// Cache and retrieve linear text marks.
-    pub fn cached_linear<F: Fn() -> iced_graphics::Primitive>(
+    pub fn cached_linear<F: Fn(Rectangle) -> iced_graphics::Primitive>(
         &self,
         bounds: Rectangle,
         text_marks: &Group,
@@ -73,30 +233,92 @@ impl PrimitiveCache {
         inverse: bool,
         builder: F,
     ) -> iced_graphics::Primitive {
-        let mut data = self.data.borrow_mut();
+        let mut data = self.data();
 
-        if !(data.bounds == bounds
+        let origin_bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            ..bounds
+        };
+
+        if !(data.cache_generation == Some(data.generation)
+            && data.bounds == origin_bounds
             && data.text_marks_hash == text_marks.hashed()
             && data.style == style
             && data.placement == placement
             && data.inverse == inverse)
         {
-            data.bounds = bounds;
+            data.bounds = origin_bounds;
             data.text_marks_hash = text_marks.hashed();
             data.style = style;
             data.placement = placement;
             data.inverse = inverse;
 
-            data.cache = Arc::new(builder());
+            data.cache_generation = Some(data.generation);
+            data.cache = Arc::new(builder(origin_bounds));
         }
 
-        iced_graphics::Primitive::Cached {
-            cache: Arc::clone(&data.cache),
+        iced_graphics::Primitive::Translate {
+            translation: Vector::new(bounds.x, bounds.y),
+            content: Box::new(iced_graphics::Primitive::Cached {
+                cache: Arc::clone(&data.cache),
+            }),
         }
     }
 
     /// Cache and retrieve radial text marks.
-    pub fn cached_radial<F: Fn() -> iced_graphics::Primitive>(
+    pub fn cached_radial<F: Fn(Point) -> iced_graphics::Primitive>(
+        &self,
+        center: Point,
+        radius: f32,
+        start_angle: f32,
+        angle_span: f32,
+        text_marks: &Group,
+        style: Style,
+        inverse: bool,
+        builder: F,
+    ) -> iced_graphics::Primitive {
+        self.cached_radial_with_angle(
+            center,
+            radius,
+            start_angle,
+            angle_span,
+            text_marks,
+            style,
+            inverse,
+            false,
+            builder,
+        )
+    }
+
+    /// Cache and retrieve radial text marks which follow their angle.
+    pub fn cached_radial_following_angle<
+        F: Fn(Point) -> iced_graphics::Primitive,
+    >(
+        &self,
+        center: Point,
+        radius: f32,
+        start_angle: f32,
+        angle_span: f32,
+        text_marks: &Group,
+        style: Style,
+        inverse: bool,
+        builder: F,
+    ) -> iced_graphics::Primitive {
+        self.cached_radial_with_angle(
+            center,
+            radius,
+            start_angle,
+            angle_span,
+            text_marks,
+            style,
+            inverse,
+            true,
+            builder,
+        )
+    }
+
+    fn cached_radial_with_angle<F: Fn(Point) -> iced_graphics::Primitive>(
         &self,
         center: Point,
         radius: f32,
@@ -105,31 +327,37 @@ impl PrimitiveCache {
         text_marks: &Group,
         style: Style,
         inverse: bool,
+        follow_angle: bool,
         builder: F,
     ) -> iced_graphics::Primitive {
-        let mut data = self.data.borrow_mut();
+        let mut data = self.data();
 
-        if !(data.center == center
+        if !(data.cache_generation == Some(data.generation)
             && data.radius == radius
             && data.start_angle == start_angle
             && data.angle_span == angle_span
             && data.text_marks_hash == text_marks.hashed()
             && data.style == style
-            && data.inverse == inverse)
+            && data.inverse == inverse
+            && data.follow_angle == follow_angle)
         {
-            data.center = center;
             data.radius = radius;
             data.start_angle = start_angle;
             data.angle_span = angle_span;
             data.text_marks_hash = text_marks.hashed();
             data.style = style;
             data.inverse = inverse;
+            data.follow_angle = follow_angle;
 
-            data.cache = Arc::new(builder());
+            data.cache_generation = Some(data.generation);
+            data.cache = Arc::new(builder(Point::ORIGIN));
         }
 
-        iced_graphics::Primitive::Cached {
-            cache: Arc::clone(&data.cache),
+        iced_graphics::Primitive::Translate {
+            translation: Vector::new(center.x, center.y),
+            content: Box::new(iced_graphics::Primitive::Cached {
+                cache: Arc::clone(&data.cache),
+            }),
         }
     }
 }
@@ -137,7 +365,7 @@ impl PrimitiveCache {
 impl Default for PrimitiveCache {
     fn default() -> Self {
         Self {
-            data: RefCell::new(PrimitiveCacheData::default()),
+            data: Arc::new(Mutex::new(PrimitiveCacheData::default())),
         }
     }
 }
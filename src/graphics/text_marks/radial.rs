@@ -1,12 +1,13 @@
 use super::PrimitiveCache;
 use crate::native::text_marks;
-use crate::style::text_marks::Style;
+use crate::style::text_marks::{Orientation, Style};
 
 use iced_graphics::{
     alignment::Horizontal, alignment::Vertical, Point, Primitive, Rectangle,
 };
 
-/// Draws text marks around an arc.
+/// Draws text marks around an arc, with the labels oriented as set by the
+/// `orientation` of the `style`.
 ///
 /// * `center` - The center point of the arc.
 /// * `radius` - The radius of the arc where the text marks start
@@ -16,7 +17,7 @@ use iced_graphics::{
 /// * `style` - The text marks style.
 /// * `h_char_offset` - Extra horizontal offset in pixels for each additional
 /// character in the text label. This is used to keep longer labels on the sides
-/// from being too close to the arc.
+/// from being too close to the arc. Only used with horizontal labels.
 /// * `inverse` - Whether to inverse the positions of the text marks (true) or
 /// not (false).
 pub fn draw_radial_text_marks(
@@ -30,6 +31,34 @@ pub fn draw_radial_text_marks(
     inverse: bool,
     cache: &PrimitiveCache,
 ) -> Primitive {
+    match style.orientation {
+        Orientation::Horizontal => (),
+        Orientation::Tangential => {
+            return draw_radial_text_marks_following_angle(
+                center,
+                radius,
+                start_angle,
+                angle_span,
+                text_marks,
+                style,
+                inverse,
+                cache,
+            )
+        }
+        Orientation::Radial => {
+            return draw_radial_text_marks_along_radius(
+                center,
+                radius,
+                start_angle,
+                angle_span,
+                text_marks,
+                style,
+                inverse,
+                cache,
+            )
+        }
+    }
+
     cache.cached_radial(
         center,
         radius,
@@ -38,7 +67,7 @@ pub fn draw_radial_text_marks(
         text_marks,
         *style,
         inverse,
-        || {
+        |center| {
             let mut primitives: Vec<Primitive> = Vec::new();
 
             let color = style.color;
@@ -49,7 +78,13 @@ pub fn draw_radial_text_marks(
 
             let start_angle = start_angle + std::f32::consts::FRAC_PI_2;
 
-            for (position, text) in text_marks.group.iter() {
+            let text_marks = super::decimated(
+                text_marks,
+                radius * angle_span.abs(),
+                |text| super::label_width(text, style).max(text_size),
+            );
+
+            for (position, text) in text_marks {
                 let angle = if inverse {
                     start_angle + position.scale_inv(angle_span)
                 } else {
@@ -71,20 +106,210 @@ pub fn draw_radial_text_marks(
                     offset_x += (text.len() as f32 - 1.0) * h_char_offset;
                 }
 
-                primitives.push(Primitive::Text {
-                    content: text.clone(),
-                    size: text_size,
-                    bounds: Rectangle {
-                        x: (center.x + offset_x).round(),
-                        y: (center.y - (dy * radius)).round(),
-                        width: text_bounds_width,
-                        height: text_bounds_height,
+                primitives.push(super::with_background(
+                    Primitive::Text {
+                        content: text.clone(),
+                        size: text_size,
+                        bounds: Rectangle {
+                            x: (center.x + offset_x).round(),
+                            y: (center.y - (dy * radius)).round(),
+                            width: text_bounds_width,
+                            height: text_bounds_height,
+                        },
+                        color,
+                        font,
+                        horizontal_alignment: Horizontal::Center,
+                        vertical_alignment: Vertical::Center,
                     },
-                    color,
-                    font,
-                    horizontal_alignment: Horizontal::Center,
-                    vertical_alignment: Vertical::Center,
+                    style,
+                ));
+            }
+
+            Primitive::Group { primitives }
+        },
+    )
+}
+
+/// Draws text marks around an arc, laying out the characters of each label
+/// along the arc so that they follow the angle of their text mark.
+///
+/// Labels on the lower half of the arc are laid out in the opposite
+/// direction so they can still be read from left to right.
+///
+/// * `center` - The center point of the arc.
+/// * `radius` - The radius of the arc where the text marks start
+/// * `start_angle` - The starting angle of the arc in radians
+/// * `angle_span` - The span of the angle in radians
+/// * `text_marks` - The group of text marks.
+/// * `style` - The text marks style.
+/// * `inverse` - Whether to inverse the positions of the text marks (true) or
+///   not (false).
+pub fn draw_radial_text_marks_following_angle(
+    center: Point,
+    radius: f32,
+    start_angle: f32,
+    angle_span: f32,
+    text_marks: &text_marks::Group,
+    style: &Style,
+    inverse: bool,
+    cache: &PrimitiveCache,
+) -> Primitive {
+    cache.cached_radial_following_angle(
+        center,
+        radius,
+        start_angle,
+        angle_span,
+        text_marks,
+        *style,
+        inverse,
+        |center| {
+            let mut primitives: Vec<Primitive> = Vec::new();
+
+            let color = style.color;
+            let font = style.font;
+            let text_size = f32::from(style.text_size);
+            let text_bounds_height = f32::from(style.bounds_height);
+
+            let char_advance = text_size * super::CHAR_ADVANCE_RATIO;
+            let char_angle = if radius > 0.0 {
+                char_advance / radius
+            } else {
+                0.0
+            };
+
+            let start_angle = start_angle + std::f32::consts::FRAC_PI_2;
+
+            let text_marks = super::decimated(
+                text_marks,
+                radius * angle_span.abs(),
+                |text| super::label_width(text, style).max(text_size),
+            );
+
+            for (position, text) in text_marks {
+                let angle = if inverse {
+                    start_angle + position.scale_inv(angle_span)
+                } else {
+                    start_angle + position.scale(angle_span)
+                };
+
+                let direction = if angle.cos() < 0.0 { -1.0 } else { 1.0 };
+
+                let char_count = text.chars().count();
+                let half_span = (char_count as f32 - 1.0) / 2.0;
+
+                for (i, c) in text.chars().enumerate() {
+                    let char_angle = angle
+                        + (direction * (i as f32 - half_span) * char_angle);
+
+                    let (dx, dy) = char_angle.sin_cos();
+
+                    primitives.push(super::with_background(
+                        Primitive::Text {
+                            content: c.to_string(),
+                            size: text_size,
+                            bounds: Rectangle {
+                                x: (center.x + (dx * radius)).round(),
+                                y: (center.y - (dy * radius)).round(),
+                                width: char_advance.ceil(),
+                                height: text_bounds_height,
+                            },
+                            color,
+                            font,
+                            horizontal_alignment: Horizontal::Center,
+                            vertical_alignment: Vertical::Center,
+                        },
+                        style,
+                    ));
+                }
+            }
+
+            Primitive::Group { primitives }
+        },
+    )
+}
+
+/// Draws text marks around an arc, laying out the characters of each label
+/// along the radius of their text mark, from the arc outwards.
+///
+/// * `center` - The center point of the arc.
+/// * `radius` - The radius of the arc where the text marks start
+/// * `start_angle` - The starting angle of the arc in radians
+/// * `angle_span` - The span of the angle in radians
+/// * `text_marks` - The group of text marks.
+/// * `style` - The text marks style.
+/// * `inverse` - Whether to inverse the positions of the text marks (true) or
+///   not (false).
+pub fn draw_radial_text_marks_along_radius(
+    center: Point,
+    radius: f32,
+    start_angle: f32,
+    angle_span: f32,
+    text_marks: &text_marks::Group,
+    style: &Style,
+    inverse: bool,
+    cache: &PrimitiveCache,
+) -> Primitive {
+    cache.cached_radial(
+        center,
+        radius,
+        start_angle,
+        angle_span,
+        text_marks,
+        *style,
+        inverse,
+        |center| {
+            let mut primitives: Vec<Primitive> = Vec::new();
+
+            let color = style.color;
+            let font = style.font;
+            let text_size = f32::from(style.text_size);
+            let text_bounds_height = f32::from(style.bounds_height);
+
+            let char_advance = text_size * super::CHAR_ADVANCE_RATIO;
+
+            let start_angle = start_angle + std::f32::consts::FRAC_PI_2;
+
+            let text_marks =
+                super::decimated(text_marks, radius * angle_span.abs(), |_| {
+                    text_size
                 });
+
+            for (position, text) in text_marks {
+                let angle = if inverse {
+                    start_angle + position.scale_inv(angle_span)
+                } else {
+                    start_angle + position.scale(angle_span)
+                };
+
+                let (dx, dy) = angle.sin_cos();
+
+                // The characters stay upright, so they take their width
+                // along a horizontal radius and their height along a
+                // vertical one.
+                let char_step =
+                    (char_advance * dx.abs()) + (text_size * dy.abs());
+
+                for (i, c) in text.chars().enumerate() {
+                    let char_radius = radius + (i as f32 * char_step);
+
+                    primitives.push(super::with_background(
+                        Primitive::Text {
+                            content: c.to_string(),
+                            size: text_size,
+                            bounds: Rectangle {
+                                x: (center.x + (dx * char_radius)).round(),
+                                y: (center.y - (dy * char_radius)).round(),
+                                width: char_advance.ceil(),
+                                height: text_bounds_height,
+                            },
+                            color,
+                            font,
+                            horizontal_alignment: Horizontal::Center,
+                            vertical_alignment: Vertical::Center,
+                        },
+                        style,
+                    ));
+                }
             }
 
             Primitive::Group { primitives }
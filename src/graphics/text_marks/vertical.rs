@@ -21,40 +21,50 @@ fn draw_aligned(
     let text_bounds_width = f32::from(style.bounds_width);
     let text_bounds_height = f32::from(style.bounds_height);
 
+    let text_marks =
+        super::decimated(text_marks, bounds.height, |_| text_bounds_height);
+
     if inverse {
-        for text_mark in &text_marks.group {
-            primitives.push(Primitive::Text {
-                content: text_mark.1.clone(),
-                size: text_size,
-                bounds: Rectangle {
-                    x,
-                    y: (bounds.y + (text_mark.0.scale(bounds.height))).round(),
-                    width: text_bounds_width,
-                    height: text_bounds_height,
+        for text_mark in &text_marks {
+            primitives.push(super::with_background(
+                Primitive::Text {
+                    content: text_mark.1.clone(),
+                    size: text_size,
+                    bounds: Rectangle {
+                        x,
+                        y: (bounds.y + (text_mark.0.scale(bounds.height)))
+                            .round(),
+                        width: text_bounds_width,
+                        height: text_bounds_height,
+                    },
+                    color,
+                    font,
+                    horizontal_alignment: align,
+                    vertical_alignment: Vertical::Center,
                 },
-                color,
-                font,
-                horizontal_alignment: align,
-                vertical_alignment: Vertical::Center,
-            });
+                style,
+            ));
         }
     } else {
-        for text_mark in &text_marks.group {
-            primitives.push(Primitive::Text {
-                content: text_mark.1.clone(),
-                size: text_size,
-                bounds: Rectangle {
-                    x,
-                    y: (bounds.y + (text_mark.0.scale_inv(bounds.height)))
-                        .round(),
-                    width: text_bounds_width,
-                    height: text_bounds_height,
+        for text_mark in &text_marks {
+            primitives.push(super::with_background(
+                Primitive::Text {
+                    content: text_mark.1.clone(),
+                    size: text_size,
+                    bounds: Rectangle {
+                        x,
+                        y: (bounds.y + (text_mark.0.scale_inv(bounds.height)))
+                            .round(),
+                        width: text_bounds_width,
+                        height: text_bounds_height,
+                    },
+                    color,
+                    font,
+                    horizontal_alignment: align,
+                    vertical_alignment: Vertical::Center,
                 },
-                color,
-                font,
-                horizontal_alignment: align,
-                vertical_alignment: Vertical::Center,
-            });
+                style,
+            ));
         }
     }
 }
@@ -81,7 +91,9 @@ pub fn draw_vertical_text_marks(
         *style,
         *placement,
         inverse,
-        || {
+        |bounds| {
+            let bounds = &style.insets.inset_rect(&bounds);
+
             let primitives = match placement {
                 Placement::BothSides { inside, offset } => {
                     let bounds = offset.offset_rect(bounds);
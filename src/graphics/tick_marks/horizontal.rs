@@ -1,10 +1,12 @@
 //! `iced_graphics` renderer for tick marks
 
 use super::PrimitiveCache;
+use super::{border_radius, line_segments};
 use crate::core::Normal;
 use crate::native::tick_marks;
-use crate::style::tick_marks::{Placement, Shape, Style};
+use crate::style::tick_marks::{Dash, LineCap, Placement, Shape, Style};
 use iced_graphics::{Background, Color, Primitive, Rectangle};
+use iced_native::image;
 
 fn draw_horizontal_lines(
     primitives: &mut Vec<Primitive>,
@@ -15,37 +17,31 @@ fn draw_horizontal_lines(
     width: f32,
     length: f32,
     color: Color,
+    line_cap: LineCap,
+    dash: Option<Dash>,
     inverse: bool,
 ) {
-    let start_x = bounds_x - (f32::from(width) / 2.0);
+    let start_x = bounds_x - (width / 2.0);
     let back_color = Background::Color(color);
+    let segments = line_segments(length, width, line_cap, dash);
 
-    if inverse {
-        for tick_mark in tick_marks {
+    for tick_mark in tick_marks {
+        let x = if inverse {
+            start_x + tick_mark.scale_inv(bounds_width)
+        } else {
+            start_x + tick_mark.scale(bounds_width)
+        };
+
+        for (offset, length) in segments.iter() {
             primitives.push(Primitive::Quad {
                 bounds: Rectangle {
-                    x: (start_x + tick_mark.scale_inv(bounds_width)),
-                    y,
-                    width: f32::from(width),
-                    height: f32::from(length),
+                    x,
+                    y: y + offset,
+                    width,
+                    height: *length,
                 },
                 background: back_color,
-                border_radius: 0.0,
-                border_width: 0.0,
-                border_color: Color::TRANSPARENT,
-            });
-        }
-    } else {
-        for tick_mark in tick_marks {
-            primitives.push(Primitive::Quad {
-                bounds: Rectangle {
-                    x: (start_x + tick_mark.scale(bounds_width)),
-                    y,
-                    width: f32::from(width),
-                    height: f32::from(length),
-                },
-                background: back_color,
-                border_radius: 0.0,
+                border_radius: border_radius(width, *length, line_cap),
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
             });
@@ -101,6 +97,37 @@ fn draw_horizontal_circles(
     }
 }
 
+fn draw_horizontal_images(
+    primitives: &mut Vec<Primitive>,
+    tick_marks: &[Normal],
+    bounds_x: f32,
+    bounds_width: f32,
+    y: f32,
+    size: f32,
+    handle: &image::Handle,
+    inverse: bool,
+) {
+    let start_x = bounds_x - (size / 2.0);
+
+    for tick_mark in tick_marks {
+        let offset = if inverse {
+            tick_mark.scale_inv(bounds_width)
+        } else {
+            tick_mark.scale(bounds_width)
+        };
+
+        primitives.push(Primitive::Image {
+            handle: handle.clone(),
+            bounds: Rectangle {
+                x: start_x + offset,
+                y,
+                width: size,
+                height: size,
+            },
+        });
+    }
+}
+
 #[inline]
 fn draw_horizontal_top_aligned_tier(
     primitives: &mut Vec<Primitive>,
@@ -117,7 +144,16 @@ fn draw_horizontal_top_aligned_tier(
                 length,
                 width,
                 color,
+                ..
+            }
+            | Shape::Wedge {
+                length,
+                outer_width: width,
+                color,
+                ..
             } => {
+                let (line_cap, dash) = shape.line_stroke();
+
                 draw_horizontal_lines(
                     primitives,
                     tick_marks,
@@ -127,6 +163,8 @@ fn draw_horizontal_top_aligned_tier(
                     *width,
                     *length,
                     *color,
+                    line_cap,
+                    dash,
                     inverse,
                 );
             }
@@ -142,6 +180,18 @@ fn draw_horizontal_top_aligned_tier(
                     inverse,
                 );
             }
+            Shape::Image { handle, size } => {
+                draw_horizontal_images(
+                    primitives,
+                    tick_marks,
+                    bounds.x,
+                    bounds.width,
+                    y,
+                    *size,
+                    handle,
+                    inverse,
+                );
+            }
         }
     }
 }
@@ -154,30 +204,27 @@ fn draw_horizontal_top_aligned(
     style: &Style,
     inverse: bool,
 ) {
-    draw_horizontal_top_aligned_tier(
-        primitives,
-        bounds,
-        y,
-        tick_marks.tier_1(),
-        &style.tier_1,
-        inverse,
-    );
-    draw_horizontal_top_aligned_tier(
-        primitives,
-        bounds,
-        y,
-        tick_marks.tier_2(),
-        &style.tier_2,
-        inverse,
-    );
-    draw_horizontal_top_aligned_tier(
-        primitives,
-        bounds,
-        y,
-        tick_marks.tier_3(),
-        &style.tier_3,
-        inverse,
-    );
+    for (positions, shape) in super::tiers(tick_marks, style).iter() {
+        draw_horizontal_top_aligned_tier(
+            primitives,
+            bounds,
+            y,
+            Some(positions),
+            shape,
+            inverse,
+        );
+    }
+
+    for (position, shape) in tick_marks.overrides() {
+        draw_horizontal_top_aligned_tier(
+            primitives,
+            bounds,
+            y,
+            Some(&vec![*position]),
+            shape,
+            inverse,
+        );
+    }
 }
 
 #[inline]
@@ -196,7 +243,16 @@ fn draw_horizontal_bottom_aligned_tier(
                 length,
                 width,
                 color,
+                ..
+            }
+            | Shape::Wedge {
+                length,
+                outer_width: width,
+                color,
+                ..
             } => {
+                let (line_cap, dash) = shape.line_stroke();
+
                 draw_horizontal_lines(
                     primitives,
                     tick_marks,
@@ -206,6 +262,8 @@ fn draw_horizontal_bottom_aligned_tier(
                     *width,
                     *length,
                     *color,
+                    line_cap,
+                    dash,
                     inverse,
                 );
             }
@@ -221,6 +279,18 @@ fn draw_horizontal_bottom_aligned_tier(
                     inverse,
                 );
             }
+            Shape::Image { handle, size } => {
+                draw_horizontal_images(
+                    primitives,
+                    tick_marks,
+                    bounds.x,
+                    bounds.width,
+                    y - *size,
+                    *size,
+                    handle,
+                    inverse,
+                );
+            }
         }
     }
 }
@@ -233,30 +303,27 @@ fn draw_horizontal_bottom_aligned(
     style: &Style,
     inverse: bool,
 ) {
-    draw_horizontal_bottom_aligned_tier(
-        primitives,
-        bounds,
-        y,
-        tick_marks.tier_1(),
-        &style.tier_1,
-        inverse,
-    );
-    draw_horizontal_bottom_aligned_tier(
-        primitives,
-        bounds,
-        y,
-        tick_marks.tier_2(),
-        &style.tier_2,
-        inverse,
-    );
-    draw_horizontal_bottom_aligned_tier(
-        primitives,
-        bounds,
-        y,
-        tick_marks.tier_3(),
-        &style.tier_3,
-        inverse,
-    );
+    for (positions, shape) in super::tiers(tick_marks, style).iter() {
+        draw_horizontal_bottom_aligned_tier(
+            primitives,
+            bounds,
+            y,
+            Some(positions),
+            shape,
+            inverse,
+        );
+    }
+
+    for (position, shape) in tick_marks.overrides() {
+        draw_horizontal_bottom_aligned_tier(
+            primitives,
+            bounds,
+            y,
+            Some(&vec![*position]),
+            shape,
+            inverse,
+        );
+    }
 }
 
 #[inline]
@@ -276,7 +343,16 @@ fn draw_horizontal_center_aligned_tier(
                 length,
                 width,
                 color,
+                ..
+            }
+            | Shape::Wedge {
+                length,
+                outer_width: width,
+                color,
+                ..
             } => {
+                let (line_cap, dash) = shape.line_stroke();
+
                 let (y, length) = if fill_length {
                     (
                         bounds.y + f32::from(*length),
@@ -295,6 +371,8 @@ fn draw_horizontal_center_aligned_tier(
                     *width,
                     length,
                     *color,
+                    line_cap,
+                    dash,
                     inverse,
                 );
             }
@@ -319,6 +397,24 @@ fn draw_horizontal_center_aligned_tier(
                     inverse,
                 );
             }
+            Shape::Image { handle, size } => {
+                let (y, size) = if fill_length {
+                    (bounds.y + *size, bounds.height - (*size * 2.0))
+                } else {
+                    (y - (size / 2.0), *size)
+                };
+
+                draw_horizontal_images(
+                    primitives,
+                    tick_marks,
+                    bounds.x,
+                    bounds.width,
+                    y,
+                    size,
+                    handle,
+                    inverse,
+                );
+            }
         }
     }
 }
@@ -332,33 +428,29 @@ fn draw_horizontal_center_aligned(
     fill_length: bool,
     inverse: bool,
 ) {
-    draw_horizontal_center_aligned_tier(
-        primitives,
-        bounds,
-        y,
-        tick_marks.tier_1(),
-        &style.tier_1,
-        fill_length,
-        inverse,
-    );
-    draw_horizontal_center_aligned_tier(
-        primitives,
-        bounds,
-        y,
-        tick_marks.tier_2(),
-        &style.tier_2,
-        fill_length,
-        inverse,
-    );
-    draw_horizontal_center_aligned_tier(
-        primitives,
-        bounds,
-        y,
-        tick_marks.tier_3(),
-        &style.tier_3,
-        fill_length,
-        inverse,
-    );
+    for (positions, shape) in super::tiers(tick_marks, style).iter() {
+        draw_horizontal_center_aligned_tier(
+            primitives,
+            bounds,
+            y,
+            Some(positions),
+            shape,
+            fill_length,
+            inverse,
+        );
+    }
+
+    for (position, shape) in tick_marks.overrides() {
+        draw_horizontal_center_aligned_tier(
+            primitives,
+            bounds,
+            y,
+            Some(&vec![*position]),
+            shape,
+            fill_length,
+            inverse,
+        );
+    }
 }
 
 #[inline]
@@ -379,7 +471,16 @@ fn draw_horizontal_center_aligned_split_tier(
                 length,
                 width,
                 color,
+                ..
+            }
+            | Shape::Wedge {
+                length,
+                outer_width: width,
+                color,
+                ..
             } => {
+                let (line_cap, dash) = shape.line_stroke();
+
                 let (left_y, length) = if fill_length {
                     let length =
                         f32::from(*length) + (bounds.height + gap) / 2.0;
@@ -399,6 +500,8 @@ fn draw_horizontal_center_aligned_split_tier(
                     *width,
                     length,
                     *color,
+                    line_cap,
+                    dash,
                     inverse,
                 );
                 draw_horizontal_lines(
@@ -410,6 +513,8 @@ fn draw_horizontal_center_aligned_split_tier(
                     *width,
                     length,
                     *color,
+                    line_cap,
+                    dash,
                     inverse,
                 );
             }
@@ -446,6 +551,36 @@ fn draw_horizontal_center_aligned_split_tier(
                     inverse,
                 );
             }
+            Shape::Image { handle, size } => {
+                let (left_y, size) = if fill_length {
+                    (bounds.y - *size, *size + ((bounds.height + gap) / 2.0))
+                } else {
+                    (y - *size - (gap / 2.0), *size)
+                };
+
+                let right_y = y + (gap / 2.0);
+
+                draw_horizontal_images(
+                    primitives,
+                    tick_marks,
+                    bounds.x,
+                    bounds.width,
+                    left_y,
+                    size,
+                    handle,
+                    inverse,
+                );
+                draw_horizontal_images(
+                    primitives,
+                    tick_marks,
+                    bounds.x,
+                    bounds.width,
+                    right_y,
+                    size,
+                    handle,
+                    inverse,
+                );
+            }
         }
     }
 }
@@ -460,36 +595,31 @@ fn draw_horizontal_center_aligned_split(
     gap: f32,
     inverse: bool,
 ) {
-    draw_horizontal_center_aligned_split_tier(
-        primitives,
-        bounds,
-        y,
-        tick_marks.tier_1(),
-        &style.tier_1,
-        fill_length,
-        gap,
-        inverse,
-    );
-    draw_horizontal_center_aligned_split_tier(
-        primitives,
-        bounds,
-        y,
-        tick_marks.tier_2(),
-        &style.tier_2,
-        fill_length,
-        gap,
-        inverse,
-    );
-    draw_horizontal_center_aligned_split_tier(
-        primitives,
-        bounds,
-        y,
-        tick_marks.tier_3(),
-        &style.tier_3,
-        fill_length,
-        gap,
-        inverse,
-    );
+    for (positions, shape) in super::tiers(tick_marks, style).iter() {
+        draw_horizontal_center_aligned_split_tier(
+            primitives,
+            bounds,
+            y,
+            Some(positions),
+            shape,
+            fill_length,
+            gap,
+            inverse,
+        );
+    }
+
+    for (position, shape) in tick_marks.overrides() {
+        draw_horizontal_center_aligned_split_tier(
+            primitives,
+            bounds,
+            y,
+            Some(&vec![*position]),
+            shape,
+            fill_length,
+            gap,
+            inverse,
+        );
+    }
 }
 
 /// Draws tick marks on a horizontal axis.
@@ -511,10 +641,12 @@ pub fn draw_horizontal_tick_marks(
     cache.cached_linear(
         *bounds,
         tick_marks,
-        *style,
+        style.clone(),
         *placement,
         inverse,
-        || {
+        |bounds| {
+            let bounds = &style.insets.inset_rect(&bounds);
+
             let primitives = match placement {
                 Placement::BothSides { offset, inside } => {
                     let bounds = offset.offset_rect(bounds);
@@ -1,8 +1,8 @@
 //! Structs for constructing a group of tick marks.
 
-use iced_native::{Point, Rectangle};
-use std::cell::RefCell;
-use std::sync::Arc;
+use crate::core::Normal;
+use iced_native::{Point, Rectangle, Vector};
+use std::sync::{Arc, Mutex, MutexGuard};
 
 pub use crate::native::tick_marks::*;
 pub use crate::style::tick_marks::*;
@@ -15,9 +15,83 @@ pub use horizontal::*;
 pub use radial::*;
 pub use vertical::*;
 
+/// Splits a line tick mark of the given `length` in the segments to draw,
+/// as `(offset, length)` pairs from the start of the line.
+///
+/// Each dash is a segment, extended by half the `width` of the line at both
+/// ends with [`LineCap::Square`] and [`LineCap::Round`].
+///
+/// [`LineCap::Square`]: enum.LineCap.html#variant.Square
+/// [`LineCap::Round`]: enum.LineCap.html#variant.Round
+fn line_segments(
+    length: f32,
+    width: f32,
+    line_cap: LineCap,
+    dash: Option<Dash>,
+) -> Vec<(f32, f32)> {
+    let mut segments = match dash {
+        Some(dash) if dash.length > 0.0 => {
+            let period = dash.length + dash.gap.max(0.0);
+
+            let mut segments = Vec::new();
+            let mut offset = 0.0;
+            while offset < length {
+                segments.push((offset, dash.length.min(length - offset)));
+                offset += period;
+            }
+            segments
+        }
+        _ => vec![(0.0, length)],
+    };
+
+    if line_cap != LineCap::Butt {
+        let half_width = width / 2.0;
+
+        for (offset, length) in segments.iter_mut() {
+            *offset -= half_width;
+            *length += width;
+        }
+    }
+
+    segments
+}
+
+/// Returns the tick marks of each tier with their shape. With a gradient in
+/// the `style`, each tick mark is returned alone with its own color.
+fn tiers(tick_marks: &Group, style: &Style) -> Vec<(Vec<Normal>, Shape)> {
+    let tiers = tick_marks.tiers().iter().zip(style.tiers.iter());
+
+    match style.gradient {
+        Some(gradient) => tiers
+            .flat_map(|(positions, shape)| {
+                positions.iter().map(move |position| {
+                    (
+                        vec![*position],
+                        shape.with_color(gradient.color_at(*position)),
+                    )
+                })
+            })
+            .collect(),
+        None => tiers
+            .map(|(positions, shape)| (positions.clone(), shape.clone()))
+            .collect(),
+    }
+}
+
+/// Returns the border radius of a line segment drawn as a quad.
+fn border_radius(width: f32, length: f32, line_cap: LineCap) -> f32 {
+    if line_cap == LineCap::Round {
+        width.min(length) / 2.0
+    } else {
+        0.0
+    }
+}
+
 #[derive(Clone)]
 struct PrimitiveCacheData {
     pub cache: Arc<iced_graphics::Primitive>,
+    pub generation: u64,
+    pub cache_generation: Option<u64>,
 
     pub bounds: Rectangle,
     pub tick_marks_hash: u64,
@@ -25,7 +99,6 @@ struct PrimitiveCacheData {
     pub placement: Placement,
     pub inverse: bool,
 
-    pub center: Point,
     pub radius: f32,
     pub start_angle: f32,
     pub angle_span: f32,
@@ -36,6 +109,8 @@ impl Default for PrimitiveCacheData {
     fn default() -> Self {
         Self {
             cache: Arc::new(iced_graphics::Primitive::None),
+            generation: 0,
+            cache_generation: None,
 
             bounds: Rectangle::default(),
             tick_marks_hash: 0,
@@ -43,7 +118,6 @@ impl Default for PrimitiveCacheData {
             placement: Placement::default(),
             inverse: false,
 
-            center: Point::default(),
             radius: 0.0,
             start_angle: 0.0,
             angle_span: 0.0,
@@ -59,14 +133,48 @@ impl std::fmt::Debug for PrimitiveCacheData {
 }
 
 /// A cache for tick mark primitives.
+///
+/// The primitives are cached relative to the position of the widget, so
+/// clones of a [`PrimitiveCache`] share the same cache and can be handed to
+/// several widgets drawing the same scale, such as a bank of knobs. The
+/// scale is then only built once for all of them.
+///
+/// [`PrimitiveCache`]: struct.PrimitiveCache.html
 #[derive(Debug, Clone)]
 pub struct PrimitiveCache {
-    data: RefCell<PrimitiveCacheData>,
+    data: Arc<Mutex<PrimitiveCacheData>>,
 }
 
 impl PrimitiveCache {
+    fn data(&self) -> MutexGuard<'_, PrimitiveCacheData> {
+        self.data.lock().unwrap_or_else(|error| error.into_inner())
+    }
+
+    /// Clears the cache, so the tick marks are built again the next time they
+    /// are drawn, even if none of the compared parameters changed.
+    ///
+    /// This is needed after changing something the cache can't see, such
+    /// as an image loaded at runtime for [`Shape::Image`] tick marks.
+    ///
+    /// This also clears the cache of all the clones of this cache, so
+    /// keeping a clone of the cache given to a widget allows clearing it.
+    ///
+    /// [`Shape::Image`]: enum.Shape.html#variant.Image
+    pub fn clear(&self) {
+        let mut data = self.data();
+
+        data.generation = data.generation.wrapping_add(1);
+        data.cache = Arc::new(iced_graphics::Primitive::None);
+    }
+
+    /// Returns the number of times this cache was cleared with
+    /// [`clear`](#method.clear).
+    pub fn generation(&self) -> u64 {
+        self.data().generation
+    }
+
     /// Cache and retrieve linear tick marks.
-    pub fn cached_linear<F: Fn() -> iced_graphics::Primitive>(
+    pub fn cached_linear<F: Fn(Rectangle) -> iced_graphics::Primitive>(
         &self,
         bounds: Rectangle,
         tick_marks: &Group,
@@ -75,30 +183,41 @@ impl PrimitiveCache {
         inverse: bool,
         builder: F,
     ) -> iced_graphics::Primitive {
-        let mut data = self.data.borrow_mut();
+        let mut data = self.data();
+
+        let origin_bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            ..bounds
+        };
 
-        if !(data.bounds == bounds
+        if !(data.cache_generation == Some(data.generation)
+            && data.bounds == origin_bounds
             && data.tick_marks_hash == tick_marks.hashed()
             && data.style == style
             && data.placement == placement
             && data.inverse == inverse)
         {
-            data.bounds = bounds;
+            data.bounds = origin_bounds;
             data.tick_marks_hash = tick_marks.hashed();
             data.style = style;
             data.placement = placement;
             data.inverse = inverse;
 
-            data.cache = Arc::new(builder());
+            data.cache_generation = Some(data.generation);
+            data.cache = Arc::new(builder(origin_bounds));
         }
 
-        iced_graphics::Primitive::Cached {
-            cache: Arc::clone(&data.cache),
+        iced_graphics::Primitive::Translate {
+            translation: Vector::new(bounds.x, bounds.y),
+            content: Box::new(iced_graphics::Primitive::Cached {
+                cache: Arc::clone(&data.cache),
+            }),
         }
     }
 
     /// Cache and retrieve radial tick marks.
-    pub fn cached_radial<F: Fn() -> iced_graphics::Primitive>(
+    pub fn cached_radial<F: Fn(Point) -> iced_graphics::Primitive>(
         &self,
         center: Point,
         radius: f32,
@@ -110,9 +229,9 @@ impl PrimitiveCache {
         inverse: bool,
         builder: F,
     ) -> iced_graphics::Primitive {
-        let mut data = self.data.borrow_mut();
+        let mut data = self.data();
 
-        if !(data.center == center
+        if !(data.cache_generation == Some(data.generation)
             && data.radius == radius
             && data.start_angle == start_angle
             && data.angle_span == angle_span
@@ -121,7 +240,6 @@ impl PrimitiveCache {
             && data.style == style
             && data.inverse == inverse)
         {
-            data.center = center;
             data.radius = radius;
             data.start_angle = start_angle;
             data.angle_span = angle_span;
@@ -130,11 +248,15 @@ impl PrimitiveCache {
             data.style = style;
             data.inverse = inverse;
 
-            data.cache = Arc::new(builder());
+            data.cache_generation = Some(data.generation);
+            data.cache = Arc::new(builder(Point::ORIGIN));
         }
 
-        iced_graphics::Primitive::Cached {
-            cache: Arc::clone(&data.cache),
+        iced_graphics::Primitive::Translate {
+            translation: Vector::new(center.x, center.y),
+            content: Box::new(iced_graphics::Primitive::Cached {
+                cache: Arc::clone(&data.cache),
+            }),
         }
     }
 }
@@ -142,7 +264,7 @@ impl PrimitiveCache {
 impl Default for PrimitiveCache {
     fn default() -> Self {
         Self {
-            data: RefCell::new(PrimitiveCacheData::default()),
+            data: Arc::new(Mutex::new(PrimitiveCacheData::default())),
         }
     }
 }
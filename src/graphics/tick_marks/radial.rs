@@ -1,11 +1,13 @@
-use iced_graphics::widget::canvas::{Fill, Frame, LineCap, Path, Stroke};
+use iced_graphics::widget::canvas::{
+    self, Fill, Frame, LineDash, Path, Stroke,
+};
 use iced_graphics::Primitive;
-use iced_native::{Color, Point, Size, Vector};
+use iced_native::{image, Color, Point, Rectangle, Size, Vector};
 
 use super::PrimitiveCache;
 use crate::core::Normal;
 use crate::native::tick_marks;
-use crate::style::tick_marks::{Shape, Style};
+use crate::style::tick_marks::{Dash, LineCap, Shape, Style};
 
 fn draw_radial_circles(
     frame: &mut Frame,
@@ -29,7 +31,7 @@ fn draw_radial_circles(
             let angle = start_angle + tick_mark.scale_inv(angle_span);
 
             frame.with_save(|frame| {
-                if angle < -0.001 || angle > 0.001 {
+                if !(-0.001..=0.001).contains(&angle) {
                     frame.rotate(angle);
                 }
 
@@ -41,7 +43,7 @@ fn draw_radial_circles(
             let angle = start_angle + tick_mark.scale(angle_span);
 
             frame.with_save(|frame| {
-                if angle < -0.001 || angle > 0.001 {
+                if !(-0.001..=0.001).contains(&angle) {
                     frame.rotate(angle);
                 }
 
@@ -51,6 +53,87 @@ fn draw_radial_circles(
     }
 }
 
+fn draw_radial_wedges(
+    frame: &mut Frame,
+    offset_radius: f32,
+    start_angle: f32,
+    angle_span: f32,
+    tick_marks: &[Normal],
+    color: Color,
+    inner_width: f32,
+    outer_width: f32,
+    length: f32,
+    inverse: bool,
+) {
+    let fill = Fill {
+        color,
+        ..Fill::default()
+    };
+
+    let half_inner_width = inner_width / 2.0;
+    let half_outer_width = outer_width / 2.0;
+    let outer_radius = offset_radius + length;
+
+    let path = Path::new(|path| {
+        path.move_to(Point::new(-half_inner_width, -offset_radius));
+        path.line_to(Point::new(half_inner_width, -offset_radius));
+        path.line_to(Point::new(half_outer_width, -outer_radius));
+        path.line_to(Point::new(-half_outer_width, -outer_radius));
+        path.close();
+    });
+
+    for tick_mark in tick_marks {
+        let angle = if inverse {
+            start_angle + tick_mark.scale_inv(angle_span)
+        } else {
+            start_angle + tick_mark.scale(angle_span)
+        };
+
+        frame.with_save(|frame| {
+            if !(-0.001..=0.001).contains(&angle) {
+                frame.rotate(angle);
+            }
+
+            frame.fill(&path, fill);
+        });
+    }
+}
+
+fn draw_radial_images(
+    images: &mut Vec<Primitive>,
+    offset_radius: f32,
+    start_angle: f32,
+    angle_span: f32,
+    tick_marks: &[Normal],
+    handle: &image::Handle,
+    size: f32,
+    inverse: bool,
+) {
+    let half_size = size / 2.0;
+
+    for tick_mark in tick_marks {
+        let angle = if inverse {
+            start_angle + tick_mark.scale_inv(angle_span)
+        } else {
+            start_angle + tick_mark.scale(angle_span)
+        };
+
+        // Same position as a point at `(0.0, -offset_radius)` rotated by
+        // `angle` in the frame, but the image itself stays upright.
+        let (sin, cos) = angle.sin_cos();
+
+        images.push(Primitive::Image {
+            handle: handle.clone(),
+            bounds: Rectangle {
+                x: (offset_radius * sin) - half_size,
+                y: -(offset_radius * cos) - half_size,
+                width: size,
+                height: size,
+            },
+        });
+    }
+}
+
 fn draw_radial_lines(
     frame: &mut Frame,
     offset_radius: f32,
@@ -60,12 +143,27 @@ fn draw_radial_lines(
     color: Color,
     width: f32,
     length: f32,
+    line_cap: LineCap,
+    dash: Option<Dash>,
     inverse: bool,
 ) {
+    let dash_segments = dash.map(|dash| [dash.length, dash.gap]);
+
     let stroke = Stroke {
         width,
         color,
-        line_cap: LineCap::Butt,
+        line_cap: match line_cap {
+            LineCap::Butt => canvas::LineCap::Butt,
+            LineCap::Square => canvas::LineCap::Square,
+            LineCap::Round => canvas::LineCap::Round,
+        },
+        line_dash: dash_segments
+            .as_ref()
+            .map(|segments| LineDash {
+                segments,
+                offset: 0,
+            })
+            .unwrap_or_default(),
         ..Stroke::default()
     };
 
@@ -79,7 +177,7 @@ fn draw_radial_lines(
             let angle = start_angle + tick_mark.scale_inv(angle_span);
 
             frame.with_save(|frame| {
-                if angle < -0.001 || angle > 0.001 {
+                if !(-0.001..=0.001).contains(&angle) {
                     frame.rotate(angle);
                 }
 
@@ -91,7 +189,7 @@ fn draw_radial_lines(
             let angle = start_angle + tick_mark.scale(angle_span);
 
             frame.with_save(|frame| {
-                if angle < -0.001 || angle > 0.001 {
+                if !(-0.001..=0.001).contains(&angle) {
                     frame.rotate(angle);
                 }
 
@@ -104,6 +202,7 @@ fn draw_radial_lines(
 #[inline]
 fn draw_tier(
     frame: &mut Frame,
+    images: &mut Vec<Primitive>,
     offset_radius: f32,
     start_angle: f32,
     angle_span: f32,
@@ -119,6 +218,8 @@ fn draw_tier(
                 length,
                 width,
                 color,
+                line_cap,
+                dash,
             } => {
                 let length = f32::from(*length);
                 let width = f32::from(*width);
@@ -133,6 +234,8 @@ fn draw_tier(
                         *color,
                         width,
                         length,
+                        *line_cap,
+                        *dash,
                         inverse,
                     );
                 } else {
@@ -145,6 +248,8 @@ fn draw_tier(
                         *color,
                         width,
                         length,
+                        *line_cap,
+                        *dash,
                         inverse,
                     );
                 }
@@ -176,30 +281,68 @@ fn draw_tier(
                     );
                 }
             }
-        }
-    }
-}
+            Shape::Wedge {
+                inner_width,
+                outer_width,
+                length,
+                color,
+            } => {
+                let offset_radius = if inside {
+                    offset_radius - length
+                } else {
+                    offset_radius
+                };
 
-fn max_length(style: &Style) -> f32 {
-    let length_1 = match style.tier_1 {
-        Shape::None => 0.0,
-        Shape::Line { length, .. } => length,
-        Shape::Circle { diameter, .. } => diameter,
-    };
+                draw_radial_wedges(
+                    frame,
+                    offset_radius,
+                    start_angle,
+                    angle_span,
+                    tick_marks,
+                    *color,
+                    *inner_width,
+                    *outer_width,
+                    *length,
+                    inverse,
+                );
+            }
+            Shape::Image { handle, size } => {
+                let half_size = size / 2.0;
 
-    let length_2 = match style.tier_1 {
-        Shape::None => 0.0,
-        Shape::Line { length, .. } => length,
-        Shape::Circle { diameter, .. } => diameter,
-    };
+                let offset_radius = if inside {
+                    offset_radius - half_size
+                } else {
+                    offset_radius + half_size
+                };
 
-    let length_3 = match style.tier_1 {
-        Shape::None => 0.0,
-        Shape::Line { length, .. } => length,
-        Shape::Circle { diameter, .. } => diameter,
-    };
+                draw_radial_images(
+                    images,
+                    offset_radius,
+                    start_angle,
+                    angle_span,
+                    tick_marks,
+                    handle,
+                    *size,
+                    inverse,
+                );
+            }
+        }
+    }
+}
 
-    f32::from(length_1.max(length_2).max(length_3))
+fn max_length(style: &Style, tick_marks: &tick_marks::Group) -> f32 {
+    style
+        .tiers
+        .iter()
+        .chain(tick_marks.overrides().iter().map(|(_, shape)| shape))
+        .map(|shape| match shape {
+            Shape::None => 0.0,
+            Shape::Line { length, .. } => *length,
+            Shape::Circle { diameter, .. } => *diameter,
+            Shape::Wedge { length, .. } => *length,
+            Shape::Image { size, .. } => *size,
+        })
+        .fold(0.0, f32::max)
 }
 
 /// Draws tick marks around an arc.
@@ -232,13 +375,13 @@ pub fn draw_radial_tick_marks(
         angle_span,
         inside,
         tick_marks,
-        *style,
+        style.clone(),
         inverse,
-        || {
+        |center| {
             let frame_radius = if inside {
                 radius
             } else {
-                radius + max_length(style)
+                radius + max_length(style, tick_marks)
             };
 
             let frame_size = frame_radius * 2.0;
@@ -247,43 +390,58 @@ pub fn draw_radial_tick_marks(
 
             frame.translate(Vector::new(frame_radius, frame_radius));
 
-            draw_tier(
-                &mut frame,
-                radius,
-                start_angle,
-                angle_span,
-                tick_marks.tier_1(),
-                &style.tier_1,
-                inside,
-                inverse,
-            );
-            draw_tier(
-                &mut frame,
-                radius,
-                start_angle,
-                angle_span,
-                tick_marks.tier_2(),
-                &style.tier_2,
-                inside,
-                inverse,
-            );
-            draw_tier(
-                &mut frame,
-                radius,
-                start_angle,
-                angle_span,
-                tick_marks.tier_3(),
-                &style.tier_3,
-                inside,
-                inverse,
-            );
-
-            Primitive::Translate {
+            let mut images = Vec::new();
+
+            for (positions, shape) in super::tiers(tick_marks, style).iter() {
+                draw_tier(
+                    &mut frame,
+                    &mut images,
+                    radius,
+                    start_angle,
+                    angle_span,
+                    Some(positions),
+                    shape,
+                    inside,
+                    inverse,
+                );
+            }
+
+            for (position, shape) in tick_marks.overrides() {
+                draw_tier(
+                    &mut frame,
+                    &mut images,
+                    radius,
+                    start_angle,
+                    angle_span,
+                    Some(&vec![*position]),
+                    shape,
+                    inside,
+                    inverse,
+                );
+            }
+
+            let geometry = Primitive::Translate {
                 translation: Vector::new(
                     center.x - frame_radius,
                     center.y - frame_radius,
                 ),
                 content: Box::new(frame.into_geometry().into_primitive()),
+            };
+
+            if images.is_empty() {
+                geometry
+            } else {
+                Primitive::Group {
+                    primitives: vec![
+                        geometry,
+                        Primitive::Translate {
+                            translation: Vector::new(center.x, center.y),
+                            content: Box::new(Primitive::Group {
+                                primitives: images,
+                            }),
+                        },
+                    ],
+                }
             }
         },
     )
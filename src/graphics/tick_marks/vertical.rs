@@ -1,10 +1,12 @@
 //! `iced_graphics` renderer for tick marks
 
 use super::PrimitiveCache;
+use super::{border_radius, line_segments};
 use crate::core::Normal;
 use crate::native::tick_marks;
-use crate::style::tick_marks::{Placement, Shape, Style};
+use crate::style::tick_marks::{Dash, LineCap, Placement, Shape, Style};
 use iced_graphics::{Background, Color, Primitive, Rectangle};
+use iced_native::image;
 
 fn draw_vertical_lines(
     primitives: &mut Vec<Primitive>,
@@ -15,37 +17,31 @@ fn draw_vertical_lines(
     width: f32,
     length: f32,
     color: Color,
+    line_cap: LineCap,
+    dash: Option<Dash>,
     inverse: bool,
 ) {
-    let start_y = bounds_y - (f32::from(width) / 2.0);
+    let start_y = bounds_y - (width / 2.0);
     let back_color = Background::Color(color);
+    let segments = line_segments(length, width, line_cap, dash);
 
-    if inverse {
-        for tick_mark in tick_marks {
+    for tick_mark in tick_marks {
+        let y = if inverse {
+            start_y + tick_mark.scale(bounds_height)
+        } else {
+            start_y + tick_mark.scale_inv(bounds_height)
+        };
+
+        for (offset, length) in segments.iter() {
             primitives.push(Primitive::Quad {
                 bounds: Rectangle {
-                    x,
-                    y: (start_y + tick_mark.scale(bounds_height)),
-                    width: f32::from(length),
-                    height: f32::from(width),
+                    x: x + offset,
+                    y,
+                    width: *length,
+                    height: width,
                 },
                 background: back_color,
-                border_radius: 0.0,
-                border_width: 0.0,
-                border_color: Color::TRANSPARENT,
-            });
-        }
-    } else {
-        for tick_mark in tick_marks {
-            primitives.push(Primitive::Quad {
-                bounds: Rectangle {
-                    x,
-                    y: (start_y + tick_mark.scale_inv(bounds_height)),
-                    width: f32::from(length),
-                    height: f32::from(width),
-                },
-                background: back_color,
-                border_radius: 0.0,
+                border_radius: border_radius(width, *length, line_cap),
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
             });
@@ -101,6 +97,37 @@ fn draw_vertical_circles(
     }
 }
 
+fn draw_vertical_images(
+    primitives: &mut Vec<Primitive>,
+    tick_marks: &[Normal],
+    bounds_y: f32,
+    bounds_height: f32,
+    x: f32,
+    size: f32,
+    handle: &image::Handle,
+    inverse: bool,
+) {
+    let start_y = bounds_y - (size / 2.0);
+
+    for tick_mark in tick_marks {
+        let offset = if inverse {
+            tick_mark.scale(bounds_height)
+        } else {
+            tick_mark.scale_inv(bounds_height)
+        };
+
+        primitives.push(Primitive::Image {
+            handle: handle.clone(),
+            bounds: Rectangle {
+                x,
+                y: start_y + offset,
+                width: size,
+                height: size,
+            },
+        });
+    }
+}
+
 #[inline]
 fn draw_vertical_left_aligned_tier(
     primitives: &mut Vec<Primitive>,
@@ -117,7 +144,16 @@ fn draw_vertical_left_aligned_tier(
                 length,
                 width,
                 color,
+                ..
+            }
+            | Shape::Wedge {
+                length,
+                outer_width: width,
+                color,
+                ..
             } => {
+                let (line_cap, dash) = shape.line_stroke();
+
                 draw_vertical_lines(
                     primitives,
                     tick_marks,
@@ -127,6 +163,8 @@ fn draw_vertical_left_aligned_tier(
                     *width,
                     *length,
                     *color,
+                    line_cap,
+                    dash,
                     inverse,
                 );
             }
@@ -142,6 +180,18 @@ fn draw_vertical_left_aligned_tier(
                     inverse,
                 );
             }
+            Shape::Image { handle, size } => {
+                draw_vertical_images(
+                    primitives,
+                    tick_marks,
+                    bounds.y,
+                    bounds.height,
+                    x,
+                    *size,
+                    handle,
+                    inverse,
+                );
+            }
         }
     }
 }
@@ -154,30 +204,27 @@ fn draw_vertical_left_aligned(
     style: &Style,
     inverse: bool,
 ) {
-    draw_vertical_left_aligned_tier(
-        primitives,
-        bounds,
-        x,
-        tick_marks.tier_1(),
-        &style.tier_1,
-        inverse,
-    );
-    draw_vertical_left_aligned_tier(
-        primitives,
-        bounds,
-        x,
-        tick_marks.tier_2(),
-        &style.tier_2,
-        inverse,
-    );
-    draw_vertical_left_aligned_tier(
-        primitives,
-        bounds,
-        x,
-        tick_marks.tier_3(),
-        &style.tier_3,
-        inverse,
-    );
+    for (positions, shape) in super::tiers(tick_marks, style).iter() {
+        draw_vertical_left_aligned_tier(
+            primitives,
+            bounds,
+            x,
+            Some(positions),
+            shape,
+            inverse,
+        );
+    }
+
+    for (position, shape) in tick_marks.overrides() {
+        draw_vertical_left_aligned_tier(
+            primitives,
+            bounds,
+            x,
+            Some(&vec![*position]),
+            shape,
+            inverse,
+        );
+    }
 }
 
 #[inline]
@@ -196,7 +243,16 @@ fn draw_vertical_right_aligned_tier(
                 length,
                 width,
                 color,
+                ..
+            }
+            | Shape::Wedge {
+                length,
+                outer_width: width,
+                color,
+                ..
             } => {
+                let (line_cap, dash) = shape.line_stroke();
+
                 draw_vertical_lines(
                     primitives,
                     tick_marks,
@@ -206,6 +262,8 @@ fn draw_vertical_right_aligned_tier(
                     *width,
                     *length,
                     *color,
+                    line_cap,
+                    dash,
                     inverse,
                 );
             }
@@ -221,6 +279,18 @@ fn draw_vertical_right_aligned_tier(
                     inverse,
                 );
             }
+            Shape::Image { handle, size } => {
+                draw_vertical_images(
+                    primitives,
+                    tick_marks,
+                    bounds.y,
+                    bounds.height,
+                    x - *size,
+                    *size,
+                    handle,
+                    inverse,
+                );
+            }
         }
     }
 }
@@ -233,30 +303,27 @@ fn draw_vertical_right_aligned(
     style: &Style,
     inverse: bool,
 ) {
-    draw_vertical_right_aligned_tier(
-        primitives,
-        bounds,
-        x,
-        tick_marks.tier_1(),
-        &style.tier_1,
-        inverse,
-    );
-    draw_vertical_right_aligned_tier(
-        primitives,
-        bounds,
-        x,
-        tick_marks.tier_2(),
-        &style.tier_2,
-        inverse,
-    );
-    draw_vertical_right_aligned_tier(
-        primitives,
-        bounds,
-        x,
-        tick_marks.tier_3(),
-        &style.tier_3,
-        inverse,
-    );
+    for (positions, shape) in super::tiers(tick_marks, style).iter() {
+        draw_vertical_right_aligned_tier(
+            primitives,
+            bounds,
+            x,
+            Some(positions),
+            shape,
+            inverse,
+        );
+    }
+
+    for (position, shape) in tick_marks.overrides() {
+        draw_vertical_right_aligned_tier(
+            primitives,
+            bounds,
+            x,
+            Some(&vec![*position]),
+            shape,
+            inverse,
+        );
+    }
 }
 
 #[inline]
@@ -276,7 +343,16 @@ fn draw_vertical_center_aligned_tier(
                 length,
                 width,
                 color,
+                ..
+            }
+            | Shape::Wedge {
+                length,
+                outer_width: width,
+                color,
+                ..
             } => {
+                let (line_cap, dash) = shape.line_stroke();
+
                 let (x, length) = if fill_length {
                     (
                         bounds.x + f32::from(*length),
@@ -295,6 +371,8 @@ fn draw_vertical_center_aligned_tier(
                     *width,
                     length,
                     *color,
+                    line_cap,
+                    dash,
                     inverse,
                 );
             }
@@ -319,6 +397,24 @@ fn draw_vertical_center_aligned_tier(
                     inverse,
                 );
             }
+            Shape::Image { handle, size } => {
+                let (x, size) = if fill_length {
+                    (bounds.x + *size, bounds.width - (*size * 2.0))
+                } else {
+                    (x - (*size / 2.0), *size)
+                };
+
+                draw_vertical_images(
+                    primitives,
+                    tick_marks,
+                    bounds.y,
+                    bounds.height,
+                    x,
+                    size,
+                    handle,
+                    inverse,
+                );
+            }
         }
     }
 }
@@ -332,33 +428,29 @@ fn draw_vertical_center_aligned(
     fill_length: bool,
     inverse: bool,
 ) {
-    draw_vertical_center_aligned_tier(
-        primitives,
-        bounds,
-        x,
-        tick_marks.tier_1(),
-        &style.tier_1,
-        fill_length,
-        inverse,
-    );
-    draw_vertical_center_aligned_tier(
-        primitives,
-        bounds,
-        x,
-        tick_marks.tier_2(),
-        &style.tier_2,
-        fill_length,
-        inverse,
-    );
-    draw_vertical_center_aligned_tier(
-        primitives,
-        bounds,
-        x,
-        tick_marks.tier_3(),
-        &style.tier_3,
-        fill_length,
-        inverse,
-    );
+    for (positions, shape) in super::tiers(tick_marks, style).iter() {
+        draw_vertical_center_aligned_tier(
+            primitives,
+            bounds,
+            x,
+            Some(positions),
+            shape,
+            fill_length,
+            inverse,
+        );
+    }
+
+    for (position, shape) in tick_marks.overrides() {
+        draw_vertical_center_aligned_tier(
+            primitives,
+            bounds,
+            x,
+            Some(&vec![*position]),
+            shape,
+            fill_length,
+            inverse,
+        );
+    }
 }
 
 #[inline]
@@ -379,7 +471,16 @@ fn draw_vertical_center_aligned_split_tier(
                 length,
                 width,
                 color,
+                ..
+            }
+            | Shape::Wedge {
+                length,
+                outer_width: width,
+                color,
+                ..
             } => {
+                let (line_cap, dash) = shape.line_stroke();
+
                 let (left_x, length) = if fill_length {
                     let length = *length + ((bounds.width + gap) / 2.0);
                     (x - length - (gap / 2.0), length)
@@ -398,6 +499,8 @@ fn draw_vertical_center_aligned_split_tier(
                     *width,
                     length,
                     *color,
+                    line_cap,
+                    dash,
                     inverse,
                 );
                 draw_vertical_lines(
@@ -409,6 +512,8 @@ fn draw_vertical_center_aligned_split_tier(
                     *width,
                     length,
                     *color,
+                    line_cap,
+                    dash,
                     inverse,
                 );
             }
@@ -445,6 +550,36 @@ fn draw_vertical_center_aligned_split_tier(
                     inverse,
                 );
             }
+            Shape::Image { handle, size } => {
+                let (left_x, size) = if fill_length {
+                    (bounds.x - *size, *size + ((bounds.width + gap) / 2.0))
+                } else {
+                    (x - *size - (gap / 2.0), *size)
+                };
+
+                let right_x = x + (gap / 2.0);
+
+                draw_vertical_images(
+                    primitives,
+                    tick_marks,
+                    bounds.y,
+                    bounds.height,
+                    left_x,
+                    size,
+                    handle,
+                    inverse,
+                );
+                draw_vertical_images(
+                    primitives,
+                    tick_marks,
+                    bounds.y,
+                    bounds.height,
+                    right_x,
+                    size,
+                    handle,
+                    inverse,
+                );
+            }
         }
     }
 }
@@ -459,36 +594,31 @@ fn draw_vertical_center_aligned_split(
     gap: f32,
     inverse: bool,
 ) {
-    draw_vertical_center_aligned_split_tier(
-        primitives,
-        bounds,
-        x,
-        tick_marks.tier_1(),
-        &style.tier_1,
-        fill_length,
-        gap,
-        inverse,
-    );
-    draw_vertical_center_aligned_split_tier(
-        primitives,
-        bounds,
-        x,
-        tick_marks.tier_2(),
-        &style.tier_2,
-        fill_length,
-        gap,
-        inverse,
-    );
-    draw_vertical_center_aligned_split_tier(
-        primitives,
-        bounds,
-        x,
-        tick_marks.tier_3(),
-        &style.tier_3,
-        fill_length,
-        gap,
-        inverse,
-    );
+    for (positions, shape) in super::tiers(tick_marks, style).iter() {
+        draw_vertical_center_aligned_split_tier(
+            primitives,
+            bounds,
+            x,
+            Some(positions),
+            shape,
+            fill_length,
+            gap,
+            inverse,
+        );
+    }
+
+    for (position, shape) in tick_marks.overrides() {
+        draw_vertical_center_aligned_split_tier(
+            primitives,
+            bounds,
+            x,
+            Some(&vec![*position]),
+            shape,
+            fill_length,
+            gap,
+            inverse,
+        );
+    }
 }
 
 /// Draws tick marks on a vertical axis.
@@ -510,10 +640,12 @@ pub fn draw_vertical_tick_marks(
     cache.cached_linear(
         *bounds,
         tick_marks,
-        *style,
+        style.clone(),
         *placement,
         inverse,
-        || {
+        |bounds| {
+            let bounds = &style.insets.inset_rect(&bounds);
+
             let primitives = match placement {
                 Placement::BothSides { offset, inside } => {
                     let bounds = offset.offset_rect(bounds);
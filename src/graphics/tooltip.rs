@@ -0,0 +1,53 @@
+//! Display the value of a widget while it is hovered or dragged
+
+use crate::native::tooltip;
+use iced_graphics::alignment::{Horizontal, Vertical};
+use iced_graphics::{backend, Backend, Primitive, Renderer};
+use iced_native::{Background, Rectangle, Size};
+
+pub use crate::native::tooltip::Tooltip;
+pub use crate::style::tooltip::Style;
+
+impl<B: Backend + backend::Text> tooltip::Renderer for Renderer<B> {
+    fn measure_tooltip(&self, text: &str, style: &Style) -> Size {
+        let (width, height) = self.backend().measure(
+            text,
+            f32::from(style.text_size),
+            style.font,
+            Size::INFINITY,
+        );
+
+        Size::new(width, height)
+    }
+
+    fn draw_tooltip(&mut self, bounds: Rectangle, text: &str, style: &Style) {
+        let primitive = Primitive::Group {
+            primitives: vec![
+                Primitive::Quad {
+                    bounds,
+                    background: Background::Color(style.background_color),
+                    border_radius: style.border_radius,
+                    border_width: style.border_width,
+                    border_color: style.border_color,
+                },
+                Primitive::Text {
+                    content: text.to_string(),
+                    size: f32::from(style.text_size),
+                    bounds: Rectangle {
+                        x: bounds.center_x(),
+                        y: bounds.center_y(),
+                        ..bounds
+                    },
+                    color: style.text_color,
+                    font: style.font,
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                },
+            ],
+        };
+
+        iced_native::Renderer::with_layer(self, bounds, |renderer| {
+            renderer.draw_primitive(primitive)
+        });
+    }
+}
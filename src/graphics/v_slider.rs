@@ -3,27 +3,35 @@
 //! [`Param`]: ../core/param/trait.Param.html
 
 use crate::core::{ModulationRange, Normal};
+use crate::graphics::background::{draw_background, Axis};
+use crate::graphics::focus_ring::draw_focus_ring;
+use crate::graphics::shadow::draw_shadow;
+use crate::graphics::sprite::draw_sprite;
 use crate::graphics::{text_marks, tick_marks};
 use crate::native::v_slider;
-use iced_graphics::{Backend, Primitive, Renderer};
-use iced_native::{Background, Color, Point, Rectangle};
+use crate::style::transition::Interpolate;
+use crate::style::Status;
+use iced_graphics::alignment::{Horizontal, Vertical};
+use iced_graphics::{backend, Backend, Primitive, Renderer};
+use iced_native::{Color, Rectangle};
 
 pub use crate::native::v_slider::State;
 pub use crate::style::v_slider::{
-    ClassicHandle, ClassicRail, ClassicStyle, ModRangePlacement, ModRangeStyle,
-    RectBipolarStyle, RectStyle, Style, StyleSheet, TextMarksStyle,
-    TextureStyle, TickMarksStyle,
+    Background, ClassicHandle, ClassicRail, ClassicStyle, ClassicValueFill,
+    EdgeLabelsStyle, FocusRing, Gradient, ModRangePlacement, ModRangeStyle,
+    RailNotches, RectBipolarStyle, RectStyle, Shadow, SpriteFrame, Style,
+    StyleLength, StyleSheet, Styles, TextMarksStyle, TextureStyle,
+    TickMarksStyle,
 };
 
 struct ValueMarkers<'a> {
     tick_marks: Option<&'a tick_marks::Group>,
     text_marks: Option<&'a text_marks::Group>,
-    mod_range_1: Option<&'a ModulationRange>,
-    mod_range_2: Option<&'a ModulationRange>,
+    mod_ranges: &'a [ModulationRange],
     tick_marks_style: Option<TickMarksStyle>,
     text_marks_style: Option<TextMarksStyle>,
-    mod_range_style_1: Option<ModRangeStyle>,
-    mod_range_style_2: Option<ModRangeStyle>,
+    mod_range_styles: Vec<ModRangeStyle>,
+    inverse: bool,
 }
 
 /// A vertical slider GUI widget that controls a [`Param`]
@@ -35,49 +43,79 @@ struct ValueMarkers<'a> {
 pub type VSlider<'a, Message, Backend> =
     v_slider::VSlider<'a, Message, Renderer<Backend>>;
 
-impl<B: Backend> v_slider::Renderer for Renderer<B> {
+impl<B: Backend + backend::Text> v_slider::Renderer for Renderer<B> {
     type Style = Box<dyn StyleSheet>;
 
     fn draw(
         &mut self,
-        bounds: Rectangle,
-        cursor_position: Point,
-        normal: Normal,
-        is_dragging: bool,
-        mod_range_1: Option<&ModulationRange>,
-        mod_range_2: Option<&ModulationRange>,
-        tick_marks: Option<&tick_marks::Group>,
-        text_marks: Option<&text_marks::Group>,
+        info: v_slider::DrawInfo<'_>,
         style_sheet: &Self::Style,
-        tick_marks_cache: &tick_marks::PrimitiveCache,
-        text_marks_cache: &text_marks::PrimitiveCache,
     ) {
-        let is_mouse_over = bounds.contains(cursor_position);
+        let v_slider::DrawInfo {
+            bounds,
+            is_hovered,
+            normal,
+            fill_from,
+            is_disabled,
+            is_inverted,
+            is_fine,
+            is_dragging,
+            is_focused,
+            mod_ranges,
+            tick_marks,
+            text_marks,
+            edge_labels,
+            style_transition,
+            tick_marks_cache,
+            text_marks_cache,
+        } = info;
 
-        let style = if is_dragging {
+        let style = if is_disabled {
+            style_sheet.disabled()
+        } else if is_fine && (is_dragging || is_hovered) {
+            style_sheet.fine()
+        } else if is_dragging {
             style_sheet.dragging()
-        } else if is_mouse_over {
+        } else if is_hovered {
             style_sheet.hovered()
+        } else if is_focused {
+            style_sheet.focused()
         } else {
             style_sheet.active()
         };
 
-        let bounds = Rectangle {
-            x: bounds.x.round(),
-            y: bounds.y.round(),
-            width: bounds.width.round(),
-            height: bounds.height.round(),
+        let style = match style_transition {
+            Some((from, t)) => {
+                status_style(style_sheet.as_ref(), from).interpolate(&style, t)
+            }
+            None => style,
+        };
+
+        let focus_ring = if is_focused {
+            draw_focus_ring(bounds, &style_sheet.focus_ring_style())
+        } else {
+            Primitive::None
         };
 
+        let bounds = round_bounds(bounds);
+
         let value_markers = ValueMarkers {
             tick_marks,
             text_marks,
-            mod_range_1,
-            mod_range_2,
+            mod_ranges,
             tick_marks_style: style_sheet.tick_marks_style(),
             text_marks_style: style_sheet.text_marks_style(),
-            mod_range_style_1: style_sheet.mod_range_style(),
-            mod_range_style_2: style_sheet.mod_range_style_2(),
+            mod_range_styles: style_sheet.mod_range_styles(),
+            inverse: is_inverted,
+        };
+
+        let (normal, fill_from) = if is_inverted {
+            (
+                Normal::from(normal.as_f32_inv()),
+                fill_from.map(|fill_from| Normal::from(fill_from.as_f32_inv())),
+            )
+        } else {
+            (normal, fill_from)
         };
 
         let primitives = match style {
@@ -91,6 +129,13 @@ impl<B: Backend> v_slider::Renderer for Renderer<B> {
             ),
             Style::Classic(style) => draw_classic_style(
                 normal,
+                fill_from.unwrap_or_else(|| {
+                    if is_inverted {
+                        Normal::max()
+                    } else {
+                        Normal::min()
+                    }
+                }),
                 &bounds,
                 &style,
                 &value_markers,
@@ -99,6 +144,11 @@ impl<B: Backend> v_slider::Renderer for Renderer<B> {
             ),
             Style::Rect(style) => draw_rect_style(
                 normal,
+                if is_inverted {
+                    fill_from.or_else(|| Some(Normal::max()))
+                } else {
+                    fill_from
+                },
                 &bounds,
                 &style,
                 &value_markers,
@@ -107,6 +157,7 @@ impl<B: Backend> v_slider::Renderer for Renderer<B> {
             ),
             Style::RectBipolar(style) => draw_rect_bipolar_style(
                 normal,
+                fill_from,
                 &bounds,
                 &style,
                 &value_markers,
@@ -115,7 +166,168 @@ impl<B: Backend> v_slider::Renderer for Renderer<B> {
             ),
         };
 
-        self.draw_primitive(primitives)
+        let edge_labels = match edge_labels {
+            Some(edge_labels) => draw_edge_labels(
+                &bounds,
+                edge_labels,
+                &style_sheet.edge_labels_style(),
+                is_inverted,
+            ),
+            None => Primitive::None,
+        };
+
+        self.draw_primitive(Primitive::Group {
+            primitives: vec![primitives, edge_labels, focus_ring],
+        })
+    }
+
+    fn handle_bounds(
+        &self,
+        bounds: Rectangle,
+        normal: Normal,
+        is_inverted: bool,
+        is_fine: bool,
+        style_sheet: &Self::Style,
+    ) -> Rectangle {
+        let style = if is_fine {
+            style_sheet.fine()
+        } else {
+            style_sheet.dragging()
+        };
+
+        let normal = if is_inverted {
+            Normal::from(normal.as_f32_inv())
+        } else {
+            normal
+        };
+
+        let bounds = round_bounds(bounds);
+
+        match style {
+            Style::Texture(style) => {
+                texture_handle_bounds(normal, &bounds, &style)
+            }
+            Style::Classic(style) => classic_handle_bounds(
+                normal,
+                &bounds,
+                style.handle.height.resolve(bounds.width).round(),
+            ),
+            Style::Rect(style) => rect_handle_bounds(
+                normal,
+                &bounds,
+                style.handle_height.resolve(bounds.width).round(),
+                style.back_border_width,
+            ),
+            Style::RectBipolar(style) => rect_handle_bounds(
+                normal,
+                &bounds,
+                style.handle_height.resolve(bounds.width).round(),
+                style.back_border_width,
+            ),
+        }
+    }
+}
+
+fn round_bounds(bounds: Rectangle) -> Rectangle {
+    Rectangle {
+        x: bounds.x.round(),
+        y: bounds.y.round(),
+        width: bounds.width.round(),
+        height: bounds.height.round(),
+    }
+}
+
+/// The bounds the center of the handle moves in.
+fn value_bounds(bounds: &Rectangle, handle_height: f32) -> Rectangle {
+    Rectangle {
+        x: bounds.x,
+        y: (bounds.y + (handle_height / 2.0)).round(),
+        width: bounds.width,
+        height: bounds.height - handle_height,
+    }
+}
+
+fn texture_handle_bounds(
+    normal: Normal,
+    bounds: &Rectangle,
+    style: &TextureStyle,
+) -> Rectangle {
+    let value_bounds = value_bounds(bounds, f32::from(style.handle_height));
+
+    Rectangle {
+        x: (bounds.center_x() + style.image_bounds.x).round(),
+        y: (value_bounds.y
+            + style.image_bounds.y
+            + normal.scale_inv(value_bounds.height))
+        .round(),
+        width: style.image_bounds.width,
+        height: style.image_bounds.height,
+    }
+}
+
+fn classic_handle_bounds(
+    normal: Normal,
+    bounds: &Rectangle,
+    handle_height: f32,
+) -> Rectangle {
+    let value_bounds = value_bounds(bounds, handle_height);
+
+    Rectangle {
+        x: bounds.x,
+        y: bounds.y + normal.scale_inv(value_bounds.height).round(),
+        width: bounds.width,
+        height: handle_height,
+    }
+}
+
+fn rect_handle_bounds(
+    normal: Normal,
+    bounds: &Rectangle,
+    handle_height: f32,
+    border_width: f32,
+) -> Rectangle {
+    let value_bounds = value_bounds(bounds, handle_height);
+    let twice_border_width = border_width * 2.0;
+
+    Rectangle {
+        x: bounds.x,
+        y: bounds.y
+            + normal
+                .scale_inv(value_bounds.height - twice_border_width)
+                .round(),
+        width: bounds.width,
+        height: handle_height + twice_border_width,
+    }
+}
+
+fn draw_edge_labels(
+    bounds: &Rectangle,
+    (min, max): (&str, &str),
+    style: &EdgeLabelsStyle,
+    inverse: bool,
+) -> Primitive {
+    let (top, bottom) = if inverse { (min, max) } else { (max, min) };
+
+    let label = |content: &str, y: f32, vertical_alignment| Primitive::Text {
+        content: String::from(content),
+        bounds: Rectangle {
+            x: bounds.center_x().round(),
+            y: y.round(),
+            width: f32::from(style.style.bounds_width),
+            height: f32::from(style.style.bounds_height),
+        },
+        color: style.style.color,
+        size: f32::from(style.style.text_size),
+        font: style.style.font,
+        horizontal_alignment: Horizontal::Center,
+        vertical_alignment,
+    };
+
+    Primitive::Group {
+        primitives: vec![
+            label(top, bounds.y - style.gap, Vertical::Bottom),
+            label(bottom, bounds.y + bounds.height + style.gap, Vertical::Top),
+        ],
     }
 }
 
@@ -125,30 +337,37 @@ fn draw_value_markers<'a>(
     value_markers: &ValueMarkers<'a>,
     tick_marks_cache: &tick_marks::PrimitiveCache,
     text_marks_cache: &text_marks::PrimitiveCache,
-) -> (Primitive, Primitive, Primitive, Primitive) {
+) -> (Primitive, Primitive, Primitive) {
     (
         draw_tick_marks(
             mark_bounds,
             value_markers.tick_marks,
             &value_markers.tick_marks_style,
+            value_markers.inverse,
             tick_marks_cache,
         ),
         draw_text_marks(
             mark_bounds,
             value_markers.text_marks,
             &value_markers.text_marks_style,
+            value_markers.inverse,
             text_marks_cache,
         ),
-        draw_mod_range(
-            mod_bounds,
-            value_markers.mod_range_1,
-            &value_markers.mod_range_style_1,
-        ),
-        draw_mod_range(
-            mod_bounds,
-            value_markers.mod_range_2,
-            &value_markers.mod_range_style_2,
-        ),
+        Primitive::Group {
+            primitives: value_markers
+                .mod_ranges
+                .iter()
+                .zip(value_markers.mod_range_styles.iter())
+                .map(|(mod_range, style)| {
+                    draw_mod_range(
+                        mod_bounds,
+                        mod_range,
+                        style,
+                        value_markers.inverse,
+                    )
+                })
+                .collect(),
+        },
     )
 }
 
@@ -156,6 +375,7 @@ fn draw_tick_marks(
     bounds: &Rectangle,
     tick_marks: Option<&tick_marks::Group>,
     tick_marks_style: &Option<TickMarksStyle>,
+    inverse: bool,
     tick_marks_cache: &tick_marks::PrimitiveCache,
 ) -> Primitive {
     if let Some(tick_marks) = tick_marks {
@@ -165,7 +385,7 @@ fn draw_tick_marks(
                 tick_marks,
                 &style.style,
                 &style.placement,
-                false,
+                inverse,
                 tick_marks_cache,
             )
         } else {
@@ -180,6 +400,7 @@ fn draw_text_marks(
     bounds: &Rectangle,
     text_marks: Option<&text_marks::Group>,
     text_marks_style: &Option<TextMarksStyle>,
+    inverse: bool,
     text_marks_cache: &text_marks::PrimitiveCache,
 ) -> Primitive {
     if let Some(text_marks) = text_marks {
@@ -189,7 +410,7 @@ fn draw_text_marks(
                 text_marks,
                 &style.style,
                 &style.placement,
-                false,
+                inverse,
                 text_marks_cache,
             )
         } else {
@@ -202,96 +423,97 @@ fn draw_text_marks(
 
 fn draw_mod_range(
     bounds: &Rectangle,
-    mod_range: Option<&ModulationRange>,
-    style: &Option<ModRangeStyle>,
+    mod_range: &ModulationRange,
+    style: &ModRangeStyle,
+    inverse: bool,
 ) -> Primitive {
-    if let Some(mod_range) = mod_range {
-        if let Some(style) = style {
-            let (x, width) = match style.placement {
-                ModRangePlacement::Center { width, offset } => (
-                    bounds.x
-                        + f32::from(offset)
-                        + ((bounds.width - f32::from(width)) / 2.0),
-                    f32::from(width),
-                ),
-                ModRangePlacement::CenterFilled { edge_padding } => (
-                    bounds.x + f32::from(edge_padding),
-                    bounds.width - (f32::from(edge_padding) * 2.0),
-                ),
-                ModRangePlacement::Left { width, offset } => (
-                    bounds.x + f32::from(offset) - f32::from(width),
-                    f32::from(width),
-                ),
-                ModRangePlacement::Right { width, offset } => (
-                    bounds.x + bounds.width + f32::from(offset),
-                    f32::from(width),
-                ),
-            };
+    let bounds = &style.insets.inset_rect(bounds);
 
-            let back: Primitive = if let Some(back_color) = style.back_color {
-                Primitive::Quad {
-                    bounds: Rectangle {
-                        x,
-                        y: bounds.y,
-                        width,
-                        height: bounds.height,
-                    },
-                    background: Background::Color(back_color),
-                    border_radius: style.back_border_radius,
-                    border_width: style.back_border_width,
-                    border_color: style.back_border_color,
-                }
-            } else {
-                Primitive::None
-            };
+    let (x, width) = match style.placement {
+        ModRangePlacement::Center { width, offset } => (
+            bounds.x
+                + f32::from(offset)
+                + ((bounds.width - f32::from(width)) / 2.0),
+            f32::from(width),
+        ),
+        ModRangePlacement::CenterFilled { edge_padding } => (
+            bounds.x + f32::from(edge_padding),
+            bounds.width - (f32::from(edge_padding) * 2.0),
+        ),
+        ModRangePlacement::Left { width, offset } => (
+            bounds.x + f32::from(offset) - f32::from(width),
+            f32::from(width),
+        ),
+        ModRangePlacement::Right { width, offset } => (
+            bounds.x + bounds.width + f32::from(offset),
+            f32::from(width),
+        ),
+    };
 
-            let filled: Primitive = {
-                if mod_range.filled_visible
-                    && (mod_range.start.as_f32() != mod_range.end.as_f32())
-                {
-                    let (start, end, color) =
-                        if mod_range.start.as_f32() > mod_range.end.as_f32() {
-                            (
-                                mod_range.start.as_f32_inv(),
-                                mod_range.end.as_f32_inv(),
-                                style.filled_color,
-                            )
-                        } else {
-                            (
-                                mod_range.end.as_f32_inv(),
-                                mod_range.start.as_f32_inv(),
-                                style.filled_inverse_color,
-                            )
-                        };
-
-                    let start_offset = bounds.height * start;
-                    let filled_height = (bounds.height * end) - start_offset;
-
-                    Primitive::Quad {
-                        bounds: Rectangle {
-                            x,
-                            y: bounds.y + start_offset,
-                            width,
-                            height: filled_height,
-                        },
-                        background: Background::Color(color),
-                        border_radius: style.back_border_radius,
-                        border_width: style.back_border_width,
-                        border_color: Color::TRANSPARENT,
-                    }
+    let back: Primitive = if let Some(back_color) = style.back_color {
+        Primitive::Quad {
+            bounds: Rectangle {
+                x,
+                y: bounds.y,
+                width,
+                height: bounds.height,
+            },
+            background: iced_native::Background::Color(back_color),
+            border_radius: style.back_border_radius,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        }
+    } else {
+        Primitive::None
+    };
+
+    let filled: Primitive = {
+        if mod_range.filled_visible
+            && (mod_range.start.as_f32() != mod_range.end.as_f32())
+        {
+            let (start, end, color) =
+                if mod_range.start.as_f32() > mod_range.end.as_f32() {
+                    (
+                        mod_range.start.as_f32_inv(),
+                        mod_range.end.as_f32_inv(),
+                        style.filled_color,
+                    )
                 } else {
-                    Primitive::None
-                }
+                    (
+                        mod_range.end.as_f32_inv(),
+                        mod_range.start.as_f32_inv(),
+                        style.filled_inverse_color,
+                    )
+                };
+
+            let (start, end) = if inverse {
+                (1.0 - end, 1.0 - start)
+            } else {
+                (start, end)
             };
 
-            Primitive::Group {
-                primitives: vec![back, filled],
+            let start_offset = bounds.height * start;
+            let filled_height = (bounds.height * end) - start_offset;
+
+            Primitive::Quad {
+                bounds: Rectangle {
+                    x,
+                    y: bounds.y + start_offset,
+                    width,
+                    height: filled_height,
+                },
+                background: iced_native::Background::Color(color),
+                border_radius: style.back_border_radius,
+                border_width: style.back_border_width,
+                border_color: Color::TRANSPARENT,
             }
         } else {
             Primitive::None
         }
-    } else {
-        Primitive::None
+    };
+
+    Primitive::Group {
+        primitives: vec![back, filled],
     }
 }
 
@@ -303,14 +525,9 @@ fn draw_texture_style<'a>(
     tick_marks_cache: &tick_marks::PrimitiveCache,
     text_marks_cache: &text_marks::PrimitiveCache,
 ) -> Primitive {
-    let value_bounds = Rectangle {
-        x: bounds.x,
-        y: (bounds.y + (f32::from(style.handle_height) / 2.0)).round(),
-        width: bounds.width,
-        height: bounds.height - f32::from(style.handle_height),
-    };
+    let value_bounds = value_bounds(bounds, f32::from(style.handle_height));
 
-    let (tick_marks, text_marks, mod_range_1, mod_range_2) = draw_value_markers(
+    let (tick_marks, text_marks, mod_ranges) = draw_value_markers(
         &value_bounds,
         &value_bounds,
         value_markers,
@@ -318,20 +535,16 @@ fn draw_texture_style<'a>(
         text_marks_cache,
     );
 
-    let (left_rail, right_rail) = draw_classic_rail(&bounds, &style.rail);
+    let (left_rail, right_rail, rail_notches) =
+        draw_classic_rail(&bounds, &value_bounds, &style.rail, value_markers);
 
-    let handle = Primitive::Image {
-        handle: style.image_handle,
-        bounds: Rectangle {
-            x: (bounds.center_x() + style.image_bounds.x).round(),
-            y: (value_bounds.y
-                + style.image_bounds.y
-                + normal.scale_inv(value_bounds.height))
-            .round(),
-            width: style.image_bounds.width,
-            height: style.image_bounds.height,
-        },
-    };
+    let handle_bounds = texture_handle_bounds(normal, bounds, &style);
+
+    let handle = draw_sprite(
+        style.image_handle,
+        handle_bounds,
+        style.sprite_frame.as_ref(),
+    );
 
     Primitive::Group {
         primitives: vec![
@@ -339,31 +552,27 @@ fn draw_texture_style<'a>(
             text_marks,
             left_rail,
             right_rail,
+            rail_notches,
             handle,
-            mod_range_1,
-            mod_range_2,
+            mod_ranges,
         ],
     }
 }
 
 fn draw_classic_style<'a>(
     normal: Normal,
+    fill_from: Normal,
     bounds: &Rectangle,
     style: &ClassicStyle,
     value_markers: &ValueMarkers<'a>,
     tick_marks_cache: &tick_marks::PrimitiveCache,
     text_marks_cache: &text_marks::PrimitiveCache,
 ) -> Primitive {
-    let handle_height = f32::from(style.handle.height);
+    let handle_height = style.handle.height.resolve(bounds.width).round();
 
-    let value_bounds = Rectangle {
-        x: bounds.x,
-        y: (bounds.y + (handle_height / 2.0)).round(),
-        width: bounds.width,
-        height: bounds.height - handle_height,
-    };
+    let value_bounds = value_bounds(bounds, handle_height);
 
-    let (tick_marks, text_marks, mod_range_1, mod_range_2) = draw_value_markers(
+    let (tick_marks, text_marks, mod_ranges) = draw_value_markers(
         &value_bounds,
         &value_bounds,
         value_markers,
@@ -371,26 +580,40 @@ fn draw_classic_style<'a>(
         text_marks_cache,
     );
 
-    let (left_rail, right_rail) = draw_classic_rail(&bounds, &style.rail);
+    let (left_rail, right_rail, rail_notches) =
+        draw_classic_rail(&bounds, &value_bounds, &style.rail, value_markers);
+
+    let value_fill = match &style.value_fill {
+        Some(value_fill) => draw_classic_value_fill(
+            normal,
+            fill_from,
+            bounds,
+            &value_bounds,
+            value_fill,
+            value_axis(value_markers.inverse),
+        ),
+        None => Primitive::None,
+    };
 
     let handle_border_radius = style.handle.border_radius;
-    let handle_offset = normal.scale_inv(value_bounds.height).round();
-    let notch_width = f32::from(style.handle.notch_width);
+    let handle_bounds = classic_handle_bounds(normal, bounds, handle_height);
+    let handle_offset = handle_bounds.y - bounds.y;
+    let notch_width = style.handle.notch_width.resolve(bounds.width);
 
-    let handle = Primitive::Quad {
-        bounds: Rectangle {
-            x: bounds.x,
-            y: bounds.y + handle_offset,
-            width: bounds.width,
-            height: handle_height,
-        },
-        background: Background::Color(style.handle.color),
-        border_radius: handle_border_radius,
-        border_width: style.handle.border_width,
-        border_color: style.handle.border_color,
-    };
+    let handle_shadow =
+        draw_shadow(handle_bounds, handle_border_radius, &style.handle.shadow);
 
-    let handle_notch: Primitive = if style.handle.notch_width != 0.0 {
+    let handle = draw_background(
+        handle_bounds,
+        &handle_bounds,
+        Axis::Vertical,
+        &style.handle.color,
+        handle_border_radius,
+        style.handle.border_width,
+        style.handle.border_color,
+    );
+
+    let handle_notch: Primitive = if notch_width != 0.0 {
         Primitive::Quad {
             bounds: Rectangle {
                 x: bounds.x,
@@ -400,7 +623,9 @@ fn draw_classic_style<'a>(
                 width: bounds.width,
                 height: notch_width,
             },
-            background: Background::Color(style.handle.notch_color),
+            background: iced_native::Background::Color(
+                style.handle.notch_color,
+            ),
             border_radius: 0.0,
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
@@ -415,35 +640,74 @@ fn draw_classic_style<'a>(
             text_marks,
             left_rail,
             right_rail,
+            rail_notches,
+            value_fill,
+            handle_shadow,
             handle,
             handle_notch,
-            mod_range_1,
-            mod_range_2,
+            mod_ranges,
         ],
     }
 }
 
+fn draw_classic_value_fill(
+    normal: Normal,
+    fill_from: Normal,
+    bounds: &Rectangle,
+    value_bounds: &Rectangle,
+    style: &ClassicValueFill,
+    axis: Axis,
+) -> Primitive {
+    let handle_y = value_bounds.y + normal.scale_inv(value_bounds.height);
+    let fill_from_y = value_bounds.y + fill_from.scale_inv(value_bounds.height);
+
+    let start_y = handle_y.min(fill_from_y).round();
+    let end_y = handle_y.max(fill_from_y).round();
+
+    if end_y <= start_y {
+        return Primitive::None;
+    }
+
+    let x = (bounds.center_x() - (style.width / 2.0)).round();
+
+    draw_background(
+        Rectangle {
+            x,
+            y: start_y,
+            width: style.width,
+            height: end_y - start_y,
+        },
+        &Rectangle {
+            x,
+            y: value_bounds.y,
+            width: style.width,
+            height: value_bounds.height,
+        },
+        axis,
+        &style.color,
+        style.border_radius,
+        0.0,
+        Color::TRANSPARENT,
+    )
+}
+
 fn draw_rect_style<'a>(
     normal: Normal,
+    fill_from: Option<Normal>,
     bounds: &Rectangle,
     style: &RectStyle,
     value_markers: &ValueMarkers<'a>,
     tick_marks_cache: &tick_marks::PrimitiveCache,
     text_marks_cache: &text_marks::PrimitiveCache,
 ) -> Primitive {
-    let handle_height = f32::from(style.handle_height);
+    let handle_height = style.handle_height.resolve(bounds.width).round();
 
     let border_width = f32::from(style.back_border_width);
     let twice_border_width = border_width * 2.0;
 
-    let value_bounds = Rectangle {
-        x: bounds.x,
-        y: (bounds.y + (handle_height / 2.0)).round(),
-        width: bounds.width,
-        height: bounds.height - handle_height,
-    };
+    let value_bounds = value_bounds(bounds, handle_height);
 
-    let (tick_marks, text_marks, mod_range_1, mod_range_2) = draw_value_markers(
+    let (tick_marks, text_marks, mod_ranges) = draw_value_markers(
         &value_bounds,
         &bounds,
         value_markers,
@@ -451,51 +715,95 @@ fn draw_rect_style<'a>(
         text_marks_cache,
     );
 
-    let empty_rect = Primitive::Quad {
-        bounds: Rectangle {
+    let empty_rect = draw_background(
+        Rectangle {
             x: bounds.x,
             y: bounds.y,
             width: bounds.width,
             height: bounds.height,
         },
-        background: Background::Color(style.back_color),
-        border_radius: style.back_border_radius,
-        border_width: style.back_border_width,
-        border_color: style.back_border_color,
-    };
+        bounds,
+        value_axis(value_markers.inverse),
+        &style.back_color,
+        style.back_border_radius,
+        style.back_border_width,
+        style.back_border_color,
+    );
 
-    let handle_offset = normal
-        .scale_inv(value_bounds.height - twice_border_width)
-        .round();
+    let handle_bounds = rect_handle_bounds(
+        normal,
+        bounds,
+        handle_height,
+        style.back_border_width,
+    );
+    let handle_offset = handle_bounds.y - bounds.y;
 
-    let filled_offset =
-        handle_offset + handle_height + f32::from(style.handle_filled_gap);
-    let filled_rect = Primitive::Quad {
-        bounds: Rectangle {
+    let filled_bounds = if let Some(fill_from) = fill_from {
+        let fill_from_offset = (handle_height / 2.0)
+            + border_width
+            + fill_from.scale_inv(value_bounds.height - twice_border_width);
+
+        if (normal.as_f32() - fill_from.as_f32()).abs() < 0.001 {
+            None
+        } else if normal > fill_from {
+            let filled_rect_offset =
+                handle_offset + handle_height + style.handle_filled_gap;
+
+            Some(Rectangle {
+                x: bounds.x,
+                y: bounds.y + filled_rect_offset,
+                width: bounds.width,
+                height: (fill_from_offset - filled_rect_offset
+                    + twice_border_width)
+                    .round(),
+            })
+        } else {
+            let filled_rect_offset = fill_from_offset.round() - border_width;
+
+            Some(Rectangle {
+                x: bounds.x,
+                y: bounds.y + filled_rect_offset,
+                width: bounds.width,
+                height: handle_offset - filled_rect_offset + twice_border_width
+                    - style.handle_filled_gap,
+            })
+        }
+    } else {
+        let filled_offset =
+            handle_offset + handle_height + f32::from(style.handle_filled_gap);
+
+        Some(Rectangle {
             x: bounds.x,
             y: bounds.y + filled_offset,
             width: bounds.width,
             height: bounds.height - filled_offset,
-        },
-        background: Background::Color(style.filled_color),
-        border_radius: style.back_border_radius,
-        border_width: style.back_border_width,
-        border_color: Color::TRANSPARENT,
+        })
     };
 
-    let handle = Primitive::Quad {
-        bounds: Rectangle {
-            x: bounds.x,
-            y: bounds.y + handle_offset,
-            width: bounds.width,
-            height: handle_height + twice_border_width,
-        },
-        background: Background::Color(style.handle_color),
-        border_radius: style.back_border_radius,
-        border_width: style.back_border_width,
-        border_color: Color::TRANSPARENT,
+    let filled_rect = if let Some(filled_bounds) = filled_bounds {
+        draw_background(
+            filled_bounds,
+            bounds,
+            value_axis(value_markers.inverse),
+            &style.filled_color,
+            style.back_border_radius,
+            style.back_border_width,
+            Color::TRANSPARENT,
+        )
+    } else {
+        Primitive::None
     };
 
+    let handle = draw_background(
+        handle_bounds,
+        &handle_bounds,
+        Axis::Vertical,
+        &style.handle_color,
+        style.back_border_radius,
+        style.back_border_width,
+        Color::TRANSPARENT,
+    );
+
     Primitive::Group {
         primitives: vec![
             empty_rect,
@@ -503,33 +811,28 @@ fn draw_rect_style<'a>(
             text_marks,
             filled_rect,
             handle,
-            mod_range_1,
-            mod_range_2,
+            mod_ranges,
         ],
     }
 }
 
 fn draw_rect_bipolar_style<'a>(
     normal: Normal,
+    fill_from: Option<Normal>,
     bounds: &Rectangle,
     style: &RectBipolarStyle,
     value_markers: &ValueMarkers<'a>,
     tick_marks_cache: &tick_marks::PrimitiveCache,
     text_marks_cache: &text_marks::PrimitiveCache,
 ) -> Primitive {
-    let handle_height = f32::from(style.handle_height);
+    let handle_height = style.handle_height.resolve(bounds.width).round();
 
     let border_width = f32::from(style.back_border_width);
     let twice_border_width = border_width * 2.0;
 
-    let value_bounds = Rectangle {
-        x: bounds.x,
-        y: (bounds.y + (handle_height / 2.0)).round(),
-        width: bounds.width,
-        height: bounds.height - handle_height,
-    };
+    let value_bounds = value_bounds(bounds, handle_height);
 
-    let (tick_marks, text_marks, mod_range_1, mod_range_2) = draw_value_markers(
+    let (tick_marks, text_marks, mod_ranges) = draw_value_markers(
         &value_bounds,
         &bounds,
         value_markers,
@@ -537,76 +840,92 @@ fn draw_rect_bipolar_style<'a>(
         text_marks_cache,
     );
 
-    let empty_rect = Primitive::Quad {
-        bounds: Rectangle {
+    let empty_rect = draw_background(
+        Rectangle {
             x: bounds.x,
             y: bounds.y,
             width: bounds.width,
             height: bounds.height,
         },
-        background: Background::Color(style.back_color),
-        border_radius: style.back_border_radius,
-        border_width: style.back_border_width,
-        border_color: style.back_border_color,
-    };
+        bounds,
+        value_axis(value_markers.inverse),
+        &style.back_color,
+        style.back_border_radius,
+        style.back_border_width,
+        style.back_border_color,
+    );
 
-    let handle_offset = normal
-        .scale_inv(value_bounds.height - twice_border_width)
-        .round();
+    let handle_bounds = rect_handle_bounds(
+        normal,
+        bounds,
+        handle_height,
+        style.back_border_width,
+    );
+    let handle_offset = handle_bounds.y - bounds.y;
 
-    let (handle_color, filled_rect) = if normal.as_f32() > 0.499
-        && normal.as_f32() < 0.501
-    {
-        (style.handle_center_color, Primitive::None)
-    } else if normal.as_f32() > 0.5 {
-        let filled_rect_offset =
-            handle_offset + handle_height + f32::from(style.handle_filled_gap);
+    let (center, center_offset) = if let Some(fill_from) = fill_from {
         (
-            style.handle_top_color,
-            Primitive::Quad {
-                bounds: Rectangle {
-                    x: bounds.x,
-                    y: bounds.y + filled_rect_offset,
-                    width: bounds.width,
-                    height: ((bounds.height / 2.0) - filled_rect_offset
-                        + twice_border_width)
-                        .round(),
-                },
-                background: Background::Color(style.top_filled_color),
-                border_radius: style.back_border_radius,
-                border_width: style.back_border_width,
-                border_color: Color::TRANSPARENT,
-            },
+            fill_from,
+            (handle_height / 2.0)
+                + border_width
+                + fill_from.scale_inv(value_bounds.height - twice_border_width),
         )
     } else {
-        let filled_rect_offset = (bounds.height / 2.0).round() - border_width;
-        (
-            style.handle_bottom_color,
-            Primitive::Quad {
-                bounds: Rectangle {
-                    x: bounds.x,
-                    y: bounds.y + filled_rect_offset,
-                    width: bounds.width,
-                    height: handle_offset - filled_rect_offset
-                        + twice_border_width
-                        - f32::from(style.handle_filled_gap),
-                },
-                background: Background::Color(style.bottom_filled_color),
-                border_radius: style.back_border_radius,
-                border_width: style.back_border_width,
-                border_color: Color::TRANSPARENT,
-            },
-        )
+        (Normal::center(), bounds.height / 2.0)
     };
 
+    let (handle_color, filled_rect) =
+        if (normal.as_f32() - center.as_f32()).abs() < 0.001 {
+            (style.handle_center_color, Primitive::None)
+        } else if normal > center {
+            let filled_rect_offset = handle_offset
+                + handle_height
+                + f32::from(style.handle_filled_gap);
+            (
+                style.handle_top_color,
+                draw_background(
+                    Rectangle {
+                        x: bounds.x,
+                        y: bounds.y + filled_rect_offset,
+                        width: bounds.width,
+                        height: (center_offset - filled_rect_offset
+                            + twice_border_width)
+                            .round(),
+                    },
+                    bounds,
+                    value_axis(value_markers.inverse),
+                    &style.top_filled_color,
+                    style.back_border_radius,
+                    style.back_border_width,
+                    Color::TRANSPARENT,
+                ),
+            )
+        } else {
+            let filled_rect_offset = center_offset.round() - border_width;
+            (
+                style.handle_bottom_color,
+                draw_background(
+                    Rectangle {
+                        x: bounds.x,
+                        y: bounds.y + filled_rect_offset,
+                        width: bounds.width,
+                        height: handle_offset - filled_rect_offset
+                            + twice_border_width
+                            - f32::from(style.handle_filled_gap),
+                    },
+                    bounds,
+                    value_axis(value_markers.inverse),
+                    &style.bottom_filled_color,
+                    style.back_border_radius,
+                    style.back_border_width,
+                    Color::TRANSPARENT,
+                ),
+            )
+        };
+
     let handle = Primitive::Quad {
-        bounds: Rectangle {
-            x: bounds.x,
-            y: bounds.y + handle_offset,
-            width: bounds.width,
-            height: handle_height + twice_border_width,
-        },
-        background: Background::Color(handle_color),
+        bounds: handle_bounds,
+        background: iced_native::Background::Color(handle_color),
         border_radius: style.back_border_radius,
         border_width: style.back_border_width,
         border_color: Color::TRANSPARENT,
@@ -619,16 +938,17 @@ fn draw_rect_bipolar_style<'a>(
             text_marks,
             filled_rect,
             handle,
-            mod_range_1,
-            mod_range_2,
+            mod_ranges,
         ],
     }
 }
 
 fn draw_classic_rail(
     bounds: &Rectangle,
+    value_bounds: &Rectangle,
     style: &ClassicRail,
-) -> (Primitive, Primitive) {
+    value_markers: &ValueMarkers<'_>,
+) -> (Primitive, Primitive, Primitive) {
     let (left_width, right_width) = style.rail_widths;
     let (left_color, right_color) = style.rail_colors;
 
@@ -642,30 +962,157 @@ fn draw_classic_rail(
     let y = bounds.y + f32::from(style.rail_padding);
     let height = bounds.height - (f32::from(style.rail_padding) * 2.0);
 
-    (
-        Primitive::Quad {
-            bounds: Rectangle {
-                x: start_x,
-                y,
-                width: left_width,
-                height,
-            },
-            background: Background::Color(left_color),
-            border_radius: 0.0,
-            border_width: 0.0,
-            border_color: Color::TRANSPARENT,
-        },
-        Primitive::Quad {
-            bounds: Rectangle {
-                x: start_x + left_width,
-                y,
-                width: right_width,
-                height,
-            },
-            background: Background::Color(right_color),
-            border_radius: 0.0,
-            border_width: 0.0,
-            border_color: Color::TRANSPARENT,
+    let axis = value_axis(value_markers.inverse);
+
+    let notches: Vec<f32> = if style.notches.is_some() {
+        notch_positions(value_markers, |normal| {
+            if value_markers.inverse {
+                value_bounds.y + normal.scale(value_bounds.height)
+            } else {
+                value_bounds.y + normal.scale_inv(value_bounds.height)
+            }
+        })
+    } else {
+        Vec::new()
+    };
+
+    let segments = match style.notches {
+        Some(RailNotches::Gap { width: gap_width }) => {
+            rail_segments(y, y + height, &notches, gap_width)
+        }
+        _ => vec![(y, y + height)],
+    };
+
+    let draw_rail = |x: f32, width: f32, background| {
+        let rail_bounds = Rectangle {
+            x,
+            y,
+            width,
+            height,
+        };
+
+        Primitive::Group {
+            primitives: segments
+                .iter()
+                .map(|(start, end)| {
+                    draw_background(
+                        Rectangle {
+                            x,
+                            y: *start,
+                            width,
+                            height: end - start,
+                        },
+                        &rail_bounds,
+                        axis,
+                        background,
+                        0.0,
+                        0.0,
+                        Color::TRANSPARENT,
+                    )
+                })
+                .collect(),
+        }
+    };
+
+    let rail_notches = match style.notches {
+        Some(RailNotches::Marker {
+            color,
+            width: marker_width,
+            length,
+        }) => Primitive::Group {
+            primitives: notches
+                .iter()
+                .map(|notch| Primitive::Quad {
+                    bounds: Rectangle {
+                        x: (start_x + ((full_width - length) / 2.0)).round(),
+                        y: (notch - (marker_width / 2.0)).round(),
+                        width: length,
+                        height: marker_width,
+                    },
+                    background: iced_native::Background::Color(color),
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                })
+                .collect(),
         },
+        _ => Primitive::None,
+    };
+
+    (
+        draw_rail(start_x, left_width, &left_color),
+        draw_rail(start_x + left_width, right_width, &right_color),
+        rail_notches,
     )
 }
+
+/// Returns the sorted positions of all the tick marks, as given by
+/// `position`.
+fn notch_positions(
+    value_markers: &ValueMarkers<'_>,
+    position: impl Fn(&Normal) -> f32,
+) -> Vec<f32> {
+    let tick_marks = match value_markers.tick_marks {
+        Some(tick_marks) => tick_marks,
+        None => return Vec::new(),
+    };
+
+    let mut positions: Vec<f32> = tick_marks
+        .tiers()
+        .iter()
+        .flat_map(|normals| normals.iter())
+        .chain(tick_marks.overrides().iter().map(|(normal, _)| normal))
+        .map(position)
+        .collect();
+
+    positions.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    positions
+}
+
+/// Splits the rail from `start` to `end` into the segments that are left
+/// after cutting a gap of `gap_width` centered on each of the sorted
+/// `notches`.
+fn rail_segments(
+    start: f32,
+    end: f32,
+    notches: &[f32],
+    gap_width: f32,
+) -> Vec<(f32, f32)> {
+    let mut segments = Vec::with_capacity(notches.len() + 1);
+    let mut segment_start = start;
+
+    for notch in notches {
+        let gap_start = (notch - (gap_width / 2.0)).round();
+
+        if gap_start > segment_start {
+            segments.push((segment_start, gap_start.min(end)));
+        }
+
+        segment_start = segment_start.max(gap_start + gap_width);
+    }
+
+    if end > segment_start {
+        segments.push((segment_start, end));
+    }
+
+    segments
+}
+
+fn value_axis(inverse: bool) -> Axis {
+    if inverse {
+        Axis::VerticalInverse
+    } else {
+        Axis::Vertical
+    }
+}
+
+fn status_style(style_sheet: &dyn StyleSheet, status: Status) -> Style {
+    match status {
+        Status::Active => style_sheet.active(),
+        Status::Hovered => style_sheet.hovered(),
+        Status::Focused => style_sheet.focused(),
+        Status::Dragging => style_sheet.dragging(),
+        Status::Disabled => style_sheet.disabled(),
+    }
+}
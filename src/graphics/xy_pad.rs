@@ -3,14 +3,26 @@
 //!
 //! [`Param`]: ../core/param/trait.Param.html
 
-use crate::core::Normal;
+use std::collections::VecDeque;
+
+use crate::core::{ModulationRange, Normal};
+use crate::graphics::background::{draw_background, Axis};
+use crate::graphics::focus_ring::draw_focus_ring;
+use crate::graphics::shadow::draw_shadow;
+use crate::graphics::{text_marks, tick_marks};
 use crate::native::xy_pad;
+use crate::style::transition::Interpolate;
+use crate::style::Status;
+use iced_graphics::alignment::{Horizontal, Vertical};
+use iced_graphics::widget::canvas::{Frame, LineCap, Path, Stroke};
 use iced_graphics::{Backend, Primitive, Renderer};
-use iced_native::{Background, Color, Point, Rectangle};
+use iced_native::{Color, Point, Rectangle, Size, Vector};
 
 pub use crate::native::xy_pad::State;
 pub use crate::style::xy_pad::{
-    HandleCircle, HandleShape, HandleSquare, Style, StyleSheet,
+    Background, FocusRing, Gradient, GridStyle, HandleCircle, HandleShape,
+    HandleSquare, ModRangeStyle, ReadoutsStyle, Shadow, Style, StyleSheet,
+    Styles, TextMarksStyle, TickMarksStyle, TrailStyle,
 };
 
 /// A 2D XY pad GUI widget that controls two [`Param`] parameters at
@@ -27,25 +39,61 @@ pub type XYPad<'a, Message, Backend> =
 impl<B: Backend> xy_pad::Renderer for Renderer<B> {
     type Style = Box<dyn StyleSheet>;
 
-    fn draw(
-        &mut self,
-        bounds: Rectangle,
-        cursor_position: Point,
-        normal_x: Normal,
-        normal_y: Normal,
-        is_dragging: bool,
-        style_sheet: &Self::Style,
-    ) {
+    fn draw(&mut self, info: xy_pad::DrawInfo<'_>, style_sheet: &Self::Style) {
+        let xy_pad::DrawInfo {
+            bounds,
+            cursor_position,
+            normal_x,
+            normal_y,
+            is_dragging,
+            is_focused,
+            is_polar,
+            locked_axis,
+            trail,
+            trail_decay,
+            mod_range_x,
+            mod_range_y,
+            snap_grid,
+            tick_marks_x,
+            tick_marks_y,
+            text_marks_x,
+            text_marks_y,
+            readouts,
+            style_transition,
+            tick_marks_cache_x,
+            tick_marks_cache_y,
+            text_marks_cache_x,
+            text_marks_cache_y,
+        } = info;
+
         let is_mouse_over = bounds.contains(cursor_position);
 
         let style = if is_dragging {
-            style_sheet.dragging()
+            match locked_axis {
+                Some(axis) => style_sheet.axis_locked(axis),
+                None => style_sheet.dragging(),
+            }
         } else if is_mouse_over {
             style_sheet.hovered()
+        } else if is_focused {
+            style_sheet.focused()
         } else {
             style_sheet.active()
         };
 
+        let style = match style_transition {
+            Some((from, t)) => {
+                status_style(style_sheet.as_ref(), from).interpolate(&style, t)
+            }
+            None => style,
+        };
+
+        let focus_ring = if is_focused {
+            draw_focus_ring(bounds, &style_sheet.focus_ring_style())
+        } else {
+            Primitive::None
+        };
+
         let bounds_x = bounds.x.floor();
         let bounds_y = bounds.y.floor();
 
@@ -57,17 +105,101 @@ impl<B: Backend> xy_pad::Renderer for Renderer<B> {
             }
         };
 
-        let back = Primitive::Quad {
-            bounds: Rectangle {
-                x: bounds_x,
-                y: bounds_y,
-                width: bounds_size,
-                height: bounds_size,
+        let pad_bounds = Rectangle {
+            x: bounds_x,
+            y: bounds_y,
+            width: bounds_size,
+            height: bounds_size,
+        };
+
+        let tick_marks_x =
+            match (tick_marks_x, style_sheet.tick_marks_style_x()) {
+                (Some(tick_marks), Some(style)) => {
+                    tick_marks::draw_horizontal_tick_marks(
+                        &pad_bounds,
+                        tick_marks,
+                        &style.style,
+                        &style.placement,
+                        false,
+                        tick_marks_cache_x,
+                    )
+                }
+                _ => Primitive::None,
+            };
+
+        let tick_marks_y =
+            match (tick_marks_y, style_sheet.tick_marks_style_y()) {
+                (Some(tick_marks), Some(style)) => {
+                    tick_marks::draw_vertical_tick_marks(
+                        &pad_bounds,
+                        tick_marks,
+                        &style.style,
+                        &style.placement,
+                        false,
+                        tick_marks_cache_y,
+                    )
+                }
+                _ => Primitive::None,
+            };
+
+        let text_marks_x =
+            match (text_marks_x, style_sheet.text_marks_style_x()) {
+                (Some(text_marks), Some(style)) => {
+                    text_marks::draw_horizontal_text_marks(
+                        &pad_bounds,
+                        text_marks,
+                        &style.style,
+                        &style.placement,
+                        false,
+                        text_marks_cache_x,
+                    )
+                }
+                _ => Primitive::None,
+            };
+
+        let text_marks_y =
+            match (text_marks_y, style_sheet.text_marks_style_y()) {
+                (Some(text_marks), Some(style)) => {
+                    text_marks::draw_vertical_text_marks(
+                        &pad_bounds,
+                        text_marks,
+                        &style.style,
+                        &style.placement,
+                        false,
+                        text_marks_cache_y,
+                    )
+                }
+                _ => Primitive::None,
+            };
+
+        let back_bounds = Rectangle {
+            x: bounds_x,
+            y: bounds_y,
+            width: bounds_size,
+            height: bounds_size,
+        };
+
+        let back = draw_background(
+            back_bounds,
+            &back_bounds,
+            Axis::Vertical,
+            &style.back_color,
+            0.0,
+            style.border_width,
+            style.border_color,
+        );
+
+        let back_image = match style.back_image.clone() {
+            Some(handle) => Primitive::Image {
+                handle,
+                bounds: Rectangle {
+                    x: bounds_x + style.border_width,
+                    y: bounds_y + style.border_width,
+                    width: (bounds_size - (style.border_width * 2.0)).max(0.0),
+                    height: (bounds_size - (style.border_width * 2.0)).max(0.0),
+                },
             },
-            background: Background::Color(style.back_color),
-            border_radius: 0.0,
-            border_width: style.border_width,
-            border_color: style.border_color,
+            None => Primitive::None,
         };
 
         let handle_x = (bounds_x + (bounds_size * normal_x.as_f32())).floor();
@@ -90,7 +222,9 @@ impl<B: Backend> xy_pad::Renderer for Renderer<B> {
                         width: bounds_size,
                         height: center_line_width,
                     },
-                    background: Background::Color(style.center_line_color),
+                    background: iced_native::Background::Color(
+                        style.center_line_color,
+                    ),
                     border_radius: 0.0,
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
@@ -102,7 +236,9 @@ impl<B: Backend> xy_pad::Renderer for Renderer<B> {
                         width: center_line_width,
                         height: bounds_size,
                     },
-                    background: Background::Color(style.center_line_color),
+                    background: iced_native::Background::Color(
+                        style.center_line_color,
+                    ),
                     border_radius: 0.0,
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
@@ -112,6 +248,27 @@ impl<B: Backend> xy_pad::Renderer for Renderer<B> {
             (Primitive::None, Primitive::None)
         };
 
+        let circle = if is_polar
+            && style.center_line_color != Color::TRANSPARENT
+        {
+            Primitive::Quad {
+                bounds: pad_bounds,
+                background: iced_native::Background::Color(Color::TRANSPARENT),
+                border_radius: bounds_size / 2.0,
+                border_width: style.center_line_width,
+                border_color: style.center_line_color,
+            }
+        } else {
+            Primitive::None
+        };
+
+        let grid = match (snap_grid, style.grid) {
+            (Some((x_steps, y_steps)), Some(grid)) => {
+                draw_grid(&pad_bounds, x_steps, y_steps, &grid)
+            }
+            _ => Primitive::None,
+        };
+
         let (h_rail, v_rail) = if style.rail_width != 0.0 {
             let rail_width = style.rail_width as f32;
             let half_rail_width = (rail_width / 2.0).floor();
@@ -123,7 +280,9 @@ impl<B: Backend> xy_pad::Renderer for Renderer<B> {
                         width: bounds_size,
                         height: style.rail_width as f32,
                     },
-                    background: Background::Color(style.h_rail_color),
+                    background: iced_native::Background::Color(
+                        style.h_rail_color,
+                    ),
                     border_radius: 0.0,
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
@@ -135,7 +294,9 @@ impl<B: Backend> xy_pad::Renderer for Renderer<B> {
                         width: style.rail_width as f32,
                         height: bounds_size,
                     },
-                    background: Background::Color(style.v_rail_color),
+                    background: iced_native::Background::Color(
+                        style.v_rail_color,
+                    ),
                     border_radius: 0.0,
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
@@ -145,40 +306,124 @@ impl<B: Backend> xy_pad::Renderer for Renderer<B> {
             (Primitive::None, Primitive::None)
         };
 
+        let mod_range_x = match (mod_range_x, style_sheet.mod_range_style_x()) {
+            (Some(mod_range), Some(style)) => mod_range_band(mod_range, &style)
+                .map_or(Primitive::None, |(start, end, color)| {
+                    let half_width = (style.width / 2.0).floor();
+
+                    Primitive::Quad {
+                        bounds: Rectangle {
+                            x: (bounds_x + (bounds_size * start)).floor(),
+                            y: handle_y - half_width,
+                            width: (bounds_size * (end - start)).floor(),
+                            height: style.width,
+                        },
+                        background: iced_native::Background::Color(color),
+                        border_radius: style.border_radius,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    }
+                }),
+            _ => Primitive::None,
+        };
+
+        let mod_range_y = match (mod_range_y, style_sheet.mod_range_style_y()) {
+            (Some(mod_range), Some(style)) => mod_range_band(mod_range, &style)
+                .map_or(Primitive::None, |(start, end, color)| {
+                    let half_width = (style.width / 2.0).floor();
+
+                    Primitive::Quad {
+                        bounds: Rectangle {
+                            x: handle_x - half_width,
+                            y: (bounds_y + (bounds_size * (1.0 - end))).floor(),
+                            width: style.width,
+                            height: (bounds_size * (end - start)).floor(),
+                        },
+                        background: iced_native::Background::Color(color),
+                        border_radius: style.border_radius,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    }
+                }),
+            _ => Primitive::None,
+        };
+
+        let trail = if trail.len() > 1 {
+            draw_trail(
+                &pad_bounds,
+                trail,
+                trail_decay,
+                &style_sheet.trail_style(),
+            )
+        } else {
+            Primitive::None
+        };
+
+        let readouts = match readouts {
+            Some(readouts) => draw_readouts(
+                &pad_bounds,
+                readouts,
+                &style_sheet.readouts_style(),
+            ),
+            None => Primitive::None,
+        };
+
         let handle = {
             match style.handle {
                 HandleShape::Circle(circle) => {
                     let diameter = circle.diameter as f32;
                     let radius = diameter / 2.0;
 
-                    Primitive::Quad {
-                        bounds: Rectangle {
-                            x: handle_x - radius,
-                            y: handle_y - radius,
-                            width: diameter,
-                            height: diameter,
-                        },
-                        background: Background::Color(circle.color),
-                        border_radius: radius,
-                        border_width: circle.border_width,
-                        border_color: circle.border_color,
+                    let handle_bounds = Rectangle {
+                        x: handle_x - radius,
+                        y: handle_y - radius,
+                        width: diameter,
+                        height: diameter,
+                    };
+
+                    Primitive::Group {
+                        primitives: vec![
+                            draw_shadow(handle_bounds, radius, &circle.shadow),
+                            Primitive::Quad {
+                                bounds: handle_bounds,
+                                background: iced_native::Background::Color(
+                                    circle.color,
+                                ),
+                                border_radius: radius,
+                                border_width: circle.border_width,
+                                border_color: circle.border_color,
+                            },
+                        ],
                     }
                 }
                 HandleShape::Square(square) => {
                     let size = square.size as f32;
                     let half_size = (size / 2.0).floor();
 
-                    Primitive::Quad {
-                        bounds: Rectangle {
-                            x: handle_x - half_size,
-                            y: handle_y - half_size,
-                            width: size,
-                            height: size,
-                        },
-                        background: Background::Color(square.color),
-                        border_radius: square.border_radius,
-                        border_width: square.border_width,
-                        border_color: square.border_color,
+                    let handle_bounds = Rectangle {
+                        x: handle_x - half_size,
+                        y: handle_y - half_size,
+                        width: size,
+                        height: size,
+                    };
+
+                    Primitive::Group {
+                        primitives: vec![
+                            draw_shadow(
+                                handle_bounds,
+                                square.border_radius,
+                                &square.shadow,
+                            ),
+                            Primitive::Quad {
+                                bounds: handle_bounds,
+                                background: iced_native::Background::Color(
+                                    square.color,
+                                ),
+                                border_radius: square.border_radius,
+                                border_width: square.border_width,
+                                border_color: square.border_color,
+                            },
+                        ],
                     }
                 }
             }
@@ -187,12 +432,185 @@ impl<B: Backend> xy_pad::Renderer for Renderer<B> {
         self.draw_primitive(Primitive::Group {
             primitives: vec![
                 back,
+                back_image,
                 h_center_line,
                 v_center_line,
+                circle,
+                grid,
+                tick_marks_x,
+                tick_marks_y,
+                text_marks_x,
+                text_marks_y,
                 h_rail,
                 v_rail,
+                mod_range_x,
+                mod_range_y,
+                trail,
                 handle,
+                readouts,
+                focus_ring,
             ],
         })
     }
 }
+
+fn draw_readouts(
+    bounds: &Rectangle,
+    (x, y): (&str, &str),
+    style: &ReadoutsStyle,
+) -> Primitive {
+    let readout = |content: &str,
+                   position: Point,
+                   horizontal_alignment,
+                   vertical_alignment| Primitive::Text {
+        content: String::from(content),
+        bounds: Rectangle {
+            x: position.x.round(),
+            y: position.y.round(),
+            width: f32::from(style.style.bounds_width),
+            height: f32::from(style.style.bounds_height),
+        },
+        color: style.style.color,
+        size: f32::from(style.style.text_size),
+        font: style.style.font,
+        horizontal_alignment,
+        vertical_alignment,
+    };
+
+    Primitive::Group {
+        primitives: vec![
+            readout(
+                y,
+                Point::new(bounds.x + style.padding, bounds.y + style.padding),
+                Horizontal::Left,
+                Vertical::Top,
+            ),
+            readout(
+                x,
+                Point::new(
+                    bounds.x + bounds.width - style.padding,
+                    bounds.y + bounds.height - style.padding,
+                ),
+                Horizontal::Right,
+                Vertical::Bottom,
+            ),
+        ],
+    }
+}
+
+/// Returns the ordered start and end of the band of a [`ModulationRange`],
+/// along with its color, or `None` if there is nothing to fill.
+///
+/// [`ModulationRange`]: ../../core/struct.ModulationRange.html
+fn mod_range_band(
+    mod_range: &ModulationRange,
+    style: &ModRangeStyle,
+) -> Option<(f32, f32, Color)> {
+    let start = mod_range.start.as_f32();
+    let end = mod_range.end.as_f32();
+
+    if !mod_range.filled_visible || start == end {
+        None
+    } else if start < end {
+        Some((start, end, style.filled_color))
+    } else {
+        Some((end, start, style.filled_inverse_color))
+    }
+}
+
+fn draw_grid(
+    bounds: &Rectangle,
+    x_steps: u32,
+    y_steps: u32,
+    style: &GridStyle,
+) -> Primitive {
+    let half_line_width = (style.line_width / 2.0).floor();
+
+    let line = |bounds: Rectangle| Primitive::Quad {
+        bounds,
+        background: iced_native::Background::Color(style.line_color),
+        border_radius: 0.0,
+        border_width: 0.0,
+        border_color: Color::TRANSPARENT,
+    };
+
+    let mut primitives: Vec<Primitive> =
+        Vec::with_capacity((x_steps + y_steps) as usize);
+
+    for step in 1..x_steps {
+        let x = bounds.x + (bounds.width * step as f32 / x_steps as f32);
+
+        primitives.push(line(Rectangle {
+            x: x.floor() - half_line_width,
+            y: bounds.y,
+            width: style.line_width,
+            height: bounds.height,
+        }));
+    }
+
+    for step in 1..y_steps {
+        let y = bounds.y + (bounds.height * step as f32 / y_steps as f32);
+
+        primitives.push(line(Rectangle {
+            x: bounds.x,
+            y: y.floor() - half_line_width,
+            width: bounds.width,
+            height: style.line_width,
+        }));
+    }
+
+    Primitive::Group { primitives }
+}
+
+fn draw_trail(
+    bounds: &Rectangle,
+    trail: &VecDeque<(Normal, Normal)>,
+    decay: f32,
+    style: &TrailStyle,
+) -> Primitive {
+    let point = |(normal_x, normal_y): &(Normal, Normal)| {
+        Point::new(
+            normal_x.scale(bounds.width),
+            normal_y.scale_inv(bounds.height),
+        )
+    };
+
+    let mut frame = Frame::new(Size::new(bounds.width, bounds.height));
+
+    let mut alpha = style.color.a;
+
+    for (from, to) in trail.iter().zip(trail.iter().skip(1)).rev() {
+        let from = point(from);
+        let to = point(to);
+
+        frame.stroke(
+            &Path::line(from, to),
+            Stroke {
+                width: style.width,
+                color: Color {
+                    a: alpha,
+                    ..style.color
+                },
+                line_cap: LineCap::Round,
+                ..Stroke::default()
+            },
+        );
+
+        alpha *= decay;
+    }
+
+    Primitive::Translate {
+        translation: Vector::new(bounds.x, bounds.y),
+        content: Box::new(frame.into_geometry().into_primitive()),
+    }
+}
+
+fn status_style(style_sheet: &dyn StyleSheet, status: Status) -> Style {
+    match status {
+        Status::Active => style_sheet.active(),
+        Status::Hovered => style_sheet.hovered(),
+        Status::Focused => style_sheet.focused(),
+        Status::Dragging => style_sheet.dragging(),
+        Status::Disabled => style_sheet.active(),
+    }
+}
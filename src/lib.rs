@@ -106,7 +106,7 @@
 //!             ),
 //!
 //!             // Add a tick mark at the center position with the tier 2 size
-//!             center_tick_mark: tick_marks::Group::center(tick_marks::Tier::Two),
+//!             center_tick_mark: tick_marks::Group::center(tick_marks::Tier::TWO),
 //!
 //!             output_text: "Move a widget!".into(),
 //!         }
@@ -195,7 +195,10 @@
 #![deny(missing_docs)]
 #![deny(missing_debug_implementations)]
 #![deny(unused_results)]
-#![forbid(rust_2018_idioms)]
+#![cfg_attr(not(feature = "serde"), forbid(rust_2018_idioms))]
+// The code generated by the serde derives allows `unused_extern_crates`,
+// which can't be allowed in a forbidden lint group.
+#![cfg_attr(feature = "serde", deny(rust_2018_idioms))]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 //extern crate simdeez;
@@ -212,14 +215,14 @@ pub use crate::core::*;
 mod platform {
     #[doc(no_inline)]
     pub use crate::graphics::{
-        h_slider, knob, mod_range_input, ramp, text_marks, tick_marks,
-        v_slider, xy_pad,
+        h_slider, knob, mod_range_input, multi_xy_pad, ramp, text_marks,
+        tick_marks, v_slider, xy_pad,
     };
 
     #[doc(no_inline)]
     pub use {
         h_slider::HSlider, knob::Knob, mod_range_input::ModRangeInput,
-        ramp::Ramp, v_slider::VSlider, xy_pad::XYPad,
+        multi_xy_pad::MultiXYPad, ramp::Ramp, v_slider::VSlider, xy_pad::XYPad,
     };
 }
 
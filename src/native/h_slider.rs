@@ -2,16 +2,23 @@
 //!
 //! [`NormalParam`]: ../core/normal_param/struct.Param.html
 
+use std::cell::Cell;
 use std::fmt::Debug;
+use std::time::{Duration, Instant};
 
 use iced_native::{
-    event, keyboard, layout, mouse, Clipboard, Element, Event, Layout, Length,
-    Point, Rectangle, Shell, Size, Widget,
+    event, keyboard, layout, mouse, overlay, Clipboard, Element, Event, Layout,
+    Length, Point, Rectangle, Shell, Size, Widget,
 };
 
-use crate::native::{text_marks, tick_marks};
+use crate::native::{text_marks, tick_marks, tooltip};
+use crate::style::transition::StatusTransition;
+use crate::style::{h_slider, Status, StyleFn};
 use crate::{
-    core::{ModulationRange, Normal, NormalParam},
+    core::{
+        ClickBehavior, Easing, GestureSummary, ModulationRange, Normal,
+        NormalParam, SliderGroup, SmoothedNormal, WheelAxes,
+    },
     IntRange,
 };
 
@@ -19,6 +26,8 @@ static DEFAULT_HEIGHT: u16 = 14;
 static DEFAULT_SCALAR: f32 = 0.9575;
 static DEFAULT_WHEEL_SCALAR: f32 = 0.01;
 static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_KEY_STEP: f32 = 0.01;
+static DEFAULT_KEY_PAGE_STEP: f32 = 0.1;
 
 /// A horizontal slider GUI widget that controls a [`NormalParam`]
 ///
@@ -30,17 +39,36 @@ static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
 pub struct HSlider<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     on_change: Box<dyn Fn(Normal) -> Message>,
+    on_drag_start: Option<Box<dyn Fn() -> Option<Message>>>,
+    on_drag_end: Option<Box<dyn Fn() -> Option<Message>>>,
+    on_drag_end_summary: Option<Box<dyn Fn(GestureSummary) -> Message>>,
     scalar: f32,
     wheel_scalar: f32,
+    wheel_axes: WheelAxes,
+    key_step: f32,
+    key_page_step: f32,
+    steps: Option<u32>,
+    show_steps: bool,
+    center_detent: Option<f32>,
+    fill_from: Option<Normal>,
+    click_behavior: ClickBehavior,
+    tooltip_format: Option<Box<dyn Fn(Normal) -> String>>,
+    tooltip_style: tooltip::Style,
+    disabled: bool,
+    inverted: bool,
+    touch_padding: f32,
     modifier_scalar: f32,
     modifier_keys: keyboard::Modifiers,
+    fine_latch_key: Option<keyboard::KeyCode>,
+    linked: Option<Linked<'a, Message>>,
+    link_modifier_keys: keyboard::Modifiers,
     width: Length,
     height: Length,
     style: Renderer::Style,
     tick_marks: Option<&'a tick_marks::Group>,
     text_marks: Option<&'a text_marks::Group>,
-    mod_range_1: Option<&'a ModulationRange>,
-    mod_range_2: Option<&'a ModulationRange>,
+    edge_labels: Option<(&'a str, &'a str)>,
+    mod_ranges: &'a [ModulationRange],
 }
 
 impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
@@ -59,20 +87,131 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         HSlider {
             state,
             on_change: Box::new(on_change),
+            on_drag_start: None,
+            on_drag_end: None,
+            on_drag_end_summary: None,
             scalar: DEFAULT_SCALAR,
             wheel_scalar: DEFAULT_WHEEL_SCALAR,
+            wheel_axes: WheelAxes::Both,
+            key_step: DEFAULT_KEY_STEP,
+            key_page_step: DEFAULT_KEY_PAGE_STEP,
+            steps: None,
+            show_steps: false,
+            center_detent: None,
+            fill_from: None,
+            click_behavior: ClickBehavior::default(),
+            tooltip_format: None,
+            tooltip_style: tooltip::Style::default(),
+            disabled: false,
+            inverted: false,
+            touch_padding: 0.0,
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
             modifier_keys: keyboard::Modifiers::CTRL,
+            fine_latch_key: None,
+            linked: None,
+            link_modifier_keys: keyboard::Modifiers::SHIFT,
             width: Length::Fill,
             height: Length::from(Length::Units(DEFAULT_HEIGHT)),
             style: Renderer::Style::default(),
             tick_marks: None,
             text_marks: None,
-            mod_range_1: None,
-            mod_range_2: None,
+            edge_labels: None,
+            mod_ranges: &[],
         }
     }
 
+    /// Sets a function that will be called when the user starts to change
+    /// the value of the [`HSlider`], either by dragging it or with the keyboard.
+    /// It can return `None` to send no message.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn on_drag_start<F>(mut self, on_drag_start: F) -> Self
+    where
+        F: 'static + Fn() -> Option<Message>,
+    {
+        self.on_drag_start = Some(Box::new(on_drag_start));
+        self
+    }
+
+    /// Sets a function that will be called when the user stops changing the
+    /// value of the [`HSlider`]. It can return `None` to send no message.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn on_drag_end<F>(mut self, on_drag_end: F) -> Self
+    where
+        F: 'static + Fn() -> Option<Message>,
+    {
+        self.on_drag_end = Some(Box::new(on_drag_end));
+        self
+    }
+
+    /// Sets a function that will be called when the user stops changing the
+    /// value of the [`HSlider`], with a [`GestureSummary`] of the whole gesture.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`GestureSummary`]: ../../core/gesture_summary/struct.GestureSummary.html
+    pub fn on_drag_end_summary<F>(mut self, on_drag_end_summary: F) -> Self
+    where
+        F: 'static + Fn(GestureSummary) -> Message,
+    {
+        self.on_drag_end_summary = Some(Box::new(on_drag_end_summary));
+        self
+    }
+
+    /// Sets whether the [`HSlider`] is inverted. An inverted [`HSlider`] has its
+    /// minimum value on the right instead of the left.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn inverted(mut self, inverted: bool) -> Self {
+        self.inverted = inverted;
+        self
+    }
+
+    /// Enables animated smoothing of the [`HSlider`]. Values set with
+    /// [`State::set_normal`], such as automation coming from the host, will
+    /// then be eased in over the given `duration` instead of jumping to their
+    /// new position. Changes made by the user are never smoothed.
+    ///
+    /// The animation only advances when your application is redrawn, so you
+    /// will want to keep requesting redraws (for example with a
+    /// `time::every` subscription) while [`State::is_animating`] returns
+    /// `true`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`State::set_normal`]: struct.State.html#method.set_normal
+    /// [`State::is_animating`]: struct.State.html#method.is_animating
+    pub fn smoothing(self, duration: Duration) -> Self {
+        self.state.smoothing = Some(duration);
+        self
+    }
+
+    /// Cross-fades the colors and the widths of the style of the [`HSlider`]
+    /// with the `easing` curve over the given `duration` when it becomes
+    /// hovered, dragged or disabled, instead of switching styles abruptly.
+    ///
+    /// The animation only advances when your application is redrawn, so you
+    /// will want to keep requesting redraws (for example with a
+    /// `time::every` subscription) while [`State::is_animating`] returns
+    /// `true`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`State::is_animating`]: struct.State.html#method.is_animating
+    pub fn style_transition(self, duration: Duration, easing: Easing) -> Self {
+        self.state.style_transition.get_mut().set(duration, easing);
+        self
+    }
+
+    /// Sets whether the [`HSlider`] is disabled. A disabled [`HSlider`] ignores
+    /// all input events and is drawn with the `disabled()` style of its
+    /// [`StyleSheet`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`StyleSheet`]: ../../style/h_slider/trait.StyleSheet.html
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
     /// Sets the width of the [`HSlider`].
     ///
     /// The default height is `Length::Fill`.
@@ -101,6 +240,46 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the style of the [`HSlider`] from a closure returning the [`Style`]
+    /// of each [`Status`] of the [`HSlider`], such as:
+    ///
+    /// `.style_fn(|status| my_style(status, &palette))`
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Style`]: ../../style/h_slider/struct.Style.html
+    /// [`Status`]: ../../style/style_fn/enum.Status.html
+    pub fn style_fn<F>(self, style_fn: F) -> Self
+    where
+        F: 'static + Fn(Status) -> h_slider::Style,
+        StyleFn<F>: Into<Renderer::Style>,
+    {
+        self.style(StyleFn::new(style_fn))
+    }
+
+    /// Sets a key that toggles the fine adjustment mode of the [`HSlider`]
+    /// while it is dragged or focused. Unlike the modifier keys, the fine
+    /// adjustment mode stays on until the key is pressed again.
+    ///
+    /// There is no fine adjustment latch key by default.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn fine_latch_key(mut self, fine_latch_key: keyboard::KeyCode) -> Self {
+        self.fine_latch_key = Some(fine_latch_key);
+        self
+    }
+
+    /// Sets the padding in pixels that expands the interactive area of the
+    /// [`HSlider`] beyond its drawn bounds, which makes it easier to grab with
+    /// a finger. The [`HSlider`] is drawn at the same size.
+    ///
+    /// The default padding is `0.0`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn touch_padding(mut self, touch_padding: f32) -> Self {
+        self.touch_padding = touch_padding;
+        self
+    }
+
     /// Sets the modifier keys of the [`HSlider`].
     ///
     /// The default modifier key is `Ctrl`.
@@ -111,6 +290,52 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         self
     }
 
+    /// Links the [`HSlider`] to the other members of a [`SliderGroup`].
+    ///
+    /// While the link modifier keys are held down, moving the [`HSlider`] also
+    /// moves the other members of the group, and `on_linked_change` is
+    /// called with the index and the new [`Normal`] of each of them.
+    ///
+    /// It expects:
+    ///   * the [`SliderGroup`] holding the current value of each member
+    ///   * the index of the [`HSlider`] in the group
+    ///   * a function that will be called for each linked member that changes
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`SliderGroup`]: ../../core/slider_group/struct.SliderGroup.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn linked<F>(
+        mut self,
+        group: &'a SliderGroup,
+        index: usize,
+        on_linked_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(usize, Normal) -> Message,
+    {
+        self.linked = Some(Linked {
+            group,
+            index,
+            on_change: Box::new(on_linked_change),
+        });
+        self
+    }
+
+    /// Sets the modifier keys that move the other members of the
+    /// [`SliderGroup`] along with the [`HSlider`].
+    ///
+    /// The default modifier key is `Shift`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`SliderGroup`]: ../../core/slider_group/struct.SliderGroup.html
+    pub fn link_modifier_keys(
+        mut self,
+        link_modifier_keys: keyboard::Modifiers,
+    ) -> Self {
+        self.link_modifier_keys = link_modifier_keys;
+        self
+    }
+
     /// Sets the scalar to use when the user drags the slider per pixel.
     ///
     /// For example, a scalar of `0.5` will cause the slider to move half a
@@ -124,6 +349,16 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the scroll wheel axes the [`HSlider`] responds to.
+    ///
+    /// The default is both axes.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn wheel_axes(mut self, wheel_axes: WheelAxes) -> Self {
+        self.wheel_axes = wheel_axes;
+        self
+    }
+
     /// Sets how much the [`Normal`] value will change for the [`HSlider`] per line scrolled
     /// by the mouse wheel.
     ///
@@ -138,6 +373,124 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         self
     }
 
+    /// Sets how much the [`Normal`] value will change for the [`HSlider`] when
+    /// an arrow key is pressed while it is focused. This is multiplied by
+    /// the `modifier_scalar` when the modifier key is held down.
+    ///
+    /// The default value is `0.01`
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn key_step(mut self, key_step: f32) -> Self {
+        self.key_step = key_step;
+        self
+    }
+
+    /// Sets how much the [`Normal`] value will change for the [`HSlider`] when
+    /// `PageUp` or `PageDown` is pressed while it is focused.
+    ///
+    /// The default value is `0.1`
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn key_page_step(mut self, key_page_step: f32) -> Self {
+        self.key_page_step = key_page_step;
+        self
+    }
+
+    /// Divides the range of the [`HSlider`] into `steps` equal steps. The handle
+    /// snaps to the nearest of the `steps + 1` positions while dragging, and
+    /// each line scrolled with the mouse wheel moves it by exactly one step.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn steps(mut self, steps: u32) -> Self {
+        self.steps = Some(steps);
+
+        let tick_marks_len = steps as usize + 1;
+        if self.state.step_tick_marks.len() != tick_marks_len {
+            self.state.step_tick_marks = tick_marks::Group::evenly_spaced(
+                tick_marks_len,
+                tick_marks::Tier::TWO,
+            );
+        }
+
+        self
+    }
+
+    /// Sets whether to display the steps set with `HSlider::steps()` as tick
+    /// marks. These replace any tick marks set with `HSlider::tick_marks()`.
+    /// Note your [`StyleSheet`] must also implement
+    /// `tick_marks_style(&self) -> Option<tick_marks::Style>` for them to
+    /// display (which the default style does).
+    ///
+    /// [`StyleSheet`]: ../../style/h_slider/trait.StyleSheet.html
+    pub fn show_steps(mut self, show_steps: bool) -> Self {
+        self.show_steps = show_steps;
+        self
+    }
+
+    /// Makes the handle of the [`HSlider`] stick to the center while it is
+    /// dragged within `width` of it, which is useful for pan and balance
+    /// parameters. `width` is the total width of the detent as a normalized
+    /// value, so `0.04` covers `0.48` to `0.52`.
+    ///
+    /// The detent is disabled while the modifier key is held down.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn center_detent(mut self, width: f32) -> Self {
+        self.center_detent = Some(width);
+        self
+    }
+
+    /// Sets the value the filled portion of the [`HSlider`] starts from, for
+    /// example unity gain. Only has an effect when using [`RectStyle`] or
+    /// [`RectBipolarStyle`], which otherwise fill from the minimum value and
+    /// from the center respectively.
+    ///
+    /// This also accepts a [`BipolarNormal`], where the center is `0.0`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`RectStyle`]: ../../style/h_slider/struct.RectStyle.html
+    /// [`RectBipolarStyle`]: ../../style/h_slider/struct.RectBipolarStyle.html
+    /// [`BipolarNormal`]: ../../core/bipolar_normal/struct.BipolarNormal.html
+    pub fn fill_from(mut self, fill_from: impl Into<Normal>) -> Self {
+        self.fill_from = Some(fill_from.into());
+        self
+    }
+
+    /// Shows a tooltip with the formatted value of the [`HSlider`] above its
+    /// handle while it is dragged.
+    ///
+    /// The tooltip is drawn as an overlay, so it isn't clipped by the
+    /// bounds of the [`HSlider`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn tooltip_format<F>(mut self, format: F) -> Self
+    where
+        F: 'static + Fn(Normal) -> String,
+    {
+        self.tooltip_format = Some(Box::new(format));
+        self
+    }
+
+    /// Sets the style of the tooltip of the [`HSlider`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn tooltip_style(mut self, style: tooltip::Style) -> Self {
+        self.tooltip_style = style;
+        self
+    }
+
+    /// Sets how the [`HSlider`] responds when it is clicked.
+    ///
+    /// The default is `ClickBehavior::RelativeDrag`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn click_behavior(mut self, click_behavior: ClickBehavior) -> Self {
+        self.click_behavior = click_behavior;
+        self
+    }
+
     /// Sets the scalar to use when the user drags the slider while holding down
     /// the modifier key.
     ///
@@ -172,39 +525,79 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         self
     }
 
+    /// Sets labels to display at the min and max ends of the [`HSlider`], such
+    /// as "DRY" and "WET", without having to build a [`text_marks::Group`].
+    ///
+    /// The style of the labels is given by the `edge_labels_style()` of the
+    /// [`StyleSheet`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`text_marks::Group`]: ../text_marks/struct.Group.html
+    /// [`StyleSheet`]: ../../style/h_slider/trait.StyleSheet.html
+    pub fn edge_labels(mut self, min: &'a str, max: &'a str) -> Self {
+        self.edge_labels = Some((min, max));
+        self
+    }
+
     /// Sets a [`ModulationRange`] to display. Note your [`StyleSheet`] must
     /// also implement `mod_range_style(&self) -> Option<ModRangeStyle>` for
-    /// them to display.
+    /// it to display.
     ///
     /// [`ModulationRange`]: ../../core/struct.ModulationRange.html
-    /// [`StyleSheet`]: ../../style/v_slider/trait.StyleSheet.html
+    /// [`StyleSheet`]: ../../style/h_slider/trait.StyleSheet.html
     pub fn mod_range(mut self, mod_range: &'a ModulationRange) -> Self {
-        self.mod_range_1 = Some(mod_range);
+        self.mod_ranges = std::slice::from_ref(mod_range);
         self
     }
 
-    /// Sets a second [`ModulationRange`] to display. Note your [`StyleSheet`] must
-    /// also implement `mod_range_style_2(&self) -> Option<ModRangeStyle>` for
-    /// them to display.
+    /// Sets the [`ModulationRange`]s to display. Note your [`StyleSheet`]
+    /// must also implement `mod_range_styles(&self) -> Vec<ModRangeStyle>`
+    /// for them to display. Each [`ModulationRange`] is drawn with the
+    /// [`ModRangeStyle`] at the same index.
     ///
     /// [`ModulationRange`]: ../../core/struct.ModulationRange.html
-    /// [`StyleSheet`]: ../../style/v_slider/trait.StyleSheet.html
-    pub fn mod_range_2(mut self, mod_range: &'a ModulationRange) -> Self {
-        self.mod_range_1 = Some(mod_range);
+    /// [`ModRangeStyle`]: ../../style/h_slider/struct.ModRangeStyle.html
+    /// [`StyleSheet`]: ../../style/h_slider/trait.StyleSheet.html
+    pub fn mod_ranges(mut self, mod_ranges: &'a [ModulationRange]) -> Self {
+        self.mod_ranges = mod_ranges;
         self
     }
 
+    fn is_fine(&self) -> bool {
+        self.state.is_fine_latched
+            || self.state.pressed_modifiers.contains(self.modifier_keys)
+    }
+
+    fn hit_bounds(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.x - self.touch_padding,
+            y: bounds.y - self.touch_padding,
+            width: bounds.width + (self.touch_padding * 2.0),
+            height: bounds.height + (self.touch_padding * 2.0),
+        }
+    }
+
     fn move_virtual_slider(
         &mut self,
         messages: &mut Shell<'_, Message>,
         mut normal_delta: f32,
     ) {
-        if self.state.pressed_modifiers.contains(self.modifier_keys) {
+        let is_fine = self.is_fine();
+        if is_fine {
             normal_delta *= self.modifier_scalar;
         }
 
-        let mut normal = self.state.continuous_normal - normal_delta;
+        let normal = self.state.continuous_normal - normal_delta;
+
+        self.set_normal_from_input(messages, normal, is_fine);
+    }
 
+    fn set_normal_from_input(
+        &mut self,
+        messages: &mut Shell<'_, Message>,
+        mut normal: f32,
+        is_fine: bool,
+    ) {
         if normal < 0.0 {
             normal = 0.0;
         } else if normal > 1.0 {
@@ -212,13 +605,152 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         }
 
         self.state.continuous_normal = normal;
+        self.state.stop_smoothing();
+
+        if let Some(steps) = self.steps {
+            normal = Normal::new(normal).quantize(steps).as_f32();
+        }
 
-        self.state.normal_param.value = normal.into();
+        if let Some(width) = self.center_detent {
+            if !is_fine {
+                normal = Normal::new(normal)
+                    .snap_to(&[Normal::center()], width / 2.0)
+                    .as_f32();
+            }
+        }
+
+        let prev_normal = self.state.normal_param.value;
+        self.state.normal_param.set_value(normal.into());
+
+        if self.state.normal_param.value != prev_normal {
+            self.state.gesture_moved = true;
+        }
 
         messages.publish((self.on_change)(self.state.normal_param.value));
+
+        if let Some(linked) = &self.linked {
+            if self
+                .state
+                .pressed_modifiers
+                .contains(self.link_modifier_keys)
+            {
+                for (index, normal) in linked.group.linked_changes(
+                    linked.index,
+                    prev_normal,
+                    self.state.normal_param.value,
+                ) {
+                    messages.publish((linked.on_change)(index, normal));
+                }
+            }
+        }
+    }
+
+    fn jump_to_cursor(
+        &mut self,
+        messages: &mut Shell<'_, Message>,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) {
+        if bounds.width > 0.0 {
+            let is_fine = self.is_fine();
+
+            let normal = (cursor_position.x - bounds.x) / bounds.width;
+
+            self.set_normal_from_input(
+                messages,
+                if self.inverted { 1.0 - normal } else { normal },
+                is_fine,
+            );
+        }
+    }
+
+    fn key_normal(&self, key_code: keyboard::KeyCode) -> Option<f32> {
+        let step = match self.steps.filter(|steps| *steps > 0) {
+            Some(steps) => 1.0 / steps as f32,
+            None => {
+                if self.is_fine() {
+                    self.key_step * self.modifier_scalar
+                } else {
+                    self.key_step
+                }
+            }
+        };
+
+        let normal = self.state.normal_param.value.as_f32();
+
+        match key_code {
+            keyboard::KeyCode::Right if self.inverted => Some(normal - step),
+            keyboard::KeyCode::Left if self.inverted => Some(normal + step),
+            keyboard::KeyCode::Right | keyboard::KeyCode::Up => {
+                Some(normal + step)
+            }
+            keyboard::KeyCode::Left | keyboard::KeyCode::Down => {
+                Some(normal - step)
+            }
+            keyboard::KeyCode::PageUp => Some(normal + self.key_page_step),
+            keyboard::KeyCode::PageDown => Some(normal - self.key_page_step),
+            keyboard::KeyCode::Home => Some(0.0),
+            keyboard::KeyCode::End => Some(1.0),
+            _ => None,
+        }
+    }
+
+    fn publish_drag_start(&mut self, messages: &mut Shell<'_, Message>) {
+        self.state.stop_smoothing();
+        self.state.gesture_start =
+            Some((self.state.normal_param.value, Instant::now()));
+        self.state.gesture_moved = false;
+
+        if let Some(message) = self
+            .on_drag_start
+            .as_ref()
+            .and_then(|on_drag_start| on_drag_start())
+        {
+            messages.publish(message);
+        }
+    }
+
+    fn publish_drag_end(&mut self, messages: &mut Shell<'_, Message>) {
+        if let Some(message) = self
+            .on_drag_end
+            .as_ref()
+            .and_then(|on_drag_end| on_drag_end())
+        {
+            messages.publish(message);
+        }
+
+        if let Some((start, start_instant)) = self.state.gesture_start.take() {
+            if let Some(on_drag_end_summary) = &self.on_drag_end_summary {
+                messages.publish(on_drag_end_summary(GestureSummary {
+                    start,
+                    end: self.state.normal_param.value,
+                    moved: self.state.gesture_moved,
+                    duration: start_instant.elapsed(),
+                }));
+            }
+        }
+    }
+
+    fn move_step(&mut self, messages: &mut Shell<'_, Message>, lines: f32) {
+        let steps = self.steps.unwrap_or(0) as f32;
+        if steps == 0.0 {
+            return;
+        }
+
+        let step = (self.state.normal_param.value.as_f32() * steps).round()
+            + lines.signum();
+
+        let is_fine = self.is_fine();
+        self.set_normal_from_input(messages, step / steps, is_fine);
     }
 }
 
+struct Linked<'a, Message> {
+    group: &'a SliderGroup,
+    index: usize,
+    on_change: Box<dyn Fn(usize, Normal) -> Message>,
+}
+
 /// The local state of an [`HSlider`].
 ///
 /// [`HSlider`]: struct.HSlider.html
@@ -226,10 +758,21 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
 pub struct State {
     normal_param: NormalParam,
     is_dragging: bool,
+    is_focused: bool,
+    gesture_start: Option<(Normal, Instant)>,
+    gesture_moved: bool,
+    is_fine_latched: bool,
+    is_cursor_outside: bool,
+    is_drag_resync_needed: bool,
     prev_drag_x: f32,
     continuous_normal: f32,
     pressed_modifiers: keyboard::Modifiers,
     last_click: Option<mouse::Click>,
+    smoothing: Option<Duration>,
+    smoothed_normal: Cell<SmoothedNormal>,
+    smoothing_tick: Cell<Instant>,
+    style_transition: Cell<StatusTransition>,
+    step_tick_marks: tick_marks::Group,
     tick_marks_cache: crate::graphics::tick_marks::PrimitiveCache,
     text_marks_cache: crate::graphics::text_marks::PrimitiveCache,
 }
@@ -246,18 +789,42 @@ impl State {
         Self {
             normal_param,
             is_dragging: false,
+            is_focused: false,
+            gesture_start: None,
+            gesture_moved: false,
+            is_fine_latched: false,
+            is_cursor_outside: false,
+            is_drag_resync_needed: false,
             prev_drag_x: 0.0,
             continuous_normal: normal_param.value.as_f32(),
             pressed_modifiers: Default::default(),
             last_click: None,
+            smoothing: None,
+            smoothed_normal: Cell::new(SmoothedNormal::new(
+                normal_param.value,
+                Duration::from_secs(0),
+            )),
+            smoothing_tick: Cell::new(Instant::now()),
+            style_transition: Cell::default(),
+            step_tick_marks: Default::default(),
             tick_marks_cache: Default::default(),
             text_marks_cache: Default::default(),
         }
     }
 
     /// Set the normalized value of the [`HSlider`].
+    ///
+    /// If smoothing is enabled, the displayed value will ease towards the
+    /// new value.
     pub fn set_normal(&mut self, normal: Normal) {
-        self.normal_param.value = normal;
+        if let (Some(duration), false) = (self.smoothing, self.is_dragging) {
+            let mut smoothed_normal = self.tick_smoothing();
+            smoothed_normal.set_duration(duration);
+            smoothed_normal.set_target(normal);
+            self.smoothed_normal.set(smoothed_normal);
+        }
+
+        self.normal_param.set_value(normal);
         self.continuous_normal = normal.into();
     }
 
@@ -291,7 +858,34 @@ impl State {
     ///
     /// ```
     pub fn snap_visible_to(&mut self, range: &IntRange) {
-        self.normal_param.value = range.snapped(self.normal_param.value);
+        self.normal_param
+            .set_value(range.snapped(self.normal_param.value));
+    }
+
+    /// Sets the cache of the tick marks of the [`HSlider`].
+    ///
+    /// Give clones of the same cache to several widgets drawing the same
+    /// tick marks, so they are only built once for all of them.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn set_tick_marks_cache(
+        &mut self,
+        cache: crate::graphics::tick_marks::PrimitiveCache,
+    ) {
+        self.tick_marks_cache = cache;
+    }
+
+    /// Sets the cache of the text marks of the [`HSlider`].
+    ///
+    /// Give clones of the same cache to several widgets drawing the same
+    /// text marks, so they are only built once for all of them.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn set_text_marks_cache(
+        &mut self,
+        cache: crate::graphics::text_marks::PrimitiveCache,
+    ) {
+        self.text_marks_cache = cache;
     }
 
     /// Is the [`HSlider`] currently in the dragging state?
@@ -300,6 +894,85 @@ impl State {
     pub fn is_dragging(&self) -> bool {
         self.is_dragging
     }
+
+    /// Is the displayed value of the [`HSlider`] currently easing towards its
+    /// value, or is its style cross-fading between two states?
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn is_animating(&self) -> bool {
+        self.tick_smoothing().is_smoothing()
+            || self.style_transition.get().is_animating()
+    }
+
+    fn update_style_transition(&self, status: Status) -> Option<(Status, f32)> {
+        let mut style_transition = self.style_transition.get();
+        let progress = style_transition.update(status, Instant::now());
+        self.style_transition.set(style_transition);
+
+        progress
+    }
+
+    /// Get the normalized value currently displayed by the [`HSlider`]. This
+    /// only differs from [`normal`] while the [`HSlider`] is animating.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`normal`]: #method.normal
+    pub fn displayed_normal(&self) -> Normal {
+        self.tick_smoothing().current()
+    }
+
+    /// Advances the smoothing of the displayed value up to now.
+    fn tick_smoothing(&self) -> SmoothedNormal {
+        let now = Instant::now();
+        let elapsed =
+            now.saturating_duration_since(self.smoothing_tick.replace(now));
+
+        let mut smoothed_normal = self.smoothed_normal.get();
+
+        if smoothed_normal.is_smoothing() {
+            let _ = smoothed_normal.tick(elapsed);
+        } else {
+            smoothed_normal.set_immediate(self.normal_param.value);
+        }
+
+        self.smoothed_normal.set(smoothed_normal);
+
+        smoothed_normal
+    }
+
+    fn stop_smoothing(&mut self) {
+        let value = self.normal_param.value;
+        self.smoothed_normal.get_mut().set_immediate(value);
+    }
+
+    /// Is the [`HSlider`] currently focused? A focused [`HSlider`] can be
+    /// controlled with the keyboard.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Is the fine adjustment mode of the [`HSlider`] latched on?
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn is_fine_latched(&self) -> bool {
+        self.is_fine_latched
+    }
+
+    /// Focuses the [`HSlider`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    /// Unfocuses the [`HSlider`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -336,9 +1009,53 @@ where
         _clipboard: &mut dyn Clipboard,
         messages: &mut Shell<'_, Message>,
     ) -> event::Status {
+        if self.disabled {
+            self.state.is_focused = false;
+
+            if self.state.is_dragging {
+                self.state.is_dragging = false;
+                self.state.continuous_normal =
+                    self.state.normal_param.value.as_f32();
+
+                self.publish_drag_end(messages);
+            }
+
+            return event::Status::Ignored;
+        }
+
         match event {
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::CursorMoved { .. } => {
+                    // The cursor may have moved without being reported
+                    // while it was outside of the window.
+                    if self.state.is_dragging
+                        && self.state.is_drag_resync_needed
+                    {
+                        self.state.is_drag_resync_needed = false;
+                        self.state.prev_drag_x = cursor_position.x;
+
+                        return event::Status::Captured;
+                    }
+
+                    // Only follow the absolute position of the cursor while
+                    // it is inside the window. Outside of the window, keep
+                    // moving relative to the last known position.
+                    if self.state.is_dragging
+                        && !self.state.is_cursor_outside
+                        && self.click_behavior == ClickBehavior::JumpToCursor
+                        && !self.is_fine()
+                    {
+                        self.state.prev_drag_x = cursor_position.x;
+
+                        self.jump_to_cursor(
+                            messages,
+                            layout.bounds(),
+                            cursor_position,
+                        );
+
+                        return event::Status::Captured;
+                    }
+
                     if self.state.is_dragging {
                         let bounds_width = layout.bounds().width;
 
@@ -347,6 +1064,11 @@ where
                                 - self.state.prev_drag_x)
                                 / bounds_width
                                 * -self.scalar;
+                            let normal_delta = if self.inverted {
+                                -normal_delta
+                            } else {
+                                normal_delta
+                            };
 
                             self.state.prev_drag_x = cursor_position.x;
 
@@ -356,42 +1078,94 @@ where
                         }
                     }
                 }
+                mouse::Event::CursorLeft => {
+                    self.state.is_cursor_outside = true;
+
+                    if self.state.is_dragging {
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::CursorEntered => {
+                    self.state.is_cursor_outside = false;
+
+                    if self.state.is_dragging {
+                        // A relative drag keeps the move from the last known
+                        // position, only the jump to the cursor needs to
+                        // skip it.
+                        self.state.is_drag_resync_needed =
+                            self.click_behavior == ClickBehavior::JumpToCursor;
+
+                        return event::Status::Captured;
+                    }
+                }
                 mouse::Event::WheelScrolled { delta } => {
-                    if self.wheel_scalar == 0.0 {
+                    let wheel_disabled = match self.steps {
+                        Some(steps) => steps == 0,
+                        None => self.wheel_scalar == 0.0,
+                    };
+
+                    if wheel_disabled {
                         return event::Status::Ignored;
                     }
 
-                    if layout.bounds().contains(cursor_position) {
-                        let lines = match delta {
-                            iced_native::mouse::ScrollDelta::Lines {
-                                y,
-                                ..
-                            } => y,
+                    if self
+                        .hit_bounds(layout.bounds())
+                        .contains(cursor_position)
+                    {
+                        let (x, y, is_pixels) = match delta {
+                            iced_native::mouse::ScrollDelta::Lines { x, y } => {
+                                (x, y, false)
+                            }
                             iced_native::mouse::ScrollDelta::Pixels {
+                                x,
                                 y,
-                                ..
-                            } => {
-                                if y > 0.0 {
-                                    1.0
-                                } else if y < 0.0 {
-                                    -1.0
-                                } else {
-                                    0.0
-                                }
-                            }
+                            } => (x, y, true),
                         };
 
+                        let mut lines = self.wheel_axes.select(x, y);
+
+                        if is_pixels {
+                            lines = if lines > 0.0 {
+                                1.0
+                            } else if lines < 0.0 {
+                                -1.0
+                            } else {
+                                0.0
+                            };
+                        }
+
+                        // Scrolling along the slider follows its direction,
+                        // like the arrow keys.
+                        if self.inverted && self.wheel_axes.is_horizontal(x, y)
+                        {
+                            lines = -lines;
+                        }
+
                         if lines != 0.0 {
-                            let normal_delta = -lines * self.wheel_scalar;
+                            if self.steps.is_some() {
+                                self.move_step(messages, lines);
+                            } else {
+                                let normal_delta = -lines * self.wheel_scalar;
 
-                            self.move_virtual_slider(messages, normal_delta);
+                                self.move_virtual_slider(
+                                    messages,
+                                    normal_delta,
+                                );
+                            }
 
                             return event::Status::Captured;
                         }
                     }
                 }
                 mouse::Event::ButtonPressed(mouse::Button::Left) => {
-                    if layout.bounds().contains(cursor_position) {
+                    self.state.is_focused = self
+                        .hit_bounds(layout.bounds())
+                        .contains(cursor_position);
+
+                    if self
+                        .hit_bounds(layout.bounds())
+                        .contains(cursor_position)
+                    {
                         let click = mouse::Click::new(
                             cursor_position,
                             self.state.last_click,
@@ -401,16 +1175,32 @@ where
                             mouse::click::Kind::Single => {
                                 self.state.is_dragging = true;
                                 self.state.prev_drag_x = cursor_position.x;
+
+                                self.publish_drag_start(messages);
+
+                                if self.click_behavior
+                                    != ClickBehavior::RelativeDrag
+                                {
+                                    self.jump_to_cursor(
+                                        messages,
+                                        layout.bounds(),
+                                        cursor_position,
+                                    );
+                                }
                             }
                             _ => {
                                 self.state.is_dragging = false;
 
-                                self.state.normal_param.value =
-                                    self.state.normal_param.default;
+                                // Reset to the default value in a gesture of
+                                // its own, like the keyboard edits.
+                                let default =
+                                    self.state.normal_param.default.as_f32();
 
-                                messages.publish((self.on_change)(
-                                    self.state.normal_param.value,
-                                ));
+                                self.publish_drag_start(messages);
+                                self.set_normal_from_input(
+                                    messages, default, true,
+                                );
+                                self.publish_drag_end(messages);
                             }
                         }
 
@@ -420,6 +1210,10 @@ where
                     }
                 }
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                    if self.state.is_dragging {
+                        self.publish_drag_end(messages);
+                    }
+
                     self.state.is_dragging = false;
                     self.state.continuous_normal =
                         self.state.normal_param.value.as_f32();
@@ -429,9 +1223,31 @@ where
                 _ => {}
             },
             Event::Keyboard(keyboard_event) => match keyboard_event {
-                keyboard::Event::KeyPressed { modifiers, .. } => {
+                keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers,
+                } => {
                     self.state.pressed_modifiers = modifiers;
 
+                    if (self.state.is_dragging || self.state.is_focused)
+                        && self.fine_latch_key == Some(key_code)
+                    {
+                        self.state.is_fine_latched =
+                            !self.state.is_fine_latched;
+                        self.state.continuous_normal =
+                            self.state.normal_param.value.as_f32();
+
+                        return event::Status::Captured;
+                    }
+
+                    if self.state.is_focused && !self.state.is_dragging {
+                        if let Some(normal) = self.key_normal(key_code) {
+                            self.publish_drag_start(messages);
+                            self.set_normal_from_input(messages, normal, true);
+                            self.publish_drag_end(messages);
+                        }
+                    }
+
                     return event::Status::Captured;
                 }
                 keyboard::Event::KeyReleased { modifiers, .. } => {
@@ -460,20 +1276,116 @@ where
         cursor_position: Point,
         _viewport: &Rectangle,
     ) {
+        let is_hovered =
+            self.hit_bounds(layout.bounds()).contains(cursor_position);
+
+        let status = if self.disabled {
+            Status::Disabled
+        } else if self.state.is_dragging {
+            Status::Dragging
+        } else if is_hovered {
+            Status::Hovered
+        } else if self.state.is_focused {
+            Status::Focused
+        } else {
+            Status::Active
+        };
+
         renderer.draw(
-            layout.bounds(),
-            cursor_position,
-            self.state.normal_param.value,
-            self.state.is_dragging,
-            self.mod_range_1,
-            self.mod_range_2,
-            self.tick_marks,
-            self.text_marks,
+            DrawInfo {
+                bounds: layout.bounds(),
+                is_hovered,
+                normal: self.state.displayed_normal(),
+                fill_from: self.fill_from,
+                is_disabled: self.disabled,
+                is_inverted: self.inverted,
+                is_fine: self.is_fine(),
+                is_dragging: self.state.is_dragging,
+                is_focused: self.state.is_focused,
+                mod_ranges: self.mod_ranges,
+                tick_marks: if self.show_steps
+                    && matches!(self.steps, Some(steps) if steps > 0)
+                {
+                    Some(&self.state.step_tick_marks)
+                } else {
+                    self.tick_marks
+                },
+                text_marks: self.text_marks,
+                edge_labels: self.edge_labels,
+                style_transition: self.state.update_style_transition(status),
+                tick_marks_cache: &self.state.tick_marks_cache,
+                text_marks_cache: &self.state.text_marks_cache,
+            },
             &self.style,
-            &self.state.tick_marks_cache,
-            &self.state.text_marks_cache,
         )
     }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if self.disabled || !self.state.is_dragging {
+            return None;
+        }
+
+        self.tooltip_format.as_ref().map(|format| {
+            let normal = self.state.displayed_normal();
+
+            let target = renderer.handle_bounds(
+                layout.bounds(),
+                normal,
+                self.inverted,
+                self.is_fine(),
+                &self.style,
+            );
+
+            tooltip::Tooltip::new(format(normal), target, self.tooltip_style)
+                .overlay()
+        })
+    }
+}
+
+/// The values an [`HSlider`] is drawn with, passed to the
+/// [`Renderer`] on every frame.
+///
+/// [`HSlider`]: struct.HSlider.html
+/// [`Renderer`]: trait.Renderer.html
+#[derive(Debug, Copy, Clone)]
+pub struct DrawInfo<'a> {
+    /// The bounds of the slider
+    pub bounds: Rectangle,
+    /// Whether the cursor is over the slider, including its touch padding
+    pub is_hovered: bool,
+    /// The displayed normal of the slider
+    pub normal: Normal,
+    /// The value the filled portion starts from, if any
+    pub fill_from: Option<Normal>,
+    /// Whether the slider is disabled
+    pub is_disabled: bool,
+    /// Whether the slider is inverted
+    pub is_inverted: bool,
+    /// Whether the slider is in fine adjustment mode
+    pub is_fine: bool,
+    /// Whether the slider is being dragged
+    pub is_dragging: bool,
+    /// Whether the slider has the keyboard focus
+    pub is_focused: bool,
+    /// The modulation ranges to display
+    pub mod_ranges: &'a [ModulationRange],
+    /// The tick marks to display, if any
+    pub tick_marks: Option<&'a tick_marks::Group>,
+    /// The text marks to display, if any
+    pub text_marks: Option<&'a text_marks::Group>,
+    /// The labels to display at the min and max ends, if any
+    pub edge_labels: Option<(&'a str, &'a str)>,
+    /// The status the style is cross-fading from and the eased
+    /// progress of the cross-fade, if any
+    pub style_transition: Option<(Status, f32)>,
+    /// The primitive cache of the tick marks
+    pub tick_marks_cache: &'a crate::tick_marks::PrimitiveCache,
+    /// The primitive cache of the text marks
+    pub text_marks_cache: &'a crate::text_marks::PrimitiveCache,
 }
 
 /// The renderer of an [`HSlider`].
@@ -482,37 +1394,28 @@ where
 /// able to use an [`HSlider`] in your user interface.
 ///
 /// [`HSlider`]: struct.HSlider.html
-pub trait Renderer: iced_native::Renderer {
+pub trait Renderer: iced_native::Renderer + tooltip::Renderer {
     /// The style supported by this renderer.
     type Style: Default;
 
-    /// Draws an [`HSlider`].
+    /// Draws an [`HSlider`] from its [`DrawInfo`] and its style.
     ///
-    /// It receives:
-    ///   * the bounds of the [`HSlider`]
-    ///   * the current cursor position
-    ///   * the current normal of the [`HSlider`]
-    ///   * the height of the handle in pixels
-    ///   * whether the slider is currently being dragged
-    ///   * any tick marks to display
-    ///   * any text marks to display
-    ///   * the style of the [`HSlider`]
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`DrawInfo`]: struct.DrawInfo.html
+    fn draw(&mut self, info: DrawInfo<'_>, style: &Self::Style);
+
+    /// Returns the bounds of the handle of a dragged [`HSlider`] displaying
+    /// `normal`, which its tooltip is anchored on.
     ///
     /// [`HSlider`]: struct.HSlider.html
-    fn draw(
-        &mut self,
+    fn handle_bounds(
+        &self,
         bounds: Rectangle,
-        cursor_position: Point,
         normal: Normal,
-        is_dragging: bool,
-        mod_range_1: Option<&ModulationRange>,
-        mod_range_2: Option<&ModulationRange>,
-        tick_marks: Option<&tick_marks::Group>,
-        text_marks: Option<&text_marks::Group>,
+        is_inverted: bool,
+        is_fine: bool,
         style: &Self::Style,
-        tick_marks_cache: &crate::tick_marks::PrimitiveCache,
-        text_marks_cache: &crate::text_marks::PrimitiveCache,
-    );
+    ) -> Rectangle;
 }
 
 impl<'a, Message, Renderer> From<HSlider<'a, Message, Renderer>>
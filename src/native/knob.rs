@@ -2,15 +2,23 @@
 //!
 //! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
 
+use std::cell::Cell;
 use std::fmt::Debug;
+use std::time::{Duration, Instant};
 
+use iced_graphics::canvas::Frame;
 use iced_native::{
-    event, keyboard, layout, mouse, Clipboard, Element, Event, Layout, Length,
-    Point, Rectangle, Shell, Size, Widget,
+    event, keyboard, layout, mouse, overlay, touch, Clipboard, Element, Event,
+    Layout, Length, Point, Rectangle, Shell, Size, Widget,
 };
 
-use crate::core::{ModulationRange, Normal, NormalParam};
-use crate::native::{text_marks, tick_marks};
+use crate::core::{
+    Easing, GestureSummary, KnobAngleRange, ModulationRange, Normal,
+    NormalParam, SmoothedNormal,
+};
+use crate::native::{text_marks, tick_marks, tooltip};
+use crate::style::transition::StatusTransition;
+use crate::style::{knob, Status, StyleFn};
 use crate::IntRange;
 
 static DEFAULT_SIZE: u16 = 30;
@@ -28,11 +36,22 @@ pub struct Knob<'a, Message, Renderer: self::Renderer> {
     on_change: Box<dyn Fn(Normal) -> Message>,
     on_drag_start: Box<dyn Fn() -> Option<Message>>,
     on_drag_end: Box<dyn Fn() -> Option<Message>>,
+    on_right_click: Option<Box<dyn Fn(Point) -> Message>>,
+    on_drag_end_summary: Option<Box<dyn Fn(GestureSummary) -> Message>>,
+    throttle: Option<Duration>,
     scalar: f32,
     wheel_scalar: f32,
+    wheel_steps: Option<u32>,
     modifier_scalar: f32,
     modifier_keys: keyboard::Modifiers,
     bipolar_center: Option<Normal>,
+    angle_range: Option<KnobAngleRange>,
+    modulated_value: Option<Normal>,
+    tooltip_format: Option<Box<dyn Fn(Normal) -> String>>,
+    tooltip_style: tooltip::Style,
+    disabled: bool,
+    touch_padding: f32,
+    overlay_draw: Option<Box<OverlayDraw>>,
     style: Renderer::Style,
     tick_marks: Option<&'a tick_marks::Group>,
     text_marks: Option<&'a text_marks::Group>,
@@ -66,11 +85,22 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
             on_change: Box::new(on_change),
             on_drag_start: Box::new(on_drag_start),
             on_drag_end: Box::new(on_drag_end),
+            on_right_click: None,
+            on_drag_end_summary: None,
+            throttle: None,
             scalar: DEFAULT_SCALAR,
             wheel_scalar: DEFAULT_WHEEL_SCALAR,
+            wheel_steps: None,
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
             modifier_keys: keyboard::Modifiers::CTRL,
             bipolar_center: None,
+            angle_range: None,
+            modulated_value: None,
+            tooltip_format: None,
+            tooltip_style: tooltip::Style::default(),
+            disabled: false,
+            touch_padding: 0.0,
+            overlay_draw: None,
             style: Renderer::Style::default(),
             tick_marks: None,
             text_marks: None,
@@ -88,6 +118,53 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
         self
     }
 
+    /// Replaces the function called when the [`Knob`] is turned with one
+    /// that is called at most once per `interval` while the [`Knob`] is
+    /// being dragged. The final value is always sent when the drag ends.
+    ///
+    /// This is useful to avoid flooding hosts that do expensive work for
+    /// each change.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn on_change_throttled<F>(
+        mut self,
+        interval: Duration,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(Normal) -> Message,
+    {
+        self.on_change = Box::new(on_change);
+        self.throttle = Some(interval);
+        self
+    }
+
+    /// Sets a function that will be called when the user stops changing the
+    /// value of the [`Knob`], with a [`GestureSummary`] of the whole gesture.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`GestureSummary`]: ../../core/gesture_summary/struct.GestureSummary.html
+    pub fn on_drag_end_summary<F>(mut self, on_drag_end_summary: F) -> Self
+    where
+        F: 'static + Fn(GestureSummary) -> Message,
+    {
+        self.on_drag_end_summary = Some(Box::new(on_drag_end_summary));
+        self
+    }
+
+    /// Sets a function that will be called when the [`Knob`] is clicked with
+    /// the right mouse button. It receives the position of the cursor, which
+    /// is useful to open a context menu.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn on_right_click<F>(mut self, on_right_click: F) -> Self
+    where
+        F: 'static + Fn(Point) -> Message,
+    {
+        self.on_right_click = Some(Box::new(on_right_click));
+        self
+    }
+
     /// Sets the style of the [`Knob`].
     ///
     /// [`Knob`]: struct.Knob.html
@@ -96,6 +173,22 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the style of the [`Knob`] from a closure returning the [`Style`]
+    /// of each [`Status`] of the [`Knob`], such as:
+    ///
+    /// `.style_fn(|status| my_style(status, &palette))`
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`Style`]: ../../style/knob/struct.Style.html
+    /// [`Status`]: ../../style/style_fn/enum.Status.html
+    pub fn style_fn<F>(self, style_fn: F) -> Self
+    where
+        F: 'static + Fn(Status) -> knob::Style,
+        StyleFn<F>: Into<Renderer::Style>,
+    {
+        self.style(StyleFn::new(style_fn))
+    }
+
     /// Sets how much the [`Normal`] value will change for the [`Knob`] per `y`
     /// pixel movement of the mouse.
     ///
@@ -122,6 +215,31 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the number of steps the range of the [`Knob`] is divided into
+    /// when using the mouse wheel. Each line scrolled moves the value by
+    /// exactly one step and snaps it to the nearest step.
+    ///
+    /// This overrides `Knob::wheel_scalar()`. Setting this to `0` disables
+    /// the scroll wheel from moving the parameter.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn wheel_steps(mut self, steps: u32) -> Self {
+        self.wheel_steps = Some(steps);
+        self
+    }
+
+    /// Sets the padding in pixels that expands the interactive area of the
+    /// [`Knob`] beyond its drawn bounds, which makes it easier to grab with
+    /// a finger. The [`Knob`] is drawn at the same size.
+    ///
+    /// The default padding is `0.0`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn touch_padding(mut self, touch_padding: f32) -> Self {
+        self.touch_padding = touch_padding;
+        self
+    }
+
     /// Sets the modifier keys of the [`Knob`].
     ///
     /// The default modifier key is `Ctrl`.
@@ -191,15 +309,139 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
     }
 
     /// Sets the value to be considered the center of the [`Knob`]. Only has
-    /// an effect when using [`ArcBipolarStyle`].
+    /// an effect when using [`ArcBipolarStyle`] or a bipolar
+    /// [`ValueArcStyle`].
+    ///
+    /// This also accepts a [`BipolarNormal`], where the center is `0.0`.
     ///
     /// [`Knob`]: struct.Knob.html
     /// [`ArcBipolarStyle`]: ../../style/knob/struct.ArcBipolarStyle.html
-    pub fn bipolar_center(mut self, bipolar_center: Normal) -> Self {
-        self.bipolar_center = Some(bipolar_center);
+    /// [`ValueArcStyle`]: ../../style/knob/struct.ValueArcStyle.html
+    /// [`BipolarNormal`]: ../../core/bipolar_normal/struct.BipolarNormal.html
+    pub fn bipolar_center(mut self, bipolar_center: impl Into<Normal>) -> Self {
+        self.bipolar_center = Some(bipolar_center.into());
+        self
+    }
+
+    /// Sets the [`KnobAngleRange`] of the [`Knob`], overriding the one
+    /// defined by its [`StyleSheet`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`KnobAngleRange`]: ../../core/struct.KnobAngleRange.html
+    /// [`StyleSheet`]: ../../style/knob/trait.StyleSheet.html
+    pub fn angle_range(mut self, angle_range: KnobAngleRange) -> Self {
+        self.angle_range = Some(angle_range);
+        self
+    }
+
+    /// Sets the modulated value to display alongside the value of the
+    /// [`Knob`]. This is useful to show the value of a parameter after
+    /// modulation has been applied by the host. Note your [`StyleSheet`]
+    /// must also implement
+    /// `modulated_value_style(&self) -> Option<ModulatedValueStyle>` for it
+    /// to display (which the default style does).
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`StyleSheet`]: ../../style/knob/trait.StyleSheet.html
+    pub fn modulated_value(mut self, modulated_value: Normal) -> Self {
+        self.modulated_value = Some(modulated_value);
+        self
+    }
+
+    /// Enables animated smoothing of the [`Knob`]. Values set with
+    /// [`State::set_normal`] will then be eased in over the given
+    /// `duration` instead of jumping to their new position. Changes made by
+    /// the user are never smoothed.
+    ///
+    /// The animation only advances when your application is redrawn, so you
+    /// will want to keep requesting redraws (for example with a
+    /// `time::every` subscription) while [`State::is_animating`] returns
+    /// `true`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`State::set_normal`]: struct.State.html#method.set_normal
+    /// [`State::is_animating`]: struct.State.html#method.is_animating
+    pub fn smoothing(self, duration: Duration) -> Self {
+        self.state.smoothing = Some(duration);
+        self
+    }
+
+    /// Cross-fades the colors and the widths of the style of the [`Knob`]
+    /// with the `easing` curve over the given `duration` when it becomes
+    /// hovered, dragged or disabled, instead of switching styles abruptly.
+    ///
+    /// The animation only advances when your application is redrawn, so you
+    /// will want to keep requesting redraws (for example with a
+    /// `time::every` subscription) while [`State::is_animating`] returns
+    /// `true`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`State::is_animating`]: struct.State.html#method.is_animating
+    pub fn style_transition(self, duration: Duration, easing: Easing) -> Self {
+        self.state.style_transition.get_mut().set(duration, easing);
+        self
+    }
+
+    /// Shows a tooltip with the formatted value of the [`Knob`] while it is
+    /// hovered or dragged.
+    ///
+    /// The tooltip is drawn as an overlay, so it isn't clipped by the
+    /// bounds of the [`Knob`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn tooltip_format<F>(mut self, format: F) -> Self
+    where
+        F: 'static + Fn(Normal) -> String,
+    {
+        self.tooltip_format = Some(Box::new(format));
         self
     }
 
+    /// Sets the style of the tooltip of the [`Knob`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn tooltip_style(mut self, style: tooltip::Style) -> Self {
+        self.tooltip_style = style;
+        self
+    }
+
+    /// Sets whether the [`Knob`] is disabled. A disabled [`Knob`] ignores
+    /// all input events and is drawn with the `disabled()` style of its
+    /// [`StyleSheet`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`StyleSheet`]: ../../style/knob/trait.StyleSheet.html
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets a function that draws extra content on top of the built-in
+    /// appearance of the [`Knob`], such as custom pointers or decorations.
+    ///
+    /// The [`Frame`] covers the bounds of the [`Knob`], and the [`KnobInfo`]
+    /// is given relative to its top-left corner.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`KnobInfo`]: struct.KnobInfo.html
+    /// [`Frame`]: https://docs.rs/iced_graphics/0.3.1/iced_graphics/widget/canvas/struct.Frame.html
+    pub fn overlay_draw<F>(mut self, overlay_draw: F) -> Self
+    where
+        F: 'static + Fn(&mut Frame, &KnobInfo),
+    {
+        self.overlay_draw = Some(Box::new(overlay_draw));
+        self
+    }
+
+    fn hit_bounds(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.x - self.touch_padding,
+            y: bounds.y - self.touch_padding,
+            width: bounds.width + (self.touch_padding * 2.0),
+            height: bounds.height + (self.touch_padding * 2.0),
+        }
+    }
+
     fn move_virtual_slider(
         &mut self,
         messages: &mut Shell<'_, Message>,
@@ -209,8 +451,123 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
             normal_delta *= self.modifier_scalar;
         }
 
-        let mut normal = self.state.continuous_normal - normal_delta;
+        let normal = self.state.continuous_normal - normal_delta;
 
+        self.set_normal_from_input(messages, normal);
+    }
+
+    fn on_touch_event(
+        &mut self,
+        touch_event: touch::Event,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        messages: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match touch_event {
+            touch::Event::FingerPressed { id, position } => {
+                if self.state.touches.len() >= 2
+                    || !self.hit_bounds(layout.bounds()).contains(position)
+                {
+                    return event::Status::Ignored;
+                }
+
+                self.state.touches.push((id, position));
+
+                if self.state.touches.len() == 1 {
+                    self.state.prev_drag_y = position.y;
+
+                    self.begin_drag(messages);
+                } else {
+                    self.state.prev_touch_angle = self.state.touch_angle();
+                }
+
+                event::Status::Captured
+            }
+            touch::Event::FingerMoved { id, position } => {
+                let finger = match self
+                    .state
+                    .touches
+                    .iter_mut()
+                    .find(|(finger, _)| *finger == id)
+                {
+                    Some(finger) => finger,
+                    None => return event::Status::Ignored,
+                };
+
+                finger.1 = position;
+
+                if self.state.touches.len() == 1 {
+                    let normal_delta =
+                        (position.y - self.state.prev_drag_y) * self.scalar;
+
+                    self.state.prev_drag_y = position.y;
+
+                    self.move_virtual_slider(messages, normal_delta);
+                } else {
+                    let angle = self.state.touch_angle();
+
+                    let mut angle_delta = angle - self.state.prev_touch_angle;
+                    if angle_delta > std::f32::consts::PI {
+                        angle_delta -= 2.0 * std::f32::consts::PI;
+                    } else if angle_delta < -std::f32::consts::PI {
+                        angle_delta += 2.0 * std::f32::consts::PI;
+                    }
+
+                    self.state.prev_touch_angle = angle;
+
+                    let angle_range = match &self.angle_range {
+                        Some(angle_range) => angle_range.clone(),
+                        None => renderer.angle_range(&self.style),
+                    };
+                    let angle_span = angle_range.max() - angle_range.min();
+
+                    if angle_span > 0.0 {
+                        self.move_virtual_slider(
+                            messages,
+                            -angle_delta / angle_span,
+                        );
+                    }
+                }
+
+                event::Status::Captured
+            }
+            touch::Event::FingerLifted { id, .. }
+            | touch::Event::FingerLost { id, .. } => {
+                if !self.state.touches.iter().any(|(finger, _)| *finger == id) {
+                    return event::Status::Ignored;
+                }
+
+                self.state.touches.retain(|(finger, _)| *finger != id);
+
+                if let Some((_, position)) = self.state.touches.first() {
+                    self.state.prev_drag_y = position.y;
+                } else {
+                    self.end_drag(messages);
+                }
+
+                event::Status::Captured
+            }
+        }
+    }
+
+    fn move_wheel_step(
+        &mut self,
+        messages: &mut Shell<'_, Message>,
+        steps: u32,
+        lines: f32,
+    ) {
+        let steps = steps as f32;
+        let step = (self.state.normal_param.value.as_f32() * steps).round()
+            + lines.signum();
+
+        self.set_normal_from_input(messages, step / steps);
+    }
+
+    fn set_normal_from_input(
+        &mut self,
+        messages: &mut Shell<'_, Message>,
+        mut normal: f32,
+    ) {
         if normal < 0.0 {
             normal = 0.0;
         } else if normal > 1.0 {
@@ -218,11 +575,100 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
         }
 
         self.state.continuous_normal = normal;
+        self.state.stop_smoothing();
+
+        if normal != self.state.normal_param.value.as_f32() {
+            self.state.gesture_moved = true;
+        }
+
+        self.state.normal_param.set_value(normal.into());
+
+        if let Some(interval) = self.throttle {
+            if self.state.is_dragging {
+                let now = Instant::now();
+
+                if let Some(last_change) = self.state.last_change {
+                    if now.saturating_duration_since(last_change) < interval {
+                        self.state.is_change_pending = true;
+                        return;
+                    }
+                }
 
-        self.state.normal_param.value = normal.into();
+                self.state.last_change = Some(now);
+            }
+        }
+
+        self.state.is_change_pending = false;
 
         messages.publish((self.on_change)(self.state.normal_param.value));
     }
+
+    fn begin_drag(&mut self, messages: &mut Shell<'_, Message>) {
+        self.state.is_dragging = true;
+        self.state.stop_smoothing();
+        self.state.gesture_start =
+            Some((self.state.normal_param.value, Instant::now()));
+        self.state.gesture_moved = false;
+
+        if let Some(message) = (self.on_drag_start)() {
+            messages.publish(message);
+        }
+    }
+
+    fn end_drag(&mut self, messages: &mut Shell<'_, Message>) {
+        self.state.is_dragging = false;
+        self.state.continuous_normal = self.state.normal_param.value.as_f32();
+        self.state.last_change = None;
+
+        if self.state.is_change_pending {
+            self.state.is_change_pending = false;
+
+            messages.publish((self.on_change)(self.state.normal_param.value));
+        }
+
+        if let Some(message) = (self.on_drag_end)() {
+            messages.publish(message);
+        }
+
+        if let Some((start, start_instant)) = self.state.gesture_start.take() {
+            if let Some(on_drag_end_summary) = &self.on_drag_end_summary {
+                messages.publish(on_drag_end_summary(GestureSummary {
+                    start,
+                    end: self.state.normal_param.value,
+                    moved: self.state.gesture_moved,
+                    duration: start_instant.elapsed(),
+                }));
+            }
+        }
+    }
+}
+
+/// A function drawing extra content on top of a [`Knob`].
+///
+/// [`Knob`]: struct.Knob.html
+pub type OverlayDraw = dyn Fn(&mut Frame, &KnobInfo);
+
+/// The geometry of a [`Knob`] as it is drawn.
+///
+/// Angles are in radians, following the convention of canvas arcs.
+///
+/// [`Knob`]: struct.Knob.html
+#[derive(Debug, Copy, Clone)]
+pub struct KnobInfo {
+    /// The bounds of the knob
+    pub bounds: Rectangle,
+    /// The angle of the minimum value
+    pub start_angle: f32,
+    /// The angle between the minimum and maximum values
+    pub angle_span: f32,
+    /// The radius of the knob
+    pub radius: f32,
+    /// The displayed value
+    pub value: Normal,
+    /// The bipolar center value, if any
+    pub bipolar_center: Option<Normal>,
+    /// The angle of the displayed value
+    pub value_angle: f32,
 }
 
 /// The local state of a [`Knob`].
@@ -235,10 +681,21 @@ pub struct State {
     /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
     pub normal_param: NormalParam,
     is_dragging: bool,
+    gesture_start: Option<(Normal, Instant)>,
+    gesture_moved: bool,
+    is_hovered: bool,
     prev_drag_y: f32,
+    touches: Vec<(touch::Finger, Point)>,
+    prev_touch_angle: f32,
     continuous_normal: f32,
     pressed_modifiers: keyboard::Modifiers,
     last_click: Option<mouse::Click>,
+    last_change: Option<Instant>,
+    is_change_pending: bool,
+    smoothing: Option<Duration>,
+    smoothed_normal: Cell<SmoothedNormal>,
+    smoothing_tick: Cell<Instant>,
+    style_transition: Cell<StatusTransition>,
     tick_marks_cache: crate::graphics::tick_marks::PrimitiveCache,
     text_marks_cache: crate::graphics::text_marks::PrimitiveCache,
 }
@@ -255,18 +712,42 @@ impl State {
         Self {
             normal_param,
             is_dragging: false,
+            gesture_start: None,
+            gesture_moved: false,
+            is_hovered: false,
             prev_drag_y: 0.0,
+            touches: Vec::with_capacity(2),
+            prev_touch_angle: 0.0,
             continuous_normal: normal_param.value.as_f32(),
             pressed_modifiers: Default::default(),
             last_click: None,
+            last_change: None,
+            is_change_pending: false,
+            smoothing: None,
+            smoothed_normal: Cell::new(SmoothedNormal::new(
+                normal_param.value,
+                Duration::from_secs(0),
+            )),
+            smoothing_tick: Cell::new(Instant::now()),
+            style_transition: Cell::default(),
             tick_marks_cache: Default::default(),
             text_marks_cache: Default::default(),
         }
     }
 
     /// Set the normalized value of the [`Knob`].
+    ///
+    /// If smoothing is enabled, the displayed value will ease towards the
+    /// new value.
     pub fn set_normal(&mut self, normal: Normal) {
-        self.normal_param.value = normal;
+        if let (Some(duration), false) = (self.smoothing, self.is_dragging) {
+            let mut smoothed_normal = self.tick_smoothing();
+            smoothed_normal.set_duration(duration);
+            smoothed_normal.set_target(normal);
+            self.smoothed_normal.set(smoothed_normal);
+        }
+
+        self.normal_param.set_value(normal);
         self.continuous_normal = normal.into();
     }
 
@@ -300,7 +781,41 @@ impl State {
     ///
     /// ```
     pub fn snap_visible_to(&mut self, range: &IntRange) {
-        self.normal_param.value = range.snapped(self.normal_param.value);
+        self.normal_param
+            .set_value(range.snapped(self.normal_param.value));
+    }
+
+    /// Sets the cache of the tick marks of the [`Knob`].
+    ///
+    /// Give clones of the same cache to several widgets drawing the same
+    /// tick marks, so they are only built once for all of them.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn set_tick_marks_cache(
+        &mut self,
+        cache: crate::graphics::tick_marks::PrimitiveCache,
+    ) {
+        self.tick_marks_cache = cache;
+    }
+
+    /// Sets the cache of the text marks of the [`Knob`].
+    ///
+    /// Give clones of the same cache to several widgets drawing the same
+    /// text marks, so they are only built once for all of them.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn set_text_marks_cache(
+        &mut self,
+        cache: crate::graphics::text_marks::PrimitiveCache,
+    ) {
+        self.text_marks_cache = cache;
+    }
+
+    fn touch_angle(&self) -> f32 {
+        match self.touches.as_slice() {
+            [(_, a), (_, b)] => (b.y - a.y).atan2(b.x - a.x),
+            _ => 0.0,
+        }
     }
 
     /// Is the [`Knob`] currently in the dragging state?
@@ -309,6 +824,56 @@ impl State {
     pub fn is_dragging(&self) -> bool {
         self.is_dragging
     }
+
+    /// Is the displayed value of the [`Knob`] currently easing towards its
+    /// value, or is its style cross-fading between two states?
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn is_animating(&self) -> bool {
+        self.tick_smoothing().is_smoothing()
+            || self.style_transition.get().is_animating()
+    }
+
+    fn update_style_transition(&self, status: Status) -> Option<(Status, f32)> {
+        let mut style_transition = self.style_transition.get();
+        let progress = style_transition.update(status, Instant::now());
+        self.style_transition.set(style_transition);
+
+        progress
+    }
+
+    /// Get the normalized value currently displayed by the [`Knob`]. This
+    /// only differs from [`normal`] while the [`Knob`] is animating.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`normal`]: #method.normal
+    pub fn displayed_normal(&self) -> Normal {
+        self.tick_smoothing().current()
+    }
+
+    /// Advances the smoothing of the displayed value up to now.
+    fn tick_smoothing(&self) -> SmoothedNormal {
+        let now = Instant::now();
+        let elapsed =
+            now.saturating_duration_since(self.smoothing_tick.replace(now));
+
+        let mut smoothed_normal = self.smoothed_normal.get();
+
+        if smoothed_normal.is_smoothing() {
+            let _ = smoothed_normal.tick(elapsed);
+        } else {
+            smoothed_normal.set_immediate(self.normal_param.value);
+        }
+
+        self.smoothed_normal.set(smoothed_normal);
+
+        smoothed_normal
+    }
+
+    fn stop_smoothing(&mut self) {
+        let value = self.normal_param.value;
+        self.smoothed_normal.get_mut().set_immediate(value);
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -341,13 +906,28 @@ where
         event: Event,
         layout: Layout<'_>,
         cursor_position: Point,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         messages: &mut Shell<'_, Message>,
     ) -> event::Status {
+        if self.disabled {
+            self.state.is_hovered = false;
+            self.state.touches.clear();
+
+            if self.state.is_dragging {
+                self.end_drag(messages);
+            }
+
+            return event::Status::Ignored;
+        }
+
         match event {
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::CursorMoved { .. } => {
+                    self.state.is_hovered = self
+                        .hit_bounds(layout.bounds())
+                        .contains(cursor_position);
+
                     if self.state.is_dragging {
                         let normal_delta = (cursor_position.y
                             - self.state.prev_drag_y)
@@ -361,11 +941,19 @@ where
                     }
                 }
                 mouse::Event::WheelScrolled { delta } => {
-                    if self.wheel_scalar == 0.0 {
+                    let wheel_disabled = match self.wheel_steps {
+                        Some(steps) => steps == 0,
+                        None => self.wheel_scalar == 0.0,
+                    };
+
+                    if wheel_disabled {
                         return event::Status::Ignored;
                     }
 
-                    if layout.bounds().contains(cursor_position) {
+                    if self
+                        .hit_bounds(layout.bounds())
+                        .contains(cursor_position)
+                    {
                         let lines = match delta {
                             iced_native::mouse::ScrollDelta::Lines {
                                 y,
@@ -386,16 +974,26 @@ where
                         };
 
                         if lines != 0.0 {
-                            let normal_delta = -lines * self.wheel_scalar;
+                            if let Some(steps) = self.wheel_steps {
+                                self.move_wheel_step(messages, steps, lines);
+                            } else {
+                                let normal_delta = -lines * self.wheel_scalar;
 
-                            self.move_virtual_slider(messages, normal_delta);
+                                self.move_virtual_slider(
+                                    messages,
+                                    normal_delta,
+                                );
+                            }
 
                             return event::Status::Captured;
                         }
                     }
                 }
                 mouse::Event::ButtonPressed(mouse::Button::Left) => {
-                    if layout.bounds().contains(cursor_position) {
+                    if self
+                        .hit_bounds(layout.bounds())
+                        .contains(cursor_position)
+                    {
                         let click = mouse::Click::new(
                             cursor_position,
                             self.state.last_click,
@@ -403,22 +1001,19 @@ where
 
                         match click.kind() {
                             mouse::click::Kind::Single => {
-                                self.state.is_dragging = true;
                                 self.state.prev_drag_y = cursor_position.y;
 
-                                if let Some(message) = (self.on_drag_start)() {
-                                    messages.publish(message);
-                                }
+                                self.begin_drag(messages);
                             }
                             _ => {
-                                self.state.is_dragging = false;
-
-                                self.state.normal_param.value =
-                                    self.state.normal_param.default;
+                                // Reset to the default value in a gesture of
+                                // its own, like the keyboard edits.
+                                let default =
+                                    self.state.normal_param.default.as_f32();
 
-                                messages.publish((self.on_change)(
-                                    self.state.normal_param.value,
-                                ));
+                                self.begin_drag(messages);
+                                self.set_normal_from_input(messages, default);
+                                self.end_drag(messages);
                             }
                         }
 
@@ -427,19 +1022,35 @@ where
                         return event::Status::Captured;
                     }
                 }
-                mouse::Event::ButtonReleased(mouse::Button::Left) => {
-                    self.state.is_dragging = false;
-                    self.state.continuous_normal =
-                        self.state.normal_param.value.as_f32();
+                mouse::Event::ButtonPressed(mouse::Button::Right) => {
+                    if let Some(on_right_click) = &self.on_right_click {
+                        if self
+                            .hit_bounds(layout.bounds())
+                            .contains(cursor_position)
+                        {
+                            messages.publish(on_right_click(cursor_position));
 
-                    if let Some(message) = (self.on_drag_end)() {
-                        messages.publish(message);
+                            return event::Status::Captured;
+                        }
+                    }
+                }
+                mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                    if self.state.is_dragging {
+                        self.end_drag(messages);
                     }
 
                     return event::Status::Captured;
                 }
                 _ => {}
             },
+            Event::Touch(touch_event) => {
+                return self.on_touch_event(
+                    touch_event,
+                    layout,
+                    renderer,
+                    messages,
+                );
+            }
             Event::Keyboard(keyboard_event) => match keyboard_event {
                 keyboard::Event::KeyPressed { modifiers, .. } => {
                     self.state.pressed_modifiers = modifiers;
@@ -472,21 +1083,125 @@ where
         cursor_position: Point,
         _viewport: &Rectangle,
     ) {
+        let is_hovered =
+            self.hit_bounds(layout.bounds()).contains(cursor_position);
+
+        let status = if self.disabled {
+            Status::Disabled
+        } else if self.state.is_dragging {
+            Status::Dragging
+        } else if is_hovered {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
         renderer.draw(
-            layout.bounds(),
-            cursor_position,
-            self.state.normal_param.value,
-            self.bipolar_center,
-            self.state.is_dragging,
-            self.mod_range_1,
-            self.mod_range_2,
-            self.tick_marks,
-            self.text_marks,
+            DrawInfo {
+                bounds: layout.bounds(),
+                is_hovered,
+                normal: self.state.displayed_normal(),
+                bipolar_center: self.bipolar_center,
+                angle_range: self.angle_range.as_ref(),
+                modulated_value: self.modulated_value,
+                overlay_draw: self.overlay_draw.as_deref(),
+                is_disabled: self.disabled,
+                is_dragging: self.state.is_dragging,
+                mod_range_1: self.mod_range_1,
+                mod_range_2: self.mod_range_2,
+                tick_marks: self.tick_marks,
+                text_marks: self.text_marks,
+                style_transition: self.state.update_style_transition(status),
+                tick_marks_cache: &self.state.tick_marks_cache,
+                text_marks_cache: &self.state.text_marks_cache,
+            },
             &self.style,
-            &self.state.tick_marks_cache,
-            &self.state.text_marks_cache,
         )
     }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if self.disabled || !(self.state.is_dragging || self.state.is_hovered) {
+            return None;
+        }
+
+        self.tooltip_format.as_ref().map(|format| {
+            tooltip::Tooltip::new(
+                format(self.state.normal_param.value),
+                layout.bounds(),
+                self.tooltip_style,
+            )
+            .overlay()
+        })
+    }
+}
+
+/// The values a [`Knob`] is drawn with, passed to the
+/// [`Renderer`] on every frame.
+///
+/// [`Knob`]: struct.Knob.html
+/// [`Renderer`]: trait.Renderer.html
+#[derive(Copy, Clone)]
+pub struct DrawInfo<'a> {
+    /// The bounds of the knob
+    pub bounds: Rectangle,
+    /// Whether the cursor is over the knob, including its touch padding
+    pub is_hovered: bool,
+    /// The displayed normal of the knob
+    pub normal: Normal,
+    /// A custom bipolar center value, if any
+    pub bipolar_center: Option<Normal>,
+    /// An angle range overriding the one of the style, if any
+    pub angle_range: Option<&'a KnobAngleRange>,
+    /// The modulated value of the knob, if any
+    pub modulated_value: Option<Normal>,
+    /// A function drawing on top of the knob, if any
+    pub overlay_draw: Option<&'a OverlayDraw>,
+    /// Whether the knob is disabled
+    pub is_disabled: bool,
+    /// Whether the knob is being dragged
+    pub is_dragging: bool,
+    /// The first modulation range to display, if any
+    pub mod_range_1: Option<&'a ModulationRange>,
+    /// The second modulation range to display, if any
+    pub mod_range_2: Option<&'a ModulationRange>,
+    /// The tick marks to display, if any
+    pub tick_marks: Option<&'a tick_marks::Group>,
+    /// The text marks to display, if any
+    pub text_marks: Option<&'a text_marks::Group>,
+    /// The status the style is cross-fading from and the eased
+    /// progress of the cross-fade, if any
+    pub style_transition: Option<(Status, f32)>,
+    /// The primitive cache of the tick marks
+    pub tick_marks_cache: &'a crate::tick_marks::PrimitiveCache,
+    /// The primitive cache of the text marks
+    pub text_marks_cache: &'a crate::text_marks::PrimitiveCache,
+}
+
+impl Debug for DrawInfo<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DrawInfo")
+            .field("bounds", &self.bounds)
+            .field("is_hovered", &self.is_hovered)
+            .field("normal", &self.normal)
+            .field("bipolar_center", &self.bipolar_center)
+            .field("angle_range", &self.angle_range)
+            .field("modulated_value", &self.modulated_value)
+            .field("overlay_draw", &self.overlay_draw.is_some())
+            .field("is_disabled", &self.is_disabled)
+            .field("is_dragging", &self.is_dragging)
+            .field("mod_range_1", &self.mod_range_1)
+            .field("mod_range_2", &self.mod_range_2)
+            .field("tick_marks", &self.tick_marks)
+            .field("text_marks", &self.text_marks)
+            .field("style_transition", &self.style_transition)
+            .field("tick_marks_cache", &self.tick_marks_cache)
+            .field("text_marks_cache", &self.text_marks_cache)
+            .finish()
+    }
 }
 
 /// The renderer of a [`Knob`].
@@ -495,38 +1210,22 @@ where
 /// able to use a [`Knob`] in your user interface.
 ///
 /// [`Knob`]: struct.Knob.html
-pub trait Renderer: iced_native::Renderer {
+pub trait Renderer: iced_native::Renderer + tooltip::Renderer {
     /// The style supported by this renderer.
     type Style: Default;
 
-    /// Draws a [`Knob`].
+    /// Returns the [`KnobAngleRange`] of the `style`, used when the [`Knob`]
+    /// doesn't override it.
     ///
-    /// It receives:
-    ///   * the bounds of the [`Knob`]
-    ///   * the current cursor position
-    ///   * the current normal of the [`Knob`]
-    ///   * optionally, a custom bipolar center value
-    ///   * whether the knob is currently being dragged
-    ///   * any tick marks to display
-    ///   * any text marks to display
-    ///   * the style of the [`Knob`]
+    /// [`KnobAngleRange`]: ../../core/struct.KnobAngleRange.html
+    /// [`Knob`]: struct.Knob.html
+    fn angle_range(&self, style: &Self::Style) -> KnobAngleRange;
+
+    /// Draws a [`Knob`] from its [`DrawInfo`] and its style.
     ///
     /// [`Knob`]: struct.Knob.html
-    fn draw(
-        &mut self,
-        bounds: Rectangle,
-        cursor_position: Point,
-        normal: Normal,
-        bipolar_center: Option<Normal>,
-        is_dragging: bool,
-        mod_range_1: Option<&ModulationRange>,
-        mod_range_2: Option<&ModulationRange>,
-        tick_marks: Option<&tick_marks::Group>,
-        text_marks: Option<&text_marks::Group>,
-        style: &Self::Style,
-        tick_marks_cache: &crate::tick_marks::PrimitiveCache,
-        text_marks_cache: &crate::text_marks::PrimitiveCache,
-    );
+    /// [`DrawInfo`]: struct.DrawInfo.html
+    fn draw(&mut self, info: DrawInfo<'_>, style: &Self::Style);
 }
 
 impl<'a, Message, Renderer> From<Knob<'a, Message, Renderer>>
@@ -541,3 +1240,95 @@ where
         Element::new(knob)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{clipboard, renderer::Null};
+
+    use super::*;
+    use crate::core::{EditHistory, Gesture};
+
+    impl tooltip::Renderer for Null {
+        fn measure_tooltip(
+            &self,
+            _text: &str,
+            _style: &tooltip::Style,
+        ) -> Size {
+            Size::ZERO
+        }
+
+        fn draw_tooltip(
+            &mut self,
+            _bounds: Rectangle,
+            _text: &str,
+            _style: &tooltip::Style,
+        ) {
+        }
+    }
+
+    impl Renderer for Null {
+        type Style = ();
+
+        fn angle_range(&self, _style: &Self::Style) -> KnobAngleRange {
+            KnobAngleRange::default()
+        }
+
+        fn draw(&mut self, _info: DrawInfo<'_>, _style: &Self::Style) {}
+    }
+
+    #[test]
+    fn double_click_reset_is_one_undo_step() {
+        let mut state =
+            State::new(NormalParam::new(0.8.into(), Normal::center()));
+        let (on_change, on_drag_start, on_drag_end) =
+            Gesture::handlers(0, |gesture| gesture);
+        let mut knob: Knob<'_, Gesture<usize>, Null> =
+            Knob::new(&mut state, on_change, on_drag_start, on_drag_end);
+
+        let node = layout::Node::new(Size::new(30.0, 30.0));
+        let cursor_position = Point::new(15.0, 15.0);
+        let mut gestures = Vec::new();
+
+        for event in [
+            mouse::Event::ButtonPressed(mouse::Button::Left),
+            mouse::Event::ButtonReleased(mouse::Button::Left),
+            mouse::Event::ButtonPressed(mouse::Button::Left),
+            mouse::Event::ButtonReleased(mouse::Button::Left),
+        ] {
+            let _ = knob.on_event(
+                Event::Mouse(event),
+                Layout::new(&node),
+                cursor_position,
+                &Null::new(),
+                &mut clipboard::Null,
+                &mut Shell::new(&mut gestures),
+            );
+        }
+
+        // The reset is bracketed like a drag, so hosts see a whole gesture.
+        assert_eq!(
+            &gestures[2..],
+            &[
+                Gesture::Begin(0),
+                Gesture::Change(0, Normal::center()),
+                Gesture::End(0),
+            ]
+        );
+
+        let mut history = EditHistory::new();
+        let mut value = Normal::from(0.8);
+
+        for gesture in &gestures {
+            history.record_gesture(gesture, value);
+
+            if let Gesture::Change(_, normal) = gesture {
+                value = *normal;
+            }
+        }
+
+        let edit = history.undo().unwrap();
+        assert_eq!(edit.old, 0.8.into());
+        assert_eq!(edit.new, Normal::center());
+        assert!(!history.can_undo());
+    }
+}
@@ -3,9 +3,12 @@
 pub mod h_slider;
 pub mod knob;
 pub mod mod_range_input;
+pub mod multi_xy_pad;
+pub mod param_kit;
 pub mod ramp;
 pub mod text_marks;
 pub mod tick_marks;
+pub mod tooltip;
 pub mod v_slider;
 pub mod xy_pad;
 
@@ -16,6 +19,10 @@ pub use knob::Knob;
 #[doc(no_inline)]
 pub use mod_range_input::ModRangeInput;
 #[doc(no_inline)]
+pub use multi_xy_pad::MultiXYPad;
+#[doc(no_inline)]
+pub use param_kit::ParamKit;
+#[doc(no_inline)]
 pub use ramp::Ramp;
 #[doc(no_inline)]
 pub use v_slider::VSlider;
@@ -147,7 +147,7 @@ impl<'a, Message, Renderer: self::Renderer>
 
         self.state.continuous_normal = normal;
 
-        self.state.normal_param.value = normal.into();
+        self.state.normal_param.set_value(normal.into());
 
         messages.publish((self.on_change)(self.state.normal_param.value));
     }
@@ -187,7 +187,7 @@ impl State {
 
     /// Set the normalized value of the [`ModRangeInput`].
     pub fn set_normal(&mut self, normal: Normal) {
-        self.normal_param.value = normal;
+        self.normal_param.set_value(normal);
         self.continuous_normal = normal.into();
     }
 
@@ -221,7 +221,8 @@ impl State {
     ///
     /// ```
     pub fn snap_visible_to(&mut self, range: &IntRange) {
-        self.normal_param.value = range.snapped(self.normal_param.value);
+        self.normal_param
+            .set_value(range.snapped(self.normal_param.value));
     }
 
     /// Is the [`ModRangeInput`] currently in the dragging state?
@@ -330,8 +331,9 @@ where
                             _ => {
                                 self.state.is_dragging = false;
 
-                                self.state.normal_param.value =
-                                    self.state.normal_param.default;
+                                self.state
+                                    .normal_param
+                                    .set_value(self.state.normal_param.default);
 
                                 messages.publish((self.on_change)(
                                     self.state.normal_param.value,
@@ -0,0 +1,517 @@
+//! Display an interactive 2D pad with several draggable nodes, each
+//! controlling its own pair of [`NormalParam`] parameters. One in the `x`
+//! coordinate and one in the `y` coordinate.
+//!
+//! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
+
+use std::fmt::Debug;
+
+use iced_native::{
+    event, keyboard, layout, mouse, Clipboard, Element, Event, Layout, Length,
+    Point, Rectangle, Shell, Size, Widget,
+};
+
+use crate::core::{Normal, NormalParam};
+
+static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+
+/// A 2D pad GUI widget with several draggable nodes, each controlling its
+/// own pair of [`NormalParam`] parameters, such as the sources of a vector
+/// synthesizer or the bands of an equalizer.
+///
+/// Clicking the pad grabs the nearest node. A [`MultiXYPad`] will try to
+/// fill the space of its container while keeping a square aspect ratio.
+///
+/// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+/// [`MultiXYPad`]: struct.MultiXYPad.html
+#[allow(missing_debug_implementations)]
+pub struct MultiXYPad<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    on_change: Box<dyn Fn(usize, Normal, Normal) -> Message>,
+    modifier_scalar: f32,
+    modifier_keys: keyboard::Modifiers,
+    size: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer> MultiXYPad<'a, Message, Renderer> {
+    /// Creates a new [`MultiXYPad`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`MultiXYPad`]
+    ///   * a function that will be called with the index of the node and
+    ///     its new normals when a node of the [`MultiXYPad`] is dragged.
+    ///
+    /// [`State`]: struct.State.html
+    /// [`MultiXYPad`]: struct.MultiXYPad.html
+    pub fn new<F>(state: &'a mut State, on_change: F) -> Self
+    where
+        F: 'static + Fn(usize, Normal, Normal) -> Message,
+    {
+        MultiXYPad {
+            state,
+            on_change: Box::new(on_change),
+            modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+            modifier_keys: keyboard::Modifiers::CTRL,
+            size: Length::Fill,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the size of the [`MultiXYPad`].
+    ///
+    /// [`MultiXYPad`]: struct.MultiXYPad.html
+    pub fn size(mut self, size: Length) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the style of the [`MultiXYPad`].
+    ///
+    /// [`MultiXYPad`]: struct.MultiXYPad.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the modifier keys of the [`MultiXYPad`].
+    ///
+    /// The default modifier key is `Ctrl`.
+    ///
+    /// [`MultiXYPad`]: struct.MultiXYPad.html
+    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
+        self.modifier_keys = modifier_keys;
+        self
+    }
+
+    /// Sets the scalar to use when the user drags a node while holding down
+    /// the modifier key.
+    ///
+    /// For example, a scalar of `0.5` will cause the node to move half a
+    /// pixel for every pixel the mouse moves.
+    ///
+    /// The default scalar is `0.02`, and the default modifier key is `Ctrl`.
+    ///
+    /// [`MultiXYPad`]: struct.MultiXYPad.html
+    pub fn modifier_scalar(mut self, scalar: f32) -> Self {
+        self.modifier_scalar = scalar;
+        self
+    }
+
+    fn set_node_from_input(
+        &mut self,
+        messages: &mut Shell<'_, Message>,
+        index: usize,
+        normal_x: f32,
+        normal_y: f32,
+    ) {
+        if let Some(node) = self.state.nodes.get_mut(index) {
+            node.continuous_normal_x = normal_x.clamp(0.0, 1.0);
+            node.continuous_normal_y = normal_y.clamp(0.0, 1.0);
+
+            node.normal_param_x
+                .set_value(node.continuous_normal_x.into());
+            node.normal_param_y
+                .set_value(node.continuous_normal_y.into());
+
+            messages.publish((self.on_change)(
+                index,
+                node.normal_param_x.value,
+                node.normal_param_y.value,
+            ));
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Node {
+    normal_param_x: NormalParam,
+    normal_param_y: NormalParam,
+    continuous_normal_x: f32,
+    continuous_normal_y: f32,
+}
+
+impl Node {
+    fn new(normal_param_x: NormalParam, normal_param_y: NormalParam) -> Self {
+        Self {
+            normal_param_x,
+            normal_param_y,
+            continuous_normal_x: normal_param_x.value.as_f32(),
+            continuous_normal_y: normal_param_y.value.as_f32(),
+        }
+    }
+
+    fn normals(&self) -> (Normal, Normal) {
+        (self.normal_param_x.value, self.normal_param_y.value)
+    }
+}
+
+/// The local state of a [`MultiXYPad`].
+///
+/// [`MultiXYPad`]: struct.MultiXYPad.html
+#[derive(Debug, Clone)]
+pub struct State {
+    nodes: Vec<Node>,
+    dragging_node: Option<usize>,
+    prev_drag_x: f32,
+    prev_drag_y: f32,
+    pressed_modifiers: keyboard::Modifiers,
+    last_click: Option<mouse::Click>,
+}
+
+impl State {
+    /// Creates a new [`MultiXYPad`] state.
+    ///
+    /// It expects:
+    /// * a pair of [`NormalParam`] for each node, assigned to its x and y
+    ///   axes
+    ///
+    /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+    /// [`MultiXYPad`]: struct.MultiXYPad.html
+    pub fn new(nodes: Vec<(NormalParam, NormalParam)>) -> Self {
+        Self {
+            nodes: nodes
+                .into_iter()
+                .map(|(normal_param_x, normal_param_y)| {
+                    Node::new(normal_param_x, normal_param_y)
+                })
+                .collect(),
+            dragging_node: None,
+            prev_drag_x: 0.0,
+            prev_drag_y: 0.0,
+            pressed_modifiers: Default::default(),
+            last_click: None,
+        }
+    }
+
+    /// Returns the number of nodes of the [`MultiXYPad`].
+    ///
+    /// [`MultiXYPad`]: struct.MultiXYPad.html
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if the [`MultiXYPad`] has no nodes.
+    ///
+    /// [`MultiXYPad`]: struct.MultiXYPad.html
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Set the normalized values of the x and y axes of the node at `index`
+    /// of the [`MultiXYPad`].
+    ///
+    /// [`MultiXYPad`]: struct.MultiXYPad.html
+    pub fn set_normals(
+        &mut self,
+        index: usize,
+        normal_x: Normal,
+        normal_y: Normal,
+    ) {
+        if let Some(node) = self.nodes.get_mut(index) {
+            node.normal_param_x.set_value(normal_x);
+            node.normal_param_y.set_value(normal_y);
+            node.continuous_normal_x = normal_x.into();
+            node.continuous_normal_y = normal_y.into();
+        }
+    }
+
+    /// Get the normalized values of the x and y axes of the node at `index`
+    /// of the [`MultiXYPad`].
+    ///
+    /// [`MultiXYPad`]: struct.MultiXYPad.html
+    pub fn normals(&self, index: usize) -> Option<(Normal, Normal)> {
+        self.nodes.get(index).map(Node::normals)
+    }
+
+    /// Set the normalized default values of the x and y axes of the node at
+    /// `index` of the [`MultiXYPad`].
+    ///
+    /// [`MultiXYPad`]: struct.MultiXYPad.html
+    pub fn set_defaults(
+        &mut self,
+        index: usize,
+        normal_x: Normal,
+        normal_y: Normal,
+    ) {
+        if let Some(node) = self.nodes.get_mut(index) {
+            node.normal_param_x.default = normal_x;
+            node.normal_param_y.default = normal_y;
+        }
+    }
+
+    /// Get the normalized default values of the x and y axes of the node at
+    /// `index` of the [`MultiXYPad`].
+    ///
+    /// [`MultiXYPad`]: struct.MultiXYPad.html
+    pub fn defaults(&self, index: usize) -> Option<(Normal, Normal)> {
+        self.nodes.get(index).map(|node| {
+            (node.normal_param_x.default, node.normal_param_y.default)
+        })
+    }
+
+    /// The index of the node of the [`MultiXYPad`] that is currently being
+    /// dragged, if any.
+    ///
+    /// [`MultiXYPad`]: struct.MultiXYPad.html
+    pub fn dragging_node(&self) -> Option<usize> {
+        self.dragging_node
+    }
+
+    /// Is a node of the [`MultiXYPad`] currently in the dragging state?
+    ///
+    /// [`MultiXYPad`]: struct.MultiXYPad.html
+    pub fn is_dragging(&self) -> bool {
+        self.dragging_node.is_some()
+    }
+
+    fn nearest_node(&self, bounds: &Rectangle, point: Point) -> Option<usize> {
+        self.nodes
+            .iter()
+            .map(|node| {
+                let x =
+                    bounds.x + node.normal_param_x.value.scale(bounds.width);
+                let y = bounds.y
+                    + node.normal_param_y.value.scale_inv(bounds.height);
+
+                (x - point.x).powi(2) + (y - point.y).powi(2)
+            })
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+            .map(|(index, _)| index)
+    }
+}
+
+fn pad_bounds(bounds: Rectangle) -> Rectangle {
+    let size = bounds.width.min(bounds.height);
+
+    Rectangle {
+        width: size,
+        height: size,
+        ..bounds
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for MultiXYPad<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.size
+    }
+
+    fn height(&self) -> Length {
+        self.size
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.size).height(self.size);
+
+        let mut size = limits.resolve(Size::ZERO);
+
+        if size.width <= size.height {
+            size.height = size.width;
+        } else {
+            size.width = size.height;
+        }
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse_event) => match mouse_event {
+                mouse::Event::CursorMoved { .. } => {
+                    if let Some(index) = self.state.dragging_node {
+                        let bounds = pad_bounds(layout.bounds());
+
+                        if bounds.width != 0.0 {
+                            let mut movement_x = (cursor_position.x
+                                - self.state.prev_drag_x)
+                                / bounds.width;
+
+                            let mut movement_y = (cursor_position.y
+                                - self.state.prev_drag_y)
+                                / bounds.height;
+
+                            if self
+                                .state
+                                .pressed_modifiers
+                                .contains(self.modifier_keys)
+                            {
+                                movement_x *= self.modifier_scalar;
+                                movement_y *= self.modifier_scalar;
+                            }
+
+                            self.state.prev_drag_x = cursor_position.x;
+                            self.state.prev_drag_y = cursor_position.y;
+
+                            let node = self.state.nodes[index];
+
+                            self.set_node_from_input(
+                                messages,
+                                index,
+                                node.continuous_normal_x + movement_x,
+                                node.continuous_normal_y - movement_y,
+                            );
+
+                            return event::Status::Captured;
+                        }
+                    }
+                }
+                mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                    let bounds = pad_bounds(layout.bounds());
+
+                    if bounds.contains(cursor_position) {
+                        let index = match self
+                            .state
+                            .nearest_node(&bounds, cursor_position)
+                        {
+                            Some(index) => index,
+                            None => return event::Status::Ignored,
+                        };
+
+                        let click = mouse::Click::new(
+                            cursor_position,
+                            self.state.last_click,
+                        );
+
+                        match click.kind() {
+                            mouse::click::Kind::Single => {
+                                self.state.dragging_node = Some(index);
+                                self.state.prev_drag_x = cursor_position.x;
+                                self.state.prev_drag_y = cursor_position.y;
+                            }
+                            _ => {
+                                self.state.dragging_node = None;
+
+                                let node = self.state.nodes[index];
+
+                                self.set_node_from_input(
+                                    messages,
+                                    index,
+                                    node.normal_param_x.default.as_f32(),
+                                    node.normal_param_y.default.as_f32(),
+                                );
+                            }
+                        }
+
+                        self.state.last_click = Some(click);
+
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                    if let Some(index) = self.state.dragging_node.take() {
+                        let node = &mut self.state.nodes[index];
+
+                        node.continuous_normal_x =
+                            node.normal_param_x.value.as_f32();
+                        node.continuous_normal_y =
+                            node.normal_param_y.value.as_f32();
+
+                        return event::Status::Captured;
+                    }
+                }
+                _ => {}
+            },
+            Event::Keyboard(keyboard_event) => match keyboard_event {
+                keyboard::Event::KeyPressed { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                keyboard::Event::KeyReleased { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                keyboard::Event::ModifiersChanged(modifiers) => {
+                    self.state.pressed_modifiers = modifiers;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _style: &iced_native::renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let nodes: Vec<(Normal, Normal)> =
+            self.state.nodes.iter().map(Node::normals).collect();
+
+        renderer.draw(
+            layout.bounds(),
+            cursor_position,
+            &nodes,
+            self.state.dragging_node,
+            &self.style,
+        )
+    }
+}
+
+/// The renderer of a [`MultiXYPad`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`MultiXYPad`] in your user interface.
+///
+/// [`MultiXYPad`]: struct.MultiXYPad.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`MultiXYPad`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`MultiXYPad`]
+    ///   * the current cursor position
+    ///   * the current normals of the x and y coordinates of each node
+    ///   * the index of the node that is currently being dragged, if any
+    ///   * the style of the [`MultiXYPad`]
+    ///
+    /// [`MultiXYPad`]: struct.MultiXYPad.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        nodes: &[(Normal, Normal)],
+        dragging_node: Option<usize>,
+        style: &Self::Style,
+    );
+}
+
+impl<'a, Message, Renderer> From<MultiXYPad<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        multi_xy_pad: MultiXYPad<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(multi_xy_pad)
+    }
+}
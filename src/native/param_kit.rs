@@ -0,0 +1,88 @@
+//! A bundle of everything a typical labeled parameter widget needs
+
+use crate::core::{Normal, NormalParam, ValueFormatter};
+use crate::native::{text_marks, tick_marks};
+
+/// A bundle of a [`NormalParam`], its tick marks, its text marks, and a
+/// formatter, generated from a [`Range`].
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{knob, native::ParamKit, FreqRange};
+///
+/// let kit = ParamKit::new(FreqRange::default(), 1_000.0);
+/// let state = knob::State::new(kit.normal_param);
+///
+/// assert_eq!(kit.format(kit.normal_param.default), "1 kHz");
+/// ```
+///
+/// The tick marks and the text marks are meant to be passed to the widget,
+/// and the [`formatter`](#method.formatter) to its `tooltip_format`.
+///
+/// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+/// [`Range`]: ../../core/range/trait.Range.html
+#[derive(Debug, Clone)]
+pub struct ParamKit<R> {
+    /// The range of the parameter
+    pub range: R,
+    /// The [`NormalParam`] of the parameter, with its value set to the
+    /// default value
+    ///
+    /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+    pub normal_param: NormalParam,
+    /// The tick marks, at the ends, the center and the quarters of the range
+    pub tick_marks: tick_marks::Group,
+    /// The text marks, with the formatted values at the ends and at the
+    /// center of the range
+    pub text_marks: text_marks::Group,
+}
+
+impl<R: ValueFormatter> ParamKit<R> {
+    /// Creates a new `ParamKit`
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - the range of the parameter
+    /// * `default` - the default value of the parameter, which is also its
+    ///   initial value
+    pub fn new(range: R, default: R::Value) -> Self {
+        let default = range.map_to_normal(default);
+
+        let tick_marks =
+            tick_marks::Group::subdivided(1, 1, 0, Some(tick_marks::Tier::ONE));
+
+        let positions: Vec<R::Value> =
+            [Normal::min(), Normal::center(), Normal::max()]
+                .iter()
+                .map(|normal| range.unmap_to_value(*normal))
+                .collect();
+
+        let text_marks = text_marks::Group::from_range(&range, &positions);
+
+        Self {
+            range,
+            normal_param: NormalParam::new(default, default),
+            tick_marks,
+            text_marks,
+        }
+    }
+
+    /// Formats the value of the parameter at `normal`, such as `"1 kHz"`.
+    pub fn format(&self, normal: Normal) -> String {
+        self.range.format_value(self.range.unmap_to_value(normal))
+    }
+
+    /// Returns a function formatting the value of the parameter at a
+    /// [`Normal`], such as for the `tooltip_format` of a widget.
+    ///
+    /// [`Normal`]: ../../core/normal/struct.Normal.html
+    pub fn formatter(&self) -> impl Fn(Normal) -> String
+    where
+        R: Clone + 'static,
+    {
+        let range = self.range.clone();
+
+        move |normal| range.format_value(range.unmap_to_value(normal))
+    }
+}
@@ -185,7 +185,7 @@ impl<'a, Message, Renderer: self::Renderer> Ramp<'a, Message, Renderer> {
 
         self.state.continuous_normal = normal;
 
-        self.state.normal_param.value = normal.into();
+        self.state.normal_param.set_value(normal.into());
 
         messages.publish((self.on_change)(self.state.normal_param.value));
     }
@@ -228,7 +228,7 @@ impl State {
 
     /// Set the normalized value of the [`Ramp`].
     pub fn set_normal(&mut self, normal: Normal) {
-        self.normal_param.value = normal;
+        self.normal_param.set_value(normal);
         self.continuous_normal = normal.into();
     }
 
@@ -262,7 +262,8 @@ impl State {
     ///
     /// ```
     pub fn snap_visible_to(&mut self, range: &IntRange) {
-        self.normal_param.value = range.snapped(self.normal_param.value);
+        self.normal_param
+            .set_value(range.snapped(self.normal_param.value));
     }
 
     /// Is the [`Ramp`] currently in the dragging state?
@@ -373,8 +374,9 @@ where
                             _ => {
                                 self.state.is_dragging = false;
 
-                                self.state.normal_param.value =
-                                    self.state.normal_param.default;
+                                self.state
+                                    .normal_param
+                                    .set_value(self.state.normal_param.default);
 
                                 messages.publish((self.on_change)(
                                     self.state.normal_param.value,
@@ -2,10 +2,19 @@
 
 use std::fmt::Debug;
 
-use crate::core::Normal;
+use crate::core::{
+    LogDBRange, Normal, PercentRange, Range, SemitoneRange, TempoRange,
+    TimeRange, ValueFormatter,
+};
+use crate::native::tick_marks::fader_legend;
 
 /// A group of text marks.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "Vec<(Normal, String)>", into = "Vec<(Normal, String)>")
+)]
 pub struct Group {
     /// The group of text marks.
     pub group: Vec<(Normal, String)>,
@@ -97,6 +106,125 @@ impl Group {
         .into()
     }
 
+    /// Returns a new [`Group`] of text marks for a pan control, with `"L"`
+    /// in the min (`0.0`) position, `"C"` in the center (`0.5`) position,
+    /// and `"R"` in the max (`1.0`) position.
+    ///
+    /// This matches the tick marks of [`tick_marks::Group::pan`].
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`tick_marks::Group::pan`]: ../tick_marks/struct.Group.html#method.pan
+    pub fn pan() -> Self {
+        Self::min_max_and_center("L", "R", "C")
+    }
+
+    /// Returns a new [`Group`] of text marks for a mixer fader along a
+    /// [`LogDBRange`], labeled `+12`, `+6`, `0`, `-6`, `-12`, `-24`, `-48`,
+    /// and `-72`. Values outside of the range are skipped.
+    ///
+    /// The minimum of the range is always labeled: `-∞` when it is below
+    /// `-72 dB`, and its value otherwise, such as `-10`.
+    ///
+    /// This matches the tick marks of [`tick_marks::Group::db_fader`].
+    ///
+    /// * `range` - the [`LogDBRange`] of the fader
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`LogDBRange`]: ../../core/range/struct.LogDBRange.html
+    /// [`tick_marks::Group::db_fader`]: ../tick_marks/struct.Group.html#method.db_fader
+    pub fn db_fader(range: &LogDBRange) -> Self {
+        fader_legend(range)
+            .into_iter()
+            .map(|(position, db)| {
+                let text = match db {
+                    None => String::from("-∞"),
+                    Some(db) => {
+                        let db = (db * 10.0).round() / 10.0 + 0.0;
+
+                        if db > 0.0 {
+                            format!("+{}", db)
+                        } else {
+                            format!("{}", db)
+                        }
+                    }
+                };
+
+                (position, text)
+            })
+            .collect::<Vec<(Normal, String)>>()
+            .into()
+    }
+
+    /// Returns a new [`Group`] of text marks for a [`TempoRange`], labeled
+    /// at each multiple of `60 BPM`, such as `"60 BPM"`, `"120 BPM"`.
+    ///
+    /// This matches the tier 1 tick marks of
+    /// [`tick_marks::Group::from_tempo_range`].
+    ///
+    /// * `range` - the [`TempoRange`] the text marks are placed along
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`TempoRange`]: ../../core/range/struct.TempoRange.html
+    /// [`tick_marks::Group::from_tempo_range`]: ../tick_marks/struct.Group.html#method.from_tempo_range
+    pub fn from_tempo_range(range: &TempoRange) -> Self {
+        let min = range.unmap_to_value(Normal::min());
+        let max = range.unmap_to_value(Normal::max());
+
+        // Allow for the rounding of the range bounds.
+        let first = (min * 0.999 / 60.0).ceil() as u32;
+        let last = (max * 1.001 / 60.0).floor() as u32;
+
+        let positions: Vec<f32> =
+            (first..=last).map(|i| i as f32 * 60.0).collect();
+
+        Self::from_range_fmt(range, &positions, |value| {
+            range.format_with_precision(value, 0)
+        })
+    }
+
+    /// Returns a new [`Group`] of text marks for a [`PercentRange`], labeled
+    /// every `25 %` up to `100 %`, or every `50 %` for larger ranges.
+    ///
+    /// * `range` - the [`PercentRange`] the text marks are placed along
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`PercentRange`]: ../../core/range/struct.PercentRange.html
+    pub fn from_percent_range(range: &PercentRange) -> Self {
+        let step = if range.max() > 100.0 { 50.0 } else { 25.0 };
+
+        // Allow for the rounding of the range bounds.
+        let last = (range.max() * 1.001 / step).floor() as u32;
+
+        let positions: Vec<f32> = (0..=last).map(|i| i as f32 * step).collect();
+
+        Self::from_range(range, &positions)
+    }
+
+    /// Returns a new [`Group`] of text marks for a [`TimeRange`], labeled
+    /// at each decade, such as `"1 ms"`, `"10 ms"`, `"100 ms"`, `"1 s"`.
+    ///
+    /// This matches the tier 1 tick marks of
+    /// [`tick_marks::Group::from_time_range`].
+    ///
+    /// * `range` - the [`TimeRange`] the text marks are placed along
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`TimeRange`]: ../../core/range/struct.TimeRange.html
+    /// [`tick_marks::Group::from_time_range`]: ../tick_marks/struct.Group.html#method.from_time_range
+    pub fn from_time_range(range: &TimeRange) -> Self {
+        let min = range.unmap_to_value(Normal::min());
+        let max = range.unmap_to_value(Normal::max());
+
+        // Allow for the rounding of the range bounds.
+        let first = (min * 0.999).log10().ceil() as i32;
+        let last = (max * 1.001).log10().floor() as i32;
+
+        let positions: Vec<f32> =
+            (first..=last).map(|exp| 10.0f32.powi(exp)).collect();
+
+        Self::from_range(range, &positions)
+    }
+
     /// Creates a group of text marks by subdividing the range
     ///
     /// * `text` - a group of strings to be evenly spaced across the range (not including the minimum and maximum positions)
@@ -152,6 +280,71 @@ impl Group {
         vec.into()
     }
 
+    /// Creates a group of evenly spaced text marks labeled with the names of
+    /// consecutive MIDI notes, such as `"C3"`, `"C#3"`, `"D3"`, for pitch and
+    /// transpose controls.
+    ///
+    /// Note names follow the convention where MIDI note `60` is `"C4"`.
+    ///
+    /// * `start_midi_note` - the MIDI note of the text mark in the minimum
+    ///   position
+    /// * `count` - the number of text marks
+    pub fn note_names(start_midi_note: u8, count: usize) -> Self {
+        let text: Vec<String> = (0..count)
+            .map(|i| {
+                SemitoneRange::note_name(i32::from(start_midi_note) + i as i32)
+            })
+            .collect();
+
+        let text: Vec<&str> = text.iter().map(String::as_str).collect();
+
+        Self::evenly_spaced(&text)
+    }
+
+    /// Creates a group of text marks at values of a [`Range`], with labels
+    /// generated from these values, such as `"-12 dB"` or `"1 kHz"`.
+    ///
+    /// Using the same `range` and values for the tick marks guarantees that
+    /// the text marks align exactly with them.
+    ///
+    /// * `range` - the [`Range`] the text marks are placed along
+    /// * `positions` - the values of the range to put a text mark at
+    /// * `format` - generates the text of the text mark at a value
+    ///
+    /// [`Range`]: ../../core/range/trait.Range.html
+    pub fn from_range_fmt<R, F>(
+        range: &R,
+        positions: &[R::Value],
+        format: F,
+    ) -> Self
+    where
+        R: Range,
+        F: Fn(R::Value) -> String,
+    {
+        positions
+            .iter()
+            .map(|value| (range.map_to_normal(*value), format(*value)))
+            .collect::<Vec<(Normal, String)>>()
+            .into()
+    }
+
+    /// Creates a group of text marks at values of a [`Range`], labeled by
+    /// the [`ValueFormatter`] of the range.
+    ///
+    /// * `range` - the [`Range`] the text marks are placed along
+    /// * `positions` - the values of the range to put a text mark at
+    ///
+    /// [`Range`]: ../../core/range/trait.Range.html
+    /// [`ValueFormatter`]: ../../core/value_formatter/trait.ValueFormatter.html
+    pub fn from_range<R: ValueFormatter>(
+        range: &R,
+        positions: &[R::Value],
+    ) -> Self {
+        Self::from_range_fmt(range, positions, |value| {
+            range.format_value(value)
+        })
+    }
+
     /// Returns the hashed value of the internal data.
     pub(crate) fn hashed(&self) -> u64 {
         self.hashed
@@ -181,3 +374,45 @@ impl From<Vec<(Normal, String)>> for Group {
         Self::from_string(vec)
     }
 }
+
+impl From<Group> for Vec<(Normal, String)> {
+    fn from(group: Group) -> Self {
+        group.group
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(group: &Group) -> Vec<&str> {
+        group.group.iter().map(|(_, text)| text.as_str()).collect()
+    }
+
+    #[test]
+    fn db_fader_with_infinite_minimum() {
+        let range = LogDBRange::new(-90.0, 12.0, Normal::from(0.75));
+        let group = Group::db_fader(&range);
+
+        assert_eq!(
+            texts(&group),
+            ["+12", "+6", "0", "-6", "-12", "-24", "-48", "-72", "-∞"]
+        );
+        assert_eq!(group.group.last().unwrap().0, Normal::min());
+    }
+
+    #[test]
+    fn db_fader_with_finite_minimum() {
+        let range = LogDBRange::new(-12.0, 12.0, Normal::center());
+        let group = Group::db_fader(&range);
+
+        assert_eq!(texts(&group), ["+12", "+6", "0", "-6", "-12"]);
+        assert!(group.group.last().unwrap().0.as_f32() < 1.0e-4);
+
+        let range = LogDBRange::new(-10.0, 6.0, Normal::from(0.6));
+        let group = Group::db_fader(&range);
+
+        assert_eq!(texts(&group), ["+6", "0", "-6", "-10"]);
+        assert_eq!(group.group.last().unwrap().0, Normal::min());
+    }
+}
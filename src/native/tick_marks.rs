@@ -2,23 +2,71 @@
 
 use std::fmt::Debug;
 
-use crate::core::Normal;
+use crate::core::{
+    FloatRange, FreqRange, LogDBRange, Normal, PercentRange, Range, TempoRange,
+    TimeRange,
+};
+use crate::style::tick_marks::Shape;
+
+/// The values in decibels of the legend of a typical mixer fader, from the
+/// top. The bottom of the fader is `-∞`.
+pub(crate) const FADER_SCALE_DB: [f32; 8] =
+    [12.0, 6.0, 0.0, -6.0, -12.0, -24.0, -48.0, -72.0];
+
+/// Returns the legend of a mixer fader along `range`, from the top: the
+/// values of [`FADER_SCALE_DB`] within the range, followed by the minimum
+/// of the range if it isn't one of them. The minimum is `None`, for `-∞`,
+/// when it is below the lowest value of [`FADER_SCALE_DB`].
+pub(crate) fn fader_legend(range: &LogDBRange) -> Vec<(Normal, Option<f32>)> {
+    // Allow for the rounding of the range bounds.
+    const TOLERANCE_DB: f32 = 0.01;
+
+    let min = range.unmap_to_value(Normal::min());
+    let max = range.unmap_to_value(Normal::max());
+
+    let mut legend: Vec<(Normal, Option<f32>)> = FADER_SCALE_DB
+        .iter()
+        .filter(|db| **db >= min - TOLERANCE_DB && **db <= max + TOLERANCE_DB)
+        .map(|db| (range.map_to_normal(*db), Some(*db)))
+        .collect();
+
+    let lowest = FADER_SCALE_DB[FADER_SCALE_DB.len() - 1];
+
+    if min < lowest - TOLERANCE_DB {
+        legend.push((Normal::min(), None));
+    } else if legend
+        .last()
+        .and_then(|(_, db)| *db)
+        .is_none_or(|db| db > min + TOLERANCE_DB)
+    {
+        legend.push((Normal::min(), Some(min)));
+    }
+
+    legend
+}
 
 /// A group of tick marks.
 ///
+/// With the `serde` feature, a `Group` is serialized as its list of tick
+/// marks and tiers, and its list of shape overrides if it has any.
+///
 /// tick mark: struct.TickMark.html
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "SerializedGroup", into = "SerializedGroup")
+)]
 pub struct Group {
-    tier_1_positions: Vec<Normal>,
-    tier_2_positions: Vec<Normal>,
-    tier_3_positions: Vec<Normal>,
+    tiers: Vec<Vec<Normal>>,
+    overrides: Vec<(Normal, Shape)>,
     len: usize,
     hashed: u64,
 }
 
 impl Default for Group {
     fn default() -> Self {
-        Group::center(Tier::One)
+        Group::center(Tier::ONE)
     }
 }
 
@@ -33,32 +81,24 @@ impl Group {
 
         let len = tick_marks.len();
 
-        let mut tier_1_positions: Vec<Normal> = Vec::new();
-        let mut tier_2_positions: Vec<Normal> = Vec::new();
-        let mut tier_3_positions: Vec<Normal> = Vec::new();
+        let mut tiers: Vec<Vec<Normal>> = Vec::new();
 
         for tick_mark in tick_marks.iter() {
             tick_mark.1.hash(&mut hasher);
             // Rust can't hash an f32 value.
             ((tick_mark.0.as_f32() * 10000000.0) as u64).hash(&mut hasher);
 
-            match tick_mark.1 {
-                Tier::One => {
-                    tier_1_positions.push(tick_mark.0);
-                }
-                Tier::Two => {
-                    tier_2_positions.push(tick_mark.0);
-                }
-                Tier::Three => {
-                    tier_3_positions.push(tick_mark.0);
-                }
+            let index = tick_mark.1.index();
+            if tiers.len() <= index {
+                tiers.resize(index + 1, Vec::new());
             }
+
+            tiers[index].push(tick_mark.0);
         }
 
         Self {
-            tier_1_positions,
-            tier_2_positions,
-            tier_3_positions,
+            tiers,
+            overrides: Vec::new(),
             len,
             hashed: hasher.finish(),
         }
@@ -137,14 +177,14 @@ impl Group {
             let one_pos = (i_1 as f32 * one_span) + one_span;
 
             if i_1 != one {
-                tick_marks.push((one_pos.into(), Tier::One));
+                tick_marks.push((one_pos.into(), Tier::ONE));
             }
 
             for i_2 in 0..two_ranges {
                 let two_pos = (i_2 as f32 * two_span) + two_span;
 
                 if i_2 != two {
-                    tick_marks.push(((one_pos - two_pos).into(), Tier::Two));
+                    tick_marks.push(((one_pos - two_pos).into(), Tier::TWO));
                 }
 
                 for i_3 in 0..three {
@@ -152,7 +192,7 @@ impl Group {
 
                     tick_marks.push((
                         (one_pos - two_pos + three_pos).into(),
-                        Tier::Three,
+                        Tier::THREE,
                     ));
                 }
             }
@@ -194,34 +234,375 @@ impl Group {
         Self::from_normalized(&tick_marks)
     }
 
-    /// Returns the positions of the tier 1 tick marks.
-    /// Returns `None` if there are no tier 1 tick marks.
-    pub fn tier_1(&self) -> Option<&Vec<Normal>> {
-        if self.tier_1_positions.is_empty() {
-            None
-        } else {
-            Some(&self.tier_1_positions)
+    /// Returns a new [`Group`] of tick marks for a pan control, going from
+    /// left (`0.0`) to right (`1.0`).
+    ///
+    /// The center tick mark is tier 1, the left and right tick marks are
+    /// tier 2, and the tick marks halfway to each side are tier 3.
+    ///
+    /// [`Group`]: struct.Group.html
+    pub fn pan() -> Self {
+        Self::from_normalized(&[
+            (Normal::min(), Tier::TWO),
+            (0.25.into(), Tier::THREE),
+            (Normal::center(), Tier::ONE),
+            (0.75.into(), Tier::THREE),
+            (Normal::max(), Tier::TWO),
+        ])
+    }
+
+    /// Creates a [`Group`] of tick marks for a mixer fader along a
+    /// [`LogDBRange`], matching the legend of [`text_marks::Group::db_fader`]
+    /// (`+12`, `+6`, `0`, `-6`, `-12`, `-24`, `-48`, `-72`, `-∞`).
+    ///
+    /// The `0 dB` tick mark is tier 1, the other values of the legend are
+    /// tier 2, and a tier 3 tick mark is placed halfway between them. Values
+    /// outside of the range are skipped.
+    ///
+    /// The minimum of the range is always marked. It is the tier 1 `-∞`
+    /// tick mark when it is below `-72 dB`, and a tier 2 tick mark
+    /// otherwise.
+    ///
+    /// * `range` - the [`LogDBRange`] of the fader
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`LogDBRange`]: ../../core/range/struct.LogDBRange.html
+    /// [`text_marks::Group::db_fader`]: ../text_marks/struct.Group.html#method.db_fader
+    pub fn db_fader(range: &LogDBRange) -> Self {
+        let legend = fader_legend(range);
+
+        let mut tick_marks: Vec<(Normal, Tier)> = legend
+            .iter()
+            .map(|(position, db)| {
+                let tier = match db {
+                    None => Tier::ONE,
+                    Some(db) if *db == 0.0 => Tier::ONE,
+                    Some(_) => Tier::TWO,
+                };
+
+                (*position, tier)
+            })
+            .collect();
+
+        let values: Vec<f32> =
+            legend.iter().filter_map(|(_, db)| *db).collect();
+
+        for pair in values.windows(2) {
+            tick_marks.push((
+                range.map_to_normal((pair[0] + pair[1]) / 2.0),
+                Tier::THREE,
+            ));
         }
+
+        Self::from_normalized(&tick_marks)
     }
 
-    /// Returns the positions of the tier 2 tick marks.
-    /// Returns `None` if there are no tier 2 tick marks.
-    pub fn tier_2(&self) -> Option<&Vec<Normal>> {
-        if self.tier_2_positions.is_empty() {
-            None
-        } else {
-            Some(&self.tier_2_positions)
+    /// Creates a [`Group`] of tick marks for a [`LogDBRange`].
+    ///
+    /// * `range` - the [`LogDBRange`] the tick marks are placed along
+    /// * `step_db` - the spacing in decibels between the tier 2 tick marks,
+    ///   counted from `0 dB` in both directions. A tier 3 tick mark is
+    ///   placed halfway between them.
+    ///
+    /// The tier 1 tick mark is placed at `0 dB`.
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`LogDBRange`]: ../../core/range/struct.LogDBRange.html
+    pub fn from_db_range(range: &LogDBRange, step_db: f32) -> Self {
+        let min = range.unmap_to_value(Normal::min());
+        let max = range.unmap_to_value(Normal::max());
+
+        let mut tick_marks: Vec<(Normal, Tier)> =
+            vec![(range.map_to_normal(0.0), Tier::ONE)];
+
+        if step_db > 0.0 {
+            let half_step = step_db / 2.0;
+            let min_steps = (min / half_step).ceil() as i32;
+            let max_steps = (max / half_step).floor() as i32;
+
+            for i in min_steps..=max_steps {
+                if i != 0 {
+                    let tier = if i % 2 == 0 { Tier::TWO } else { Tier::THREE };
+
+                    tick_marks.push((
+                        range.map_to_normal(i as f32 * half_step),
+                        tier,
+                    ));
+                }
+            }
         }
+
+        Self::from_normalized(&tick_marks)
     }
 
-    /// Returns the positions of the tier 3 tick marks.
-    /// Returns `None` if there are no tier 3 tick marks.
-    pub fn tier_3(&self) -> Option<&Vec<Normal>> {
-        if self.tier_3_positions.is_empty() {
-            None
-        } else {
-            Some(&self.tier_3_positions)
+    /// Creates a [`Group`] of tick marks for a [`FreqRange`], placed at the
+    /// multiples of each decade (`10 Hz`, `100 Hz`, `1 kHz`, `10 kHz`).
+    ///
+    /// * `range` - the [`FreqRange`] the tick marks are placed along
+    /// * `lowest_tier` - the lowest [`Tier`] of tick marks to create:
+    ///   * `Tier::ONE` - only the decades
+    ///   * `Tier::TWO` - the decades, plus `2x` and `5x` each decade
+    ///   * `Tier::THREE` - every multiple of each decade
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`FreqRange`]: ../../core/range/struct.FreqRange.html
+    /// [`Tier`]: enum.Tier.html
+    pub fn from_freq_range(range: &FreqRange, lowest_tier: Tier) -> Self {
+        Self::from_decade_multiples(range, |multiple| {
+            let tier = match multiple {
+                1 => Tier::ONE,
+                2 | 5 => Tier::TWO,
+                _ => Tier::THREE,
+            };
+
+            if tier > lowest_tier {
+                None
+            } else {
+                Some(tier)
+            }
+        })
+    }
+
+    /// Creates a [`Group`] of tick marks for a [`FreqRange`] with the
+    /// classic layout of spectrum analyzers and equalizers: tier 1 tick
+    /// marks at each decade (`100 Hz`, `1 kHz`, `10 kHz`), and tier 2 tick
+    /// marks at every other multiple of each decade (`200 Hz`, `300 Hz`,
+    /// `400 Hz`, ...).
+    ///
+    /// This is [`from_freq_range`] with `Tier::THREE`, where the tier 3
+    /// tick marks are promoted to tier 2.
+    ///
+    /// * `range` - the [`FreqRange`] the tick marks are placed along
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`FreqRange`]: ../../core/range/struct.FreqRange.html
+    /// [`from_freq_range`]: #method.from_freq_range
+    pub fn log_subdivided(range: &FreqRange) -> Self {
+        let group = Self::from_freq_range(range, Tier::THREE);
+
+        // Promote the tier 3 tick marks of every multiple to tier 2.
+        let tick_marks: Vec<(Normal, Tier)> = group
+            .tiers
+            .iter()
+            .enumerate()
+            .flat_map(|(index, positions)| {
+                let tier = Tier(index).min(Tier::TWO);
+                positions.iter().map(move |position| (*position, tier))
+            })
+            .collect();
+
+        Self::from_normalized(&tick_marks)
+    }
+
+    /// Creates a [`Group`] of tick marks for a [`TimeRange`], placed at the
+    /// multiples of each decade (`1 ms`, `10 ms`, `100 ms`, `1 s`).
+    ///
+    /// * `range` - the [`TimeRange`] the tick marks are placed along
+    /// * `lowest_tier` - the lowest [`Tier`] of tick marks to create:
+    ///   * `Tier::ONE` - only the decades
+    ///   * `Tier::TWO` - the decades, plus `2x` and `5x` each decade
+    ///   * `Tier::THREE` - every multiple of each decade
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`TimeRange`]: ../../core/range/struct.TimeRange.html
+    /// [`Tier`]: enum.Tier.html
+    pub fn from_time_range(range: &TimeRange, lowest_tier: Tier) -> Self {
+        Self::from_decade_multiples(range, |multiple| {
+            let tier = match multiple {
+                1 => Tier::ONE,
+                2 | 5 => Tier::TWO,
+                _ => Tier::THREE,
+            };
+
+            if tier > lowest_tier {
+                None
+            } else {
+                Some(tier)
+            }
+        })
+    }
+
+    /// Creates a [`Group`] of tick marks for a logarithmic range, placed at
+    /// the multiples of each decade with the [`Tier`] returned by `tier`,
+    /// if any.
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`Tier`]: enum.Tier.html
+    fn from_decade_multiples<R: Range<Value = f32>>(
+        range: &R,
+        tier: impl Fn(u32) -> Option<Tier>,
+    ) -> Self {
+        let min = range.unmap_to_value(Normal::min());
+        let max = range.unmap_to_value(Normal::max());
+
+        let mut tick_marks: Vec<(Normal, Tier)> = Vec::new();
+
+        let mut decade = 10.0f32.powi(min.log10().floor() as i32);
+
+        while decade <= max {
+            for multiple in 1..10 {
+                let tier = match tier(multiple) {
+                    Some(tier) => tier,
+                    None => continue,
+                };
+
+                let value = decade * multiple as f32;
+
+                // Allow for the rounding of the range bounds.
+                if value >= min * 0.999 && value <= max * 1.001 {
+                    tick_marks.push((range.map_to_normal(value), tier));
+                }
+            }
+
+            decade *= 10.0;
+        }
+
+        Self::from_normalized(&tick_marks)
+    }
+
+    /// Creates a [`Group`] of tick marks for a [`TempoRange`], placed at
+    /// common tempos.
+    ///
+    /// Multiples of `60 BPM` are tier 1, and the other multiples of
+    /// `20 BPM` are tier 2.
+    ///
+    /// * `range` - the [`TempoRange`] the tick marks are placed along
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`TempoRange`]: ../../core/range/struct.TempoRange.html
+    pub fn from_tempo_range(range: &TempoRange) -> Self {
+        let min = range.unmap_to_value(Normal::min());
+        let max = range.unmap_to_value(Normal::max());
+
+        // Allow for the rounding of the range bounds.
+        let first = (min * 0.999 / 20.0).ceil() as u32;
+        let last = (max * 1.001 / 20.0).floor() as u32;
+
+        let tick_marks: Vec<(Normal, Tier)> = (first..=last)
+            .map(|i| {
+                let tier = if i % 3 == 0 { Tier::ONE } else { Tier::TWO };
+
+                (range.map_to_normal(i as f32 * 20.0), tier)
+            })
+            .collect();
+
+        Self::from_normalized(&tick_marks)
+    }
+
+    /// Creates a [`Group`] of tick marks for a [`FloatRange`], placed at
+    /// evenly spaced values of the range rather than evenly spaced positions,
+    /// so they show the law of a skewed range, such as
+    /// [`FloatRange::audio_taper`].
+    ///
+    /// The tick marks at the ends and at the middle value of the range are in
+    /// tier 1, the others in tier 2.
+    ///
+    /// * `range` - the [`FloatRange`] the tick marks are placed along
+    /// * `divisions` - the number of divisions of the range, `10` gives a
+    ///   tick mark every `10 %` of the range
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`FloatRange`]: ../../core/range/struct.FloatRange.html
+    /// [`FloatRange::audio_taper`]: ../../core/range/struct.FloatRange.html#method.audio_taper
+    pub fn from_float_range(range: &FloatRange, divisions: usize) -> Self {
+        let divisions = divisions.max(1);
+
+        let min = range.unmap_to_value(Normal::min());
+        let max = range.unmap_to_value(Normal::max());
+        let step = (max - min) / divisions as f32;
+
+        let tick_marks: Vec<(Normal, Tier)> = (0..=divisions)
+            .map(|i| {
+                let tier = if i == 0 || i == divisions || i * 2 == divisions {
+                    Tier::ONE
+                } else {
+                    Tier::TWO
+                };
+
+                (range.map_to_normal(min + (i as f32 * step)), tier)
+            })
+            .collect();
+
+        Self::from_normalized(&tick_marks)
+    }
+
+    /// Creates a [`Group`] of tick marks for a [`PercentRange`], placed
+    /// every `10 %`, with every `50 %` in tier 1 and the others in tier 2.
+    ///
+    /// * `range` - the [`PercentRange`] the tick marks are placed along
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`PercentRange`]: ../../core/range/struct.PercentRange.html
+    pub fn from_percent_range(range: &PercentRange) -> Self {
+        // Allow for the rounding of the range bounds.
+        let last = (range.max() * 1.001 / 10.0).floor() as u32;
+
+        let tick_marks: Vec<(Normal, Tier)> = (0..=last)
+            .map(|i| {
+                let tier = if i % 5 == 0 { Tier::ONE } else { Tier::TWO };
+
+                (range.map_to_normal(i as f32 * 10.0), tier)
+            })
+            .collect();
+
+        Self::from_normalized(&tick_marks)
+    }
+
+    /// Overrides the [`Shape`] of individual tick marks, for example to
+    /// show a red tick mark at `0 dB`.
+    ///
+    /// An override replaces the tick mark of the [`Group`] at the same
+    /// position, or adds a tick mark if there is none.
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`Shape`]: ../../style/tick_marks/enum.Shape.html
+    pub fn with_overrides(mut self, overrides: Vec<(Normal, Shape)>) -> Self {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = iced_native::Hasher::default();
+        self.hashed.hash(&mut hasher);
+
+        for (position, shape) in overrides.iter() {
+            for positions in self.tiers.iter_mut() {
+                positions.retain(|tier_position| {
+                    (tier_position.as_f32() - position.as_f32()).abs()
+                        > f32::EPSILON
+                });
+            }
+
+            position.as_f32().to_bits().hash(&mut hasher);
+            hash_shape(shape, &mut hasher);
         }
+
+        self.overrides = overrides;
+        self.len = self.tiers.iter().map(Vec::len).sum::<usize>()
+            + self.overrides.len();
+        self.hashed = hasher.finish();
+
+        self
+    }
+
+    /// Returns the tick marks whose [`Shape`] overrides the [`Shape`] of
+    /// their tier.
+    ///
+    /// [`Shape`]: ../../style/tick_marks/enum.Shape.html
+    pub fn overrides(&self) -> &[(Normal, Shape)] {
+        &self.overrides
+    }
+
+    /// Returns the positions of the tick marks of the given [`Tier`].
+    /// Returns `None` if there are no tick marks of this [`Tier`].
+    ///
+    /// [`Tier`]: struct.Tier.html
+    pub fn tier(&self, tier: Tier) -> Option<&Vec<Normal>> {
+        self.tiers
+            .get(tier.index())
+            .filter(|positions| !positions.is_empty())
+    }
+
+    /// Returns the positions of the tick marks of every tier, from the
+    /// largest to the smallest tier. A tier with no tick marks is empty.
+    pub fn tiers(&self) -> &[Vec<Normal>] {
+        &self.tiers
     }
 
     /// Returns the total number of tick marks.
@@ -229,12 +610,88 @@ impl Group {
         self.len
     }
 
+    /// Returns the position of the tick mark nearest to `normal`, including
+    /// the tick marks with a shape override, or `None` if there are no tick
+    /// marks.
+    pub fn nearest(&self, normal: Normal) -> Option<Normal> {
+        let positions: Vec<Normal> = self
+            .tiers
+            .iter()
+            .flatten()
+            .chain(self.overrides.iter().map(|(position, _)| position))
+            .copied()
+            .collect();
+
+        if positions.is_empty() {
+            None
+        } else {
+            Some(normal.snap_to(&positions, f32::INFINITY))
+        }
+    }
+
+    /// Returns the position of the tick mark nearest to `normal` if it is
+    /// at most `threshold` away from it, such as to snap a value to a tick
+    /// mark.
+    ///
+    /// * `normal` - the position to test
+    /// * `threshold` - the maximum distance in normalized units
+    pub fn within(&self, normal: Normal, threshold: f32) -> Option<Normal> {
+        self.nearest(normal).filter(|position| {
+            (position.as_f32() - normal.as_f32()).abs() <= threshold
+        })
+    }
+
     /// Returns the hashed value of the internal data.
     pub(crate) fn hashed(&self) -> u64 {
         self.hashed
     }
 }
 
+fn hash_shape<H: std::hash::Hasher>(shape: &Shape, hasher: &mut H) {
+    use std::hash::Hash;
+
+    let (kind, size, width, color) = match shape {
+        Shape::None => (0u8, 0.0, 0.0, None),
+        Shape::Line {
+            length,
+            width,
+            color,
+            line_cap,
+            dash,
+        } => {
+            (*line_cap as u8).hash(hasher);
+            if let Some(dash) = dash {
+                dash.length.to_bits().hash(hasher);
+                dash.gap.to_bits().hash(hasher);
+            }
+            (1u8, *length, *width, Some(color))
+        }
+        Shape::Circle { diameter, color } => (2u8, *diameter, 0.0, Some(color)),
+        Shape::Wedge {
+            inner_width,
+            outer_width,
+            length,
+            color,
+        } => {
+            inner_width.to_bits().hash(hasher);
+            (3u8, *length, *outer_width, Some(color))
+        }
+        Shape::Image { handle, size } => {
+            handle.id().hash(hasher);
+            (4u8, *size, 0.0, None)
+        }
+    };
+
+    kind.hash(hasher);
+    size.to_bits().hash(hasher);
+    width.to_bits().hash(hasher);
+    if let Some(color) = color {
+        for component in color.into_linear().iter() {
+            component.to_bits().hash(hasher);
+        }
+    }
+}
+
 impl From<Vec<(Normal, Tier)>> for Group {
     fn from(vec: Vec<(Normal, Tier)>) -> Self {
         Self::from_normalized(&vec)
@@ -247,23 +704,154 @@ impl From<&[(Normal, Tier)]> for Group {
     }
 }
 
+impl From<Group> for Vec<(Normal, Tier)> {
+    fn from(group: Group) -> Self {
+        group
+            .tiers
+            .iter()
+            .enumerate()
+            .flat_map(|(index, positions)| {
+                positions
+                    .iter()
+                    .map(move |position| (*position, Tier(index)))
+            })
+            .collect()
+    }
+}
+
+/// The serialized form of a [`Group`]
+///
+/// [`Group`]: struct.Group.html
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SerializedGroup {
+    tick_marks: Vec<(Normal, Tier)>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    overrides: Vec<(Normal, Shape)>,
+}
+
+#[cfg(feature = "serde")]
+impl From<SerializedGroup> for Group {
+    fn from(group: SerializedGroup) -> Self {
+        Self::from_normalized(&group.tick_marks).with_overrides(group.overrides)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Group> for SerializedGroup {
+    fn from(group: Group) -> Self {
+        Self {
+            overrides: group.overrides.clone(),
+            tick_marks: group.into(),
+        }
+    }
+}
+
 /// Tier of sizes for a tick mark.
 ///
-/// * One - large-sized tick mark
-/// * Two - medium-sized tick mark
-/// * Small - small-sized tick mark
-#[derive(Debug, Copy, Clone, PartialEq, std::hash::Hash)]
-pub enum Tier {
+/// A tier is the index of the [`Shape`] of the tick mark in the `tiers` of
+/// the tick marks [`Style`], `0` being the largest tick mark. There can be
+/// as many tiers as needed, for example for dense frequency scales.
+///
+/// [`Shape`]: ../../style/tick_marks/enum.Shape.html
+/// [`Style`]: ../../style/tick_marks/struct.Style.html
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, std::hash::Hash,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tier(pub usize);
+
+impl Tier {
     /// large-sized tick mark
-    One,
+    pub const ONE: Tier = Tier(0);
     /// medium-sized tick mark
-    Two,
+    pub const TWO: Tier = Tier(1);
     /// small-sized tick mark
-    Three,
+    pub const THREE: Tier = Tier(2);
+    /// tiny-sized tick mark
+    pub const FOUR: Tier = Tier(3);
+
+    /// large-sized tick mark
+    #[deprecated(note = "use `Tier::ONE` instead")]
+    #[allow(non_upper_case_globals)]
+    pub const One: Tier = Tier::ONE;
+    /// medium-sized tick mark
+    #[deprecated(note = "use `Tier::TWO` instead")]
+    #[allow(non_upper_case_globals)]
+    pub const Two: Tier = Tier::TWO;
+    /// small-sized tick mark
+    #[deprecated(note = "use `Tier::THREE` instead")]
+    #[allow(non_upper_case_globals)]
+    pub const Three: Tier = Tier::THREE;
+
+    /// Returns the index of the tier in the `tiers` of the tick marks
+    /// [`Style`].
+    ///
+    /// [`Style`]: ../../style/tick_marks/struct.Style.html
+    pub fn index(self) -> usize {
+        self.0
+    }
 }
 
 impl Default for Tier {
     fn default() -> Self {
-        Tier::One
+        Tier::ONE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn db_fader_with_finite_minimum() {
+        let range = LogDBRange::new(-12.0, 12.0, Normal::center());
+        let group = Group::db_fader(&range);
+
+        // Only the `0 dB` tick mark is tier 1, there is no `-∞` one.
+        assert_eq!(group.tier(Tier::ONE).unwrap().len(), 1);
+        assert_eq!(group.tier(Tier::TWO).unwrap().len(), 4);
+        assert_eq!(group.tier(Tier::THREE).unwrap().len(), 4);
+        assert_eq!(group.nearest(Normal::min()).unwrap(), Normal::min());
+
+        let range = LogDBRange::new(-90.0, 12.0, Normal::from(0.75));
+        let group = Group::db_fader(&range);
+
+        assert!(group.tier(Tier::ONE).unwrap().contains(&Normal::min()));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_tier_aliases() {
+        assert_eq!(Tier::One, Tier::ONE);
+        assert_eq!(Tier::Two, Tier::TWO);
+        assert_eq!(Tier::Three, Tier::THREE);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn serialize_overrides() {
+        let group = Group::min_max_and_center(Tier::ONE, Tier::TWO)
+            .with_overrides(vec![(
+                Normal::center(),
+                Shape::Circle {
+                    diameter: 4.0,
+                    color: iced_native::Color::WHITE,
+                },
+            )]);
+
+        let json = serde_json::to_string(&group).unwrap();
+        let group: Group = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(group.len(), 3);
+        assert_eq!(group.overrides().len(), 1);
+        assert_eq!(group.overrides()[0].0, Normal::center());
+
+        let group: Group =
+            serde_json::from_str(r#"{"tick_marks":[[0.0,0],[1.0,1]]}"#)
+                .unwrap();
+
+        assert_eq!(group.len(), 2);
+        assert!(group.overrides().is_empty());
     }
 }
@@ -0,0 +1,153 @@
+//! An overlay that displays the value of a widget while it is hovered or
+//! dragged
+
+use iced_native::{layout, overlay, renderer, Layout, Point, Rectangle, Size};
+
+pub use crate::style::tooltip::Style;
+
+/// An overlay that displays the formatted value of a widget.
+///
+/// It is placed above the widget, or below it if there isn't enough room.
+/// When placed beside the widget, it is placed to its right, or to its left
+/// if there isn't enough room.
+#[derive(Debug, Clone)]
+pub struct Tooltip {
+    text: String,
+    target: Rectangle,
+    style: Style,
+    beside: bool,
+}
+
+impl Tooltip {
+    /// Creates a new [`Tooltip`].
+    ///
+    /// It expects:
+    ///   * the text to display
+    ///   * the bounds of the widget the [`Tooltip`] belongs to
+    ///   * the [`Style`] of the [`Tooltip`]
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    /// [`Style`]: ../../style/tooltip/struct.Style.html
+    pub fn new(text: String, target: Rectangle, style: Style) -> Self {
+        Self {
+            text,
+            target,
+            style,
+            beside: false,
+        }
+    }
+
+    /// Places the [`Tooltip`] beside its target instead of above it.
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn beside(mut self) -> Self {
+        self.beside = true;
+        self
+    }
+
+    /// Turns the [`Tooltip`] into an overlay [`Element`].
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    /// [`Element`]: https://docs.rs/iced_native/0.5.1/iced_native/overlay/struct.Element.html
+    pub fn overlay<'a, Message, Renderer>(
+        self,
+    ) -> overlay::Element<'a, Message, Renderer>
+    where
+        Message: 'a,
+        Renderer: self::Renderer + 'a,
+    {
+        let position = self.target.position();
+
+        overlay::Element::new(position, Box::new(self))
+    }
+}
+
+impl<Message, Renderer> overlay::Overlay<Message, Renderer> for Tooltip
+where
+    Renderer: self::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        _position: Point,
+    ) -> layout::Node {
+        let text_size = renderer.measure_tooltip(&self.text, &self.style);
+        let padding = f32::from(self.style.padding) * 2.0;
+
+        let size = Size::new(
+            (text_size.width + padding).ceil(),
+            (text_size.height + padding).ceil(),
+        );
+
+        let (x, y) = if self.beside {
+            let right = self.target.x + self.target.width + self.style.offset;
+
+            let x = if right + size.width <= bounds.width {
+                right
+            } else {
+                self.target.x - self.style.offset - size.width
+            };
+
+            let y = (self.target.center_y() - (size.height / 2.0))
+                .min(bounds.height - size.height)
+                .max(0.0);
+
+            (x, y)
+        } else {
+            let x = (self.target.center_x() - (size.width / 2.0))
+                .min(bounds.width - size.width)
+                .max(0.0);
+
+            let above = self.target.y - self.style.offset - size.height;
+
+            let y = if above >= 0.0 {
+                above
+            } else {
+                self.target.y + self.target.height + self.style.offset
+            };
+
+            (x, y)
+        };
+
+        let mut node = layout::Node::new(size);
+        node.move_to(Point::new(x.round(), y.round()));
+
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) {
+        renderer.draw_tooltip(layout.bounds(), &self.text, &self.style)
+    }
+}
+
+/// The renderer of a [`Tooltip`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`Tooltip`] in your user interface.
+///
+/// [`Tooltip`]: struct.Tooltip.html
+pub trait Renderer: iced_native::Renderer {
+    /// Measures the size of the text of a [`Tooltip`], not including its
+    /// padding.
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    fn measure_tooltip(&self, text: &str, style: &Style) -> Size;
+
+    /// Draws a [`Tooltip`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Tooltip`]
+    ///   * the text to display
+    ///   * the [`Style`] of the [`Tooltip`]
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    /// [`Style`]: ../../style/tooltip/struct.Style.html
+    fn draw_tooltip(&mut self, bounds: Rectangle, text: &str, style: &Style);
+}
@@ -3,17 +3,27 @@
 //!
 //! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
 
+use std::cell::Cell;
+use std::collections::VecDeque;
 use std::fmt::Debug;
+use std::time::{Duration, Instant};
 
 use iced_native::{
     event, keyboard, layout, mouse, Clipboard, Element, Event, Layout, Length,
     Point, Rectangle, Shell, Size, Widget,
 };
 
-use crate::core::{Normal, NormalParam};
+use crate::core::{
+    Easing, LockedAxis, ModulationRange, Normal, NormalParam, Polar,
+};
+use crate::native::{text_marks, tick_marks};
+use crate::style::transition::StatusTransition;
+use crate::style::{xy_pad, Status, StyleFn};
 use crate::IntRange;
 
 static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_KEY_STEP: f32 = 0.01;
+static DEFAULT_WHEEL_SCALAR: f32 = 0.01;
 
 /// A 2D XY pad GUI widget that controls two [`NormalParam`] parameters at
 /// once. One in the `x` coordinate and one in the `y` coordinate.
@@ -27,12 +37,33 @@ static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
 pub struct XYPad<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     on_change: Box<dyn Fn(Normal, Normal) -> Message>,
+    on_drag_start: Option<Box<dyn Fn() -> Option<Message>>>,
+    on_drag_end: Option<Box<dyn Fn() -> Option<Message>>>,
+    on_wheel: Option<Box<dyn Fn(Normal) -> Message>>,
+    on_wheel_start: Option<Box<dyn Fn() -> Option<Message>>>,
+    on_wheel_end: Option<Box<dyn Fn() -> Option<Message>>>,
+    wheel_scalar: f32,
+    key_step: f32,
     modifier_scalar: f32,
     modifier_keys: keyboard::Modifiers,
+    axis_lock_keys: keyboard::Modifiers,
     size: Length,
     style: Renderer::Style,
+    tick_marks_x: Option<&'a tick_marks::Group>,
+    tick_marks_y: Option<&'a tick_marks::Group>,
+    text_marks_x: Option<&'a text_marks::Group>,
+    text_marks_y: Option<&'a text_marks::Group>,
+    mod_range_x: Option<&'a ModulationRange>,
+    mod_range_y: Option<&'a ModulationRange>,
+    snap_grid: Option<(u32, u32)>,
+    spring_return: Option<((Normal, Normal), Duration, Easing)>,
+    trail_decay: f32,
+    polar: bool,
+    readouts: Option<Readouts>,
 }
 
+type Readouts = (Box<dyn Fn(Normal) -> String>, Box<dyn Fn(Normal) -> String>);
+
 impl<'a, Message, Renderer: self::Renderer> XYPad<'a, Message, Renderer> {
     /// Creates a new [`XYPad`].
     ///
@@ -49,13 +80,133 @@ impl<'a, Message, Renderer: self::Renderer> XYPad<'a, Message, Renderer> {
         XYPad {
             state,
             on_change: Box::new(on_change),
+            on_drag_start: None,
+            on_drag_end: None,
+            on_wheel: None,
+            on_wheel_start: None,
+            on_wheel_end: None,
+            wheel_scalar: DEFAULT_WHEEL_SCALAR,
+            key_step: DEFAULT_KEY_STEP,
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
             modifier_keys: keyboard::Modifiers::CTRL,
+            axis_lock_keys: keyboard::Modifiers::SHIFT,
             size: Length::Fill,
             style: Renderer::Style::default(),
+            tick_marks_x: None,
+            tick_marks_y: None,
+            text_marks_x: None,
+            text_marks_y: None,
+            mod_range_x: None,
+            mod_range_y: None,
+            snap_grid: None,
+            spring_return: None,
+            trail_decay: 0.0,
+            polar: false,
+            readouts: None,
         }
     }
 
+    /// Sets a function that will be called when the user starts to change
+    /// the values of the [`XYPad`], either by dragging it or with the
+    /// keyboard. It can return `None` to send no message.
+    ///
+    /// A burst of key presses, such as an arrow key held down, is reported
+    /// as a single gesture.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn on_drag_start<F>(mut self, on_drag_start: F) -> Self
+    where
+        F: 'static + Fn() -> Option<Message>,
+    {
+        self.on_drag_start = Some(Box::new(on_drag_start));
+        self
+    }
+
+    /// Sets a function that will be called when the user stops changing the
+    /// values of the [`XYPad`]. It can return `None` to send no message.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn on_drag_end<F>(mut self, on_drag_end: F) -> Self
+    where
+        F: 'static + Fn() -> Option<Message>,
+    {
+        self.on_drag_end = Some(Box::new(on_drag_end));
+        self
+    }
+
+    /// Sets a function that will be called when the user scrolls the mouse
+    /// wheel over the [`XYPad`], with the new value of the third parameter
+    /// of its [`State`], such as the drive of a filter while the x and y
+    /// axes control its cutoff and resonance.
+    ///
+    /// The third parameter is set with [`State::with_wheel_param`].
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`State`]: struct.State.html
+    /// [`State::with_wheel_param`]: struct.State.html#method.with_wheel_param
+    pub fn on_wheel<F>(mut self, on_wheel: F) -> Self
+    where
+        F: 'static + Fn(Normal) -> Message,
+    {
+        self.on_wheel = Some(Box::new(on_wheel));
+        self
+    }
+
+    /// Sets a function that will be called before the third parameter of the
+    /// [`XYPad`] is changed with the mouse wheel. It can
+    /// return `None` to send no message.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn on_wheel_start<F>(mut self, on_wheel_start: F) -> Self
+    where
+        F: 'static + Fn() -> Option<Message>,
+    {
+        self.on_wheel_start = Some(Box::new(on_wheel_start));
+        self
+    }
+
+    /// Sets a function that will be called after the third parameter of the
+    /// [`XYPad`] is changed with the mouse wheel. It can
+    /// return `None` to send no message.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn on_wheel_end<F>(mut self, on_wheel_end: F) -> Self
+    where
+        F: 'static + Fn() -> Option<Message>,
+    {
+        self.on_wheel_end = Some(Box::new(on_wheel_end));
+        self
+    }
+
+    /// Sets how much the [`Normal`] value of the third parameter of the
+    /// [`XYPad`] will change per line scrolled by the mouse wheel. This is
+    /// multiplied by the `modifier_scalar` when the modifier key is held
+    /// down.
+    ///
+    /// The default value is `0.01`
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn wheel_scalar(mut self, wheel_scalar: f32) -> Self {
+        self.wheel_scalar = wheel_scalar;
+        self
+    }
+
+    /// Sets how much the [`Normal`] values will change for the [`XYPad`]
+    /// when an arrow key is pressed while it is focused. This is multiplied
+    /// by the `modifier_scalar` when the modifier key is held down.
+    ///
+    /// `Left` and `Right` nudge the x axis, `Up` and `Down` the y axis.
+    ///
+    /// The default value is `0.01`
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn key_step(mut self, key_step: f32) -> Self {
+        self.key_step = key_step;
+        self
+    }
+
     /// Sets the size of the [`XYPad`].
     ///
     /// [`XYPad`]: struct.XYPad.html
@@ -72,6 +223,22 @@ impl<'a, Message, Renderer: self::Renderer> XYPad<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the style of the [`XYPad`] from a closure returning the [`Style`]
+    /// of each [`Status`] of the [`XYPad`], such as:
+    ///
+    /// `.style_fn(|status| my_style(status, &palette))`
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Style`]: ../../style/xy_pad/struct.Style.html
+    /// [`Status`]: ../../style/style_fn/enum.Status.html
+    pub fn style_fn<F>(self, style_fn: F) -> Self
+    where
+        F: 'static + Fn(Status) -> xy_pad::Style,
+        StyleFn<F>: Into<Renderer::Style>,
+    {
+        self.style(StyleFn::new(style_fn))
+    }
+
     /// Sets the modifier keys of the [`XYPad`].
     ///
     /// The default modifier key is `Ctrl`.
@@ -82,6 +249,21 @@ impl<'a, Message, Renderer: self::Renderer> XYPad<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the modifier keys that constrain a drag of the [`XYPad`] to a
+    /// single axis while they are held down. The drag is locked to
+    /// whichever axis moves the most first.
+    ///
+    /// The default axis lock key is `Shift`.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn axis_lock_keys(
+        mut self,
+        axis_lock_keys: keyboard::Modifiers,
+    ) -> Self {
+        self.axis_lock_keys = axis_lock_keys;
+        self
+    }
+
     /// Sets the scalar to use when the user drags the slider while holding down
     /// the modifier key.
     ///
@@ -95,22 +277,391 @@ impl<'a, Message, Renderer: self::Renderer> XYPad<'a, Message, Renderer> {
         self.modifier_scalar = scalar;
         self
     }
+
+    /// Sets the tick marks to display along the x axis. Note your
+    /// [`StyleSheet`] must also implement
+    /// `tick_marks_style_x(&self) -> Option<TickMarksStyle>` for them to
+    /// display (which the default style does).
+    ///
+    /// [`StyleSheet`]: ../../style/xy_pad/trait.StyleSheet.html
+    pub fn tick_marks_x(mut self, tick_marks: &'a tick_marks::Group) -> Self {
+        self.tick_marks_x = Some(tick_marks);
+        self
+    }
+
+    /// Sets the tick marks to display along the y axis. Note your
+    /// [`StyleSheet`] must also implement
+    /// `tick_marks_style_y(&self) -> Option<TickMarksStyle>` for them to
+    /// display (which the default style does).
+    ///
+    /// [`StyleSheet`]: ../../style/xy_pad/trait.StyleSheet.html
+    pub fn tick_marks_y(mut self, tick_marks: &'a tick_marks::Group) -> Self {
+        self.tick_marks_y = Some(tick_marks);
+        self
+    }
+
+    /// Sets the text marks to display along the x axis. Note your
+    /// [`StyleSheet`] must also implement
+    /// `text_marks_style_x(&self) -> Option<TextMarksStyle>` for them to
+    /// display (which the default style does).
+    ///
+    /// [`StyleSheet`]: ../../style/xy_pad/trait.StyleSheet.html
+    pub fn text_marks_x(mut self, text_marks: &'a text_marks::Group) -> Self {
+        self.text_marks_x = Some(text_marks);
+        self
+    }
+
+    /// Sets the text marks to display along the y axis. Note your
+    /// [`StyleSheet`] must also implement
+    /// `text_marks_style_y(&self) -> Option<TextMarksStyle>` for them to
+    /// display (which the default style does).
+    ///
+    /// [`StyleSheet`]: ../../style/xy_pad/trait.StyleSheet.html
+    pub fn text_marks_y(mut self, text_marks: &'a text_marks::Group) -> Self {
+        self.text_marks_y = Some(text_marks);
+        self
+    }
+
+    /// Sets a [`ModulationRange`] to display along the horizontal rail for
+    /// the x axis. Note your [`StyleSheet`] must also implement
+    /// `mod_range_style_x(&self) -> Option<ModRangeStyle>` for it to
+    /// display.
+    ///
+    /// [`ModulationRange`]: ../../core/struct.ModulationRange.html
+    /// [`StyleSheet`]: ../../style/xy_pad/trait.StyleSheet.html
+    pub fn mod_range_x(mut self, mod_range: &'a ModulationRange) -> Self {
+        self.mod_range_x = Some(mod_range);
+        self
+    }
+
+    /// Sets a [`ModulationRange`] to display along the vertical rail for
+    /// the y axis. Note your [`StyleSheet`] must also implement
+    /// `mod_range_style_y(&self) -> Option<ModRangeStyle>` for it to
+    /// display.
+    ///
+    /// [`ModulationRange`]: ../../core/struct.ModulationRange.html
+    /// [`StyleSheet`]: ../../style/xy_pad/trait.StyleSheet.html
+    pub fn mod_range_y(mut self, mod_range: &'a ModulationRange) -> Self {
+        self.mod_range_y = Some(mod_range);
+        self
+    }
+
+    /// Snaps the handle of the [`XYPad`] to the intersections of a grid with
+    /// `x_steps` columns and `y_steps` rows while it is dragged. Holding down
+    /// the modifier key bypasses the snapping.
+    ///
+    /// An axis with `0` steps is not snapped. The grid is drawn if the
+    /// [`Style`] has a `grid`.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Style`]: ../../style/xy_pad/struct.Style.html
+    pub fn snap_grid(mut self, x_steps: u32, y_steps: u32) -> Self {
+        self.snap_grid = Some((x_steps, y_steps));
+        self
+    }
+
+    /// Makes the handle of the [`XYPad`] spring back to the `rest` position
+    /// of the x and y axes when it is released, easing in over the given
+    /// `duration`.
+    ///
+    /// The return follows the time elapsed since the release: the handle is
+    /// drawn at its returning position on every redraw. Call
+    /// [`State::advance_spring_return`] from a `time::every` subscription
+    /// while [`State::is_returning`] returns `true` to move the parameters
+    /// along with the handle.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`State::advance_spring_return`]: struct.State.html#method.advance_spring_return
+    /// [`State::is_returning`]: struct.State.html#method.is_returning
+    pub fn spring_return(
+        mut self,
+        rest: (Normal, Normal),
+        duration: Duration,
+        easing: Easing,
+    ) -> Self {
+        self.spring_return = Some((rest, duration, easing));
+        self
+    }
+
+    /// Cross-fades the colors and the widths of the style of the [`XYPad`]
+    /// with the `easing` curve over the given `duration` when it becomes
+    /// hovered or dragged, instead of switching styles abruptly.
+    ///
+    /// The animation only advances when your application is redrawn, so you
+    /// will want to keep requesting redraws (for example with a
+    /// `time::every` subscription) while [`State::is_animating`] returns
+    /// `true`.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`State::is_animating`]: struct.State.html#method.is_animating
+    pub fn style_transition(self, duration: Duration, easing: Easing) -> Self {
+        self.state.style_transition.get_mut().set(duration, easing);
+        self
+    }
+
+    /// Shows a trail behind the handle of the [`XYPad`] made of its last
+    /// `length` positions, useful to visualize performance gestures.
+    ///
+    /// Each position of the trail is drawn with the opacity of the next
+    /// newer one multiplied by `decay`, in the range `[0.0, 1.0]`, so it
+    /// fades out towards its oldest position.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn trail(mut self, length: usize, decay: f32) -> Self {
+        self.state.trail_length = length;
+        let excess = self.state.trail.len().saturating_sub(length);
+        let _ = self.state.trail.drain(..excess);
+        self.trail_decay = decay.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Constrains the handle of the [`XYPad`] to the circle inscribed in the
+    /// pad. The function given to [`XYPad::new`] then receives the angle and
+    /// the radius of the handle, as described in [`Polar`], instead of its
+    /// x and y coordinates.
+    ///
+    /// The [`State`] still holds the x and y coordinates of the handle, see
+    /// [`State::polar`] and [`State::set_polar`] to convert them.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`XYPad::new`]: struct.XYPad.html#method.new
+    /// [`Polar`]: ../../core/polar/struct.Polar.html
+    /// [`State`]: struct.State.html
+    /// [`State::polar`]: struct.State.html#method.polar
+    /// [`State::set_polar`]: struct.State.html#method.set_polar
+    pub fn polar(mut self, polar: bool) -> Self {
+        self.polar = polar;
+        self
+    }
+
+    /// Shows the values of the x and y axes in the corners of the [`XYPad`]
+    /// while it is hovered or dragged, formatted by `format_x` and
+    /// `format_y`. The y value is shown in the top left corner and the x
+    /// value in the bottom right corner.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn readouts<FX, FY>(mut self, format_x: FX, format_y: FY) -> Self
+    where
+        FX: 'static + Fn(Normal) -> String,
+        FY: 'static + Fn(Normal) -> String,
+    {
+        self.readouts = Some((Box::new(format_x), Box::new(format_y)));
+        self
+    }
+
+    fn set_normals_from_input(
+        &mut self,
+        messages: &mut Shell<'_, Message>,
+        normal_x: f32,
+        normal_y: f32,
+    ) {
+        let (normal_x, normal_y) = if self.polar {
+            Polar::constrain_xy(normal_x, normal_y)
+        } else {
+            (normal_x, normal_y)
+        };
+
+        let normal_x = normal_x.clamp(0.0, 1.0);
+        let normal_y = normal_y.clamp(0.0, 1.0);
+
+        self.state.continuous_normal_x = normal_x;
+        self.state.continuous_normal_y = normal_y;
+
+        let (normal_x, normal_y) = match self.snap_grid {
+            Some((x_steps, y_steps))
+                if !self
+                    .state
+                    .pressed_modifiers
+                    .contains(self.modifier_keys) =>
+            {
+                (snap(normal_x, x_steps), snap(normal_y, y_steps))
+            }
+            _ => (normal_x, normal_y),
+        };
+
+        self.state.normal_param_x.set_value(normal_x.into());
+        self.state.normal_param_y.set_value(normal_y.into());
+        self.state.push_trail();
+
+        self.publish_normals(messages);
+    }
+
+    fn publish_normals(&self, messages: &mut Shell<'_, Message>) {
+        if self.polar {
+            let polar = self.state.polar();
+
+            messages.publish((self.on_change)(polar.angle, polar.radius));
+        } else {
+            messages.publish((self.on_change)(
+                self.state.normal_param_x.value,
+                self.state.normal_param_y.value,
+            ));
+        }
+    }
+}
+
+impl<'a, Message, Renderer: self::Renderer> XYPad<'a, Message, Renderer> {
+    fn key_normals(&self, key_code: keyboard::KeyCode) -> Option<(f32, f32)> {
+        let step = if self.state.pressed_modifiers.contains(self.modifier_keys)
+        {
+            self.key_step * self.modifier_scalar
+        } else {
+            self.key_step
+        };
+
+        let normal_x = self.state.continuous_normal_x;
+        let normal_y = self.state.continuous_normal_y;
+
+        match key_code {
+            keyboard::KeyCode::Right => Some((normal_x + step, normal_y)),
+            keyboard::KeyCode::Left => Some((normal_x - step, normal_y)),
+            keyboard::KeyCode::Up => Some((normal_x, normal_y + step)),
+            keyboard::KeyCode::Down => Some((normal_x, normal_y - step)),
+            _ => None,
+        }
+    }
+
+    fn publish_drag_start(&mut self, messages: &mut Shell<'_, Message>) {
+        // Grab the handle where it is drawn.
+        let _ = self.state.advance_spring_return();
+        self.state.spring_return = None;
+
+        if let Some(message) = self
+            .on_drag_start
+            .as_ref()
+            .and_then(|on_drag_start| on_drag_start())
+        {
+            messages.publish(message);
+        }
+    }
+
+    fn publish_drag_end(&mut self, messages: &mut Shell<'_, Message>) {
+        if let Some(message) = self
+            .on_drag_end
+            .as_ref()
+            .and_then(|on_drag_end| on_drag_end())
+        {
+            messages.publish(message);
+        }
+    }
+
+    fn move_wheel_param(
+        &mut self,
+        messages: &mut Shell<'_, Message>,
+        lines: f32,
+    ) {
+        let mut normal_delta = lines * self.wheel_scalar;
+        if self.state.pressed_modifiers.contains(self.modifier_keys) {
+            normal_delta *= self.modifier_scalar;
+        }
+
+        if let (Some(on_wheel), Some(wheel_param)) =
+            (&self.on_wheel, &mut self.state.wheel_param)
+        {
+            if let Some(message) = self
+                .on_wheel_start
+                .as_ref()
+                .and_then(|on_wheel_start| on_wheel_start())
+            {
+                messages.publish(message);
+            }
+
+            wheel_param
+                .set_value((wheel_param.value.as_f32() + normal_delta).into());
+
+            messages.publish(on_wheel(wheel_param.value));
+
+            if let Some(message) = self
+                .on_wheel_end
+                .as_ref()
+                .and_then(|on_wheel_end| on_wheel_end())
+            {
+                messages.publish(message);
+            }
+        }
+    }
+
+    fn end_key_gesture(&mut self, messages: &mut Shell<'_, Message>) {
+        if self.state.is_key_nudging {
+            self.state.is_key_nudging = false;
+            self.publish_drag_end(messages);
+        }
+    }
+}
+
+fn is_nudge_key(key_code: keyboard::KeyCode) -> bool {
+    matches!(
+        key_code,
+        keyboard::KeyCode::Right
+            | keyboard::KeyCode::Left
+            | keyboard::KeyCode::Up
+            | keyboard::KeyCode::Down
+    )
+}
+
+fn snap(normal: f32, steps: u32) -> f32 {
+    Normal::new(normal).quantize(steps).as_f32()
 }
 
 /// The local state of a [`XYPad`].
 ///
 /// [`XYPad`]: struct.XYPad.html
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub struct State {
     normal_param_x: NormalParam,
     normal_param_y: NormalParam,
     is_dragging: bool,
+    is_focused: bool,
+    is_key_nudging: bool,
     prev_drag_x: f32,
     prev_drag_y: f32,
     continuous_normal_x: f32,
     continuous_normal_y: f32,
     pressed_modifiers: keyboard::Modifiers,
+    locked_axis: Option<LockedAxis>,
     last_click: Option<mouse::Click>,
+    tick_marks_cache_x: crate::graphics::tick_marks::PrimitiveCache,
+    tick_marks_cache_y: crate::graphics::tick_marks::PrimitiveCache,
+    text_marks_cache_x: crate::graphics::text_marks::PrimitiveCache,
+    text_marks_cache_y: crate::graphics::text_marks::PrimitiveCache,
+    spring_return: Option<SpringReturn>,
+    trail_length: usize,
+    trail: VecDeque<(Normal, Normal)>,
+    wheel_param: Option<NormalParam>,
+    style_transition: Cell<StatusTransition>,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct SpringReturn {
+    from: (Normal, Normal),
+    to: (Normal, Normal),
+    start: Instant,
+    duration: Duration,
+    easing: Easing,
+}
+
+impl SpringReturn {
+    fn is_done(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.start) >= self.duration
+    }
+
+    fn normals(&self, now: Instant) -> (Normal, Normal) {
+        if self.is_done(now) {
+            return self.to;
+        }
+
+        let elapsed = now.saturating_duration_since(self.start);
+        let t = self
+            .easing
+            .apply(elapsed.as_secs_f32() / self.duration.as_secs_f32());
+
+        let lerp = |from: Normal, to: Normal| {
+            let from = from.as_f32();
+            Normal::from(from + ((to.as_f32() - from) * t))
+        };
+
+        (lerp(self.from.0, self.to.0), lerp(self.from.1, self.to.1))
+    }
 }
 
 impl State {
@@ -130,24 +681,36 @@ impl State {
             normal_param_x,
             normal_param_y,
             is_dragging: false,
+            is_focused: false,
+            is_key_nudging: false,
             prev_drag_x: 0.0,
             prev_drag_y: 0.0,
             continuous_normal_x: normal_param_x.value.as_f32(),
             continuous_normal_y: normal_param_y.value.as_f32(),
             pressed_modifiers: Default::default(),
+            locked_axis: None,
             last_click: None,
+            tick_marks_cache_x: Default::default(),
+            tick_marks_cache_y: Default::default(),
+            text_marks_cache_x: Default::default(),
+            text_marks_cache_y: Default::default(),
+            spring_return: None,
+            trail_length: 0,
+            trail: VecDeque::new(),
+            wheel_param: None,
+            style_transition: Cell::default(),
         }
     }
 
     /// Set the normalized value of the x axis of the [`XYPad`].
     pub fn set_normal_x(&mut self, normal: Normal) {
-        self.normal_param_x.value = normal;
+        self.normal_param_x.set_value(normal);
         self.continuous_normal_x = normal.into();
     }
 
     /// Set the normalized value of the y axis of the [`XYPad`].
     pub fn set_normal_y(&mut self, normal: Normal) {
-        self.normal_param_y.value = normal;
+        self.normal_param_y.set_value(normal);
         self.continuous_normal_y = normal.into();
     }
 
@@ -196,7 +759,8 @@ impl State {
     ///
     /// ```
     pub fn snap_visible_x_to(&mut self, range: &IntRange) {
-        self.normal_param_x.value = range.snapped(self.normal_param_x.value);
+        self.normal_param_x
+            .set_value(range.snapped(self.normal_param_x.value));
     }
 
     /// Snap the visible value of the y axis of the [`XYPad`] to the nearest value
@@ -214,7 +778,61 @@ impl State {
     ///
     /// ```
     pub fn snap_visible_y_to(&mut self, range: &IntRange) {
-        self.normal_param_y.value = range.snapped(self.normal_param_y.value);
+        self.normal_param_y
+            .set_value(range.snapped(self.normal_param_y.value));
+    }
+
+    /// Assigns a third [`NormalParam`] to the [`XYPad`], controlled with the
+    /// mouse wheel while it is hovered. See [`XYPad::on_wheel`].
+    ///
+    /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`XYPad::on_wheel`]: struct.XYPad.html#method.on_wheel
+    pub fn with_wheel_param(mut self, normal_param: NormalParam) -> Self {
+        self.wheel_param = Some(normal_param);
+        self
+    }
+
+    /// Set the normalized value of the third parameter of the [`XYPad`],
+    /// if it has one.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn set_wheel_normal(&mut self, normal: Normal) {
+        if let Some(wheel_param) = &mut self.wheel_param {
+            wheel_param.set_value(normal);
+        }
+    }
+
+    /// Get the normalized value of the third parameter of the [`XYPad`],
+    /// if it has one.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn wheel_normal(&self) -> Option<Normal> {
+        self.wheel_param.map(|wheel_param| wheel_param.value)
+    }
+
+    /// Get the position of the handle of the [`XYPad`] as [`Polar`]
+    /// coordinates, as received in polar mode.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Polar`]: ../../core/polar/struct.Polar.html
+    pub fn polar(&self) -> Polar {
+        Polar::from_xy(
+            self.normal_param_x.value.as_f32(),
+            self.normal_param_y.value.as_f32(),
+        )
+    }
+
+    /// Set the position of the handle of the [`XYPad`] from [`Polar`]
+    /// coordinates.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Polar`]: ../../core/polar/struct.Polar.html
+    pub fn set_polar(&mut self, polar: Polar) {
+        let (normal_x, normal_y) = polar.to_xy();
+
+        self.set_normal_x(normal_x.into());
+        self.set_normal_y(normal_y.into());
     }
 
     /// Is the [`XYPad`] currently in the dragging state?
@@ -223,6 +841,121 @@ impl State {
     pub fn is_dragging(&self) -> bool {
         self.is_dragging
     }
+
+    /// Is the [`XYPad`] currently focused? A focused [`XYPad`] can be
+    /// controlled with the arrow keys.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Focuses the [`XYPad`].
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    /// Unfocuses the [`XYPad`].
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+
+    /// The axis the drag of the [`XYPad`] is currently locked to, if any.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn locked_axis(&self) -> Option<LockedAxis> {
+        self.locked_axis
+    }
+
+    /// Is the handle of the [`XYPad`] currently springing back to its rest
+    /// position?
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn is_returning(&self) -> bool {
+        self.spring_return.is_some()
+    }
+
+    /// Is the handle of the [`XYPad`] currently springing back to its rest
+    /// position, or is its style cross-fading between two states?
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn is_animating(&self) -> bool {
+        self.is_returning() || self.style_transition.get().is_animating()
+    }
+
+    fn update_style_transition(&self, status: Status) -> Option<(Status, f32)> {
+        let mut style_transition = self.style_transition.get();
+        let progress = style_transition.update(status, Instant::now());
+        self.style_transition.set(style_transition);
+
+        progress
+    }
+
+    /// Advances the spring return of the [`XYPad`] and returns the new
+    /// normalized values of the x and y axes, or `None` if the handle is not
+    /// returning.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn advance_spring_return(&mut self) -> Option<(Normal, Normal)> {
+        let spring_return = self.spring_return?;
+
+        let now = Instant::now();
+        let (normal_x, normal_y) = spring_return.normals(now);
+
+        if spring_return.is_done(now) {
+            self.spring_return = None;
+        }
+
+        self.set_normal_x(normal_x);
+        self.set_normal_y(normal_y);
+        self.push_trail();
+
+        Some((normal_x, normal_y))
+    }
+
+    /// Get the normalized values of the x and y axes currently displayed by
+    /// the [`XYPad`]. These only differ from the values of the parameters
+    /// while the handle is springing back to its rest position.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn displayed_normals(&self) -> (Normal, Normal) {
+        match self.spring_return {
+            Some(spring_return) => spring_return.normals(Instant::now()),
+            None => (self.normal_param_x.value, self.normal_param_y.value),
+        }
+    }
+
+    /// The last positions of the handle of the [`XYPad`] shown by its
+    /// trail, from the oldest to the newest.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn trail(&self) -> &VecDeque<(Normal, Normal)> {
+        &self.trail
+    }
+
+    /// Clears the trail of the [`XYPad`].
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn clear_trail(&mut self) {
+        self.trail.clear();
+    }
+
+    fn push_trail(&mut self) {
+        if self.trail_length == 0 {
+            return;
+        }
+
+        if self.trail.len() >= self.trail_length {
+            let _ = self.trail.pop_front();
+        }
+
+        self.trail
+            .push_back((self.normal_param_x.value, self.normal_param_y.value));
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -267,6 +1000,35 @@ where
     ) -> event::Status {
         match event {
             Event::Mouse(mouse_event) => match mouse_event {
+                mouse::Event::WheelScrolled { delta } => {
+                    if self.on_wheel.is_none()
+                        || self.state.wheel_param.is_none()
+                        || self.wheel_scalar == 0.0
+                    {
+                        return event::Status::Ignored;
+                    }
+
+                    if layout.bounds().contains(cursor_position) {
+                        let lines = match delta {
+                            mouse::ScrollDelta::Lines { y, .. } => y,
+                            mouse::ScrollDelta::Pixels { y, .. } => {
+                                if y > 0.0 {
+                                    1.0
+                                } else if y < 0.0 {
+                                    -1.0
+                                } else {
+                                    0.0
+                                }
+                            }
+                        };
+
+                        if lines != 0.0 {
+                            self.move_wheel_param(messages, lines);
+
+                            return event::Status::Captured;
+                        }
+                    }
+                }
                 mouse::Event::CursorMoved { .. } => {
                     if self.state.is_dragging {
                         let bounds_size = {
@@ -294,6 +1056,30 @@ where
                                 movement_y *= self.modifier_scalar;
                             }
 
+                            if self
+                                .state
+                                .pressed_modifiers
+                                .contains(self.axis_lock_keys)
+                            {
+                                if self.state.locked_axis.is_none()
+                                    && (movement_x != 0.0 || movement_y != 0.0)
+                                {
+                                    self.state.locked_axis = if movement_x.abs()
+                                        >= movement_y.abs()
+                                    {
+                                        Some(LockedAxis::X)
+                                    } else {
+                                        Some(LockedAxis::Y)
+                                    };
+                                }
+
+                                match self.state.locked_axis {
+                                    Some(LockedAxis::X) => movement_y = 0.0,
+                                    Some(LockedAxis::Y) => movement_x = 0.0,
+                                    None => (),
+                                }
+                            }
+
                             let normal_x =
                                 self.state.continuous_normal_x + movement_x;
                             let normal_y =
@@ -302,22 +1088,20 @@ where
                             self.state.prev_drag_x = cursor_position.x;
                             self.state.prev_drag_y = cursor_position.y;
 
-                            self.state.continuous_normal_x = normal_x;
-                            self.state.normal_param_x.value = normal_x.into();
-
-                            self.state.continuous_normal_y = normal_y;
-                            self.state.normal_param_y.value = normal_y.into();
-
-                            messages.publish((self.on_change)(
-                                self.state.normal_param_x.value,
-                                self.state.normal_param_y.value,
-                            ));
+                            self.set_normals_from_input(
+                                messages, normal_x, normal_y,
+                            );
 
                             return event::Status::Captured;
                         }
                     }
                 }
                 mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                    self.end_key_gesture(messages);
+
+                    self.state.is_focused =
+                        layout.bounds().contains(cursor_position);
+
                     if layout.bounds().contains(cursor_position) {
                         let click = mouse::Click::new(
                             cursor_position,
@@ -326,6 +1110,7 @@ where
 
                         match click.kind() {
                             mouse::click::Kind::Single => {
+                                self.publish_drag_start(messages);
                                 self.state.is_dragging = true;
                                 self.state.prev_drag_x = cursor_position.x;
                                 self.state.prev_drag_y = cursor_position.y;
@@ -348,31 +1133,26 @@ where
                                     - ((cursor_position.y - layout.bounds().y)
                                         / bounds_size);
 
-                                self.state.continuous_normal_x = normal_x;
-                                self.state.normal_param_x.value =
-                                    normal_x.into();
-
-                                self.state.continuous_normal_y = normal_y;
-                                self.state.normal_param_y.value =
-                                    normal_y.into();
-
-                                messages.publish((self.on_change)(
-                                    self.state.normal_param_x.value,
-                                    self.state.normal_param_y.value,
-                                ));
+                                self.set_normals_from_input(
+                                    messages, normal_x, normal_y,
+                                );
                             }
                             _ => {
                                 self.state.is_dragging = false;
 
-                                self.state.normal_param_x.value =
-                                    self.state.normal_param_x.default;
-                                self.state.normal_param_y.value =
-                                    self.state.normal_param_y.default;
+                                // Reset to the default values in a gesture of
+                                // its own, like the keyboard edits.
+                                self.publish_drag_start(messages);
 
-                                messages.publish((self.on_change)(
-                                    self.state.normal_param_x.value,
-                                    self.state.normal_param_y.value,
-                                ));
+                                self.state.normal_param_x.set_value(
+                                    self.state.normal_param_x.default,
+                                );
+                                self.state.normal_param_y.set_value(
+                                    self.state.normal_param_y.default,
+                                );
+
+                                self.publish_normals(messages);
+                                self.publish_drag_end(messages);
                             }
                         }
 
@@ -382,7 +1162,27 @@ where
                     }
                 }
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                    if self.state.is_dragging {
+                        self.publish_drag_end(messages);
+
+                        if let Some((rest, duration, easing)) =
+                            self.spring_return
+                        {
+                            self.state.spring_return = Some(SpringReturn {
+                                from: (
+                                    self.state.normal_param_x.value,
+                                    self.state.normal_param_y.value,
+                                ),
+                                to: rest,
+                                start: Instant::now(),
+                                duration,
+                                easing,
+                            });
+                        }
+                    }
+
                     self.state.is_dragging = false;
+                    self.state.locked_axis = None;
                     self.state.continuous_normal_x =
                         self.state.normal_param_x.value.as_f32();
                     self.state.continuous_normal_y =
@@ -393,19 +1193,56 @@ where
                 _ => {}
             },
             Event::Keyboard(keyboard_event) => match keyboard_event {
-                keyboard::Event::KeyPressed { modifiers, .. } => {
+                keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers,
+                } => {
                     self.state.pressed_modifiers = modifiers;
 
+                    if !modifiers.contains(self.axis_lock_keys) {
+                        self.state.locked_axis = None;
+                    }
+
+                    if self.state.is_focused && !self.state.is_dragging {
+                        if let Some((normal_x, normal_y)) =
+                            self.key_normals(key_code)
+                        {
+                            if !self.state.is_key_nudging {
+                                self.state.is_key_nudging = true;
+                                self.publish_drag_start(messages);
+                            }
+
+                            self.set_normals_from_input(
+                                messages, normal_x, normal_y,
+                            );
+                        }
+                    }
+
                     return event::Status::Captured;
                 }
-                keyboard::Event::KeyReleased { modifiers, .. } => {
+                keyboard::Event::KeyReleased {
+                    key_code,
+                    modifiers,
+                } => {
                     self.state.pressed_modifiers = modifiers;
 
+                    if !modifiers.contains(self.axis_lock_keys) {
+                        self.state.locked_axis = None;
+                    }
+
+                    if is_nudge_key(key_code) {
+                        self.end_key_gesture(messages);
+                    }
+
                     return event::Status::Captured;
                 }
                 keyboard::Event::ModifiersChanged(modifiers) => {
                     self.state.pressed_modifiers = modifiers;
 
+                    if !modifiers.contains(self.axis_lock_keys) {
+                        self.state.locked_axis = None;
+                    }
+
                     return event::Status::Captured;
                 }
                 _ => {}
@@ -424,17 +1261,117 @@ where
         cursor_position: Point,
         _viewport: &Rectangle,
     ) {
+        let (normal_x, normal_y) = self.state.displayed_normals();
+
+        let readouts = match &self.readouts {
+            Some((format_x, format_y))
+                if self.state.is_dragging
+                    || layout.bounds().contains(cursor_position) =>
+            {
+                Some((format_x(normal_x), format_y(normal_y)))
+            }
+            _ => None,
+        };
+
+        let status = if self.state.is_dragging {
+            Status::Dragging
+        } else if layout.bounds().contains(cursor_position) {
+            Status::Hovered
+        } else if self.state.is_focused {
+            Status::Focused
+        } else {
+            Status::Active
+        };
+
         renderer.draw(
-            layout.bounds(),
-            cursor_position,
-            self.state.normal_param_x.value,
-            self.state.normal_param_y.value,
-            self.state.is_dragging,
+            DrawInfo {
+                bounds: layout.bounds(),
+                cursor_position,
+                normal_x,
+                normal_y,
+                is_dragging: self.state.is_dragging,
+                is_focused: self.state.is_focused,
+                is_polar: self.polar,
+                locked_axis: self.state.locked_axis,
+                trail: &self.state.trail,
+                trail_decay: self.trail_decay,
+                mod_range_x: self.mod_range_x,
+                mod_range_y: self.mod_range_y,
+                snap_grid: self.snap_grid,
+                tick_marks_x: self.tick_marks_x,
+                tick_marks_y: self.tick_marks_y,
+                text_marks_x: self.text_marks_x,
+                text_marks_y: self.text_marks_y,
+                readouts: readouts
+                    .as_ref()
+                    .map(|(x, y)| (x.as_str(), y.as_str())),
+                style_transition: self.state.update_style_transition(status),
+                tick_marks_cache_x: &self.state.tick_marks_cache_x,
+                tick_marks_cache_y: &self.state.tick_marks_cache_y,
+                text_marks_cache_x: &self.state.text_marks_cache_x,
+                text_marks_cache_y: &self.state.text_marks_cache_y,
+            },
             &self.style,
         )
     }
 }
 
+/// The values an [`XYPad`] is drawn with, passed to the
+/// [`Renderer`] on every frame.
+///
+/// [`XYPad`]: struct.XYPad.html
+/// [`Renderer`]: trait.Renderer.html
+#[derive(Debug, Copy, Clone)]
+pub struct DrawInfo<'a> {
+    /// The bounds of the XY pad
+    pub bounds: Rectangle,
+    /// The current cursor position
+    pub cursor_position: Point,
+    /// The normal of the x coordinate of the XY pad
+    pub normal_x: Normal,
+    /// The normal of the y coordinate of the XY pad
+    pub normal_y: Normal,
+    /// Whether the XY pad is being dragged
+    pub is_dragging: bool,
+    /// Whether the XY pad has the keyboard focus
+    pub is_focused: bool,
+    /// Whether the handle is constrained to the inscribed circle
+    pub is_polar: bool,
+    /// The axis the drag is locked to, if any
+    pub locked_axis: Option<LockedAxis>,
+    /// The positions of the trail, from the oldest to the newest
+    pub trail: &'a VecDeque<(Normal, Normal)>,
+    /// The decay of the trail
+    pub trail_decay: f32,
+    /// The modulation range to display along the x rail, if any
+    pub mod_range_x: Option<&'a ModulationRange>,
+    /// The modulation range to display along the y rail, if any
+    pub mod_range_y: Option<&'a ModulationRange>,
+    /// The number of x and y steps of the snapping grid, if any
+    pub snap_grid: Option<(u32, u32)>,
+    /// The tick marks to display along the x axis, if any
+    pub tick_marks_x: Option<&'a tick_marks::Group>,
+    /// The tick marks to display along the y axis, if any
+    pub tick_marks_y: Option<&'a tick_marks::Group>,
+    /// The text marks to display along the x axis, if any
+    pub text_marks_x: Option<&'a text_marks::Group>,
+    /// The text marks to display along the y axis, if any
+    pub text_marks_y: Option<&'a text_marks::Group>,
+    /// The formatted x and y values to display in the corners, if any
+    pub readouts: Option<(&'a str, &'a str)>,
+    /// The status the style is cross-fading from and the eased
+    /// progress of the cross-fade, if any
+    pub style_transition: Option<(Status, f32)>,
+    /// The primitive cache of the x tick marks
+    pub tick_marks_cache_x: &'a crate::tick_marks::PrimitiveCache,
+    /// The primitive cache of the y tick marks
+    pub tick_marks_cache_y: &'a crate::tick_marks::PrimitiveCache,
+    /// The primitive cache of the x text marks
+    pub text_marks_cache_x: &'a crate::text_marks::PrimitiveCache,
+    /// The primitive cache of the y text marks
+    pub text_marks_cache_y: &'a crate::text_marks::PrimitiveCache,
+}
+
 /// The renderer of an [`XYPad`].
 ///
 /// Your renderer will need to implement this trait before being
@@ -445,26 +1382,11 @@ pub trait Renderer: iced_native::Renderer {
     /// The style supported by this renderer.
     type Style: Default;
 
-    /// Draws an [`XYPad`].
-    ///
-    /// It receives:
-    ///   * the bounds of the [`XYPad`]
-    ///   * the current cursor position
-    ///   * the current normal of the x coordinate of the [`XYPad`]
-    ///   * the current normal of the y coordinate of the [`XYPad`]
-    ///   * whether the xy_pad is currently being dragged
-    ///   * the style of the [`XYPad`]
+    /// Draws an [`XYPad`] from its [`DrawInfo`] and its style.
     ///
     /// [`XYPad`]: struct.XYPad.html
-    fn draw(
-        &mut self,
-        bounds: Rectangle,
-        cursor_position: Point,
-        normal_x: Normal,
-        normal_y: Normal,
-        is_dragging: bool,
-        style: &Self::Style,
-    );
+    /// [`DrawInfo`]: struct.DrawInfo.html
+    fn draw(&mut self, info: DrawInfo<'_>, style: &Self::Style);
 }
 
 impl<'a, Message, Renderer> From<XYPad<'a, Message, Renderer>>
@@ -0,0 +1,116 @@
+//! A background that can either be a solid color or a gradient
+
+use iced_native::Color;
+
+/// The background of a part of a widget, such as the rail or the filled
+/// portion of a slider.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Background {
+    /// A solid color
+    Color(
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::style::color_serde")
+        )]
+        Color,
+    ),
+    /// A linear [`Gradient`]
+    ///
+    /// [`Gradient`]: struct.Gradient.html
+    Gradient(Gradient),
+}
+
+impl From<Color> for Background {
+    fn from(color: Color) -> Self {
+        Background::Color(color)
+    }
+}
+
+impl From<Gradient> for Background {
+    fn from(gradient: Gradient) -> Self {
+        Background::Gradient(gradient)
+    }
+}
+
+/// A linear gradient that follows the value axis of a widget, going from
+/// the color at the minimum value to the color at the maximum value.
+///
+/// For example, a gain slider that fades from green to yellow to red:
+///
+/// ```
+/// use iced_audio::style::background::Gradient;
+/// use iced_native::Color;
+///
+/// let gradient = Gradient {
+///     start: Color::from_rgb(0.0, 0.8, 0.0),
+///     middle: Some((0.75, Color::from_rgb(0.9, 0.9, 0.0))),
+///     end: Color::from_rgb(0.9, 0.0, 0.0),
+/// };
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gradient {
+    /// The color at the minimum value
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub start: Color,
+    /// An optional color in the middle of the gradient and its position
+    /// along the gradient in the range `[0.0, 1.0]`
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::color_serde::option_stop")
+    )]
+    pub middle: Option<(f32, Color)>,
+    /// The color at the maximum value
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub end: Color,
+}
+
+impl Gradient {
+    /// Returns the color of the gradient at the given position in the range
+    /// `[0.0, 1.0]`.
+    pub fn color_at(&self, position: f32) -> Color {
+        let position = position.clamp(0.0, 1.0);
+
+        match self.middle {
+            Some((middle_position, middle)) => {
+                let middle_position = middle_position.clamp(0.0, 1.0);
+
+                if position <= middle_position {
+                    if middle_position <= 0.0 {
+                        middle
+                    } else {
+                        lerp(self.start, middle, position / middle_position)
+                    }
+                } else {
+                    lerp(
+                        middle,
+                        self.end,
+                        (position - middle_position) / (1.0 - middle_position),
+                    )
+                }
+            }
+            None => lerp(self.start, self.end, position),
+        }
+    }
+
+    /// Returns the positions in the range `[0.0, 1.0]` where the color of
+    /// the gradient changes direction, including both ends.
+    pub fn stops(&self) -> Vec<f32> {
+        match self.middle {
+            Some((middle_position, _)) => {
+                vec![0.0, middle_position.clamp(0.0, 1.0), 1.0]
+            }
+            None => vec![0.0, 1.0],
+        }
+    }
+}
+
+fn lerp(a: Color, b: Color, amount: f32) -> Color {
+    Color {
+        r: a.r + ((b.r - a.r) * amount),
+        g: a.g + ((b.g - a.g) * amount),
+        b: a.b + ((b.b - a.b) * amount),
+        a: a.a + ((b.a - a.a) * amount),
+    }
+}
@@ -0,0 +1,126 @@
+//! Serialization of the colors of the styles
+//!
+//! A color is written as its `r`, `g`, `b` and `a` components, where `a`
+//! can be omitted for an opaque color.
+
+use iced_native::Color;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "Color")]
+pub struct ColorDef {
+    r: f32,
+    g: f32,
+    b: f32,
+    #[serde(default = "opaque")]
+    a: f32,
+}
+
+fn opaque() -> f32 {
+    1.0
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+struct Wrapper(#[serde(with = "ColorDef")] Color);
+
+pub fn serialize<S: Serializer>(
+    color: &Color,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    ColorDef::serialize(color, serializer)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Color, D::Error> {
+    ColorDef::deserialize(deserializer)
+}
+
+/// Serialization of an optional color
+pub mod option {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        color: &Option<Color>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        color.map(Wrapper).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Color>, D::Error> {
+        Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|color| color.0))
+    }
+}
+
+/// Serialization of an optional color stop, made of its position and its
+/// color
+pub mod option_stop {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        stop: &Option<(f32, Color)>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        stop.map(|(position, color)| (position, Wrapper(color)))
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<(f32, Color)>, D::Error> {
+        Ok(Option::<(f32, Wrapper)>::deserialize(deserializer)?
+            .map(|(position, color)| (position, color.0)))
+    }
+}
+
+/// Serialization of a pair of colors
+pub mod pair {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        colors: &(Color, Color),
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        (Wrapper(colors.0), Wrapper(colors.1)).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<(Color, Color), D::Error> {
+        let (first, second) = <(Wrapper, Wrapper)>::deserialize(deserializer)?;
+        Ok((first.0, second.0))
+    }
+}
+
+/// Serialization of an array of colors
+pub mod array {
+    use super::*;
+    use std::convert::TryInto;
+
+    pub fn serialize<S: Serializer, const N: usize>(
+        colors: &[Color; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(colors.iter().map(|color| Wrapper(*color)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[Color; N], D::Error> {
+        let colors: Vec<Color> = Vec::<Wrapper>::deserialize(deserializer)?
+            .into_iter()
+            .map(|wrapper| wrapper.0)
+            .collect();
+        let len = colors.len();
+
+        colors.try_into().map_err(|_| {
+            serde::de::Error::invalid_length(
+                len,
+                &format!("{} colors", N).as_str(),
+            )
+        })
+    }
+}
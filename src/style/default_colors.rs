@@ -3,6 +3,7 @@
 use iced_native::Color;
 
 pub const BORDER: Color = Color::from_rgb(0.315, 0.315, 0.315);
+pub const BORDER_DISABLED: Color = Color::from_rgb(0.65, 0.65, 0.65);
 pub const LIGHT_BACK: Color = Color::from_rgb(0.97, 0.97, 0.97);
 pub const LIGHT_BACK_HOVER: Color = Color::from_rgb(0.93, 0.93, 0.93);
 pub const LIGHT_BACK_DRAG: Color = Color::from_rgb(0.92, 0.92, 0.92);
@@ -49,6 +50,15 @@ pub const TEXT_MARK: Color = Color {
 };
 
 pub const KNOB_BACK_HOVER: Color = Color::from_rgb(0.96, 0.96, 0.96);
+pub const KNOB_MODULATED_NOTCH: Color = Color {
+    r: 0.315,
+    g: 0.315,
+    b: 0.315,
+    a: 0.4,
+};
+
+pub const TOOLTIP_BACK: Color = Color::from_rgb(0.97, 0.97, 0.97);
+pub const TOOLTIP_TEXT: Color = Color::from_rgb(0.26, 0.26, 0.26);
 
 pub const RAMP_BACK_HOVER: Color = Color::from_rgb(0.95, 0.95, 0.95);
 
@@ -64,6 +74,95 @@ pub const XY_PAD_CENTER_LINE: Color = Color {
     b: 0.56,
     a: 0.5,
 };
+pub const MULTI_XY_PAD_NODES: [Color; 4] = [
+    Color::from_rgb(0.18, 0.52, 0.82),
+    Color::from_rgb(0.86, 0.38, 0.18),
+    Color::from_rgb(0.3, 0.7, 0.3),
+    Color::from_rgb(0.66, 0.36, 0.78),
+];
+
+pub const FOCUS_RING: Color = Color {
+    r: 0.26,
+    g: 0.52,
+    b: 0.96,
+    a: 0.8,
+};
+
+pub const DARK_BORDER: Color = Color::from_rgb(0.7, 0.7, 0.7);
+pub const DARK_BORDER_DISABLED: Color = Color::from_rgb(0.4, 0.4, 0.4);
+pub const DARK_BACK: Color = Color::from_rgb(0.2, 0.2, 0.2);
+pub const DARK_BACK_HOVER: Color = Color::from_rgb(0.24, 0.24, 0.24);
+pub const DARK_BACK_DRAG: Color = Color::from_rgb(0.26, 0.26, 0.26);
+
+pub const DARK_SLIDER_RAIL: (Color, Color) = (
+    Color {
+        r: 0.08,
+        g: 0.08,
+        b: 0.08,
+        a: 0.75,
+    },
+    Color {
+        r: 0.44,
+        g: 0.44,
+        b: 0.44,
+        a: 0.75,
+    },
+);
+
+pub const DARK_TICK_TIER_1: Color = Color {
+    r: 0.62,
+    g: 0.62,
+    b: 0.62,
+    a: 0.93,
+};
+pub const DARK_TICK_TIER_2: Color = Color {
+    r: 0.62,
+    g: 0.62,
+    b: 0.62,
+    a: 0.83,
+};
+pub const DARK_TICK_TIER_3: Color = Color {
+    r: 0.62,
+    g: 0.62,
+    b: 0.62,
+    a: 0.65,
+};
+
+pub const DARK_TEXT_MARK: Color = Color {
+    r: 0.85,
+    g: 0.85,
+    b: 0.85,
+    a: 0.93,
+};
+
+pub const DARK_KNOB_MODULATED_NOTCH: Color = Color {
+    r: 0.7,
+    g: 0.7,
+    b: 0.7,
+    a: 0.4,
+};
+
+pub const DARK_TOOLTIP_BACK: Color = Color::from_rgb(0.15, 0.15, 0.15);
+pub const DARK_TOOLTIP_TEXT: Color = Color::from_rgb(0.88, 0.88, 0.88);
+
+pub const DARK_XY_PAD_RAIL: Color = Color {
+    r: 0.62,
+    g: 0.62,
+    b: 0.62,
+    a: 0.9,
+};
+pub const DARK_XY_PAD_CENTER_LINE: Color = Color {
+    r: 0.62,
+    g: 0.62,
+    b: 0.62,
+    a: 0.4,
+};
+pub const DARK_MULTI_XY_PAD_NODES: [Color; 4] = [
+    Color::from_rgb(0.33, 0.64, 0.92),
+    Color::from_rgb(0.95, 0.52, 0.3),
+    Color::from_rgb(0.45, 0.8, 0.45),
+    Color::from_rgb(0.78, 0.52, 0.9),
+];
 
 /*
 pub const DB_METER_BACK: Color = Color::from_rgb(0.45, 0.45, 0.45);
@@ -81,3 +180,10 @@ pub const DB_METER_CLIP_MARKER: Color = Color {
 pub const DB_METER_GAP: Color = Color::from_rgb(0.25, 0.25, 0.25);
 pub const PHASE_METER_CENTER_LINE: Color = Color::from_rgb(0.92, 0.92, 0.92);
 */
+
+pub const DARK_FOCUS_RING: Color = Color {
+    r: 0.4,
+    g: 0.64,
+    b: 1.0,
+    a: 0.8,
+};
@@ -0,0 +1,32 @@
+//! An outline around a widget which has the keyboard focus
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+
+/// An outline drawn around a widget which has the keyboard focus, so the
+/// widget controlled by the keyboard can be told apart from the others.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FocusRing {
+    /// The color of the outline
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub color: Color,
+    /// The width (thickness) of the outline
+    pub width: f32,
+    /// The gap in pixels between the bounds of the widget and the outline
+    pub offset: f32,
+    /// The radius of the corners of the outline
+    pub border_radius: f32,
+}
+
+impl Default for FocusRing {
+    fn default() -> Self {
+        Self {
+            color: default_colors::FOCUS_RING,
+            width: 2.0,
+            offset: 2.0,
+            border_radius: 3.0,
+        }
+    }
+}
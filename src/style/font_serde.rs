@@ -0,0 +1,48 @@
+//! Serialization of the fonts of the styles
+//!
+//! The default font is written as `Default`, and an external font as its
+//! name and its bytes.
+//!
+//! The renderer only takes fonts which live as long as the program, so the
+//! name and the bytes of a deserialized external font are leaked. Theme
+//! files with external fonts are meant to be loaded once.
+
+use iced_native::Font;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize)]
+#[serde(rename = "Font")]
+enum FontRef<'a> {
+    Default,
+    External { name: &'a str, bytes: &'a [u8] },
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Font")]
+enum FontDef {
+    Default,
+    External { name: String, bytes: Vec<u8> },
+}
+
+pub fn serialize<S: Serializer>(
+    font: &Font,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match font {
+        Font::Default => FontRef::Default,
+        Font::External { name, bytes } => FontRef::External { name, bytes },
+    }
+    .serialize(serializer)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Font, D::Error> {
+    Ok(match FontDef::deserialize(deserializer)? {
+        FontDef::Default => Font::Default,
+        FontDef::External { name, bytes } => Font::External {
+            name: Box::leak(name.into_boxed_str()),
+            bytes: Box::leak(bytes.into_boxed_slice()),
+        },
+    })
+}
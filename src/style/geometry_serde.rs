@@ -0,0 +1,55 @@
+//! Serialization of the rectangles and sizes of the styles
+
+/// Serialization of a rectangle
+pub mod rectangle {
+    use iced_native::Rectangle;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(remote = "Rectangle")]
+    struct RectangleDef {
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+    }
+
+    pub fn serialize<S: Serializer>(
+        rectangle: &Rectangle,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        RectangleDef::serialize(rectangle, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Rectangle, D::Error> {
+        RectangleDef::deserialize(deserializer)
+    }
+}
+
+/// Serialization of a size
+pub mod size {
+    use iced_native::Size;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(remote = "Size")]
+    struct SizeDef {
+        width: f32,
+        height: f32,
+    }
+
+    pub fn serialize<S: Serializer>(
+        size: &Size,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        SizeDef::serialize(size, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Size, D::Error> {
+        SizeDef::deserialize(deserializer)
+    }
+}
@@ -0,0 +1,97 @@
+//! Serialization of the image handles of the styles
+//!
+//! An image is written as the path of its file, its encoded bytes or its
+//! decoded pixels, depending on how its handle was created, so no image
+//! data is lost.
+
+use std::path::{Path, PathBuf};
+
+use iced_native::image::{Data, Handle};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize)]
+#[serde(rename = "Handle")]
+enum HandleRef<'a> {
+    Path(&'a Path),
+    Bytes(&'a [u8]),
+    Pixels {
+        width: u32,
+        height: u32,
+        pixels: &'a [u8],
+    },
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Handle")]
+enum HandleDef {
+    Path(PathBuf),
+    Bytes(Vec<u8>),
+    Pixels {
+        width: u32,
+        height: u32,
+        pixels: Vec<u8>,
+    },
+}
+
+impl<'a> From<&'a Handle> for HandleRef<'a> {
+    fn from(handle: &'a Handle) -> Self {
+        match handle.data() {
+            Data::Path(path) => HandleRef::Path(path),
+            Data::Bytes(bytes) => HandleRef::Bytes(bytes),
+            Data::Pixels {
+                width,
+                height,
+                pixels,
+            } => HandleRef::Pixels {
+                width: *width,
+                height: *height,
+                pixels,
+            },
+        }
+    }
+}
+
+impl From<HandleDef> for Handle {
+    fn from(handle: HandleDef) -> Self {
+        match handle {
+            HandleDef::Path(path) => Handle::from_path(path),
+            HandleDef::Bytes(bytes) => Handle::from_memory(bytes),
+            HandleDef::Pixels {
+                width,
+                height,
+                pixels,
+            } => Handle::from_pixels(width, height, pixels),
+        }
+    }
+}
+
+pub fn serialize<S: Serializer>(
+    handle: &Handle,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    HandleRef::from(handle).serialize(serializer)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Handle, D::Error> {
+    Ok(HandleDef::deserialize(deserializer)?.into())
+}
+
+/// Serialization of an optional image handle
+pub mod option {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        handle: &Option<Handle>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        handle.as_ref().map(HandleRef::from).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Handle>, D::Error> {
+        Ok(Option::<HandleDef>::deserialize(deserializer)?.map(Handle::from))
+    }
+}
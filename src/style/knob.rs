@@ -7,13 +7,20 @@ use iced_native::Color;
 
 pub use iced_graphics::canvas::LineCap;
 
-use crate::style::{default_colors, text_marks, tick_marks};
+use crate::core::{Insets, Offset};
+pub use crate::style::background::{Background, Gradient};
+pub use crate::style::length::StyleLength;
+use crate::style::style_fn::{Status, StyleFn};
+use crate::style::theme::{Palette, Theme};
+use crate::style::transition::Interpolate;
+use crate::style::{text_marks, tick_marks};
 use crate::KnobAngleRange;
 
 /// The appearance of a [`Knob`],
 ///
 /// [`Knob`]: ../../native/knob/struct.Knob.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Style {
     //Texture(TextureStyle),
     /// A classic circular style
@@ -32,8 +39,10 @@ pub enum Style {
 /// [`Knob`]: ../../native/knob/struct.Knob.html
 /// [`Handle`]: https://docs.rs/iced/0.1.1/iced/widget/image/struct.Handle.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextureStyle {
     /// the [`Handle`] to the image texture
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::image_serde"))]
     pub texture: image::Handle,
     /// the width of the knob, not including padding
     pub knob_width: u16,
@@ -46,36 +55,17 @@ pub struct TextureStyle {
 }
 */
 
-/// A length in a [`Knob`] stylesheet
-///
-/// [`Knob`]: ../../native/knob/struct.Knob.html
-#[derive(Debug, Clone)]
-pub enum StyleLength {
-    /// The diameter of the knob scaled to this value
-    Scaled(f32),
-    /// Absolute length in pixels
-    Units(f32),
-}
-
-impl StyleLength {
-    /// Returns the length based on the given knob diameter
-    #[inline]
-    pub fn from_knob_diameter(&self, knob_diameter: f32) -> f32 {
-        match self {
-            StyleLength::Scaled(scale) => knob_diameter * *scale,
-            StyleLength::Units(units) => *units,
-        }
-    }
-}
-
 /// Circle notch
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CircleNotch {
     /// The color of the circle
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub color: Color,
     /// The width of the border
     pub border_width: f32,
     /// The color of the border
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub border_color: Color,
     /// The diameter of the circle
     pub diameter: StyleLength,
@@ -85,21 +75,40 @@ pub struct CircleNotch {
 
 /// Line notch
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LineNotch {
     /// The color of the line
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub color: Color,
     /// The width (thickness) of the line
     pub width: StyleLength,
     /// The length of the line
     pub length: StyleLength,
     /// The cap at the ends of the line
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::line_cap_serde")
+    )]
     pub cap: LineCap,
     /// The offset from the edge of the knob to the center of the notch.
     pub offset: StyleLength,
+    /// Scales the length of the line with the value of the knob. The line is
+    /// `length` long at the maximum value and `length` scaled by this
+    /// factor at the minimum value. Set this to `None` for a constant length.
+    pub length_scale_with_value: Option<f32>,
+    /// The color of the line at the maximum value of the knob. The color of
+    /// the line is interpolated from `color` at the minimum value to this
+    /// color. Set this to `None` for a constant color.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::color_serde::option")
+    )]
+    pub max_value_color: Option<Color>,
 }
 
 /// The shape of the notch
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NotchShape {
     /// No notch
     None,
@@ -114,15 +123,64 @@ pub enum NotchShape {
 /// [`Style`]: enum.Style.html
 /// [`Knob`]: ../../native/knob/struct.Knob.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CircleStyle {
-    /// The color of the knob
-    pub color: Color,
+    /// The color or gradient of the knob. A gradient goes from the bottom
+    /// to the top of the knob.
+    pub color: Background,
     /// The width of the border around the knob
     pub border_width: f32,
     /// The color of the border around the knob
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub border_color: Color,
     /// The shape of the notch
     pub notch: NotchShape,
+    /// An optional drop shadow drawn beneath the knob
+    pub shadow: Option<CircleShadow>,
+    /// An optional highlight drawn on top of the knob
+    pub highlight: Option<CircleHighlight>,
+}
+
+/// A drop shadow beneath a [`CircleStyle`] knob.
+///
+/// The blur is approximated by layering translucent circles that grow out
+/// from the edge of the knob.
+///
+/// [`CircleStyle`]: struct.CircleStyle.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CircleShadow {
+    /// The color of the shadow. Its alpha is spread across all of the layers.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub color: Color,
+    /// The offset of the shadow from the knob in pixels
+    pub offset: Offset,
+    /// How far the shadow extends past the edge of the knob
+    pub spread: StyleLength,
+    /// The number of layers used to approximate the blur
+    pub layers: u16,
+}
+
+/// A highlight on top of a [`CircleStyle`] knob.
+///
+/// The gradient is approximated by layering translucent circles that shrink
+/// from the edge of the knob towards the highlight.
+///
+/// [`CircleStyle`]: struct.CircleStyle.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CircleHighlight {
+    /// The color at the brightest point of the highlight. Its alpha is spread
+    /// across all of the layers.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub color: Color,
+    /// The diameter of the brightest point of the highlight
+    pub diameter: StyleLength,
+    /// The offset from the center of the knob towards the top of the knob
+    /// to the center of the brightest point of the highlight
+    pub offset: StyleLength,
+    /// The number of layers used to approximate the gradient
+    pub layers: u16,
 }
 
 /// A modern arc [`Style`] of a [`Knob`]
@@ -130,16 +188,23 @@ pub struct CircleStyle {
 /// [`Style`]: enum.Style.html
 /// [`Knob`]: ../../native/knob/struct.Knob.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArcStyle {
     /// The width (thickness) of the arc
     pub width: StyleLength,
     /// The color of an empty portion of the arc
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub empty_color: Color,
     /// The color of the filled portion of the arc
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub filled_color: Color,
     /// The shape of the notch
     pub notch: NotchShape,
     /// The cap at the ends of the arc
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::line_cap_serde")
+    )]
     pub cap: LineCap,
 }
 
@@ -150,14 +215,18 @@ pub struct ArcStyle {
 /// [`Style`]: enum.Style.html
 /// [`Knob`]: ../../native/knob/struct.Knob.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArcBipolarStyle {
     /// The width (thickness) of the arc
     pub width: StyleLength,
     /// The color of the empty background portion of the arc
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub empty_color: Color,
     /// The color of the filled portion to the left of the center
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub left_filled_color: Color,
     /// The color of the filled portion to the right of the center
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub right_filled_color: Color,
     /// The shape of the notch when in the center position
     pub notch_center: NotchShape,
@@ -165,6 +234,10 @@ pub struct ArcBipolarStyle {
     /// center. Set this to `None` to only use `notch_center`.
     pub notch_left_right: Option<(NotchShape, NotchShape)>,
     /// The cap at the ends of the arc
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::line_cap_serde")
+    )]
     pub cap: LineCap,
 }
 
@@ -172,6 +245,7 @@ pub struct ArcBipolarStyle {
 ///
 /// [`Knob`]: ../../native/knob/struct.Knob.html
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ValueArcStyle {
     /// The width (thickness) of the arc
     pub width: f32,
@@ -179,14 +253,27 @@ pub struct ValueArcStyle {
     pub offset: f32,
     /// The color of the empty background portion in the arc. Set this to
     /// `None` for no background arc.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::color_serde::option")
+    )]
     pub empty_color: Option<Color>,
     /// The color of a filled portion of the ring. If `right_filled_color` is
     /// `Some`, then this will only apply to the left side of the ring.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub left_filled_color: Color,
     /// The color of a filled portion on the right side of the ring.
     /// Set this to `None` for unipolar mode.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::color_serde::option")
+    )]
     pub right_filled_color: Option<Color>,
     /// The cap at the ends of the arc
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::line_cap_serde")
+    )]
     pub cap: LineCap,
 }
 
@@ -195,6 +282,7 @@ pub struct ValueArcStyle {
 /// [`ModulationRange`]: ../../core/struct.ModulationRange.html
 /// [`Knob`]: ../../native/knob/struct.Knob.html
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ModRangeArcStyle {
     /// The width (thickness) of the arc
     pub width: f32,
@@ -202,13 +290,60 @@ pub struct ModRangeArcStyle {
     pub offset: f32,
     /// The color of an empty background portion in the arc. Set this to
     /// `None` for no background arc.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::color_serde::option")
+    )]
     pub empty_color: Option<Color>,
     /// The color of a filled portion of the arc
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub filled_color: Color,
     /// The color of a filled portion of the arc when `end` is less than
     /// `start`
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub filled_inverse_color: Color,
     /// The cap at the ends of the arc
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::line_cap_serde")
+    )]
+    pub cap: LineCap,
+}
+
+/// A style for the modulated value of a [`Knob`], drawn as a ghost
+/// indicator distinct from the value set by the user.
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModulatedValueStyle {
+    /// The shape of the ghost notch drawn at the modulated value. Set this
+    /// to `NotchShape::None` for no ghost notch.
+    pub notch: NotchShape,
+    /// The arc drawn between the value and the modulated value. Set this to
+    /// `None` for no arc.
+    pub arc: Option<ModulatedValueArcStyle>,
+}
+
+/// A style for the arc between the value and the modulated value of a
+/// [`Knob`]
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModulatedValueArcStyle {
+    /// The width (thickness) of the arc
+    pub width: f32,
+    /// The offset from the edge of the `Knob` in pixels
+    pub offset: f32,
+    /// The color of the arc
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub color: Color,
+    /// The cap at the ends of the arc
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::line_cap_serde")
+    )]
     pub cap: LineCap,
 }
 
@@ -216,6 +351,7 @@ pub struct ModRangeArcStyle {
 ///
 /// [`Knob`]: ../../native/knob/struct.Knob.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TickMarksStyle {
     /// The style of the tick marks
     pub style: tick_marks::Style,
@@ -227,6 +363,7 @@ pub struct TickMarksStyle {
 ///
 /// [`Knob`]: ../../native/knob/struct.Knob.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextMarksStyle {
     /// The style of the text marks
     pub style: text_marks::Style,
@@ -255,6 +392,134 @@ impl std::default::Default for TextMarksStyle {
     }
 }
 
+impl Interpolate for Style {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        match (self, to) {
+            (Style::Circle(from), Style::Circle(to)) => {
+                Style::Circle(from.interpolate(to, t))
+            }
+            (Style::Arc(from), Style::Arc(to)) => {
+                Style::Arc(from.interpolate(to, t))
+            }
+            (Style::ArcBipolar(from), Style::ArcBipolar(to)) => {
+                Style::ArcBipolar(from.interpolate(to, t))
+            }
+            _ => to.clone(),
+        }
+    }
+}
+
+impl Interpolate for CircleNotch {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        CircleNotch {
+            color: self.color.interpolate(&to.color, t),
+            border_width: self.border_width.interpolate(&to.border_width, t),
+            border_color: self.border_color.interpolate(&to.border_color, t),
+            diameter: self.diameter.interpolate(&to.diameter, t),
+            offset: self.offset.interpolate(&to.offset, t),
+        }
+    }
+}
+
+impl Interpolate for LineNotch {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        LineNotch {
+            color: self.color.interpolate(&to.color, t),
+            width: self.width.interpolate(&to.width, t),
+            length: self.length.interpolate(&to.length, t),
+            cap: to.cap,
+            offset: self.offset.interpolate(&to.offset, t),
+            length_scale_with_value: self
+                .length_scale_with_value
+                .interpolate(&to.length_scale_with_value, t),
+            max_value_color: self
+                .max_value_color
+                .interpolate(&to.max_value_color, t),
+        }
+    }
+}
+
+impl Interpolate for NotchShape {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        match (self, to) {
+            (NotchShape::Circle(from), NotchShape::Circle(to)) => {
+                NotchShape::Circle(from.interpolate(to, t))
+            }
+            (NotchShape::Line(from), NotchShape::Line(to)) => {
+                NotchShape::Line(from.interpolate(to, t))
+            }
+            _ => to.clone(),
+        }
+    }
+}
+
+impl Interpolate for CircleStyle {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        CircleStyle {
+            color: self.color.interpolate(&to.color, t),
+            border_width: self.border_width.interpolate(&to.border_width, t),
+            border_color: self.border_color.interpolate(&to.border_color, t),
+            notch: self.notch.interpolate(&to.notch, t),
+            shadow: self.shadow.interpolate(&to.shadow, t),
+            highlight: self.highlight.interpolate(&to.highlight, t),
+        }
+    }
+}
+
+impl Interpolate for CircleShadow {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        CircleShadow {
+            color: self.color.interpolate(&to.color, t),
+            offset: self.offset.interpolate(&to.offset, t),
+            spread: self.spread.interpolate(&to.spread, t),
+            layers: to.layers,
+        }
+    }
+}
+
+impl Interpolate for CircleHighlight {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        CircleHighlight {
+            color: self.color.interpolate(&to.color, t),
+            diameter: self.diameter.interpolate(&to.diameter, t),
+            offset: self.offset.interpolate(&to.offset, t),
+            layers: to.layers,
+        }
+    }
+}
+
+impl Interpolate for ArcStyle {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        ArcStyle {
+            width: self.width.interpolate(&to.width, t),
+            empty_color: self.empty_color.interpolate(&to.empty_color, t),
+            filled_color: self.filled_color.interpolate(&to.filled_color, t),
+            notch: self.notch.interpolate(&to.notch, t),
+            cap: to.cap,
+        }
+    }
+}
+
+impl Interpolate for ArcBipolarStyle {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        ArcBipolarStyle {
+            width: self.width.interpolate(&to.width, t),
+            empty_color: self.empty_color.interpolate(&to.empty_color, t),
+            left_filled_color: self
+                .left_filled_color
+                .interpolate(&to.left_filled_color, t),
+            right_filled_color: self
+                .right_filled_color
+                .interpolate(&to.right_filled_color, t),
+            notch_center: self.notch_center.interpolate(&to.notch_center, t),
+            notch_left_right: self
+                .notch_left_right
+                .interpolate(&to.notch_left_right, t),
+            cap: to.cap,
+        }
+    }
+}
+
 /// A set of rules that dictate the style of a [`Knob`].
 ///
 /// [`Knob`]: ../../native/knob/struct.Knob.html
@@ -269,11 +534,30 @@ pub trait StyleSheet {
     /// [`Knob`]: ../../native/knob/struct.Knob.html
     fn hovered(&self) -> Style;
 
+    /// Produces the style of a focused [`Knob`], which can be controlled
+    /// with the keyboard.
+    ///
+    /// The default is the style of a hovered [`Knob`].
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn focused(&self) -> Style {
+        self.hovered()
+    }
+
     /// Produces the style of a [`Knob`] that is being dragged.
     ///
     /// [`Knob`]: ../../native/knob/struct.Knob.html
     fn dragging(&self) -> Style;
 
+    /// Produces the style of a disabled [`Knob`].
+    ///
+    /// The default is the style of an active [`Knob`].
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn disabled(&self) -> Style {
+        self.active()
+    }
+
     /// a [`KnobAngleRange`] that defines the minimum and maximum angle that the
     /// knob rotates
     ///
@@ -330,33 +614,47 @@ pub trait StyleSheet {
     fn text_marks_style(&self) -> Option<TextMarksStyle> {
         None
     }
+
+    /// The style of the modulated value of a [`Knob`]
+    ///
+    /// For no modulated value indicator, don't override this or set this to
+    /// return `None`.
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn modulated_value_style(&self) -> Option<ModulatedValueStyle> {
+        None
+    }
 }
 
-struct Default;
-impl Default {
-    const ACTIVE_CIRCLE_STYLE: CircleStyle = CircleStyle {
-        color: default_colors::LIGHT_BACK,
+fn active_circle_style(palette: &Palette) -> CircleStyle {
+    CircleStyle {
+        color: Background::Color(palette.back),
         border_width: 1.0,
-        border_color: default_colors::BORDER,
+        border_color: palette.border,
         notch: NotchShape::Circle(CircleNotch {
-            color: default_colors::BORDER,
+            color: palette.border,
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
             diameter: StyleLength::Scaled(0.17),
             offset: StyleLength::Scaled(0.15),
         }),
-    };
+        shadow: None,
+        highlight: None,
+    }
 }
-impl StyleSheet for Default {
+
+impl StyleSheet for Theme {
     fn active(&self) -> Style {
-        Style::Circle(Self::ACTIVE_CIRCLE_STYLE)
+        Style::Circle(active_circle_style(&self.palette()))
     }
 
     #[allow(irrefutable_let_patterns)]
     fn hovered(&self) -> Style {
+        let palette = &self.palette();
+
         Style::Circle(CircleStyle {
-            color: default_colors::KNOB_BACK_HOVER,
-            ..Self::ACTIVE_CIRCLE_STYLE
+            color: Background::Color(palette.knob_back_hover),
+            ..active_circle_style(palette)
         })
     }
 
@@ -364,21 +662,43 @@ impl StyleSheet for Default {
         self.hovered()
     }
 
+    fn disabled(&self) -> Style {
+        let palette = &self.palette();
+
+        Style::Circle(CircleStyle {
+            border_color: palette.border_disabled,
+            notch: NotchShape::Circle(CircleNotch {
+                color: palette.border_disabled,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                diameter: StyleLength::Scaled(0.17),
+                offset: StyleLength::Scaled(0.15),
+            }),
+            ..active_circle_style(palette)
+        })
+    }
+
     fn tick_marks_style(&self) -> Option<TickMarksStyle> {
+        let palette = &self.palette();
+
         Some(TickMarksStyle {
             style: tick_marks::Style {
-                tier_1: tick_marks::Shape::Circle {
-                    diameter: 4.0,
-                    color: default_colors::TICK_TIER_1,
-                },
-                tier_2: tick_marks::Shape::Circle {
-                    diameter: 2.0,
-                    color: default_colors::TICK_TIER_2,
-                },
-                tier_3: tick_marks::Shape::Circle {
-                    diameter: 2.0,
-                    color: default_colors::TICK_TIER_3,
-                },
+                tiers: vec![
+                    tick_marks::Shape::Circle {
+                        diameter: 4.0,
+                        color: palette.tick_tiers[0],
+                    },
+                    tick_marks::Shape::Circle {
+                        diameter: 2.0,
+                        color: palette.tick_tiers[1],
+                    },
+                    tick_marks::Shape::Circle {
+                        diameter: 2.0,
+                        color: palette.tick_tiers[2],
+                    },
+                ],
+                gradient: None,
+                insets: Insets::ZERO,
             },
             offset: 3.5,
         })
@@ -386,17 +706,217 @@ impl StyleSheet for Default {
 
     fn text_marks_style(&self) -> Option<TextMarksStyle> {
         Some(TextMarksStyle {
-            style: text_marks::Style::default(),
+            style: text_marks::Style::from(self),
             offset: 14.0,
             h_char_offset: 3.0,
             v_offset: -0.75,
         })
     }
+
+    fn modulated_value_style(&self) -> Option<ModulatedValueStyle> {
+        let palette = &self.palette();
+
+        Some(ModulatedValueStyle {
+            notch: NotchShape::Circle(CircleNotch {
+                color: palette.modulated_notch,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                diameter: StyleLength::Scaled(0.17),
+                offset: StyleLength::Scaled(0.15),
+            }),
+            arc: None,
+        })
+    }
+}
+
+impl<F> StyleSheet for StyleFn<F>
+where
+    F: Fn(Status) -> Style,
+{
+    fn active(&self) -> Style {
+        self.style(Status::Active)
+    }
+
+    fn hovered(&self) -> Style {
+        self.style(Status::Hovered)
+    }
+
+    fn focused(&self) -> Style {
+        self.style(Status::Focused)
+    }
+
+    fn dragging(&self) -> Style {
+        self.style(Status::Dragging)
+    }
+
+    fn disabled(&self) -> Style {
+        self.style(Status::Disabled)
+    }
+
+    fn tick_marks_style(&self) -> Option<TickMarksStyle> {
+        Theme::default().tick_marks_style()
+    }
+
+    fn text_marks_style(&self) -> Option<TextMarksStyle> {
+        Theme::default().text_marks_style()
+    }
+
+    fn modulated_value_style(&self) -> Option<ModulatedValueStyle> {
+        Theme::default().modulated_value_style()
+    }
+}
+
+/// The styles of a [`Knob`] in each of its states, which make up a
+/// [`StyleSheet`].
+///
+/// With the `serde` feature, they can be written to and loaded from a
+/// [`ThemeFile`]. The styles missing from a theme file are taken from
+/// [`Theme::Light`].
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+/// [`StyleSheet`]: trait.StyleSheet.html
+/// [`ThemeFile`]: ../theme/struct.ThemeFile.html
+/// [`Theme::Light`]: ../theme/enum.Theme.html#variant.Light
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Styles {
+    /// The style of an active [`Knob`]
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    pub active: Style,
+    /// The style of a hovered [`Knob`]
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    pub hovered: Style,
+    /// The style of a focused [`Knob`]
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    pub focused: Style,
+    /// The style of a [`Knob`] that is being dragged
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    pub dragging: Style,
+    /// The style of a disabled [`Knob`]
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    pub disabled: Style,
+    /// The range of the angles a [`Knob`] rotates
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    pub angle_range: KnobAngleRange,
+    /// The style of the tick marks around a [`Knob`]
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    pub tick_marks: Option<TickMarksStyle>,
+    /// The style of the value arc around a [`Knob`]
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    pub value_arc: Option<ValueArcStyle>,
+    /// The style of the modulation range arc around a [`Knob`]
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    pub mod_range_arc: Option<ModRangeArcStyle>,
+    /// The style of the second modulation range arc around a [`Knob`]
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    pub mod_range_arc_2: Option<ModRangeArcStyle>,
+    /// The style of the text marks around a [`Knob`]
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    pub text_marks: Option<TextMarksStyle>,
+    /// The style of the modulated value of a [`Knob`]
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    pub modulated_value: Option<ModulatedValueStyle>,
+}
+
+impl Styles {
+    /// Captures the styles produced by a [`StyleSheet`], such as a
+    /// [`Theme`], so they can be edited or written to a theme file.
+    ///
+    /// [`StyleSheet`]: trait.StyleSheet.html
+    /// [`Theme`]: ../theme/enum.Theme.html
+    pub fn from_style_sheet(style_sheet: &dyn StyleSheet) -> Self {
+        Self {
+            active: style_sheet.active(),
+            hovered: style_sheet.hovered(),
+            focused: style_sheet.focused(),
+            dragging: style_sheet.dragging(),
+            disabled: style_sheet.disabled(),
+            angle_range: style_sheet.angle_range(),
+            tick_marks: style_sheet.tick_marks_style(),
+            value_arc: style_sheet.value_arc_style(),
+            mod_range_arc: style_sheet.mod_range_arc_style(),
+            mod_range_arc_2: style_sheet.mod_range_arc_style_2(),
+            text_marks: style_sheet.text_marks_style(),
+            modulated_value: style_sheet.modulated_value_style(),
+        }
+    }
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self::from_style_sheet(&Theme::default())
+    }
+}
+
+impl StyleSheet for Styles {
+    fn active(&self) -> Style {
+        self.active.clone()
+    }
+
+    fn hovered(&self) -> Style {
+        self.hovered.clone()
+    }
+
+    fn focused(&self) -> Style {
+        self.focused.clone()
+    }
+
+    fn dragging(&self) -> Style {
+        self.dragging.clone()
+    }
+
+    fn disabled(&self) -> Style {
+        self.disabled.clone()
+    }
+
+    fn angle_range(&self) -> KnobAngleRange {
+        self.angle_range.clone()
+    }
+
+    fn tick_marks_style(&self) -> Option<TickMarksStyle> {
+        self.tick_marks.clone()
+    }
+
+    fn value_arc_style(&self) -> Option<ValueArcStyle> {
+        self.value_arc
+    }
+
+    fn mod_range_arc_style(&self) -> Option<ModRangeArcStyle> {
+        self.mod_range_arc
+    }
+
+    fn mod_range_arc_style_2(&self) -> Option<ModRangeArcStyle> {
+        self.mod_range_arc_2
+    }
+
+    fn text_marks_style(&self) -> Option<TextMarksStyle> {
+        self.text_marks.clone()
+    }
+
+    fn modulated_value_style(&self) -> Option<ModulatedValueStyle> {
+        self.modulated_value.clone()
+    }
 }
 
 impl std::default::Default for Box<dyn StyleSheet> {
     fn default() -> Self {
-        Box::new(Default)
+        Box::new(Theme::Light)
     }
 }
 
@@ -0,0 +1,82 @@
+//! Lengths in stylesheets which scale with the size of the widget
+
+/// The number of logical pixels in a typographic point, at 96 pixels per
+/// inch
+const PIXELS_PER_POINT: f32 = 96.0 / 72.0;
+
+/// A length in a stylesheet
+///
+/// Relative lengths are based on the shorter side of the widget, which is
+/// the diameter of a [`Knob`], the height of an [`HSlider`], or the width
+/// of a [`VSlider`].
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::knob::StyleLength;
+///
+/// let width = StyleLength::Clamped {
+///     min: 2.0,
+///     max: 6.0,
+///     scale: 0.1,
+/// };
+///
+/// assert_eq!(width.resolve(30.0), 3.0);
+/// assert_eq!(width.resolve(100.0), 6.0);
+/// assert_eq!(StyleLength::Percent(25.0).resolve(40.0), 10.0);
+/// assert_eq!(StyleLength::Points(9.0).resolve(40.0), 12.0);
+/// ```
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+/// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StyleLength {
+    /// The shorter side of the widget scaled to this value
+    Scaled(f32),
+    /// Absolute length in pixels
+    Units(f32),
+    /// A percentage of the shorter side of the widget, in the range
+    /// `[0.0, 100.0]`
+    Percent(f32),
+    /// The shorter side of the widget scaled to `scale`, kept between `min`
+    /// and `max` pixels, so the length follows the size of the widget
+    /// without getting too thin or too thick
+    Clamped {
+        /// The minimum length in pixels
+        min: f32,
+        /// The maximum length in pixels
+        max: f32,
+        /// The scale applied to the shorter side of the widget
+        scale: f32,
+    },
+    /// Absolute length in typographic points (1/72 of an inch), at 96
+    /// pixels per inch. Like every length in pixels, it is then scaled by
+    /// the scale factor of the window, which follows the DPI of the
+    /// display.
+    Points(f32),
+}
+
+impl StyleLength {
+    /// Returns the length in pixels for a widget whose shorter side is
+    /// `shorter_side` pixels long
+    #[inline]
+    pub fn resolve(&self, shorter_side: f32) -> f32 {
+        match self {
+            StyleLength::Scaled(scale) => shorter_side * *scale,
+            StyleLength::Units(units) => *units,
+            StyleLength::Percent(percent) => shorter_side * *percent / 100.0,
+            StyleLength::Clamped { min, max, scale } => {
+                (shorter_side * *scale).max(*min).min(*max)
+            }
+            StyleLength::Points(points) => *points * PIXELS_PER_POINT,
+        }
+    }
+
+    /// Returns the length based on the given knob diameter
+    #[inline]
+    pub fn from_knob_diameter(&self, knob_diameter: f32) -> f32 {
+        self.resolve(knob_diameter)
+    }
+}
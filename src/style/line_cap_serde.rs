@@ -0,0 +1,25 @@
+//! Serialization of the line caps of the knob styles
+
+use iced_graphics::canvas::LineCap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "LineCap")]
+enum LineCapDef {
+    Butt,
+    Square,
+    Round,
+}
+
+pub fn serialize<S: Serializer>(
+    line_cap: &LineCap,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    LineCapDef::serialize(line_cap, serializer)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<LineCap, D::Error> {
+    LineCapDef::deserialize(deserializer)
+}
@@ -1,16 +1,40 @@
 //! Various styles for widgets
 
+#[cfg(feature = "serde")]
+mod color_serde;
 mod default_colors;
+#[cfg(feature = "serde")]
+mod font_serde;
+#[cfg(feature = "serde")]
+mod geometry_serde;
+#[cfg(feature = "serde")]
+mod image_serde;
+#[cfg(feature = "serde")]
+mod line_cap_serde;
 
 pub mod h_slider;
 pub mod knob;
 pub mod mod_range_input;
+pub mod multi_xy_pad;
 pub mod ramp;
 pub mod v_slider;
 pub mod xy_pad;
 
+pub mod background;
+pub mod focus_ring;
+pub mod length;
+pub mod presets;
+pub mod shadow;
+pub mod sprite;
+pub mod style_fn;
 pub mod text_marks;
+pub mod theme;
 pub mod tick_marks;
+pub mod tooltip;
+pub mod transition;
+
+pub use style_fn::{Status, StyleFn};
+pub use theme::{Palette, Theme, ThemeFile};
 
 //pub mod db_meter;
 //pub mod phase_meter;
@@ -4,12 +4,13 @@
 
 use iced_native::Color;
 
-use crate::style::default_colors;
+use crate::style::theme::{Palette, Theme};
 
 /// The appearance of an [`ModRangeInput`]
 ///
 /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Style {
     /// A circle style
     Circle(CircleStyle),
@@ -28,12 +29,15 @@ pub enum Style {
 /// [`Style`]: enum.Style.html
 /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CircleStyle {
     /// Color of the circle
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub color: Color,
     /// Width of the border
     pub border_width: f32,
     /// Color of the border
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub border_color: Color,
 }
 
@@ -42,14 +46,17 @@ pub struct CircleStyle {
 /// [`Style`]: enum.Style.html
 /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SquareStyle {
     /// Color of the square
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub color: Color,
     /// Width of the border
     pub border_width: f32,
     /// Radius of the border
     pub border_radius: f32,
     /// Color of the border
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub border_color: Color,
 }
 
@@ -67,29 +74,41 @@ pub trait StyleSheet {
     /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
     fn hovered(&self) -> Style;
 
+    /// Produces the style of a focused [`ModRangeInput`], which can be controlled
+    /// with the keyboard.
+    ///
+    /// The default is the style of a hovered [`ModRangeInput`].
+    ///
+    /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+    fn focused(&self) -> Style {
+        self.hovered()
+    }
+
     /// Produces the style of a [`ModRangeInput`] that is being dragged.
     ///
     /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
     fn dragging(&self) -> Style;
 }
 
-struct Default;
-impl Default {
-    const ACTIVE_STYLE: CircleStyle = CircleStyle {
-        color: default_colors::LIGHT_BACK,
+fn active_style(palette: &Palette) -> CircleStyle {
+    CircleStyle {
+        color: palette.back,
         border_width: 1.0,
-        border_color: default_colors::BORDER,
-    };
+        border_color: palette.border,
+    }
 }
-impl StyleSheet for Default {
+
+impl StyleSheet for Theme {
     fn active(&self) -> Style {
-        Style::Circle(Self::ACTIVE_STYLE)
+        Style::Circle(active_style(&self.palette()))
     }
 
     fn hovered(&self) -> Style {
+        let palette = &self.palette();
+
         Style::Circle(CircleStyle {
-            color: default_colors::KNOB_BACK_HOVER,
-            ..Self::ACTIVE_STYLE
+            color: palette.knob_back_hover,
+            ..active_style(palette)
         })
     }
 
@@ -124,9 +143,85 @@ impl StyleSheet for DefaultInvisible {
     }
 }
 
+/// The styles of a [`ModRangeInput`] in each of its states, which make up a
+/// [`StyleSheet`].
+///
+/// With the `serde` feature, they can be written to and loaded from a
+/// [`ThemeFile`]. The styles missing from a theme file are taken from
+/// [`Theme::Light`].
+///
+/// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+/// [`StyleSheet`]: trait.StyleSheet.html
+/// [`ThemeFile`]: ../theme/struct.ThemeFile.html
+/// [`Theme::Light`]: ../theme/enum.Theme.html#variant.Light
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Styles {
+    /// The style of an active [`ModRangeInput`]
+    ///
+    /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+    pub active: Style,
+    /// The style of a hovered [`ModRangeInput`]
+    ///
+    /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+    pub hovered: Style,
+    /// The style of a focused [`ModRangeInput`]
+    ///
+    /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+    pub focused: Style,
+    /// The style of a [`ModRangeInput`] that is being dragged
+    ///
+    /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+    pub dragging: Style,
+}
+
+impl Styles {
+    /// Captures the styles produced by a [`StyleSheet`], such as a
+    /// [`Theme`], so they can be edited or written to a theme file.
+    ///
+    /// [`StyleSheet`]: trait.StyleSheet.html
+    /// [`Theme`]: ../theme/enum.Theme.html
+    pub fn from_style_sheet(style_sheet: &dyn StyleSheet) -> Self {
+        Self {
+            active: style_sheet.active(),
+            hovered: style_sheet.hovered(),
+            focused: style_sheet.focused(),
+            dragging: style_sheet.dragging(),
+        }
+    }
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self::from_style_sheet(&Theme::default())
+    }
+}
+
+impl StyleSheet for Styles {
+    fn active(&self) -> Style {
+        self.active.clone()
+    }
+
+    fn hovered(&self) -> Style {
+        self.hovered.clone()
+    }
+
+    fn focused(&self) -> Style {
+        self.focused.clone()
+    }
+
+    fn dragging(&self) -> Style {
+        self.dragging.clone()
+    }
+}
+
 impl std::default::Default for Box<dyn StyleSheet> {
     fn default() -> Self {
-        Box::new(Default)
+        Box::new(Theme::Light)
     }
 }
 
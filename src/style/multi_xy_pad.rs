@@ -0,0 +1,264 @@
+//! Style for the [`MultiXYPad`] widget
+//!
+//! [`MultiXYPad`]: ../native/multi_xy_pad/struct.MultiXYPad.html
+
+use iced_native::Color;
+
+use crate::style::theme::{Palette, Theme};
+pub use crate::style::xy_pad::{
+    HandleCircle, HandleShape, HandleSquare, Shadow,
+};
+
+/// The appearance of the pad of a [`MultiXYPad`].
+///
+/// [`MultiXYPad`]: ../../native/multi_xy_pad/struct.MultiXYPad.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Style {
+    /// the color of the background square
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub back_color: Color,
+    /// the width of the border of the background square
+    pub border_width: f32,
+    /// the color of the border of the background square
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub border_color: Color,
+    /// the width of the center line markings
+    pub center_line_width: f32,
+    /// the color of the center line markings
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub center_line_color: Color,
+}
+
+/// A set of rules that dictate the style of a [`MultiXYPad`].
+///
+/// [`MultiXYPad`]: ../../native/multi_xy_pad/struct.MultiXYPad.html
+pub trait StyleSheet {
+    /// Produces the style of an active [`MultiXYPad`].
+    ///
+    /// [`MultiXYPad`]: ../../native/multi_xy_pad/struct.MultiXYPad.html
+    fn active(&self) -> Style;
+
+    /// Produces the style of a hovered [`MultiXYPad`].
+    ///
+    /// [`MultiXYPad`]: ../../native/multi_xy_pad/struct.MultiXYPad.html
+    fn hovered(&self) -> Style;
+
+    /// Produces the style of a focused [`MultiXYPad`], which can be controlled
+    /// with the keyboard.
+    ///
+    /// The default is the style of a hovered [`MultiXYPad`].
+    ///
+    /// [`MultiXYPad`]: ../../native/multi_xy_pad/struct.MultiXYPad.html
+    fn focused(&self) -> Style {
+        self.hovered()
+    }
+
+    /// Produces the style of a [`MultiXYPad`] that is being dragged.
+    ///
+    /// [`MultiXYPad`]: ../../native/multi_xy_pad/struct.MultiXYPad.html
+    fn dragging(&self) -> Style;
+
+    /// Produces the [`HandleShape`] of the node at `index` of a
+    /// [`MultiXYPad`], so each node can have its own color.
+    ///
+    /// [`HandleShape`]: ../xy_pad/enum.HandleShape.html
+    /// [`MultiXYPad`]: ../../native/multi_xy_pad/struct.MultiXYPad.html
+    fn node(&self, index: usize) -> HandleShape;
+
+    /// Produces the [`HandleShape`] of the node at `index` of a
+    /// [`MultiXYPad`] while it is being dragged.
+    ///
+    /// The default is the `node()` style.
+    ///
+    /// [`HandleShape`]: ../xy_pad/enum.HandleShape.html
+    /// [`MultiXYPad`]: ../../native/multi_xy_pad/struct.MultiXYPad.html
+    fn node_dragging(&self, index: usize) -> HandleShape {
+        self.node(index)
+    }
+}
+
+fn active_style(palette: &Palette) -> Style {
+    Style {
+        back_color: palette.back,
+        border_width: 1.0,
+        border_color: palette.border,
+        center_line_width: 1.0,
+        center_line_color: palette.xy_pad_center_line,
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        active_style(&self.palette())
+    }
+
+    fn hovered(&self) -> Style {
+        let palette = &self.palette();
+
+        Style {
+            back_color: palette.back_hover,
+            ..active_style(palette)
+        }
+    }
+
+    fn dragging(&self) -> Style {
+        let palette = &self.palette();
+
+        Style {
+            back_color: palette.back_drag,
+            ..active_style(palette)
+        }
+    }
+
+    fn node(&self, index: usize) -> HandleShape {
+        let palette = &self.palette();
+        let colors = palette.nodes;
+
+        HandleShape::Circle(HandleCircle {
+            color: colors[index % colors.len()],
+            diameter: 11.0,
+            border_width: 2.0,
+            border_color: palette.border,
+            shadow: None,
+        })
+    }
+
+    fn node_dragging(&self, index: usize) -> HandleShape {
+        let palette = &self.palette();
+        let colors = palette.nodes;
+
+        HandleShape::Circle(HandleCircle {
+            color: colors[index % colors.len()],
+            diameter: 9.0,
+            border_width: 2.0,
+            border_color: palette.border,
+            shadow: None,
+        })
+    }
+}
+
+/// The styles of a [`MultiXYPad`] in each of its states, which make up a
+/// [`StyleSheet`].
+///
+/// With the `serde` feature, they can be written to and loaded from a
+/// [`ThemeFile`]. The styles missing from a theme file are taken from
+/// [`Theme::Light`].
+///
+/// [`MultiXYPad`]: ../../native/multi_xy_pad/struct.MultiXYPad.html
+/// [`StyleSheet`]: trait.StyleSheet.html
+/// [`ThemeFile`]: ../theme/struct.ThemeFile.html
+/// [`Theme::Light`]: ../theme/enum.Theme.html#variant.Light
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Styles {
+    /// The style of an active [`MultiXYPad`]
+    ///
+    /// [`MultiXYPad`]: ../../native/multi_xy_pad/struct.MultiXYPad.html
+    pub active: Style,
+    /// The style of a hovered [`MultiXYPad`]
+    ///
+    /// [`MultiXYPad`]: ../../native/multi_xy_pad/struct.MultiXYPad.html
+    pub hovered: Style,
+    /// The style of a focused [`MultiXYPad`]
+    ///
+    /// [`MultiXYPad`]: ../../native/multi_xy_pad/struct.MultiXYPad.html
+    pub focused: Style,
+    /// The style of a [`MultiXYPad`] that is being dragged
+    ///
+    /// [`MultiXYPad`]: ../../native/multi_xy_pad/struct.MultiXYPad.html
+    pub dragging: Style,
+    /// The shapes of the nodes of a [`MultiXYPad`], repeated for the nodes
+    /// after the last one
+    ///
+    /// [`MultiXYPad`]: ../../native/multi_xy_pad/struct.MultiXYPad.html
+    pub nodes: Vec<HandleShape>,
+    /// The shapes of the nodes of a [`MultiXYPad`] while they are dragged,
+    /// repeated for the nodes after the last one
+    ///
+    /// [`MultiXYPad`]: ../../native/multi_xy_pad/struct.MultiXYPad.html
+    pub nodes_dragging: Vec<HandleShape>,
+}
+
+impl Styles {
+    /// Captures the styles produced by a [`StyleSheet`], such as a
+    /// [`Theme`], so they can be edited or written to a theme file.
+    ///
+    /// The shapes of as many nodes as a [`Palette`] has colors are
+    /// captured.
+    ///
+    /// [`StyleSheet`]: trait.StyleSheet.html
+    /// [`Theme`]: ../theme/enum.Theme.html
+    /// [`Palette`]: ../theme/struct.Palette.html
+    pub fn from_style_sheet(style_sheet: &dyn StyleSheet) -> Self {
+        let nodes = 0..Palette::LIGHT.nodes.len();
+
+        Self {
+            active: style_sheet.active(),
+            hovered: style_sheet.hovered(),
+            focused: style_sheet.focused(),
+            dragging: style_sheet.dragging(),
+            nodes: nodes.clone().map(|i| style_sheet.node(i)).collect(),
+            nodes_dragging: nodes
+                .map(|i| style_sheet.node_dragging(i))
+                .collect(),
+        }
+    }
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self::from_style_sheet(&Theme::default())
+    }
+}
+
+impl StyleSheet for Styles {
+    fn active(&self) -> Style {
+        self.active.clone()
+    }
+
+    fn hovered(&self) -> Style {
+        self.hovered.clone()
+    }
+
+    fn focused(&self) -> Style {
+        self.focused.clone()
+    }
+
+    fn dragging(&self) -> Style {
+        self.dragging.clone()
+    }
+
+    fn node(&self, index: usize) -> HandleShape {
+        match self.nodes.iter().cycle().nth(index) {
+            Some(node) => node.clone(),
+            None => Theme::default().node(index),
+        }
+    }
+
+    fn node_dragging(&self, index: usize) -> HandleShape {
+        match self.nodes_dragging.iter().cycle().nth(index) {
+            Some(node) => node.clone(),
+            None => Theme::default().node_dragging(index),
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Theme::Light)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
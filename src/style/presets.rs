@@ -0,0 +1,865 @@
+//! Complete, coordinated looks for all the widgets
+//!
+//! A [`Preset`] implements the `StyleSheet` of every widget, so it can be
+//! passed directly to the `style()` method of a widget:
+//!
+//! ```
+//! use iced_audio::{h_slider, knob, style::presets::Preset};
+//!
+//! let knob_style: Box<dyn knob::StyleSheet> = Preset::Hardware.into();
+//! let h_slider_style: Box<dyn h_slider::StyleSheet> = Preset::Hardware.into();
+//! ```
+//!
+//! [`Preset`]: enum.Preset.html
+
+use iced_native::Color;
+
+use crate::core::{Insets, LockedAxis, Offset};
+use crate::style::background::{Background, Gradient};
+use crate::style::length::StyleLength;
+use crate::style::shadow::Shadow;
+use crate::style::theme::Palette;
+use crate::style::{
+    h_slider, knob, mod_range_input, multi_xy_pad, ramp, text_marks,
+    tick_marks, v_slider, xy_pad,
+};
+
+/// A complete look for all the widgets
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Preset {
+    /// Flat widgets with a blue accent, meant for a light background
+    FlatLight,
+    /// Flat widgets with a blue accent, meant for a dark background
+    FlatDark,
+    /// Skeuomorphic widgets modeled after hardware, with shaded knobs,
+    /// notched slider rails and an amber accent, meant for a dark background
+    Hardware,
+    /// Thin black and white widgets, meant for a white background
+    MinimalMono,
+}
+
+/// The colors of a [`Preset`]
+///
+/// [`Preset`]: enum.Preset.html
+struct Colors {
+    back: Color,
+    back_hover: Color,
+    border: Color,
+    border_disabled: Color,
+    empty: Color,
+    filled: Color,
+    filled_hover: Color,
+    handle: Color,
+    tick: Color,
+    text: Color,
+    nodes: [Color; 4],
+}
+
+impl Colors {
+    const FLAT_LIGHT: Colors = Colors {
+        back: Color::from_rgb(0.97, 0.97, 0.97),
+        back_hover: Color::from_rgb(0.93, 0.93, 0.93),
+        border: Color::from_rgb(0.75, 0.75, 0.75),
+        border_disabled: Color::from_rgb(0.8, 0.8, 0.8),
+        empty: Color::from_rgb(0.86, 0.86, 0.86),
+        filled: Color::from_rgb(0.18, 0.52, 0.82),
+        filled_hover: Color::from_rgb(0.26, 0.6, 0.9),
+        handle: Color::from_rgb(0.26, 0.26, 0.26),
+        tick: Color::from_rgb(0.56, 0.56, 0.56),
+        text: Color::from_rgb(0.26, 0.26, 0.26),
+        nodes: Palette::LIGHT.nodes,
+    };
+
+    const FLAT_DARK: Colors = Colors {
+        back: Color::from_rgb(0.18, 0.18, 0.18),
+        back_hover: Color::from_rgb(0.22, 0.22, 0.22),
+        border: Color::from_rgb(0.32, 0.32, 0.32),
+        border_disabled: Color::from_rgb(0.3, 0.3, 0.3),
+        empty: Color::from_rgb(0.3, 0.3, 0.3),
+        filled: Color::from_rgb(0.33, 0.64, 0.92),
+        filled_hover: Color::from_rgb(0.45, 0.72, 0.95),
+        handle: Color::from_rgb(0.9, 0.9, 0.9),
+        tick: Color::from_rgb(0.6, 0.6, 0.6),
+        text: Color::from_rgb(0.85, 0.85, 0.85),
+        nodes: Palette::DARK.nodes,
+    };
+
+    const HARDWARE: Colors = Colors {
+        back: Color::from_rgb(0.22, 0.22, 0.23),
+        back_hover: Color::from_rgb(0.26, 0.26, 0.27),
+        border: Color::from_rgb(0.06, 0.06, 0.06),
+        border_disabled: Color::from_rgb(0.35, 0.35, 0.35),
+        empty: Color::from_rgb(0.1, 0.1, 0.1),
+        filled: Color::from_rgb(0.96, 0.62, 0.2),
+        filled_hover: Color::from_rgb(1.0, 0.7, 0.3),
+        handle: Color::from_rgb(0.82, 0.82, 0.8),
+        tick: Color::from_rgb(0.75, 0.75, 0.72),
+        text: Color::from_rgb(0.8, 0.8, 0.78),
+        nodes: Palette::DARK.nodes,
+    };
+
+    const MINIMAL_MONO: Colors = Colors {
+        back: Color::WHITE,
+        back_hover: Color::from_rgb(0.95, 0.95, 0.95),
+        border: Color::BLACK,
+        border_disabled: Color::from_rgb(0.7, 0.7, 0.7),
+        empty: Color::from_rgb(0.88, 0.88, 0.88),
+        filled: Color::BLACK,
+        filled_hover: Color::from_rgb(0.25, 0.25, 0.25),
+        handle: Color::BLACK,
+        tick: Color::BLACK,
+        text: Color::BLACK,
+        nodes: [Color::BLACK; 4],
+    };
+}
+
+/// The state of a widget, for the styles which only change a few colors
+/// between states
+#[derive(Copy, Clone, PartialEq)]
+enum State {
+    Active,
+    Hovered,
+    Disabled,
+}
+
+fn with_alpha(color: Color, a: f32) -> Color {
+    Color { a, ..color }
+}
+
+impl Preset {
+    fn colors(&self) -> Colors {
+        match self {
+            Preset::FlatLight => Colors::FLAT_LIGHT,
+            Preset::FlatDark => Colors::FLAT_DARK,
+            Preset::Hardware => Colors::HARDWARE,
+            Preset::MinimalMono => Colors::MINIMAL_MONO,
+        }
+    }
+
+    fn filled(&self, state: State) -> Color {
+        let colors = self.colors();
+
+        match state {
+            State::Active => colors.filled,
+            State::Hovered => colors.filled_hover,
+            State::Disabled => colors.border_disabled,
+        }
+    }
+
+    fn text_marks(&self) -> text_marks::Style {
+        text_marks::Style {
+            color: self.colors().text,
+            ..Default::default()
+        }
+    }
+
+    /// The tick marks as lines of the given lengths, from tier 1 to tier 3
+    fn tick_lines(&self, lengths: [f32; 3]) -> tick_marks::Style {
+        let color = self.colors().tick;
+        let alphas = [0.93, 0.83, 0.65];
+        let widths = match self {
+            Preset::MinimalMono => [1.0, 1.0, 1.0],
+            _ => [2.0, 1.0, 1.0],
+        };
+
+        tick_marks::Style {
+            tiers: (0..3)
+                .map(|i| tick_marks::Shape::Line {
+                    length: lengths[i],
+                    width: widths[i],
+                    color: with_alpha(color, alphas[i]),
+                    line_cap: tick_marks::LineCap::Butt,
+                    dash: None,
+                })
+                .collect(),
+            gradient: None,
+            insets: Insets::ZERO,
+        }
+    }
+
+    fn knob_notch(&self, state: State) -> knob::NotchShape {
+        let color = if state == State::Disabled {
+            self.colors().border_disabled
+        } else {
+            self.colors().handle
+        };
+
+        let (width, length) = match self {
+            Preset::MinimalMono => (0.04, 0.2),
+            Preset::Hardware => (0.06, 0.25),
+            _ => (0.09, 0.17),
+        };
+
+        knob::NotchShape::Line(knob::LineNotch {
+            color,
+            width: StyleLength::Scaled(width),
+            length: StyleLength::Scaled(length),
+            cap: knob::LineCap::Round,
+            offset: StyleLength::Scaled(0.15),
+            length_scale_with_value: None,
+            max_value_color: None,
+        })
+    }
+
+    fn knob(&self, state: State) -> knob::Style {
+        let colors = self.colors();
+
+        match self {
+            Preset::Hardware => knob::Style::Circle(knob::CircleStyle {
+                color: Background::Gradient(Gradient {
+                    start: colors.empty,
+                    middle: None,
+                    end: if state == State::Hovered {
+                        colors.back_hover
+                    } else {
+                        colors.back
+                    },
+                }),
+                border_width: 1.0,
+                border_color: colors.border,
+                notch: self.knob_notch(state),
+                shadow: Some(knob::CircleShadow {
+                    color: with_alpha(Color::BLACK, 0.6),
+                    offset: Offset { x: 0.0, y: 2.0 },
+                    spread: StyleLength::Scaled(0.08),
+                    layers: 6,
+                }),
+                highlight: Some(knob::CircleHighlight {
+                    color: with_alpha(Color::WHITE, 0.12),
+                    diameter: StyleLength::Scaled(0.3),
+                    offset: StyleLength::Scaled(0.25),
+                    layers: 6,
+                }),
+            }),
+            Preset::MinimalMono => knob::Style::Arc(knob::ArcStyle {
+                width: StyleLength::Scaled(0.05),
+                empty_color: colors.empty,
+                filled_color: self.filled(state),
+                notch: self.knob_notch(state),
+                cap: knob::LineCap::Butt,
+            }),
+            Preset::FlatLight | Preset::FlatDark => {
+                knob::Style::Arc(knob::ArcStyle {
+                    width: StyleLength::Scaled(0.12),
+                    empty_color: colors.empty,
+                    filled_color: self.filled(state),
+                    notch: self.knob_notch(state),
+                    cap: knob::LineCap::Round,
+                })
+            }
+        }
+    }
+
+    fn xy_pad_handle(&self, state: State) -> xy_pad::HandleShape {
+        let colors = self.colors();
+
+        match self {
+            Preset::FlatLight | Preset::FlatDark => {
+                xy_pad::HandleShape::Circle(xy_pad::HandleCircle {
+                    color: self.filled(state),
+                    diameter: 11.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                    shadow: None,
+                })
+            }
+            Preset::Hardware => {
+                xy_pad::HandleShape::Circle(xy_pad::HandleCircle {
+                    color: colors.handle,
+                    diameter: if state == State::Hovered { 11.0 } else { 13.0 },
+                    border_width: 2.0,
+                    border_color: self.filled(state),
+                    shadow: self.handle_shadow(),
+                })
+            }
+            Preset::MinimalMono => {
+                xy_pad::HandleShape::Square(xy_pad::HandleSquare {
+                    color: if state == State::Hovered {
+                        colors.back_hover
+                    } else {
+                        colors.back
+                    },
+                    size: 10,
+                    border_width: 1.0,
+                    border_radius: 0.0,
+                    border_color: colors.border,
+                    shadow: None,
+                })
+            }
+        }
+    }
+
+    fn xy_pad(&self, state: State) -> xy_pad::Style {
+        let colors = self.colors();
+
+        let (rail_width, rail_color) = match self {
+            Preset::FlatLight | Preset::FlatDark => {
+                (2.0, with_alpha(colors.filled, 0.6))
+            }
+            Preset::Hardware => (1.0, with_alpha(colors.filled, 0.8)),
+            Preset::MinimalMono => (1.0, colors.border),
+        };
+
+        xy_pad::Style {
+            rail_width,
+            h_rail_color: rail_color,
+            v_rail_color: rail_color,
+            handle: self.xy_pad_handle(state),
+            back_color: xy_pad::Background::Color(self.pad_back()),
+            border_width: 1.0,
+            border_color: colors.border,
+            back_image: None,
+            center_line_width: 1.0,
+            center_line_color: with_alpha(colors.tick, 0.5),
+            grid: match self {
+                Preset::Hardware => Some(xy_pad::GridStyle {
+                    line_width: 1.0,
+                    line_color: with_alpha(colors.tick, 0.15),
+                }),
+                _ => None,
+            },
+        }
+    }
+
+    fn h_slider(&self, state: State) -> h_slider::Style {
+        let colors = self.colors();
+
+        match self {
+            Preset::Hardware => {
+                h_slider::Style::Classic(h_slider::ClassicStyle {
+                    rail: h_slider::ClassicRail {
+                        rail_colors: (
+                            h_slider::Background::Color(colors.border),
+                            h_slider::Background::Color(with_alpha(
+                                Color::WHITE,
+                                0.15,
+                            )),
+                        ),
+                        rail_widths: (2.0, 1.0),
+                        rail_padding: 12.0,
+                        notches: Some(h_slider::RailNotches::Marker {
+                            color: with_alpha(colors.tick, 0.6),
+                            width: 1.0,
+                            length: 8.0,
+                        }),
+                    },
+                    handle: h_slider::ClassicHandle {
+                        color: self.fader_cap(state),
+                        width: StyleLength::Units(34.0),
+                        notch_width: StyleLength::Units(4.0),
+                        notch_color: self.filled(state),
+                        border_radius: 2.0,
+                        border_color: colors.border,
+                        border_width: 1.0,
+                        shadow: self.handle_shadow(),
+                    },
+                    value_fill: None,
+                })
+            }
+            _ => h_slider::Style::Rect(h_slider::RectStyle {
+                back_color: h_slider::Background::Color(colors.empty),
+                back_border_width: self.rect_border_width(),
+                back_border_radius: self.rect_border_radius(),
+                back_border_color: colors.border,
+                filled_color: h_slider::Background::Color(self.filled(state)),
+                handle_color: h_slider::Background::Color(colors.handle),
+                handle_width: self.rect_handle_size(),
+                handle_filled_gap: 1.0,
+            }),
+        }
+    }
+
+    fn v_slider(&self, state: State) -> v_slider::Style {
+        let colors = self.colors();
+
+        match self {
+            Preset::Hardware => {
+                v_slider::Style::Classic(v_slider::ClassicStyle {
+                    rail: v_slider::ClassicRail {
+                        rail_colors: (
+                            v_slider::Background::Color(colors.border),
+                            v_slider::Background::Color(with_alpha(
+                                Color::WHITE,
+                                0.15,
+                            )),
+                        ),
+                        rail_widths: (2.0, 1.0),
+                        rail_padding: 12.0,
+                        notches: Some(v_slider::RailNotches::Marker {
+                            color: with_alpha(colors.tick, 0.6),
+                            width: 1.0,
+                            length: 8.0,
+                        }),
+                    },
+                    handle: v_slider::ClassicHandle {
+                        color: self.fader_cap(state),
+                        height: StyleLength::Units(34.0),
+                        notch_width: StyleLength::Units(4.0),
+                        notch_color: self.filled(state),
+                        border_radius: 2.0,
+                        border_color: colors.border,
+                        border_width: 1.0,
+                        shadow: self.handle_shadow(),
+                    },
+                    value_fill: None,
+                })
+            }
+            _ => v_slider::Style::Rect(v_slider::RectStyle {
+                back_color: v_slider::Background::Color(colors.empty),
+                back_border_width: self.rect_border_width(),
+                back_border_radius: self.rect_border_radius(),
+                back_border_color: colors.border,
+                filled_color: v_slider::Background::Color(self.filled(state)),
+                handle_color: v_slider::Background::Color(colors.handle),
+                handle_height: self.rect_handle_size(),
+                handle_filled_gap: 1.0,
+            }),
+        }
+    }
+
+    /// The handle of the hardware sliders, shaded like a fader cap
+    fn fader_cap(&self, state: State) -> Background {
+        let handle = self.colors().handle;
+
+        if state == State::Hovered {
+            Background::Color(Color::WHITE)
+        } else {
+            Background::Gradient(Gradient {
+                start: handle,
+                middle: Some((0.5, Color::WHITE)),
+                end: handle,
+            })
+        }
+    }
+
+    /// The drop shadow beneath the handles of the hardware widgets
+    fn handle_shadow(&self) -> Option<Shadow> {
+        match self {
+            Preset::Hardware => Some(Shadow {
+                color: with_alpha(Color::BLACK, 0.6),
+                offset: Offset { x: 0.0, y: 2.0 },
+                spread: 3.0,
+                layers: 4,
+            }),
+            _ => None,
+        }
+    }
+
+    fn pad_back(&self) -> Color {
+        let colors = self.colors();
+
+        match self {
+            Preset::Hardware => colors.empty,
+            _ => colors.back,
+        }
+    }
+
+    fn rect_border_width(&self) -> f32 {
+        match self {
+            Preset::MinimalMono => 1.0,
+            _ => 0.0,
+        }
+    }
+
+    fn rect_border_radius(&self) -> f32 {
+        match self {
+            Preset::MinimalMono => 0.0,
+            _ => 2.0,
+        }
+    }
+
+    fn rect_handle_size(&self) -> StyleLength {
+        match self {
+            Preset::MinimalMono => StyleLength::Units(2.0),
+            _ => StyleLength::Units(4.0),
+        }
+    }
+
+    fn mod_range_input(&self, state: State) -> mod_range_input::Style {
+        let colors = self.colors();
+
+        mod_range_input::Style::Circle(match self {
+            Preset::FlatLight | Preset::FlatDark => {
+                mod_range_input::CircleStyle {
+                    color: self.filled(state),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                }
+            }
+            Preset::Hardware => mod_range_input::CircleStyle {
+                color: if state == State::Hovered {
+                    colors.back_hover
+                } else {
+                    colors.back
+                },
+                border_width: 1.0,
+                border_color: colors.filled,
+            },
+            Preset::MinimalMono => mod_range_input::CircleStyle {
+                color: if state == State::Hovered {
+                    colors.back_hover
+                } else {
+                    colors.back
+                },
+                border_width: 1.0,
+                border_color: colors.border,
+            },
+        })
+    }
+}
+
+impl knob::StyleSheet for Preset {
+    fn active(&self) -> knob::Style {
+        self.knob(State::Active)
+    }
+
+    fn hovered(&self) -> knob::Style {
+        self.knob(State::Hovered)
+    }
+
+    fn dragging(&self) -> knob::Style {
+        self.knob(State::Hovered)
+    }
+
+    fn disabled(&self) -> knob::Style {
+        self.knob(State::Disabled)
+    }
+
+    fn tick_marks_style(&self) -> Option<knob::TickMarksStyle> {
+        let style = match self {
+            Preset::Hardware => {
+                let color = self.colors().tick;
+
+                tick_marks::Style {
+                    tiers: vec![
+                        tick_marks::Shape::Circle {
+                            diameter: 4.0,
+                            color,
+                        },
+                        tick_marks::Shape::Circle {
+                            diameter: 2.0,
+                            color: with_alpha(color, 0.83),
+                        },
+                        tick_marks::Shape::Circle {
+                            diameter: 2.0,
+                            color: with_alpha(color, 0.65),
+                        },
+                    ],
+                    gradient: None,
+                    insets: Insets::ZERO,
+                }
+            }
+            _ => self.tick_lines([4.0, 3.0, 2.0]),
+        };
+
+        Some(knob::TickMarksStyle { style, offset: 3.5 })
+    }
+
+    fn value_arc_style(&self) -> Option<knob::ValueArcStyle> {
+        match self {
+            Preset::Hardware => Some(knob::ValueArcStyle {
+                width: 2.0,
+                offset: 2.0,
+                empty_color: None,
+                left_filled_color: self.colors().filled,
+                right_filled_color: None,
+                cap: knob::LineCap::Butt,
+            }),
+            _ => None,
+        }
+    }
+
+    fn mod_range_arc_style(&self) -> Option<knob::ModRangeArcStyle> {
+        let colors = self.colors();
+
+        Some(knob::ModRangeArcStyle {
+            width: 2.0,
+            offset: 6.0,
+            empty_color: None,
+            filled_color: with_alpha(colors.filled, 0.7),
+            filled_inverse_color: with_alpha(colors.tick, 0.7),
+            cap: knob::LineCap::Butt,
+        })
+    }
+
+    fn text_marks_style(&self) -> Option<knob::TextMarksStyle> {
+        Some(knob::TextMarksStyle {
+            style: self.text_marks(),
+            ..Default::default()
+        })
+    }
+}
+
+impl h_slider::StyleSheet for Preset {
+    fn active(&self) -> h_slider::Style {
+        self.h_slider(State::Active)
+    }
+
+    fn hovered(&self) -> h_slider::Style {
+        self.h_slider(State::Hovered)
+    }
+
+    fn dragging(&self) -> h_slider::Style {
+        self.h_slider(State::Hovered)
+    }
+
+    fn disabled(&self) -> h_slider::Style {
+        self.h_slider(State::Disabled)
+    }
+
+    fn tick_marks_style(&self) -> Option<h_slider::TickMarksStyle> {
+        Some(match self {
+            Preset::Hardware => h_slider::TickMarksStyle {
+                style: self.tick_lines([24.0, 22.0, 18.0]),
+                placement: tick_marks::Placement::Center {
+                    offset: Offset::ZERO,
+                    fill_length: false,
+                },
+            },
+            _ => h_slider::TickMarksStyle {
+                style: self.tick_lines([4.0, 3.0, 2.0]),
+                placement: tick_marks::Placement::BothSides {
+                    offset: Offset::ZERO,
+                    inside: false,
+                },
+            },
+        })
+    }
+
+    fn text_marks_style(&self) -> Option<h_slider::TextMarksStyle> {
+        Some(h_slider::TextMarksStyle {
+            style: self.text_marks(),
+            placement: text_marks::Placement::RightOrBottom {
+                inside: false,
+                offset: Offset { x: 0.0, y: 7.0 },
+            },
+        })
+    }
+
+    fn edge_labels_style(&self) -> h_slider::EdgeLabelsStyle {
+        h_slider::EdgeLabelsStyle {
+            style: self.text_marks(),
+            ..Default::default()
+        }
+    }
+}
+
+impl v_slider::StyleSheet for Preset {
+    fn active(&self) -> v_slider::Style {
+        self.v_slider(State::Active)
+    }
+
+    fn hovered(&self) -> v_slider::Style {
+        self.v_slider(State::Hovered)
+    }
+
+    fn dragging(&self) -> v_slider::Style {
+        self.v_slider(State::Hovered)
+    }
+
+    fn disabled(&self) -> v_slider::Style {
+        self.v_slider(State::Disabled)
+    }
+
+    fn tick_marks_style(&self) -> Option<v_slider::TickMarksStyle> {
+        Some(match self {
+            Preset::Hardware => v_slider::TickMarksStyle {
+                style: self.tick_lines([24.0, 22.0, 18.0]),
+                placement: tick_marks::Placement::Center {
+                    offset: Offset::ZERO,
+                    fill_length: false,
+                },
+            },
+            _ => v_slider::TickMarksStyle {
+                style: self.tick_lines([4.0, 3.0, 2.0]),
+                placement: tick_marks::Placement::BothSides {
+                    offset: Offset::ZERO,
+                    inside: false,
+                },
+            },
+        })
+    }
+
+    fn text_marks_style(&self) -> Option<v_slider::TextMarksStyle> {
+        Some(v_slider::TextMarksStyle {
+            style: self.text_marks(),
+            placement: text_marks::Placement::LeftOrTop {
+                inside: false,
+                offset: Offset { x: -7.0, y: 0.0 },
+            },
+        })
+    }
+
+    fn edge_labels_style(&self) -> v_slider::EdgeLabelsStyle {
+        v_slider::EdgeLabelsStyle {
+            style: self.text_marks(),
+            ..Default::default()
+        }
+    }
+}
+
+impl xy_pad::StyleSheet for Preset {
+    fn active(&self) -> xy_pad::Style {
+        self.xy_pad(State::Active)
+    }
+
+    fn hovered(&self) -> xy_pad::Style {
+        self.xy_pad(State::Hovered)
+    }
+
+    fn dragging(&self) -> xy_pad::Style {
+        self.xy_pad(State::Hovered)
+    }
+
+    fn axis_locked(&self, axis: LockedAxis) -> xy_pad::Style {
+        let dragging = self.xy_pad(State::Hovered);
+        let color = self.colors().filled_hover;
+
+        match axis {
+            LockedAxis::X => xy_pad::Style {
+                h_rail_color: color,
+                ..dragging
+            },
+            LockedAxis::Y => xy_pad::Style {
+                v_rail_color: color,
+                ..dragging
+            },
+        }
+    }
+
+    fn trail_style(&self) -> xy_pad::TrailStyle {
+        xy_pad::TrailStyle {
+            width: 2.0,
+            color: with_alpha(self.colors().filled, 0.8),
+        }
+    }
+
+    fn readouts_style(&self) -> xy_pad::ReadoutsStyle {
+        xy_pad::ReadoutsStyle {
+            style: self.text_marks(),
+            ..Default::default()
+        }
+    }
+
+    fn tick_marks_style_x(&self) -> Option<xy_pad::TickMarksStyle> {
+        Some(xy_pad::TickMarksStyle {
+            style: self.tick_lines([6.0, 4.0, 3.0]),
+            placement: tick_marks::Placement::BothSides {
+                offset: Offset::ZERO,
+                inside: true,
+            },
+        })
+    }
+
+    fn tick_marks_style_y(&self) -> Option<xy_pad::TickMarksStyle> {
+        self.tick_marks_style_x()
+    }
+
+    fn text_marks_style_x(&self) -> Option<xy_pad::TextMarksStyle> {
+        Some(xy_pad::TextMarksStyle {
+            style: self.text_marks(),
+            placement: text_marks::Placement::RightOrBottom {
+                inside: false,
+                offset: Offset { x: 0.0, y: 4.0 },
+            },
+        })
+    }
+
+    fn text_marks_style_y(&self) -> Option<xy_pad::TextMarksStyle> {
+        Some(xy_pad::TextMarksStyle {
+            style: self.text_marks(),
+            placement: text_marks::Placement::LeftOrTop {
+                inside: false,
+                offset: Offset { x: -4.0, y: 0.0 },
+            },
+        })
+    }
+}
+
+impl multi_xy_pad::StyleSheet for Preset {
+    fn active(&self) -> multi_xy_pad::Style {
+        let style = self.xy_pad(State::Active);
+
+        multi_xy_pad::Style {
+            back_color: self.pad_back(),
+            border_width: style.border_width,
+            border_color: style.border_color,
+            center_line_width: style.center_line_width,
+            center_line_color: style.center_line_color,
+        }
+    }
+
+    fn hovered(&self) -> multi_xy_pad::Style {
+        self.active()
+    }
+
+    fn dragging(&self) -> multi_xy_pad::Style {
+        self.active()
+    }
+
+    fn node(&self, index: usize) -> multi_xy_pad::HandleShape {
+        let colors = self.colors();
+
+        multi_xy_pad::HandleShape::Circle(multi_xy_pad::HandleCircle {
+            color: colors.nodes[index % colors.nodes.len()],
+            diameter: 11.0,
+            border_width: 1.0,
+            border_color: colors.border,
+            shadow: self.handle_shadow(),
+        })
+    }
+
+    fn node_dragging(&self, index: usize) -> multi_xy_pad::HandleShape {
+        let colors = self.colors();
+
+        multi_xy_pad::HandleShape::Circle(multi_xy_pad::HandleCircle {
+            color: colors.nodes[index % colors.nodes.len()],
+            diameter: 9.0,
+            border_width: 1.0,
+            border_color: colors.border,
+            shadow: self.handle_shadow(),
+        })
+    }
+}
+
+impl ramp::StyleSheet for Preset {
+    fn active(&self) -> ramp::Style {
+        let colors = self.colors();
+
+        ramp::Style {
+            back_color: colors.back,
+            back_border_width: 1.0,
+            back_border_color: colors.border,
+            line_width: 2.0,
+            line_center_color: colors.handle,
+            line_up_color: colors.filled,
+            line_down_color: colors.filled,
+        }
+    }
+
+    fn hovered(&self) -> ramp::Style {
+        ramp::Style {
+            back_color: self.colors().back_hover,
+            ..self.active()
+        }
+    }
+
+    fn dragging(&self) -> ramp::Style {
+        self.hovered()
+    }
+}
+
+impl mod_range_input::StyleSheet for Preset {
+    fn active(&self) -> mod_range_input::Style {
+        self.mod_range_input(State::Active)
+    }
+
+    fn hovered(&self) -> mod_range_input::Style {
+        self.mod_range_input(State::Hovered)
+    }
+
+    fn dragging(&self) -> mod_range_input::Style {
+        self.mod_range_input(State::Hovered)
+    }
+}
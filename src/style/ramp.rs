@@ -4,26 +4,32 @@
 
 use iced_native::Color;
 
-use crate::style::default_colors;
+use crate::style::theme::{Palette, Theme};
 
 /// The appearance of a [`Ramp`],
 ///
 /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Style {
     /// The color of the background rectangle
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub back_color: Color,
     /// The width of the border of the background rectangle
     pub back_border_width: f32,
     /// The color of the border of the background rectangle
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub back_border_color: Color,
     /// The width of the ramp line,
     pub line_width: f32,
     /// The color of the ramp line when it is in the center (straight) position
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub line_center_color: Color,
     /// The color of the ramp line when it is in the up position
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub line_up_color: Color,
     /// The color of the ramp line when it is in the down position
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub line_down_color: Color,
 }
 
@@ -41,33 +47,45 @@ pub trait StyleSheet {
     /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
     fn hovered(&self) -> Style;
 
+    /// Produces the style of a focused [`Ramp`], which can be controlled
+    /// with the keyboard.
+    ///
+    /// The default is the style of a hovered [`Ramp`].
+    ///
+    /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+    fn focused(&self) -> Style {
+        self.hovered()
+    }
+
     /// Produces the style of a [`Ramp`] that is being dragged.
     ///
     /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
     fn dragging(&self) -> Style;
 }
 
-struct Default;
-impl Default {
-    const ACTIVE_STYLE: Style = Style {
-        back_color: default_colors::LIGHT_BACK,
+fn active_style(palette: &Palette) -> Style {
+    Style {
+        back_color: palette.back,
         back_border_width: 1.0,
-        back_border_color: default_colors::BORDER,
+        back_border_color: palette.border,
         line_width: 2.0,
-        line_center_color: default_colors::BORDER,
-        line_up_color: default_colors::BORDER,
-        line_down_color: default_colors::BORDER,
-    };
+        line_center_color: palette.border,
+        line_up_color: palette.border,
+        line_down_color: palette.border,
+    }
 }
-impl StyleSheet for Default {
+
+impl StyleSheet for Theme {
     fn active(&self) -> Style {
-        Self::ACTIVE_STYLE
+        active_style(&self.palette())
     }
 
     fn hovered(&self) -> Style {
+        let palette = &self.palette();
+
         Style {
-            back_color: default_colors::RAMP_BACK_HOVER,
-            ..Self::ACTIVE_STYLE
+            back_color: palette.ramp_back_hover,
+            ..active_style(palette)
         }
     }
 
@@ -76,9 +94,85 @@ impl StyleSheet for Default {
     }
 }
 
+/// The styles of a [`Ramp`] in each of its states, which make up a
+/// [`StyleSheet`].
+///
+/// With the `serde` feature, they can be written to and loaded from a
+/// [`ThemeFile`]. The styles missing from a theme file are taken from
+/// [`Theme::Light`].
+///
+/// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+/// [`StyleSheet`]: trait.StyleSheet.html
+/// [`ThemeFile`]: ../theme/struct.ThemeFile.html
+/// [`Theme::Light`]: ../theme/enum.Theme.html#variant.Light
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Styles {
+    /// The style of an active [`Ramp`]
+    ///
+    /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+    pub active: Style,
+    /// The style of a hovered [`Ramp`]
+    ///
+    /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+    pub hovered: Style,
+    /// The style of a focused [`Ramp`]
+    ///
+    /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+    pub focused: Style,
+    /// The style of a [`Ramp`] that is being dragged
+    ///
+    /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+    pub dragging: Style,
+}
+
+impl Styles {
+    /// Captures the styles produced by a [`StyleSheet`], such as a
+    /// [`Theme`], so they can be edited or written to a theme file.
+    ///
+    /// [`StyleSheet`]: trait.StyleSheet.html
+    /// [`Theme`]: ../theme/enum.Theme.html
+    pub fn from_style_sheet(style_sheet: &dyn StyleSheet) -> Self {
+        Self {
+            active: style_sheet.active(),
+            hovered: style_sheet.hovered(),
+            focused: style_sheet.focused(),
+            dragging: style_sheet.dragging(),
+        }
+    }
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self::from_style_sheet(&Theme::default())
+    }
+}
+
+impl StyleSheet for Styles {
+    fn active(&self) -> Style {
+        self.active.clone()
+    }
+
+    fn hovered(&self) -> Style {
+        self.hovered.clone()
+    }
+
+    fn focused(&self) -> Style {
+        self.focused.clone()
+    }
+
+    fn dragging(&self) -> Style {
+        self.dragging.clone()
+    }
+}
+
 impl std::default::Default for Box<dyn StyleSheet> {
     fn default() -> Self {
-        Box::new(Default)
+        Box::new(Theme::Light)
     }
 }
 
@@ -0,0 +1,24 @@
+//! A drop shadow beneath a part of a widget
+
+use iced_native::Color;
+
+use crate::core::Offset;
+
+/// A drop shadow beneath a part of a widget, such as the handle of a
+/// slider or of an XY pad.
+///
+/// The blur is approximated by layering translucent rectangles that grow
+/// out from the edge of the shape and follow its border radius.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Shadow {
+    /// The color of the shadow. Its alpha is spread across all of the layers.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub color: Color,
+    /// The offset of the shadow from the shape in pixels
+    pub offset: Offset,
+    /// How far the shadow extends past the edge of the shape in pixels
+    pub spread: f32,
+    /// The number of layers used to approximate the blur
+    pub layers: u16,
+}
@@ -0,0 +1,25 @@
+//! Select a single frame from a sprite sheet texture
+
+use iced_native::Size;
+
+use crate::core::Offset;
+
+/// A frame inside a sprite sheet image.
+///
+/// The whole sprite sheet is drawn so that the frame lines up with the
+/// bounds of the image, and anything outside of the frame is clipped. This
+/// allows the states of a widget to share one image by selecting different
+/// frames.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpriteFrame {
+    /// The size of the whole sprite sheet in pixels
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::geometry_serde::size")
+    )]
+    pub sheet_size: Size,
+    /// The offset of the top-left corner of the frame from the top-left
+    /// corner of the sprite sheet in pixels
+    pub offset: Offset,
+}
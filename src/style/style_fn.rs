@@ -0,0 +1,72 @@
+//! Inline styles defined by a closure
+//!
+//! [`StyleFn`] implements the `StyleSheet` of the [`Knob`], [`HSlider`],
+//! [`VSlider`] and [`XYPad`] widgets by calling a closure with the
+//! [`Status`] of the widget. It is what the `style_fn()` method of these
+//! widgets uses.
+//!
+//! ```
+//! use iced_audio::knob::{self, StyleSheet};
+//! use iced_audio::style::{Status, StyleFn, Theme};
+//!
+//! let style: Box<dyn knob::StyleSheet> = StyleFn::new(|status| match status {
+//!     Status::Hovered | Status::Dragging => Theme::Dark.hovered(),
+//!     _ => Theme::Light.active(),
+//! })
+//! .into();
+//! ```
+//!
+//! [`StyleFn`]: struct.StyleFn.html
+//! [`Status`]: enum.Status.html
+//! [`Knob`]: ../../native/knob/struct.Knob.html
+//! [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+//! [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+//! [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+
+use std::fmt;
+
+/// The status of a widget, which selects its style
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// The widget is idle
+    Active,
+    /// The cursor is over the widget
+    Hovered,
+    /// The widget has the keyboard focus
+    Focused,
+    /// The widget is being dragged
+    Dragging,
+    /// The widget is disabled
+    Disabled,
+}
+
+/// A `StyleSheet` which produces the style of a widget from its [`Status`]
+/// with a closure.
+///
+/// Only the styles of the widget itself come from the closure. The other
+/// styles, such as the styles of the tick marks and of the text marks, are
+/// the ones of the default [`Theme`].
+///
+/// [`Status`]: enum.Status.html
+/// [`Theme`]: ../theme/enum.Theme.html
+pub struct StyleFn<F>(F);
+
+impl<F> StyleFn<F> {
+    /// Creates a new `StyleFn` from the closure `f`.
+    pub fn new(f: F) -> Self {
+        StyleFn(f)
+    }
+
+    pub(crate) fn style<S>(&self, status: Status) -> S
+    where
+        F: Fn(Status) -> S,
+    {
+        (self.0)(status)
+    }
+}
+
+impl<F> fmt::Debug for StyleFn<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("StyleFn")
+    }
+}
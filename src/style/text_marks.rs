@@ -3,11 +3,12 @@
 /// [`TextMarkGroup`]: ../../core/text_marks/struct.TextMarkGroup.html
 use iced_graphics::{Color, Font};
 
-use crate::core::Offset;
+use crate::core::{Insets, Offset};
 use crate::style::default_colors;
 
 /// The alignment of text in text marks.
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Align {
     /// Align to the start of the text.
     Start,
@@ -19,6 +20,7 @@ pub enum Align {
 
 /// The placement of text marks relative to the widget
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Placement {
     /// Text marks on both sides of the widget.
     BothSides {
@@ -66,17 +68,60 @@ impl std::default::Default for Placement {
 ///
 /// [`TextMarkGroup`]: ../../core/text_marks/struct.TextMarkGroup.html
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Style {
     /// The color of the text.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub color: Color,
     /// The size of the text.
     pub text_size: u16,
     /// The font of the text.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::font_serde"))]
     pub font: Font,
     /// The width of the text bounds.
     pub bounds_width: u16,
     /// The height of the text bounds.
     pub bounds_height: u16,
+    /// The orientation of the labels of radial text marks, such as the
+    /// text marks around a knob. This has no effect on linear text marks.
+    pub orientation: Orientation,
+    /// An optional background drawn behind each label, to keep it legible
+    /// over busy textures and meters.
+    pub background: Option<LabelBackground>,
+    /// The insets of the bounds of linear text marks, to pad the scale
+    /// asymmetrically. This has no effect on radial text marks.
+    pub insets: Insets,
+}
+
+/// The background drawn behind the labels of text marks
+///
+/// Radial labels laid out along the arc or along the radius get a
+/// background behind each of their characters.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LabelBackground {
+    /// The color of the background.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub color: Color,
+    /// The space between the text and the edges of the background.
+    pub padding: f32,
+    /// The radius of the corners of the background.
+    pub border_radius: f32,
+}
+
+/// The orientation of the labels of radial text marks
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Orientation {
+    /// The labels are drawn horizontally.
+    #[default]
+    Horizontal,
+    /// The characters of each label are laid out along the arc, so they
+    /// follow the angle of their text mark.
+    Tangential,
+    /// The characters of each label are laid out along the radius, from
+    /// the arc outwards, like the labels printed on hardware faceplates.
+    Radial,
 }
 
 impl std::cmp::PartialEq for Style {
@@ -85,6 +130,9 @@ impl std::cmp::PartialEq for Style {
             && self.text_size == rhs.text_size
             && self.bounds_width == rhs.bounds_width
             && self.bounds_height == rhs.bounds_width
+            && self.orientation == rhs.orientation
+            && self.background == rhs.background
+            && self.insets == rhs.insets
             && match self.font {
                 Font::Default => match rhs.font {
                     Font::Default => true,
@@ -109,6 +157,9 @@ impl std::default::Default for Style {
             font: Default::default(),
             bounds_width: 30,
             bounds_height: 14,
+            orientation: Orientation::Horizontal,
+            background: None,
+            insets: Insets::ZERO,
         }
     }
 }
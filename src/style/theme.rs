@@ -0,0 +1,542 @@
+//! Built-in light and dark themes for all the widgets
+//!
+//! The version of `iced` this crate is built on has no built-in theme, so
+//! this crate provides its own [`Theme`]. It implements the `StyleSheet` of
+//! every widget, so it can be passed directly to the `style()` method of a
+//! widget:
+//!
+//! ```
+//! use iced_audio::{knob, style::Theme};
+//!
+//! let style: Box<dyn knob::StyleSheet> = Theme::Dark.into();
+//! ```
+//!
+//! The default style of every widget is [`Theme::Light`].
+//!
+//! With the `json` or the `ron` feature, a [`Theme::Custom`] can be loaded
+//! from a theme file describing its [`Palette`], so the colors of the
+//! widgets can be edited without recompiling:
+//!
+//! ```
+//! # #[cfg(feature = "json")]
+//! # {
+//! use iced_audio::style::Theme;
+//!
+//! let theme = Theme::from_json(
+//!     r#"{ "back": { "r": 0.1, "g": 0.1, "b": 0.12 } }"#,
+//! )
+//! .unwrap();
+//!
+//! assert_eq!(theme.palette().back.b, 0.12);
+//! # }
+//! ```
+//!
+//! The colors missing from a theme file are taken from [`Palette::LIGHT`].
+//!
+//! A [`ThemeFile`] goes further and describes the full styles of any of the
+//! widgets, on top of a [`Palette`] for the others:
+//!
+//! ```
+//! # #[cfg(feature = "json")]
+//! # {
+//! use iced_audio::{knob, style::ThemeFile};
+//!
+//! let theme_file = ThemeFile::from_json(
+//!     r#"{ "knob": { "angle_range": { "min": 0.5, "max": 5.5 } } }"#,
+//! )
+//! .unwrap();
+//!
+//! let style: Box<dyn knob::StyleSheet> = (&theme_file).into();
+//! assert_eq!(style.angle_range().min(), 0.5);
+//! # }
+//! ```
+//!
+//! [`Theme`]: enum.Theme.html
+//! [`Theme::Light`]: enum.Theme.html#variant.Light
+//! [`Theme::Custom`]: enum.Theme.html#variant.Custom
+//! [`Palette`]: struct.Palette.html
+//! [`Palette::LIGHT`]: struct.Palette.html#associatedconstant.LIGHT
+//! [`ThemeFile`]: struct.ThemeFile.html
+
+use iced_native::Color;
+
+use crate::style::{
+    default_colors, focus_ring, h_slider, knob, mod_range_input, multi_xy_pad,
+    ramp, text_marks, tick_marks, tooltip, v_slider, xy_pad,
+};
+
+/// A built-in theme for the widgets
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Theme {
+    /// Dark widgets meant for a light background
+    #[default]
+    Light,
+    /// Light widgets meant for a dark background
+    Dark,
+    /// Widgets with a custom [`Palette`], such as one loaded from a theme
+    /// file
+    ///
+    /// [`Palette`]: struct.Palette.html
+    Custom(Box<Palette>),
+}
+
+impl Theme {
+    /// Returns the [`Palette`] of the theme.
+    ///
+    /// [`Palette`]: struct.Palette.html
+    pub fn palette(&self) -> Palette {
+        match self {
+            Theme::Light => Palette::LIGHT,
+            Theme::Dark => Palette::DARK,
+            Theme::Custom(palette) => **palette,
+        }
+    }
+
+    /// Loads a [`Theme::Custom`] from the JSON description of its
+    /// [`Palette`].
+    ///
+    /// [`Theme::Custom`]: enum.Theme.html#variant.Custom
+    /// [`Palette`]: struct.Palette.html
+    #[cfg(feature = "json")]
+    pub fn from_json(text: &str) -> Result<Theme, serde_json::Error> {
+        Ok(Theme::Custom(Box::new(serde_json::from_str(text)?)))
+    }
+
+    /// Loads a [`Theme::Custom`] from the RON description of its
+    /// [`Palette`].
+    ///
+    /// [`Theme::Custom`]: enum.Theme.html#variant.Custom
+    /// [`Palette`]: struct.Palette.html
+    #[cfg(feature = "ron")]
+    pub fn from_ron(text: &str) -> Result<Theme, ron::error::SpannedError> {
+        Ok(Theme::Custom(Box::new(ron::from_str(text)?)))
+    }
+}
+
+/// The colors used by a [`Theme`]
+///
+/// [`Theme`]: enum.Theme.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Palette {
+    /// The color of the backgrounds of the widgets
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub back: Color,
+    /// The color of the backgrounds of the widgets when hovered
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub back_hover: Color,
+    /// The color of the backgrounds of the widgets when dragged
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub back_drag: Color,
+    /// The color of the body of a knob when hovered or dragged
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub knob_back_hover: Color,
+    /// The color of the background of a ramp when hovered or dragged
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub ramp_back_hover: Color,
+    /// The color of the borders and notches
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub border: Color,
+    /// The color of the borders and notches of disabled widgets
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub border_disabled: Color,
+    /// The colors of the two lines of the rail of a slider
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::color_serde::pair")
+    )]
+    pub slider_rail: (Color, Color),
+    /// The colors of the tick marks, from tier 1 to tier 3
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::color_serde::array")
+    )]
+    pub tick_tiers: [Color; 3],
+    /// The color of the text marks
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub text_mark: Color,
+    /// The color of the notch showing the modulated value of a knob
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub modulated_notch: Color,
+    /// The color of the rails of an XY pad
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub xy_pad_rail: Color,
+    /// The color of the center lines and of the grid of an XY pad
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub xy_pad_center_line: Color,
+    /// The colors of the nodes of a multi XY pad
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::color_serde::array")
+    )]
+    pub nodes: [Color; 4],
+    /// The color of the background of a tooltip
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub tooltip_back: Color,
+    /// The color of the text of a tooltip
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub tooltip_text: Color,
+    /// The color of the outline of the widgets which have the keyboard
+    /// focus
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub focus_ring: Color,
+}
+
+impl Palette {
+    /// The palette of [`Theme::Light`], which is the default look of the
+    /// widgets
+    ///
+    /// [`Theme::Light`]: enum.Theme.html#variant.Light
+    pub const LIGHT: Palette = Palette {
+        back: default_colors::LIGHT_BACK,
+        back_hover: default_colors::LIGHT_BACK_HOVER,
+        back_drag: default_colors::LIGHT_BACK_DRAG,
+        knob_back_hover: default_colors::KNOB_BACK_HOVER,
+        ramp_back_hover: default_colors::RAMP_BACK_HOVER,
+        border: default_colors::BORDER,
+        border_disabled: default_colors::BORDER_DISABLED,
+        slider_rail: default_colors::SLIDER_RAIL,
+        tick_tiers: [
+            default_colors::TICK_TIER_1,
+            default_colors::TICK_TIER_2,
+            default_colors::TICK_TIER_3,
+        ],
+        text_mark: default_colors::TEXT_MARK,
+        modulated_notch: default_colors::KNOB_MODULATED_NOTCH,
+        xy_pad_rail: default_colors::XY_PAD_RAIL,
+        xy_pad_center_line: default_colors::XY_PAD_CENTER_LINE,
+        nodes: default_colors::MULTI_XY_PAD_NODES,
+        tooltip_back: default_colors::TOOLTIP_BACK,
+        tooltip_text: default_colors::TOOLTIP_TEXT,
+        focus_ring: default_colors::FOCUS_RING,
+    };
+
+    /// The palette of [`Theme::Dark`]
+    ///
+    /// [`Theme::Dark`]: enum.Theme.html#variant.Dark
+    pub const DARK: Palette = Palette {
+        back: default_colors::DARK_BACK,
+        back_hover: default_colors::DARK_BACK_HOVER,
+        back_drag: default_colors::DARK_BACK_DRAG,
+        knob_back_hover: default_colors::DARK_BACK_HOVER,
+        ramp_back_hover: default_colors::DARK_BACK_HOVER,
+        border: default_colors::DARK_BORDER,
+        border_disabled: default_colors::DARK_BORDER_DISABLED,
+        slider_rail: default_colors::DARK_SLIDER_RAIL,
+        tick_tiers: [
+            default_colors::DARK_TICK_TIER_1,
+            default_colors::DARK_TICK_TIER_2,
+            default_colors::DARK_TICK_TIER_3,
+        ],
+        text_mark: default_colors::DARK_TEXT_MARK,
+        modulated_notch: default_colors::DARK_KNOB_MODULATED_NOTCH,
+        xy_pad_rail: default_colors::DARK_XY_PAD_RAIL,
+        xy_pad_center_line: default_colors::DARK_XY_PAD_CENTER_LINE,
+        nodes: default_colors::DARK_MULTI_XY_PAD_NODES,
+        tooltip_back: default_colors::DARK_TOOLTIP_BACK,
+        tooltip_text: default_colors::DARK_TOOLTIP_TEXT,
+        focus_ring: default_colors::DARK_FOCUS_RING,
+    };
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette::LIGHT
+    }
+}
+
+/// A theme file, with the full styles of any of the widgets and the
+/// [`Palette`] of the others
+///
+/// The styles of a widget are made of the style of each of its states,
+/// such as [`knob::Styles`]. A [`ThemeFile`] can be passed directly to the
+/// `style()` method of a widget, which uses its styles if the file has some
+/// and a [`Theme::Custom`] with its [`Palette`] otherwise.
+///
+/// [`Palette`]: struct.Palette.html
+/// [`knob::Styles`]: ../knob/struct.Styles.html
+/// [`ThemeFile`]: struct.ThemeFile.html
+/// [`Theme::Custom`]: enum.Theme.html#variant.Custom
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct ThemeFile {
+    /// The colors of the widgets which have no styles in the file
+    pub palette: Palette,
+    /// The styles of the knobs
+    pub knob: Option<knob::Styles>,
+    /// The styles of the horizontal sliders
+    pub h_slider: Option<h_slider::Styles>,
+    /// The styles of the vertical sliders
+    pub v_slider: Option<v_slider::Styles>,
+    /// The styles of the XY pads
+    pub xy_pad: Option<xy_pad::Styles>,
+    /// The styles of the multi XY pads
+    pub multi_xy_pad: Option<multi_xy_pad::Styles>,
+    /// The styles of the ramps
+    pub ramp: Option<ramp::Styles>,
+    /// The styles of the modulation range inputs
+    pub mod_range_input: Option<mod_range_input::Styles>,
+}
+
+impl ThemeFile {
+    /// Returns the [`Theme`] of the widgets which have no styles in the
+    /// file.
+    ///
+    /// [`Theme`]: enum.Theme.html
+    pub fn theme(&self) -> Theme {
+        Theme::Custom(Box::new(self.palette))
+    }
+
+    /// Loads a [`ThemeFile`] from its JSON description.
+    ///
+    /// [`ThemeFile`]: struct.ThemeFile.html
+    #[cfg(feature = "json")]
+    pub fn from_json(text: &str) -> Result<ThemeFile, serde_json::Error> {
+        serde_json::from_str(text)
+    }
+
+    /// Loads a [`ThemeFile`] from its RON description.
+    ///
+    /// [`ThemeFile`]: struct.ThemeFile.html
+    #[cfg(feature = "ron")]
+    pub fn from_ron(text: &str) -> Result<ThemeFile, ron::error::SpannedError> {
+        ron::from_str(text)
+    }
+}
+
+impl From<&ThemeFile> for Box<dyn knob::StyleSheet> {
+    fn from(theme_file: &ThemeFile) -> Self {
+        match &theme_file.knob {
+            Some(styles) => Box::new(styles.clone()),
+            None => Box::new(theme_file.theme()),
+        }
+    }
+}
+
+impl From<&ThemeFile> for Box<dyn h_slider::StyleSheet> {
+    fn from(theme_file: &ThemeFile) -> Self {
+        match &theme_file.h_slider {
+            Some(styles) => Box::new(styles.clone()),
+            None => Box::new(theme_file.theme()),
+        }
+    }
+}
+
+impl From<&ThemeFile> for Box<dyn v_slider::StyleSheet> {
+    fn from(theme_file: &ThemeFile) -> Self {
+        match &theme_file.v_slider {
+            Some(styles) => Box::new(styles.clone()),
+            None => Box::new(theme_file.theme()),
+        }
+    }
+}
+
+impl From<&ThemeFile> for Box<dyn xy_pad::StyleSheet> {
+    fn from(theme_file: &ThemeFile) -> Self {
+        match &theme_file.xy_pad {
+            Some(styles) => Box::new(styles.clone()),
+            None => Box::new(theme_file.theme()),
+        }
+    }
+}
+
+impl From<&ThemeFile> for Box<dyn multi_xy_pad::StyleSheet> {
+    fn from(theme_file: &ThemeFile) -> Self {
+        match &theme_file.multi_xy_pad {
+            Some(styles) => Box::new(styles.clone()),
+            None => Box::new(theme_file.theme()),
+        }
+    }
+}
+
+impl From<&ThemeFile> for Box<dyn ramp::StyleSheet> {
+    fn from(theme_file: &ThemeFile) -> Self {
+        match &theme_file.ramp {
+            Some(styles) => Box::new(styles.clone()),
+            None => Box::new(theme_file.theme()),
+        }
+    }
+}
+
+impl From<&ThemeFile> for Box<dyn mod_range_input::StyleSheet> {
+    fn from(theme_file: &ThemeFile) -> Self {
+        match &theme_file.mod_range_input {
+            Some(styles) => Box::new(styles.clone()),
+            None => Box::new(theme_file.theme()),
+        }
+    }
+}
+
+impl From<&Theme> for text_marks::Style {
+    fn from(theme: &Theme) -> Self {
+        Self {
+            color: theme.palette().text_mark,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&Theme> for tick_marks::Style {
+    fn from(theme: &Theme) -> Self {
+        let colors = theme.palette().tick_tiers;
+        let style = tick_marks::Style::default();
+
+        let tiers = style
+            .tiers
+            .iter()
+            .zip(colors.iter())
+            .map(|(shape, color)| shape.with_color(*color))
+            .collect();
+
+        Self { tiers, ..style }
+    }
+}
+
+impl From<&Theme> for tooltip::Style {
+    fn from(theme: &Theme) -> Self {
+        let palette = theme.palette();
+
+        Self {
+            background_color: palette.tooltip_back,
+            border_color: palette.border,
+            text_color: palette.tooltip_text,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&Theme> for focus_ring::FocusRing {
+    fn from(theme: &Theme) -> Self {
+        Self {
+            color: theme.palette().focus_ring,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use super::*;
+    use crate::core::Offset;
+    use crate::style::presets::Preset;
+    use crate::style::sprite::SpriteFrame;
+    use iced_native::{image, Font, Rectangle, Size};
+
+    fn preset_theme_file(preset: Preset) -> ThemeFile {
+        ThemeFile {
+            palette: Palette::DARK,
+            knob: Some(knob::Styles::from_style_sheet(&preset)),
+            h_slider: Some(h_slider::Styles::from_style_sheet(&preset)),
+            v_slider: Some(v_slider::Styles::from_style_sheet(&preset)),
+            xy_pad: Some(xy_pad::Styles::from_style_sheet(&preset)),
+            multi_xy_pad: Some(multi_xy_pad::Styles::from_style_sheet(&preset)),
+            ramp: Some(ramp::Styles::from_style_sheet(&preset)),
+            mod_range_input: Some(mod_range_input::Styles::from_style_sheet(
+                &preset,
+            )),
+        }
+    }
+
+    fn assert_json_round_trip(theme_file: &ThemeFile) {
+        let json = serde_json::to_string(theme_file).unwrap();
+        let loaded = ThemeFile::from_json(&json).unwrap();
+
+        assert_eq!(serde_json::to_string(&loaded).unwrap(), json);
+    }
+
+    #[test]
+    fn presets_round_trip() {
+        for preset in [
+            Preset::FlatLight,
+            Preset::FlatDark,
+            Preset::Hardware,
+            Preset::MinimalMono,
+        ] {
+            assert_json_round_trip(&preset_theme_file(preset));
+        }
+    }
+
+    #[test]
+    fn texture_and_font_round_trip() {
+        let mut h_slider = h_slider::Styles::default();
+        h_slider.active = h_slider::Style::Texture(h_slider::TextureStyle {
+            rail: h_slider::ClassicRail {
+                rail_colors: (
+                    h_slider::Background::Color(Palette::DARK.back),
+                    h_slider::Background::Color(Palette::DARK.border),
+                ),
+                rail_widths: (1.0, 2.0),
+                rail_padding: 4.0,
+                notches: None,
+            },
+            image_handle: image::Handle::from_pixels(
+                1,
+                1,
+                vec![0, 64, 128, 255],
+            ),
+            handle_width: 12,
+            image_bounds: Rectangle {
+                x: -6.0,
+                y: -10.0,
+                width: 12.0,
+                height: 20.0,
+            },
+            sprite_frame: Some(SpriteFrame {
+                sheet_size: Size::new(24.0, 20.0),
+                offset: Offset { x: 12.0, y: 0.0 },
+            }),
+        });
+
+        if let Some(text_marks) = h_slider.text_marks.as_mut() {
+            text_marks.style.font = Font::External {
+                name: "Test",
+                bytes: &[1, 2, 3],
+            };
+        }
+
+        let theme_file = ThemeFile {
+            h_slider: Some(h_slider),
+            ..ThemeFile::default()
+        };
+
+        assert_json_round_trip(&theme_file);
+    }
+
+    #[cfg(feature = "ron")]
+    #[test]
+    fn ron_round_trip() {
+        let theme_file = preset_theme_file(Preset::Hardware);
+
+        let text = ron::to_string(&theme_file).unwrap();
+        let loaded = ThemeFile::from_ron(&text).unwrap();
+
+        assert_eq!(ron::to_string(&loaded).unwrap(), text);
+    }
+
+    #[test]
+    fn missing_styles_use_the_palette() {
+        let theme_file = ThemeFile::from_json(
+            r#"{
+                "palette": { "back": { "r": 0.1, "g": 0.1, "b": 0.12 } },
+                "knob": { "angle_range": { "min": 0.5, "max": 5.5 } }
+            }"#,
+        )
+        .unwrap();
+
+        let knob: Box<dyn knob::StyleSheet> = (&theme_file).into();
+        assert_eq!(knob.angle_range().min(), 0.5);
+        assert_eq!(knob.angle_range().max(), 5.5);
+
+        let ramp: Box<dyn ramp::StyleSheet> = (&theme_file).into();
+        assert_eq!(ramp.active().back_color, theme_file.palette.back);
+    }
+}
@@ -1,13 +1,14 @@
 //! Various styles for a [`TickMarkGroup`] in a bar meter widget
 ///
 /// [`TickMarkGroup`]: ../../core/tick_marks/struct.TickMarkGroup.html
-use iced_native::Color;
+use iced_native::{image, Color};
 
-use crate::core::Offset;
+use crate::core::{Insets, Normal, Offset};
 use crate::style::default_colors;
 
 /// The placement of tick marks relative to the widget
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Placement {
     /// Tick marks on both sides of the widget.
     BothSides {
@@ -66,18 +67,79 @@ impl std::default::Default for Placement {
 }
 
 /// The style of a tick mark
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Style {
-    /// The style of a tier 1 tick mark.
-    pub tier_1: Shape,
-    /// The style of a tier 2 tick mark.
-    pub tier_2: Shape,
-    /// The style of a tier 3 tick mark.
-    pub tier_3: Shape,
+    /// The shape of the tick marks of each tier, from the largest (tier 1)
+    /// to the smallest. Tick marks of a tier without a shape are not drawn.
+    pub tiers: Vec<Shape>,
+    /// An optional gradient replacing the color of each tick mark by the
+    /// color of the gradient at its position. Tick marks with a per-mark
+    /// shape override keep the color of their shape.
+    pub gradient: Option<Gradient>,
+    /// The insets of the bounds of linear tick marks, to pad the scale
+    /// asymmetrically. This has no effect on radial tick marks.
+    pub insets: Insets,
 }
 
-/// The shape of a tick mark
+/// A color gradient along the scale of tick marks
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gradient {
+    /// The color of the tick marks at the minimum position (`0.0`).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub start: Color,
+    /// The color of the tick marks at the maximum position (`1.0`).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub end: Color,
+}
+
+impl Gradient {
+    /// Returns the color of the gradient at the `normal` position.
+    pub fn color_at(&self, normal: Normal) -> Color {
+        let t = normal.as_f32();
+        let mix = |start: f32, end: f32| start + ((end - start) * t);
+
+        Color {
+            r: mix(self.start.r, self.end.r),
+            g: mix(self.start.g, self.end.g),
+            b: mix(self.start.b, self.end.b),
+            a: mix(self.start.a, self.end.a),
+        }
+    }
+}
+
+/// The shape used at the ends of a [`Shape::Line`] tick mark, or at the ends
+/// of each of its dashes.
+///
+/// [`Shape::Line`]: enum.Shape.html#variant.Line
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LineCap {
+    /// The line does not extend beyond its two ends.
+    #[default]
+    Butt,
+    /// The line is extended by a square at both ends.
+    Square,
+    /// The line is extended by a semicircle at both ends.
+    Round,
+}
+
+/// The dash pattern of a [`Shape::Line`] tick mark
+///
+/// [`Shape::Line`]: enum.Shape.html#variant.Line
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dash {
+    /// The length of each dash.
+    pub length: f32,
+    /// The length of the gap between two dashes.
+    pub gap: f32,
+}
+
+/// The shape of a tick mark
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Shape {
     /// No shape
     None,
@@ -90,7 +152,17 @@ pub enum Shape {
         width: f32,
 
         /// The color of the tick mark.
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::style::color_serde")
+        )]
         color: Color,
+
+        /// The shape of the ends of the tick mark.
+        line_cap: LineCap,
+
+        /// The dash pattern of the tick mark, or `None` for a solid line.
+        dash: Option<Dash>,
     },
     /// Circle shape
     Circle {
@@ -98,28 +170,171 @@ pub enum Shape {
         diameter: f32,
 
         /// The color of the tick mark.
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::style::color_serde")
+        )]
+        color: Color,
+    },
+    /// Tapered wedge shape, pointing at the center of radial tick marks.
+    ///
+    /// On linear tick marks, this is drawn as a line as wide as the outer
+    /// end of the wedge.
+    Wedge {
+        /// The width of the end of the tick mark closest to the center.
+        inner_width: f32,
+
+        /// The width of the end of the tick mark farthest from the center.
+        outer_width: f32,
+
+        /// The length of the tick mark.
+        length: f32,
+
+        /// The color of the tick mark.
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::style::color_serde")
+        )]
         color: Color,
     },
+    /// Image shape, such as a small icon.
+    ///
+    /// The image is drawn upright, placed the same way as a
+    /// [`Shape::Circle`] with a `diameter` of `size`.
+    ///
+    /// [`Shape::Circle`]: enum.Shape.html#variant.Circle
+    Image {
+        /// The handle of the image.
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::style::image_serde")
+        )]
+        handle: image::Handle,
+
+        /// The width and height of the tick mark.
+        size: f32,
+    },
+}
+
+impl Shape {
+    /// Returns this shape with another color. An image is left unchanged.
+    pub(crate) fn with_color(&self, color: Color) -> Shape {
+        let mut shape = self.clone();
+
+        match &mut shape {
+            Shape::Line { color: c, .. }
+            | Shape::Circle { color: c, .. }
+            | Shape::Wedge { color: c, .. } => *c = color,
+            Shape::None | Shape::Image { .. } => (),
+        }
+
+        shape
+    }
+
+    /// Returns the line cap and the dash pattern used to draw this shape as
+    /// a line. A [`Shape::Wedge`] drawn as a line is solid, with butt ends.
+    ///
+    /// [`Shape::Wedge`]: enum.Shape.html#variant.Wedge
+    pub(crate) fn line_stroke(&self) -> (LineCap, Option<Dash>) {
+        match self {
+            Shape::Line { line_cap, dash, .. } => (*line_cap, *dash),
+            _ => (LineCap::Butt, None),
+        }
+    }
+}
+
+impl PartialEq for Shape {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Shape::None, Shape::None) => true,
+            (
+                Shape::Line {
+                    length,
+                    width,
+                    color,
+                    line_cap,
+                    dash,
+                },
+                Shape::Line {
+                    length: other_length,
+                    width: other_width,
+                    color: other_color,
+                    line_cap: other_line_cap,
+                    dash: other_dash,
+                },
+            ) => {
+                length == other_length
+                    && width == other_width
+                    && color == other_color
+                    && line_cap == other_line_cap
+                    && dash == other_dash
+            }
+            (
+                Shape::Circle { diameter, color },
+                Shape::Circle {
+                    diameter: other_diameter,
+                    color: other_color,
+                },
+            ) => diameter == other_diameter && color == other_color,
+            (
+                Shape::Wedge {
+                    inner_width,
+                    outer_width,
+                    length,
+                    color,
+                },
+                Shape::Wedge {
+                    inner_width: other_inner_width,
+                    outer_width: other_outer_width,
+                    length: other_length,
+                    color: other_color,
+                },
+            ) => {
+                inner_width == other_inner_width
+                    && outer_width == other_outer_width
+                    && length == other_length
+                    && color == other_color
+            }
+            (
+                Shape::Image { handle, size },
+                Shape::Image {
+                    handle: other_handle,
+                    size: other_size,
+                },
+            ) => handle.id() == other_handle.id() && size == other_size,
+            _ => false,
+        }
+    }
 }
 
 impl std::default::Default for Style {
     fn default() -> Self {
         Self {
-            tier_1: Shape::Line {
-                length: 4.0,
-                width: 2.0,
-                color: default_colors::TICK_TIER_1,
-            },
-            tier_2: Shape::Line {
-                length: 3.0,
-                width: 2.0,
-                color: default_colors::TICK_TIER_2,
-            },
-            tier_3: Shape::Line {
-                length: 2.0,
-                width: 1.0,
-                color: default_colors::TICK_TIER_3,
-            },
+            tiers: vec![
+                Shape::Line {
+                    length: 4.0,
+                    width: 2.0,
+                    color: default_colors::TICK_TIER_1,
+                    line_cap: LineCap::Butt,
+                    dash: None,
+                },
+                Shape::Line {
+                    length: 3.0,
+                    width: 2.0,
+                    color: default_colors::TICK_TIER_2,
+                    line_cap: LineCap::Butt,
+                    dash: None,
+                },
+                Shape::Line {
+                    length: 2.0,
+                    width: 1.0,
+                    color: default_colors::TICK_TIER_3,
+                    line_cap: LineCap::Butt,
+                    dash: None,
+                },
+            ],
+            gradient: None,
+            insets: Insets::ZERO,
         }
     }
 }
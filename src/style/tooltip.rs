@@ -0,0 +1,49 @@
+//! Various styles for the value tooltip of a widget
+
+use iced_graphics::{Color, Font};
+
+use crate::style::default_colors;
+
+/// The style of a value tooltip shown while hovering or dragging a widget
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Style {
+    /// The color of the background of the tooltip
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub background_color: Color,
+    /// The width of the border around the tooltip
+    pub border_width: f32,
+    /// The color of the border around the tooltip
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub border_color: Color,
+    /// The radius of the corners of the tooltip
+    pub border_radius: f32,
+    /// The color of the text
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub text_color: Color,
+    /// The size of the text
+    pub text_size: u16,
+    /// The font of the text
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::font_serde"))]
+    pub font: Font,
+    /// The padding between the text and the edge of the tooltip in pixels
+    pub padding: u16,
+    /// The distance between the tooltip and the widget in pixels
+    pub offset: f32,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            background_color: default_colors::TOOLTIP_BACK,
+            border_width: 1.0,
+            border_color: default_colors::BORDER,
+            border_radius: 3.0,
+            text_color: default_colors::TOOLTIP_TEXT,
+            text_size: 12,
+            font: Default::default(),
+            padding: 4,
+            offset: 6.0,
+        }
+    }
+}
@@ -0,0 +1,287 @@
+//! Animated cross-fades between the styles of the states of a widget
+//!
+//! When enabled with the `style_transition()` method of the [`Knob`],
+//! [`HSlider`], [`VSlider`] and [`XYPad`] widgets, the colors and the
+//! widths of the [`Style`] of a widget are interpolated with [`Interpolate`]
+//! from the style of its previous [`Status`] to the style of its new
+//! [`Status`], so the hover and drag highlights don't pop abruptly.
+//!
+//! Values which can't be interpolated, such as the variant of a style enum
+//! or a line cap, switch to the new style at the start of the transition.
+//!
+//! [`Interpolate`]: trait.Interpolate.html
+//! [`Status`]: ../style_fn/enum.Status.html
+//! [`Style`]: ../knob/enum.Style.html
+//! [`Knob`]: ../../native/knob/struct.Knob.html
+//! [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+//! [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+//! [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+
+use std::time::{Duration, Instant};
+
+use iced_native::Color;
+
+use crate::core::{Easing, Offset};
+use crate::style::background::{Background, Gradient};
+use crate::style::length::StyleLength;
+use crate::style::shadow::Shadow;
+use crate::style::style_fn::Status;
+
+/// A value which can be interpolated towards another value of the same
+/// type.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::style::transition::Interpolate;
+/// use iced_native::Color;
+///
+/// let gray = Color::BLACK.interpolate(&Color::WHITE, 0.5);
+/// assert_eq!(gray, Color::from_rgb(0.5, 0.5, 0.5));
+/// ```
+pub trait Interpolate: Sized {
+    /// Returns the value at the progress `t` from `self` to `to`, where
+    /// `0.0` is `self` and `1.0` is `to`.
+    fn interpolate(&self, to: &Self, t: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        self + ((to - self) * t)
+    }
+}
+
+impl Interpolate for u16 {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        f32::from(*self).interpolate(&f32::from(*to), t).round() as u16
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        Color {
+            r: self.r.interpolate(&to.r, t),
+            g: self.g.interpolate(&to.g, t),
+            b: self.b.interpolate(&to.b, t),
+            a: self.a.interpolate(&to.a, t),
+        }
+    }
+}
+
+impl Interpolate for Offset {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        Offset {
+            x: self.x.interpolate(&to.x, t),
+            y: self.y.interpolate(&to.y, t),
+        }
+    }
+}
+
+impl<A: Interpolate, B: Interpolate> Interpolate for (A, B) {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        (self.0.interpolate(&to.0, t), self.1.interpolate(&to.1, t))
+    }
+}
+
+/// A value is only interpolated when it is set in both styles. Otherwise
+/// the new value is used.
+impl<T: Interpolate + Clone> Interpolate for Option<T> {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        match (self, to) {
+            (Some(from), Some(to)) => Some(from.interpolate(to, t)),
+            _ => to.clone(),
+        }
+    }
+}
+
+/// Lengths are only interpolated when they are of the same kind.
+/// Otherwise the new length is used.
+impl Interpolate for StyleLength {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        match (self, to) {
+            (StyleLength::Scaled(from), StyleLength::Scaled(to)) => {
+                StyleLength::Scaled(from.interpolate(to, t))
+            }
+            (StyleLength::Units(from), StyleLength::Units(to)) => {
+                StyleLength::Units(from.interpolate(to, t))
+            }
+            (StyleLength::Percent(from), StyleLength::Percent(to)) => {
+                StyleLength::Percent(from.interpolate(to, t))
+            }
+            (
+                StyleLength::Clamped {
+                    min: from_min,
+                    max: from_max,
+                    scale: from_scale,
+                },
+                StyleLength::Clamped { min, max, scale },
+            ) => StyleLength::Clamped {
+                min: from_min.interpolate(min, t),
+                max: from_max.interpolate(max, t),
+                scale: from_scale.interpolate(scale, t),
+            },
+            (StyleLength::Points(from), StyleLength::Points(to)) => {
+                StyleLength::Points(from.interpolate(to, t))
+            }
+            _ => *to,
+        }
+    }
+}
+
+/// A solid color and a gradient are interpolated by treating the solid
+/// color as a gradient of a single color.
+impl Interpolate for Background {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        match (self, to) {
+            (Background::Color(from), Background::Color(to)) => {
+                Background::Color(from.interpolate(to, t))
+            }
+            (from, to) => Background::Gradient(
+                as_gradient(from).interpolate(&as_gradient(to), t),
+            ),
+        }
+    }
+}
+
+impl Interpolate for Gradient {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        let middle = match (self.middle, to.middle) {
+            (None, None) => None,
+            (from_middle, to_middle) => {
+                let position = to_middle.or(from_middle).map(|m| m.0);
+                let position = position.unwrap_or(0.5);
+
+                let from_middle =
+                    from_middle.unwrap_or((position, self.color_at(position)));
+                let to_middle =
+                    to_middle.unwrap_or((position, to.color_at(position)));
+
+                Some(from_middle.interpolate(&to_middle, t))
+            }
+        };
+
+        Gradient {
+            start: self.start.interpolate(&to.start, t),
+            middle,
+            end: self.end.interpolate(&to.end, t),
+        }
+    }
+}
+
+impl Interpolate for Shadow {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        Shadow {
+            color: self.color.interpolate(&to.color, t),
+            offset: self.offset.interpolate(&to.offset, t),
+            spread: self.spread.interpolate(&to.spread, t),
+            layers: to.layers,
+        }
+    }
+}
+
+fn as_gradient(background: &Background) -> Gradient {
+    match background {
+        Background::Color(color) => Gradient {
+            start: *color,
+            middle: None,
+            end: *color,
+        },
+        Background::Gradient(gradient) => *gradient,
+    }
+}
+
+/// Keeps track of the [`Status`] of a widget and of the cross-fade from its
+/// previous [`Status`].
+///
+/// [`Status`]: ../style_fn/enum.Status.html
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct StatusTransition {
+    duration: Duration,
+    easing: Easing,
+    from: Status,
+    to: Status,
+    start: Option<Instant>,
+}
+
+impl Default for StatusTransition {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_secs(0),
+            easing: Easing::default(),
+            from: Status::Active,
+            to: Status::Active,
+            start: None,
+        }
+    }
+}
+
+impl StatusTransition {
+    /// Sets the duration and the [`Easing`] curve of the cross-fades. A
+    /// `duration` of zero disables them.
+    ///
+    /// [`Easing`]: ../../core/easing/enum.Easing.html
+    pub(crate) fn set(&mut self, duration: Duration, easing: Easing) {
+        self.duration = duration;
+        self.easing = easing;
+    }
+
+    /// Sets the current `status` of the widget and returns the [`Status`]
+    /// to cross-fade from and the eased progress of the cross-fade, if the
+    /// widget is transitioning.
+    ///
+    /// [`Status`]: ../style_fn/enum.Status.html
+    pub(crate) fn update(
+        &mut self,
+        status: Status,
+        now: Instant,
+    ) -> Option<(Status, f32)> {
+        if self.duration == Duration::from_secs(0) {
+            self.to = status;
+            self.start = None;
+            return None;
+        }
+
+        if status != self.to {
+            let elapsed = self.elapsed(now);
+
+            // Going back to the previous status before the end of the
+            // cross-fade reverses it instead of restarting it.
+            self.start = match elapsed {
+                Some(elapsed) if status == self.from => {
+                    now.checked_sub(self.duration - elapsed)
+                }
+                _ => Some(now),
+            };
+
+            self.from = self.to;
+            self.to = status;
+        }
+
+        match self.elapsed(now) {
+            Some(elapsed) => Some((
+                self.from,
+                self.easing
+                    .apply(elapsed.as_secs_f32() / self.duration.as_secs_f32()),
+            )),
+            None => {
+                self.start = None;
+                None
+            }
+        }
+    }
+
+    /// Returns whether the widget is cross-fading between two styles.
+    pub(crate) fn is_animating(&self) -> bool {
+        self.elapsed(Instant::now()).is_some()
+    }
+
+    fn elapsed(&self, now: Instant) -> Option<Duration> {
+        let elapsed = now.saturating_duration_since(self.start?);
+
+        if elapsed < self.duration {
+            Some(elapsed)
+        } else {
+            None
+        }
+    }
+}
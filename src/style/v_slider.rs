@@ -4,13 +4,22 @@
 
 use iced_native::{image, Color, Rectangle};
 
-use crate::core::Offset;
-use crate::style::{default_colors, text_marks, tick_marks};
+use crate::core::{Insets, Offset};
+pub use crate::style::background::{Background, Gradient};
+pub use crate::style::focus_ring::FocusRing;
+pub use crate::style::length::StyleLength;
+pub use crate::style::shadow::Shadow;
+pub use crate::style::sprite::SpriteFrame;
+use crate::style::style_fn::{Status, StyleFn};
+use crate::style::theme::{Palette, Theme};
+use crate::style::transition::Interpolate;
+use crate::style::{text_marks, tick_marks};
 
 /// The appearance of a [`VSlider`].
 ///
 /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Style {
     /// uses an image texture for the handle
     Texture(TextureStyle),
@@ -25,13 +34,46 @@ pub enum Style {
 
 /// A classic line rail style
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClassicRail {
-    /// Colors of the left and right of the rail
-    pub rail_colors: (Color, Color),
+    /// Colors or gradients of the left and right of the rail
+    pub rail_colors: (Background, Background),
     /// Width (thickness) of the left and right of the rail
     pub rail_widths: (f32, f32),
     /// The padding from the rail to the top and bottom edges of the widget
     pub rail_padding: f32,
+    /// Optional notches in the rail at the positions of the tick marks of
+    /// the [`VSlider`]
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    pub notches: Option<RailNotches>,
+}
+
+/// Notches in a [`ClassicRail`] at the positions of the tick marks, giving
+/// the rail the look of a hardware detent groove
+///
+/// [`ClassicRail`]: struct.ClassicRail.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RailNotches {
+    /// A gap in the rail at each tick mark
+    Gap {
+        /// The width of each gap along the rail
+        width: f32,
+    },
+    /// A marker across the rail at each tick mark
+    Marker {
+        /// The color of each marker
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::style::color_serde")
+        )]
+        color: Color,
+        /// The width of each marker along the rail
+        width: f32,
+        /// The length of each marker across the rail
+        length: f32,
+    },
 }
 
 /// A [`Style`] for a [`VSlider`] that uses an image texture for the handle
@@ -40,16 +82,37 @@ pub struct ClassicRail {
 /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
 /// [`Handle`]: https://docs.rs/iced/0.1.1/iced/widget/image/struct.Handle.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextureStyle {
     /// The rail style
     pub rail: ClassicRail,
     /// The [`Handle`] to the image texture
+    ///
+    /// Each state of the [`StyleSheet`] can return its own [`Handle`], so
+    /// the texture can change while the [`VSlider`] is hovered or dragged.
+    ///
+    /// [`Handle`]: https://docs.rs/iced/0.1.1/iced/widget/image/struct.Handle.html
+    /// [`StyleSheet`]: trait.StyleSheet.html
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::image_serde"))]
     pub image_handle: image::Handle,
     /// The effective height of the handle (not including any padding on the texture)
     pub handle_height: u16,
     /// The bounds of the image texture, where the origin is in the
     /// center of the handle.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::geometry_serde::rectangle")
+    )]
     pub image_bounds: Rectangle,
+    /// An optional frame of a sprite sheet to draw instead of the whole
+    /// image texture. The frame is drawn inside the `image_bounds`.
+    ///
+    /// Each state of the [`StyleSheet`] can select a different frame of the
+    /// same sprite sheet.
+    ///
+    /// [`StyleSheet`]: trait.StyleSheet.html
+    pub sprite_frame: Option<SpriteFrame>,
 }
 
 /// A classic [`Style`] for a [`VSlider`], modeled after hardware sliders
@@ -58,11 +121,31 @@ pub struct TextureStyle {
 /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
 /// [`ClassicHandle`]: struct.ClassicHandle.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClassicStyle {
     /// The rail style
     pub rail: ClassicRail,
     /// a `ClassicHandle` defining the style of the handle
     pub handle: ClassicHandle,
+    /// An optional filled bar drawn on top of the rail, from the minimum
+    /// value (or the `fill_from` value of the [`VSlider`]) to the handle
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    pub value_fill: Option<ClassicValueFill>,
+}
+
+/// The appearance of the filled bar of a [`ClassicStyle`]
+///
+/// [`ClassicStyle`]: struct.ClassicStyle.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClassicValueFill {
+    /// Color or gradient of the filled bar
+    pub color: Background,
+    /// Width (thickness) of the filled bar
+    pub width: f32,
+    /// Radius of the ends of the filled bar
+    pub border_radius: f32,
 }
 
 /// The [`ClassicStyle`] appearance of the handle of a [`VSlider`]
@@ -70,21 +153,27 @@ pub struct ClassicStyle {
 /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
 /// [`ClassicStyle`]: struct.ClassicStyle.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClassicHandle {
-    /// background color
-    pub color: Color,
+    /// background color or gradient. A gradient goes from the bottom to the top of
+    /// the handle.
+    pub color: Background,
     /// height of the handle
-    pub height: u16,
+    pub height: StyleLength,
     /// the width (thickness) of the middle notch
-    pub notch_width: f32,
+    pub notch_width: StyleLength,
     /// color of the middle notch
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub notch_color: Color,
     /// radius of the background rectangle
     pub border_radius: f32,
     /// width of the background rectangle
     pub border_width: f32,
     /// color of the background rectangle border
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub border_color: Color,
+    /// An optional drop shadow drawn beneath the handle
+    pub shadow: Option<Shadow>,
 }
 
 /// A modern [`Style`] for a [`VSlider`]. It is composed of a background
@@ -93,21 +182,24 @@ pub struct ClassicHandle {
 /// [`Style`]: enum.Style.html
 /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RectStyle {
-    /// color of the background rectangle
-    pub back_color: Color,
+    /// color or gradient of the background rectangle
+    pub back_color: Background,
     /// width of the background rectangle border
     pub back_border_width: f32,
     /// radius of the background rectangle
     pub back_border_radius: f32,
     /// color of the background rectangle border
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub back_border_color: Color,
-    /// color of a filled portion in the background rectangle
-    pub filled_color: Color,
-    /// color of the handle rectangle
-    pub handle_color: Color,
+    /// color or gradient of a filled portion in the background rectangle
+    pub filled_color: Background,
+    /// color or gradient of the handle rectangle. A gradient goes from
+    /// the bottom to the top of the handle.
+    pub handle_color: Background,
     /// height of the handle rectangle
-    pub handle_height: u16,
+    pub handle_height: StyleLength,
     /// height of the gap between the handle and the filled
     /// portion of the background rectangle
     pub handle_filled_gap: f32,
@@ -120,31 +212,36 @@ pub struct RectStyle {
 /// [`Style`]: enum.Style.html
 /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RectBipolarStyle {
-    /// color of the background rectangle
-    pub back_color: Color,
+    /// color or gradient of the background rectangle
+    pub back_color: Background,
     /// width of the background rectangle border
     pub back_border_width: f32,
     /// radius of the background rectangle
     pub back_border_radius: f32,
     /// color of the background rectangle border
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub back_border_color: Color,
-    /// color of a filled portion in the background
+    /// color or gradient of a filled portion in the background
     /// rectangle on the top side of the center
-    pub top_filled_color: Color,
-    /// color of a filled portion in the background
+    pub top_filled_color: Background,
+    /// color or gradient of a filled portion in the background
     /// rectangle on the bottom side of the center
-    pub bottom_filled_color: Color,
+    pub bottom_filled_color: Background,
     /// color of the handle rectangle when it is on the
     /// top side of the center
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub handle_top_color: Color,
     /// color of the handle rectangle when it is on the
     /// bottom side of the center
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub handle_bottom_color: Color,
     /// color of the handle rectangle when it is in the center
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub handle_center_color: Color,
     /// height of the handle rectangle
-    pub handle_height: u16,
+    pub handle_height: StyleLength,
     /// height of the gap between the handle and the filled
     /// portion of the background rectangle
     pub handle_filled_gap: f32,
@@ -155,6 +252,7 @@ pub struct RectBipolarStyle {
 /// [`ModRangeStyle`]: struct.ModRangeStyle.html
 /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ModRangePlacement {
     /// In the center of the widget
     Center {
@@ -190,6 +288,7 @@ pub enum ModRangePlacement {
 /// [`ModulationRange`]: ../../core/struct.ModulationRange.html
 /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ModRangeStyle {
     /// The placement of the line relative to the widget
     pub placement: ModRangePlacement,
@@ -198,21 +297,32 @@ pub struct ModRangeStyle {
     /// The radius of the background border.
     pub back_border_radius: f32,
     /// The color of the background border.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub back_border_color: Color,
     /// The color of the background.
     /// Set to `None` for no background.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::color_serde::option")
+    )]
     pub back_color: Option<Color>,
     /// The color of a filled portion of the line.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub filled_color: Color,
     /// The color of a filled portion of the line when `end` is less than
     /// `start`.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub filled_inverse_color: Color,
+    /// The insets of the bounds of the widget the mod range is placed
+    /// in, to pad it asymmetrically.
+    pub insets: Insets,
 }
 
 /// Style of tick marks for a [`VSlider`].
 ///
 /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TickMarksStyle {
     /// The style of the tick marks
     pub style: tick_marks::Style,
@@ -224,6 +334,7 @@ pub struct TickMarksStyle {
 ///
 /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextMarksStyle {
     /// The style of the text marks
     pub style: text_marks::Style,
@@ -231,6 +342,187 @@ pub struct TextMarksStyle {
     pub placement: text_marks::Placement,
 }
 
+/// Style of the min and max edge labels of a [`VSlider`].
+///
+/// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EdgeLabelsStyle {
+    /// The style of the text of the labels
+    pub style: text_marks::Style,
+    /// The gap in pixels between the labels and the ends of the [`VSlider`]
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    pub gap: f32,
+}
+
+impl std::default::Default for EdgeLabelsStyle {
+    fn default() -> Self {
+        Self {
+            style: text_marks::Style::default(),
+            gap: 4.0,
+        }
+    }
+}
+
+impl Interpolate for Style {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        match (self, to) {
+            (Style::Texture(from), Style::Texture(to)) => {
+                Style::Texture(TextureStyle {
+                    rail: from.rail.interpolate(&to.rail, t),
+                    ..to.clone()
+                })
+            }
+            (Style::Classic(from), Style::Classic(to)) => {
+                Style::Classic(from.interpolate(to, t))
+            }
+            (Style::Rect(from), Style::Rect(to)) => {
+                Style::Rect(from.interpolate(to, t))
+            }
+            (Style::RectBipolar(from), Style::RectBipolar(to)) => {
+                Style::RectBipolar(from.interpolate(to, t))
+            }
+            _ => to.clone(),
+        }
+    }
+}
+
+impl Interpolate for ClassicRail {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        ClassicRail {
+            rail_colors: self.rail_colors.interpolate(&to.rail_colors, t),
+            rail_widths: self.rail_widths.interpolate(&to.rail_widths, t),
+            rail_padding: self.rail_padding.interpolate(&to.rail_padding, t),
+            notches: self.notches.interpolate(&to.notches, t),
+        }
+    }
+}
+
+impl Interpolate for RailNotches {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        match (self, to) {
+            (
+                RailNotches::Gap { width: from_width },
+                RailNotches::Gap { width },
+            ) => RailNotches::Gap {
+                width: from_width.interpolate(width, t),
+            },
+            (
+                RailNotches::Marker {
+                    color: from_color,
+                    width: from_width,
+                    length: from_length,
+                },
+                RailNotches::Marker {
+                    color,
+                    width,
+                    length,
+                },
+            ) => RailNotches::Marker {
+                color: from_color.interpolate(color, t),
+                width: from_width.interpolate(width, t),
+                length: from_length.interpolate(length, t),
+            },
+            _ => *to,
+        }
+    }
+}
+
+impl Interpolate for ClassicStyle {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        ClassicStyle {
+            rail: self.rail.interpolate(&to.rail, t),
+            handle: self.handle.interpolate(&to.handle, t),
+            value_fill: self.value_fill.interpolate(&to.value_fill, t),
+        }
+    }
+}
+
+impl Interpolate for ClassicValueFill {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        ClassicValueFill {
+            color: self.color.interpolate(&to.color, t),
+            width: self.width.interpolate(&to.width, t),
+            border_radius: self.border_radius.interpolate(&to.border_radius, t),
+        }
+    }
+}
+
+impl Interpolate for ClassicHandle {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        ClassicHandle {
+            color: self.color.interpolate(&to.color, t),
+            height: self.height.interpolate(&to.height, t),
+            notch_width: self.notch_width.interpolate(&to.notch_width, t),
+            notch_color: self.notch_color.interpolate(&to.notch_color, t),
+            border_radius: self.border_radius.interpolate(&to.border_radius, t),
+            border_width: self.border_width.interpolate(&to.border_width, t),
+            border_color: self.border_color.interpolate(&to.border_color, t),
+            shadow: self.shadow.interpolate(&to.shadow, t),
+        }
+    }
+}
+
+impl Interpolate for RectStyle {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        RectStyle {
+            back_color: self.back_color.interpolate(&to.back_color, t),
+            back_border_width: self
+                .back_border_width
+                .interpolate(&to.back_border_width, t),
+            back_border_radius: self
+                .back_border_radius
+                .interpolate(&to.back_border_radius, t),
+            back_border_color: self
+                .back_border_color
+                .interpolate(&to.back_border_color, t),
+            filled_color: self.filled_color.interpolate(&to.filled_color, t),
+            handle_color: self.handle_color.interpolate(&to.handle_color, t),
+            handle_height: self.handle_height.interpolate(&to.handle_height, t),
+            handle_filled_gap: self
+                .handle_filled_gap
+                .interpolate(&to.handle_filled_gap, t),
+        }
+    }
+}
+
+impl Interpolate for RectBipolarStyle {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        RectBipolarStyle {
+            back_color: self.back_color.interpolate(&to.back_color, t),
+            back_border_width: self
+                .back_border_width
+                .interpolate(&to.back_border_width, t),
+            back_border_radius: self
+                .back_border_radius
+                .interpolate(&to.back_border_radius, t),
+            back_border_color: self
+                .back_border_color
+                .interpolate(&to.back_border_color, t),
+            top_filled_color: self
+                .top_filled_color
+                .interpolate(&to.top_filled_color, t),
+            bottom_filled_color: self
+                .bottom_filled_color
+                .interpolate(&to.bottom_filled_color, t),
+            handle_top_color: self
+                .handle_top_color
+                .interpolate(&to.handle_top_color, t),
+            handle_bottom_color: self
+                .handle_bottom_color
+                .interpolate(&to.handle_bottom_color, t),
+            handle_center_color: self
+                .handle_center_color
+                .interpolate(&to.handle_center_color, t),
+            handle_height: self.handle_height.interpolate(&to.handle_height, t),
+            handle_filled_gap: self
+                .handle_filled_gap
+                .interpolate(&to.handle_filled_gap, t),
+        }
+    }
+}
+
 /// A set of rules that dictate the style of a [`VSlider`].
 ///
 /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
@@ -245,11 +537,40 @@ pub trait StyleSheet {
     /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
     fn hovered(&self) -> Style;
 
+    /// Produces the style of a focused [`VSlider`], which can be controlled
+    /// with the keyboard.
+    ///
+    /// The default is the style of a hovered [`VSlider`].
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    fn focused(&self) -> Style {
+        self.hovered()
+    }
+
     /// Produces the style of a [`VSlider`] that is being dragged.
     ///
     /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
     fn dragging(&self) -> Style;
 
+    /// Produces the style of a [`VSlider`] that is hovered or dragged while
+    /// it is in fine adjustment mode.
+    ///
+    /// The default is the style of a [`VSlider`] that is being dragged.
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    fn fine(&self) -> Style {
+        self.dragging()
+    }
+
+    /// Produces the style of a disabled [`VSlider`].
+    ///
+    /// The default is the style of an active [`VSlider`].
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    fn disabled(&self) -> Style {
+        self.active()
+    }
+
     /// The style of tick marks for a [`VSlider`]
     ///
     /// For no tick marks, don't override this or set this to return `None`.
@@ -269,14 +590,17 @@ pub trait StyleSheet {
         None
     }
 
-    /// The style of a second [`ModulationRange`] line for a [`VSlider`]
+    /// The styles of the [`ModulationRange`] lines for an [`VSlider`], one for
+    /// each [`ModulationRange`] in the order they were given. Give each
+    /// style its own placement to stack the lines. A [`ModulationRange`]
+    /// without a matching style is not displayed.
     ///
-    /// For no second modulation range line, don't override this or set this to return `None`.
+    /// The default is the style returned by `mod_range_style()`, if any.
     ///
     /// [`ModulationRange`]: ../../core/struct.ModulationRange.html
     /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
-    fn mod_range_style_2(&self) -> Option<ModRangeStyle> {
-        None
+    fn mod_range_styles(&self) -> Vec<ModRangeStyle> {
+        self.mod_range_style().into_iter().collect()
     }
 
     /// The style of text marks for a [`VSlider`]
@@ -287,70 +611,122 @@ pub trait StyleSheet {
     fn text_marks_style(&self) -> Option<TextMarksStyle> {
         None
     }
+
+    /// The style of the min and max edge labels of a [`VSlider`]
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    fn edge_labels_style(&self) -> EdgeLabelsStyle {
+        EdgeLabelsStyle::default()
+    }
+
+    /// The style of the outline drawn around a [`VSlider`] which has the
+    /// keyboard focus
+    ///
+    /// For no outline, set this to return `None`.
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    fn focus_ring_style(&self) -> Option<FocusRing> {
+        Some(FocusRing::default())
+    }
 }
 
-struct Default;
-impl Default {
-    const ACTIVE_STYLE: ClassicStyle = ClassicStyle {
+fn active_style(palette: &Palette) -> ClassicStyle {
+    ClassicStyle {
         rail: ClassicRail {
-            rail_colors: default_colors::SLIDER_RAIL,
+            rail_colors: (
+                Background::Color(palette.slider_rail.0),
+                Background::Color(palette.slider_rail.1),
+            ),
             rail_widths: (1.0, 1.0),
             rail_padding: 12.0,
+            notches: None,
         },
         handle: ClassicHandle {
-            color: default_colors::LIGHT_BACK,
-            height: 34,
-            notch_width: 4.0,
-            notch_color: default_colors::BORDER,
+            color: Background::Color(palette.back),
+            height: StyleLength::Units(34.0),
+            notch_width: StyleLength::Units(4.0),
+            notch_color: palette.border,
             border_radius: 2.0,
-            border_color: default_colors::BORDER,
+            border_color: palette.border,
             border_width: 1.0,
+            shadow: None,
         },
-    };
+        value_fill: None,
+    }
 }
-impl StyleSheet for Default {
+
+impl StyleSheet for Theme {
     fn active(&self) -> Style {
-        Style::Classic(Self::ACTIVE_STYLE)
+        Style::Classic(active_style(&self.palette()))
     }
 
     fn hovered(&self) -> Style {
+        let palette = &self.palette();
+
         Style::Classic(ClassicStyle {
             handle: ClassicHandle {
-                color: default_colors::LIGHT_BACK_HOVER,
-                ..Self::ACTIVE_STYLE.handle
+                color: Background::Color(palette.back_hover),
+                ..active_style(palette).handle
             },
-            ..Self::ACTIVE_STYLE
+            ..active_style(palette)
         })
     }
 
     fn dragging(&self) -> Style {
+        let palette = &self.palette();
+
         Style::Classic(ClassicStyle {
             handle: ClassicHandle {
-                color: default_colors::LIGHT_BACK_DRAG,
-                ..Self::ACTIVE_STYLE.handle
+                color: Background::Color(palette.back_drag),
+                ..active_style(palette).handle
             },
-            ..Self::ACTIVE_STYLE
+            ..active_style(palette)
+        })
+    }
+
+    fn disabled(&self) -> Style {
+        let palette = &self.palette();
+
+        Style::Classic(ClassicStyle {
+            handle: ClassicHandle {
+                notch_color: palette.border_disabled,
+                border_color: palette.border_disabled,
+                ..active_style(palette).handle
+            },
+            ..active_style(palette)
         })
     }
 
     fn tick_marks_style(&self) -> Option<TickMarksStyle> {
+        let palette = &self.palette();
+
         Some(TickMarksStyle {
             style: tick_marks::Style {
-                tier_1: tick_marks::Shape::Line {
-                    length: 24.0,
-                    width: 2.0,
-                    color: default_colors::TICK_TIER_1,
-                },
-                tier_2: tick_marks::Shape::Line {
-                    length: 22.0,
-                    width: 1.0,
-                    color: default_colors::TICK_TIER_2,
-                },
-                tier_3: tick_marks::Shape::Line {
-                    length: 18.0,
-                    width: 1.0,
-                    color: default_colors::TICK_TIER_3,
-                },
+                tiers: vec![
+                    tick_marks::Shape::Line {
+                        length: 24.0,
+                        width: 2.0,
+                        color: palette.tick_tiers[0],
+                        line_cap: tick_marks::LineCap::Butt,
+                        dash: None,
+                    },
+                    tick_marks::Shape::Line {
+                        length: 22.0,
+                        width: 1.0,
+                        color: palette.tick_tiers[1],
+                        line_cap: tick_marks::LineCap::Butt,
+                        dash: None,
+                    },
+                    tick_marks::Shape::Line {
+                        length: 18.0,
+                        width: 1.0,
+                        color: palette.tick_tiers[2],
+                        line_cap: tick_marks::LineCap::Butt,
+                        dash: None,
+                    },
+                ],
+                gradient: None,
+                insets: Insets::ZERO,
             },
             placement: tick_marks::Placement::Center {
                 offset: Offset::ZERO,
@@ -361,18 +737,202 @@ impl StyleSheet for Default {
 
     fn text_marks_style(&self) -> Option<TextMarksStyle> {
         Some(TextMarksStyle {
-            style: text_marks::Style::default(),
+            style: text_marks::Style::from(self),
             placement: text_marks::Placement::LeftOrTop {
                 inside: false,
                 offset: Offset { x: -7.0, y: 0.0 },
             },
         })
     }
+
+    fn focus_ring_style(&self) -> Option<FocusRing> {
+        Some(FocusRing::from(self))
+    }
+}
+
+impl<F> StyleSheet for StyleFn<F>
+where
+    F: Fn(Status) -> Style,
+{
+    fn active(&self) -> Style {
+        self.style(Status::Active)
+    }
+
+    fn hovered(&self) -> Style {
+        self.style(Status::Hovered)
+    }
+
+    fn focused(&self) -> Style {
+        self.style(Status::Focused)
+    }
+
+    fn dragging(&self) -> Style {
+        self.style(Status::Dragging)
+    }
+
+    fn disabled(&self) -> Style {
+        self.style(Status::Disabled)
+    }
+
+    fn tick_marks_style(&self) -> Option<TickMarksStyle> {
+        Theme::default().tick_marks_style()
+    }
+
+    fn text_marks_style(&self) -> Option<TextMarksStyle> {
+        Theme::default().text_marks_style()
+    }
+
+    fn focus_ring_style(&self) -> Option<FocusRing> {
+        Theme::default().focus_ring_style()
+    }
+}
+
+/// The styles of a [`VSlider`] in each of its states, which make up a
+/// [`StyleSheet`].
+///
+/// With the `serde` feature, they can be written to and loaded from a
+/// [`ThemeFile`]. The styles missing from a theme file are taken from
+/// [`Theme::Light`].
+///
+/// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+/// [`StyleSheet`]: trait.StyleSheet.html
+/// [`ThemeFile`]: ../theme/struct.ThemeFile.html
+/// [`Theme::Light`]: ../theme/enum.Theme.html#variant.Light
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Styles {
+    /// The style of an active [`VSlider`]
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    pub active: Style,
+    /// The style of a hovered [`VSlider`]
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    pub hovered: Style,
+    /// The style of a focused [`VSlider`]
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    pub focused: Style,
+    /// The style of a [`VSlider`] that is being dragged
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    pub dragging: Style,
+    /// The style of a [`VSlider`] moved with fine control
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    pub fine: Style,
+    /// The style of a disabled [`VSlider`]
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    pub disabled: Style,
+    /// The style of the tick marks of a [`VSlider`]
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    pub tick_marks: Option<TickMarksStyle>,
+    /// The styles of the modulation ranges of a [`VSlider`]
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    pub mod_ranges: Vec<ModRangeStyle>,
+    /// The style of the text marks of a [`VSlider`]
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    pub text_marks: Option<TextMarksStyle>,
+    /// The style of the edge labels of a [`VSlider`]
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    pub edge_labels: EdgeLabelsStyle,
+    /// The outline of a [`VSlider`] which has the keyboard focus
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    pub focus_ring: Option<FocusRing>,
+}
+
+impl Styles {
+    /// Captures the styles produced by a [`StyleSheet`], such as a
+    /// [`Theme`], so they can be edited or written to a theme file.
+    ///
+    /// [`StyleSheet`]: trait.StyleSheet.html
+    /// [`Theme`]: ../theme/enum.Theme.html
+    pub fn from_style_sheet(style_sheet: &dyn StyleSheet) -> Self {
+        Self {
+            active: style_sheet.active(),
+            hovered: style_sheet.hovered(),
+            focused: style_sheet.focused(),
+            dragging: style_sheet.dragging(),
+            fine: style_sheet.fine(),
+            disabled: style_sheet.disabled(),
+            tick_marks: style_sheet.tick_marks_style(),
+            mod_ranges: style_sheet.mod_range_styles(),
+            text_marks: style_sheet.text_marks_style(),
+            edge_labels: style_sheet.edge_labels_style(),
+            focus_ring: style_sheet.focus_ring_style(),
+        }
+    }
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self::from_style_sheet(&Theme::default())
+    }
+}
+
+impl StyleSheet for Styles {
+    fn active(&self) -> Style {
+        self.active.clone()
+    }
+
+    fn hovered(&self) -> Style {
+        self.hovered.clone()
+    }
+
+    fn focused(&self) -> Style {
+        self.focused.clone()
+    }
+
+    fn dragging(&self) -> Style {
+        self.dragging.clone()
+    }
+
+    fn fine(&self) -> Style {
+        self.fine.clone()
+    }
+
+    fn disabled(&self) -> Style {
+        self.disabled.clone()
+    }
+
+    fn tick_marks_style(&self) -> Option<TickMarksStyle> {
+        self.tick_marks.clone()
+    }
+
+    fn mod_range_style(&self) -> Option<ModRangeStyle> {
+        self.mod_ranges.first().cloned()
+    }
+
+    fn mod_range_styles(&self) -> Vec<ModRangeStyle> {
+        self.mod_ranges.clone()
+    }
+
+    fn text_marks_style(&self) -> Option<TextMarksStyle> {
+        self.text_marks.clone()
+    }
+
+    fn edge_labels_style(&self) -> EdgeLabelsStyle {
+        self.edge_labels.clone()
+    }
+
+    fn focus_ring_style(&self) -> Option<FocusRing> {
+        self.focus_ring
+    }
 }
 
 impl std::default::Default for Box<dyn StyleSheet> {
     fn default() -> Self {
-        Box::new(Default)
+        Box::new(Theme::Light)
     }
 }
 
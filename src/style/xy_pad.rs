@@ -2,36 +2,77 @@
 //!
 //! [`XYPad`]: ../native/xy_pad/struct.XYPad.html
 
-use iced_native::Color;
+use iced_native::{image, Color};
 
-use crate::style::default_colors;
+use crate::core::{Insets, LockedAxis, Offset};
+pub use crate::style::background::{Background, Gradient};
+pub use crate::style::focus_ring::FocusRing;
+pub use crate::style::shadow::Shadow;
+use crate::style::style_fn::{Status, StyleFn};
+use crate::style::theme::{Palette, Theme};
+use crate::style::transition::Interpolate;
+use crate::style::{default_colors, text_marks, tick_marks};
 
 /// The appearance of an [`XYPad`].
 ///
 /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
 /// [`HandleShape`]: enum.HandleShape.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Style {
     /// the width of the horizontal and vertical rail lines
     pub rail_width: f32,
     /// color of the horizontal rail line
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub h_rail_color: Color,
     /// color of the vertical rail line
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub v_rail_color: Color,
     /// the [`HandleShape`] of the handle
     ///
     /// [`HandleShape`]: enum.HandleShape.html
     pub handle: HandleShape,
-    /// the color of the background square
-    pub back_color: Color,
+    /// the color or gradient of the background square. A gradient goes
+    /// from the bottom to the top of the square.
+    pub back_color: Background,
     /// the width of the border of the background square
     pub border_width: f32,
     /// the color of the border of the background square
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub border_color: Color,
+    /// an optional image drawn over the background square, inside its
+    /// border, such as the heat map of a filter plane. The rest of the
+    /// [`XYPad`] is drawn over it.
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::image_serde::option")
+    )]
+    pub back_image: Option<image::Handle>,
     /// the width of the center line markings
     pub center_line_width: f32,
     /// the color of the center line markings
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub center_line_color: Color,
+    /// the style of the snapping grid, drawn when the [`XYPad`] snaps to a
+    /// grid. Set to `None` to hide the grid.
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    pub grid: Option<GridStyle>,
+}
+
+/// The style of the snapping grid of an [`XYPad`]
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GridStyle {
+    /// the width of the grid lines
+    pub line_width: f32,
+    /// the color of the grid lines
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub line_color: Color,
 }
 
 /// The shape of the handle for the [`Style`] of an [`XYPad`]
@@ -39,6 +80,7 @@ pub struct Style {
 /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
 /// [`Style`]: struct.Style.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HandleShape {
     /// a circular handle
     Circle(HandleCircle),
@@ -51,15 +93,20 @@ pub enum HandleShape {
 /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
 /// [`Style`]: struct.Style.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HandleCircle {
     /// the color of the circle
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub color: Color,
     /// the diameter of the circle
     pub diameter: f32,
     /// the width of the border of the circle
     pub border_width: f32,
     /// the color of the border of the circle
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub border_color: Color,
+    /// an optional drop shadow drawn beneath the circle
+    pub shadow: Option<Shadow>,
 }
 
 /// a square handle style for the [`Style`] of an [`XYPad`]
@@ -67,8 +114,10 @@ pub struct HandleCircle {
 /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
 /// [`Style`]: struct.Style.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HandleSquare {
     /// the color of the square
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub color: Color,
     /// the size of the square
     pub size: u16,
@@ -77,7 +126,161 @@ pub struct HandleSquare {
     /// the radius of the corners of the square
     pub border_radius: f32,
     /// the color of the border of the square
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
     pub border_color: Color,
+    /// an optional drop shadow drawn beneath the square
+    pub shadow: Option<Shadow>,
+}
+
+/// A style for a [`ModulationRange`] band along a rail of an [`XYPad`]
+///
+/// [`ModulationRange`]: ../../core/struct.ModulationRange.html
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModRangeStyle {
+    /// The width (thickness) of the band across the rail
+    pub width: f32,
+    /// The radius of the corners of the band
+    pub border_radius: f32,
+    /// The color of the band.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub filled_color: Color,
+    /// The color of the band when `end` is less than `start`.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub filled_inverse_color: Color,
+}
+
+/// The style of the trail of an [`XYPad`]
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrailStyle {
+    /// the width of the trail line
+    pub width: f32,
+    /// the color of the newest part of the trail, which fades out towards
+    /// its oldest part
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::color_serde"))]
+    pub color: Color,
+}
+
+/// The style of the value readouts in the corners of an [`XYPad`]
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReadoutsStyle {
+    /// The style of the text of the readouts
+    pub style: text_marks::Style,
+    /// The padding in pixels between the readouts and the edges of the
+    /// [`XYPad`]
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    pub padding: f32,
+}
+
+impl std::default::Default for ReadoutsStyle {
+    fn default() -> Self {
+        Self {
+            style: text_marks::Style::default(),
+            padding: 4.0,
+        }
+    }
+}
+
+/// Style of tick marks for an axis of an [`XYPad`].
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TickMarksStyle {
+    /// The style of the tick marks
+    pub style: tick_marks::Style,
+    /// The placement of the tick marks
+    pub placement: tick_marks::Placement,
+}
+
+/// Style of text marks for an axis of an [`XYPad`].
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextMarksStyle {
+    /// The style of the text marks
+    pub style: text_marks::Style,
+    /// The placement of the text marks
+    pub placement: text_marks::Placement,
+}
+
+impl Interpolate for Style {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        Style {
+            rail_width: self.rail_width.interpolate(&to.rail_width, t),
+            h_rail_color: self.h_rail_color.interpolate(&to.h_rail_color, t),
+            v_rail_color: self.v_rail_color.interpolate(&to.v_rail_color, t),
+            handle: self.handle.interpolate(&to.handle, t),
+            back_color: self.back_color.interpolate(&to.back_color, t),
+            border_width: self.border_width.interpolate(&to.border_width, t),
+            border_color: self.border_color.interpolate(&to.border_color, t),
+            back_image: to.back_image.clone(),
+            center_line_width: self
+                .center_line_width
+                .interpolate(&to.center_line_width, t),
+            center_line_color: self
+                .center_line_color
+                .interpolate(&to.center_line_color, t),
+            grid: self.grid.interpolate(&to.grid, t),
+        }
+    }
+}
+
+impl Interpolate for GridStyle {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        GridStyle {
+            line_width: self.line_width.interpolate(&to.line_width, t),
+            line_color: self.line_color.interpolate(&to.line_color, t),
+        }
+    }
+}
+
+impl Interpolate for HandleShape {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        match (self, to) {
+            (HandleShape::Circle(from), HandleShape::Circle(to)) => {
+                HandleShape::Circle(from.interpolate(to, t))
+            }
+            (HandleShape::Square(from), HandleShape::Square(to)) => {
+                HandleShape::Square(from.interpolate(to, t))
+            }
+            _ => to.clone(),
+        }
+    }
+}
+
+impl Interpolate for HandleCircle {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        HandleCircle {
+            color: self.color.interpolate(&to.color, t),
+            diameter: self.diameter.interpolate(&to.diameter, t),
+            border_width: self.border_width.interpolate(&to.border_width, t),
+            border_color: self.border_color.interpolate(&to.border_color, t),
+            shadow: self.shadow.interpolate(&to.shadow, t),
+        }
+    }
+}
+
+impl Interpolate for HandleSquare {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        HandleSquare {
+            color: self.color.interpolate(&to.color, t),
+            size: self.size.interpolate(&to.size, t),
+            border_width: self.border_width.interpolate(&to.border_width, t),
+            border_radius: self.border_radius.interpolate(&to.border_radius, t),
+            border_color: self.border_color.interpolate(&to.border_color, t),
+            shadow: self.shadow.interpolate(&to.shadow, t),
+        }
+    }
 }
 
 /// A set of rules that dictate the style of an [`XYPad`].
@@ -94,62 +297,510 @@ pub trait StyleSheet {
     /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
     fn hovered(&self) -> Style;
 
+    /// Produces the style of a focused [`XYPad`], which can be controlled
+    /// with the keyboard.
+    ///
+    /// The default is the style of a hovered [`XYPad`].
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    fn focused(&self) -> Style {
+        self.hovered()
+    }
+
     /// Produces the style of an [`XYPad`] that is being dragged.
     ///
     /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
     fn dragging(&self) -> Style;
+
+    /// Produces the style of an [`XYPad`] that is being dragged while the
+    /// drag is locked to the given axis, for example to highlight the rail
+    /// the handle moves along.
+    ///
+    /// The default is the `dragging()` style.
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    fn axis_locked(&self, _axis: LockedAxis) -> Style {
+        self.dragging()
+    }
+
+    /// The style of the trail of an [`XYPad`], shown when the [`XYPad`] has a
+    /// trail.
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    fn trail_style(&self) -> TrailStyle {
+        TrailStyle {
+            width: 2.0,
+            color: default_colors::BORDER,
+        }
+    }
+
+    /// The style of the value readouts of an [`XYPad`], shown when the
+    /// [`XYPad`] has readouts.
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    fn readouts_style(&self) -> ReadoutsStyle {
+        ReadoutsStyle::default()
+    }
+
+    /// The style of the [`ModulationRange`] band along the horizontal rail
+    /// of an [`XYPad`].
+    ///
+    /// For no band, don't override this or set this to return `None`.
+    ///
+    /// [`ModulationRange`]: ../../core/struct.ModulationRange.html
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    fn mod_range_style_x(&self) -> Option<ModRangeStyle> {
+        None
+    }
+
+    /// The style of the [`ModulationRange`] band along the vertical rail
+    /// of an [`XYPad`].
+    ///
+    /// For no band, don't override this or set this to return `None`.
+    ///
+    /// [`ModulationRange`]: ../../core/struct.ModulationRange.html
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    fn mod_range_style_y(&self) -> Option<ModRangeStyle> {
+        None
+    }
+
+    /// The style of the tick marks along the x axis of an [`XYPad`]. They
+    /// are drawn along the top and/or bottom edges of the pad.
+    ///
+    /// For no tick marks, don't override this or set this to return `None`.
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    fn tick_marks_style_x(&self) -> Option<TickMarksStyle> {
+        None
+    }
+
+    /// The style of the tick marks along the y axis of an [`XYPad`]. They
+    /// are drawn along the left and/or right edges of the pad.
+    ///
+    /// For no tick marks, don't override this or set this to return `None`.
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    fn tick_marks_style_y(&self) -> Option<TickMarksStyle> {
+        None
+    }
+
+    /// The style of the text marks along the x axis of an [`XYPad`]. They
+    /// are drawn along the top and/or bottom edges of the pad.
+    ///
+    /// For no text marks, don't override this or set this to return `None`.
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    fn text_marks_style_x(&self) -> Option<TextMarksStyle> {
+        None
+    }
+
+    /// The style of the text marks along the y axis of an [`XYPad`]. They
+    /// are drawn along the left and/or right edges of the pad.
+    ///
+    /// For no text marks, don't override this or set this to return `None`.
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    fn text_marks_style_y(&self) -> Option<TextMarksStyle> {
+        None
+    }
+
+    /// The style of the outline drawn around an [`XYPad`] which has the
+    /// keyboard focus
+    ///
+    /// For no outline, set this to return `None`.
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    fn focus_ring_style(&self) -> Option<FocusRing> {
+        Some(FocusRing::default())
+    }
 }
 
-struct Default;
-impl Default {
-    const ACTIVE_HANDLE: HandleCircle = HandleCircle {
-        color: default_colors::LIGHT_BACK,
+fn active_handle(palette: &Palette) -> HandleCircle {
+    HandleCircle {
+        color: palette.back,
         diameter: 11.0,
         border_width: 2.0,
-        border_color: default_colors::BORDER,
-    };
-    const ACTIVE_STYLE: Style = Style {
+        border_color: palette.border,
+        shadow: None,
+    }
+}
+
+fn active_style(palette: &Palette) -> Style {
+    Style {
         rail_width: 2.0,
-        h_rail_color: default_colors::XY_PAD_RAIL,
-        v_rail_color: default_colors::XY_PAD_RAIL,
-        handle: HandleShape::Circle(Self::ACTIVE_HANDLE),
-        back_color: default_colors::LIGHT_BACK,
+        h_rail_color: palette.xy_pad_rail,
+        v_rail_color: palette.xy_pad_rail,
+        handle: HandleShape::Circle(active_handle(palette)),
+        back_color: Background::Color(palette.back),
         border_width: 1.0,
-        border_color: default_colors::BORDER,
+        border_color: palette.border,
+        back_image: None,
         center_line_width: 1.0,
-        center_line_color: default_colors::XY_PAD_CENTER_LINE,
-    };
+        center_line_color: palette.xy_pad_center_line,
+        grid: Some(GridStyle {
+            line_width: 1.0,
+            line_color: palette.xy_pad_center_line,
+        }),
+    }
+}
+
+fn tick_marks_tiers(palette: &Palette) -> [tick_marks::Shape; 3] {
+    [
+        tick_marks::Shape::Line {
+            length: 6.0,
+            width: 2.0,
+            color: palette.tick_tiers[0],
+            line_cap: tick_marks::LineCap::Butt,
+            dash: None,
+        },
+        tick_marks::Shape::Line {
+            length: 4.0,
+            width: 1.0,
+            color: palette.tick_tiers[1],
+            line_cap: tick_marks::LineCap::Butt,
+            dash: None,
+        },
+        tick_marks::Shape::Line {
+            length: 3.0,
+            width: 1.0,
+            color: palette.tick_tiers[2],
+            line_cap: tick_marks::LineCap::Butt,
+            dash: None,
+        },
+    ]
 }
-impl StyleSheet for Default {
+
+impl StyleSheet for Theme {
     fn active(&self) -> Style {
-        Self::ACTIVE_STYLE
+        active_style(&self.palette())
     }
 
     fn hovered(&self) -> Style {
+        let palette = &self.palette();
+
         Style {
             handle: HandleShape::Circle(HandleCircle {
-                color: default_colors::LIGHT_BACK_HOVER,
-                ..Self::ACTIVE_HANDLE
+                color: palette.back_hover,
+                ..active_handle(palette)
             }),
-            ..Self::ACTIVE_STYLE
+            ..active_style(palette)
         }
     }
 
     fn dragging(&self) -> Style {
+        let palette = &self.palette();
+
         Style {
             handle: HandleShape::Circle(HandleCircle {
-                color: default_colors::LIGHT_BACK_DRAG,
+                color: palette.back_drag,
                 diameter: 9.0,
-                ..Self::ACTIVE_HANDLE
+                ..active_handle(palette)
             }),
-            ..Self::ACTIVE_STYLE
+            ..active_style(palette)
+        }
+    }
+
+    fn axis_locked(&self, axis: LockedAxis) -> Style {
+        let dragging = self.dragging();
+        let palette = &self.palette();
+
+        match axis {
+            LockedAxis::X => Style {
+                h_rail_color: palette.border,
+                ..dragging
+            },
+            LockedAxis::Y => Style {
+                v_rail_color: palette.border,
+                ..dragging
+            },
+        }
+    }
+
+    fn tick_marks_style_x(&self) -> Option<TickMarksStyle> {
+        let palette = &self.palette();
+
+        Some(TickMarksStyle {
+            style: tick_marks::Style {
+                tiers: tick_marks_tiers(palette).to_vec(),
+                gradient: None,
+                insets: Insets::ZERO,
+            },
+            placement: tick_marks::Placement::BothSides {
+                offset: Offset::ZERO,
+                inside: true,
+            },
+        })
+    }
+
+    fn tick_marks_style_y(&self) -> Option<TickMarksStyle> {
+        self.tick_marks_style_x()
+    }
+
+    fn text_marks_style_x(&self) -> Option<TextMarksStyle> {
+        Some(TextMarksStyle {
+            style: text_marks::Style::from(self),
+            placement: text_marks::Placement::RightOrBottom {
+                inside: false,
+                offset: Offset { x: 0.0, y: 4.0 },
+            },
+        })
+    }
+
+    fn text_marks_style_y(&self) -> Option<TextMarksStyle> {
+        Some(TextMarksStyle {
+            style: text_marks::Style::from(self),
+            placement: text_marks::Placement::LeftOrTop {
+                inside: false,
+                offset: Offset { x: -4.0, y: 0.0 },
+            },
+        })
+    }
+
+    fn trail_style(&self) -> TrailStyle {
+        TrailStyle {
+            width: 2.0,
+            color: self.palette().border,
+        }
+    }
+
+    fn readouts_style(&self) -> ReadoutsStyle {
+        ReadoutsStyle {
+            style: text_marks::Style::from(self),
+            ..ReadoutsStyle::default()
+        }
+    }
+
+    fn focus_ring_style(&self) -> Option<FocusRing> {
+        Some(FocusRing::from(self))
+    }
+}
+
+impl<F> StyleSheet for StyleFn<F>
+where
+    F: Fn(Status) -> Style,
+{
+    fn active(&self) -> Style {
+        self.style(Status::Active)
+    }
+
+    fn hovered(&self) -> Style {
+        self.style(Status::Hovered)
+    }
+
+    fn focused(&self) -> Style {
+        self.style(Status::Focused)
+    }
+
+    fn dragging(&self) -> Style {
+        self.style(Status::Dragging)
+    }
+
+    fn trail_style(&self) -> TrailStyle {
+        Theme::default().trail_style()
+    }
+
+    fn readouts_style(&self) -> ReadoutsStyle {
+        Theme::default().readouts_style()
+    }
+
+    fn tick_marks_style_x(&self) -> Option<TickMarksStyle> {
+        Theme::default().tick_marks_style_x()
+    }
+
+    fn tick_marks_style_y(&self) -> Option<TickMarksStyle> {
+        Theme::default().tick_marks_style_y()
+    }
+
+    fn text_marks_style_x(&self) -> Option<TextMarksStyle> {
+        Theme::default().text_marks_style_x()
+    }
+
+    fn text_marks_style_y(&self) -> Option<TextMarksStyle> {
+        Theme::default().text_marks_style_y()
+    }
+
+    fn focus_ring_style(&self) -> Option<FocusRing> {
+        Theme::default().focus_ring_style()
+    }
+}
+
+/// The styles of a [`XYPad`] in each of its states, which make up a
+/// [`StyleSheet`].
+///
+/// With the `serde` feature, they can be written to and loaded from a
+/// [`ThemeFile`]. The styles missing from a theme file are taken from
+/// [`Theme::Light`].
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+/// [`StyleSheet`]: trait.StyleSheet.html
+/// [`ThemeFile`]: ../theme/struct.ThemeFile.html
+/// [`Theme::Light`]: ../theme/enum.Theme.html#variant.Light
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Styles {
+    /// The style of an active [`XYPad`]
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    pub active: Style,
+    /// The style of a hovered [`XYPad`]
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    pub hovered: Style,
+    /// The style of a focused [`XYPad`]
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    pub focused: Style,
+    /// The style of a [`XYPad`] that is being dragged
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    pub dragging: Style,
+    /// The style of a [`XYPad`] dragged along the x axis only
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    pub axis_locked_x: Style,
+    /// The style of a [`XYPad`] dragged along the y axis only
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    pub axis_locked_y: Style,
+    /// The style of the trail of the handle of a [`XYPad`]
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    pub trail: TrailStyle,
+    /// The style of the value readouts of a [`XYPad`]
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    pub readouts: ReadoutsStyle,
+    /// The style of the modulation range of the x axis of a [`XYPad`]
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    pub mod_range_x: Option<ModRangeStyle>,
+    /// The style of the modulation range of the y axis of a [`XYPad`]
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    pub mod_range_y: Option<ModRangeStyle>,
+    /// The style of the tick marks of the x axis of a [`XYPad`]
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    pub tick_marks_x: Option<TickMarksStyle>,
+    /// The style of the tick marks of the y axis of a [`XYPad`]
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    pub tick_marks_y: Option<TickMarksStyle>,
+    /// The style of the text marks of the x axis of a [`XYPad`]
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    pub text_marks_x: Option<TextMarksStyle>,
+    /// The style of the text marks of the y axis of a [`XYPad`]
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    pub text_marks_y: Option<TextMarksStyle>,
+    /// The outline of a [`XYPad`] which has the keyboard focus
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    pub focus_ring: Option<FocusRing>,
+}
+
+impl Styles {
+    /// Captures the styles produced by a [`StyleSheet`], such as a
+    /// [`Theme`], so they can be edited or written to a theme file.
+    ///
+    /// [`StyleSheet`]: trait.StyleSheet.html
+    /// [`Theme`]: ../theme/enum.Theme.html
+    pub fn from_style_sheet(style_sheet: &dyn StyleSheet) -> Self {
+        Self {
+            active: style_sheet.active(),
+            hovered: style_sheet.hovered(),
+            focused: style_sheet.focused(),
+            dragging: style_sheet.dragging(),
+            axis_locked_x: style_sheet.axis_locked(LockedAxis::X),
+            axis_locked_y: style_sheet.axis_locked(LockedAxis::Y),
+            trail: style_sheet.trail_style(),
+            readouts: style_sheet.readouts_style(),
+            mod_range_x: style_sheet.mod_range_style_x(),
+            mod_range_y: style_sheet.mod_range_style_y(),
+            tick_marks_x: style_sheet.tick_marks_style_x(),
+            tick_marks_y: style_sheet.tick_marks_style_y(),
+            text_marks_x: style_sheet.text_marks_style_x(),
+            text_marks_y: style_sheet.text_marks_style_y(),
+            focus_ring: style_sheet.focus_ring_style(),
         }
     }
 }
 
+impl Default for Styles {
+    fn default() -> Self {
+        Self::from_style_sheet(&Theme::default())
+    }
+}
+
+impl StyleSheet for Styles {
+    fn active(&self) -> Style {
+        self.active.clone()
+    }
+
+    fn hovered(&self) -> Style {
+        self.hovered.clone()
+    }
+
+    fn focused(&self) -> Style {
+        self.focused.clone()
+    }
+
+    fn dragging(&self) -> Style {
+        self.dragging.clone()
+    }
+
+    fn axis_locked(&self, axis: LockedAxis) -> Style {
+        match axis {
+            LockedAxis::X => self.axis_locked_x.clone(),
+            LockedAxis::Y => self.axis_locked_y.clone(),
+        }
+    }
+
+    fn trail_style(&self) -> TrailStyle {
+        self.trail
+    }
+
+    fn readouts_style(&self) -> ReadoutsStyle {
+        self.readouts.clone()
+    }
+
+    fn mod_range_style_x(&self) -> Option<ModRangeStyle> {
+        self.mod_range_x.clone()
+    }
+
+    fn mod_range_style_y(&self) -> Option<ModRangeStyle> {
+        self.mod_range_y.clone()
+    }
+
+    fn tick_marks_style_x(&self) -> Option<TickMarksStyle> {
+        self.tick_marks_x.clone()
+    }
+
+    fn tick_marks_style_y(&self) -> Option<TickMarksStyle> {
+        self.tick_marks_y.clone()
+    }
+
+    fn text_marks_style_x(&self) -> Option<TextMarksStyle> {
+        self.text_marks_x.clone()
+    }
+
+    fn text_marks_style_y(&self) -> Option<TextMarksStyle> {
+        self.text_marks_y.clone()
+    }
+
+    fn focus_ring_style(&self) -> Option<FocusRing> {
+        self.focus_ring
+    }
+}
+
 impl std::default::Default for Box<dyn StyleSheet> {
     fn default() -> Self {
-        Box::new(Default)
+        Box::new(Theme::Light)
     }
 }
 